@@ -6,7 +6,8 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use voltage_modbus::bytes::{regs_to_f32, regs_to_f64, regs_to_u32, ByteOrder};
 use voltage_modbus::coalescer::{ReadCoalescer, ReadRequest};
-use voltage_modbus::pdu::PduBuilder;
+use voltage_modbus::pdu::{ModbusPdu, PduBuilder};
+use voltage_modbus::transport::AtomicTransportStats;
 
 fn bench_pdu_builder(c: &mut Criterion) {
     let mut g = c.benchmark_group("pdu_builder");
@@ -32,6 +33,51 @@ fn bench_pdu_builder(c: &mut Criterion) {
     g.finish();
 }
 
+fn bench_pdu_construction(c: &mut Criterion) {
+    let mut g = c.benchmark_group("pdu_construction");
+    let request = [0x03, 0x00, 0x64, 0x00, 0x0A];
+
+    g.bench_function("small_from_slice", |b| {
+        b.iter(|| ModbusPdu::from_slice(black_box(&request)).unwrap())
+    });
+
+    // What every PDU cost before the small-buffer path: zero 253 bytes, then copy.
+    g.bench_function("full_buffer_baseline", |b| {
+        b.iter(|| {
+            let input = black_box(&request);
+            let mut data = [0u8; 253];
+            data[..input.len()].copy_from_slice(input);
+            black_box((data, input.len()))
+        })
+    });
+
+    let response = [0u8; 251];
+    g.bench_function("large_from_slice", |b| {
+        b.iter(|| ModbusPdu::from_slice(black_box(&response)).unwrap())
+    });
+
+    g.finish();
+}
+
+fn bench_transport_stats(c: &mut Criterion) {
+    let mut g = c.benchmark_group("transport_stats");
+    let stats = AtomicTransportStats::new();
+
+    g.bench_function("increment", |b| b.iter(|| stats.increment_requests_sent()));
+
+    // Hot-path cost per request: one counter bump plus a byte count.
+    g.bench_function("record_request", |b| {
+        b.iter(|| {
+            stats.increment_requests_sent();
+            stats.add_bytes_sent(black_box(12));
+        })
+    });
+
+    g.bench_function("snapshot", |b| b.iter(|| black_box(stats.snapshot())));
+
+    g.finish();
+}
+
 fn bench_byte_order_decode(c: &mut Criterion) {
     let mut g = c.benchmark_group("byte_order_decode");
     let regs2 = [0x4048u16, 0xF5C3];
@@ -88,6 +134,8 @@ fn bench_coalescer(c: &mut Criterion) {
 criterion_group!(
     benches,
     bench_pdu_builder,
+    bench_pdu_construction,
+    bench_transport_stats,
     bench_byte_order_decode,
     bench_coalescer
 );
@@ -0,0 +1,7 @@
+//! Python extension module for `voltage_modbus`.
+//!
+//! The bindings and the `#[pymodule]` entry point live in
+//! `voltage_modbus::py_bindings`; linking them here is what puts
+//! `PyInit_voltage_modbus` into the shared library maturin installs.
+
+pub use voltage_modbus::py_bindings::*;
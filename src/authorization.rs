@@ -0,0 +1,367 @@
+//! # Write Authorization
+//!
+//! In multi-user HMI and SCADA systems not every operator may change every
+//! setpoint. [`WriteAuthorizationMiddleware`] wraps any [`ModbusClient`] and
+//! asks an authorizer callback before each write; a rejected write fails with
+//! [`ModbusError::Unauthorized`] and is never sent to the device. Reads are
+//! passed through unchanged.
+//!
+//! The authorizer receives the slave ID, the function code and a register or
+//! coil address. Multi-register and multi-coil writes (FC0F, FC10) are checked
+//! for every address they touch, so a write straddling the edge of an allowed
+//! range is rejected as a whole. Until an authorizer is set every write is
+//! allowed.
+//!
+//! ```rust
+//! use voltage_modbus::authorization::{AllowedRanges, WriteAuthorizationMiddleware};
+//! use voltage_modbus::testing::EchoTransport;
+//! use voltage_modbus::{GenericModbusClient, ModbusClient, ModbusError};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> voltage_modbus::ModbusResult<()> {
+//! let client = GenericModbusClient::new(EchoTransport::new());
+//! let mut client = WriteAuthorizationMiddleware::new(client);
+//! // Operators may only change the setpoints at 100..=119 on slave 1.
+//! client.set_authorizer(AllowedRanges::new().allow(1, 100, 119).into_authorizer());
+//!
+//! client.write_06(1, 105, 42).await?;
+//! assert!(matches!(
+//!     client.write_06(1, 0, 42).await,
+//!     Err(ModbusError::Unauthorized { slave_id: 1, address: 0 })
+//! ));
+//! let _ = client.read_03(1, 0, 10).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::capabilities::DeviceIdentification;
+use crate::client::ModbusClient;
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::{ModbusFunction, SlaveId};
+use crate::transport::TransportStats;
+
+/// Authorization callback: `(slave_id, function_code, address) -> allowed`.
+pub type Authorizer = Arc<dyn Fn(SlaveId, u8, u16) -> bool + Send + Sync>;
+
+/// Inclusive `(slave_id, start, end)` address ranges that may be written.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllowedRanges {
+    /// Writable ranges; an address is allowed if any range covers it.
+    pub ranges: Vec<(SlaveId, u16, u16)>,
+}
+
+impl AllowedRanges {
+    /// Create an empty set that allows nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow writes to `start..=end` on `slave_id`.
+    pub fn allow(mut self, slave_id: SlaveId, start: u16, end: u16) -> Self {
+        self.ranges.push((slave_id, start, end));
+        self
+    }
+
+    /// Whether `address` on `slave_id` may be written.
+    pub fn is_allowed(&self, slave_id: SlaveId, address: u16) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(slave, start, end)| slave == slave_id && (start..=end).contains(&address))
+    }
+
+    /// Turn the ranges into a callback for
+    /// [`WriteAuthorizationMiddleware::set_authorizer`].
+    pub fn into_authorizer(self) -> impl Fn(SlaveId, u8, u16) -> bool + Send + Sync + 'static {
+        move |slave_id, _function, address| self.is_allowed(slave_id, address)
+    }
+}
+
+/// Client wrapper that checks every write against an authorizer.
+pub struct WriteAuthorizationMiddleware<C: ModbusClient> {
+    inner: C,
+    authorizer: Option<Authorizer>,
+}
+
+impl<C: ModbusClient> WriteAuthorizationMiddleware<C> {
+    /// Wrap `inner`; all writes are allowed until an authorizer is set.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            authorizer: None,
+        }
+    }
+
+    /// Install the callback deciding whether a write may proceed.
+    pub fn set_authorizer(&mut self, f: impl Fn(SlaveId, u8, u16) -> bool + Send + Sync + 'static) {
+        self.authorizer = Some(Arc::new(f));
+    }
+
+    /// Remove the authorizer, allowing all writes again.
+    pub fn clear_authorizer(&mut self) {
+        self.authorizer = None;
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped client (bypasses authorization).
+    pub fn inner_mut(&mut self) -> &mut C {
+        &mut self.inner
+    }
+
+    /// Unwrap the client.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// Check `count` consecutive addresses starting at `address`.
+    fn authorize(
+        &self,
+        slave_id: SlaveId,
+        function: ModbusFunction,
+        address: u16,
+        count: usize,
+    ) -> ModbusResult<()> {
+        let Some(authorizer) = &self.authorizer else {
+            return Ok(());
+        };
+        let end = (u32::from(address) + count as u32).min(u32::from(u16::MAX) + 1);
+        for addr in u32::from(address)..end {
+            let addr = addr as u16;
+            if !authorizer(slave_id, function.to_u8(), addr) {
+                tracing::warn!(
+                    slave_id,
+                    function = function.to_u8(),
+                    address = addr,
+                    "modbus.write.unauthorized"
+                );
+                return Err(ModbusError::unauthorized(slave_id, addr));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C: ModbusClient> ModbusClient for WriteAuthorizationMiddleware<C> {
+    async fn read_01(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<bool>> {
+        self.inner.read_01(slave_id, address, quantity).await
+    }
+
+    async fn read_02(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<bool>> {
+        self.inner.read_02(slave_id, address, quantity).await
+    }
+
+    async fn read_03(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner.read_03(slave_id, address, quantity).await
+    }
+
+    async fn read_03_with_timeout(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        timeout: Duration,
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner
+            .read_03_with_timeout(slave_id, address, quantity, timeout)
+            .await
+    }
+
+    async fn read_04(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner.read_04(slave_id, address, quantity).await
+    }
+
+    async fn write_05(&mut self, slave_id: SlaveId, address: u16, value: bool) -> ModbusResult<()> {
+        self.authorize(slave_id, ModbusFunction::WriteSingleCoil, address, 1)?;
+        self.inner.write_05(slave_id, address, value).await
+    }
+
+    async fn write_06(&mut self, slave_id: SlaveId, address: u16, value: u16) -> ModbusResult<()> {
+        self.authorize(slave_id, ModbusFunction::WriteSingleRegister, address, 1)?;
+        self.inner.write_06(slave_id, address, value).await
+    }
+
+    async fn write_0f(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &[bool],
+    ) -> ModbusResult<()> {
+        self.authorize(
+            slave_id,
+            ModbusFunction::WriteMultipleCoils,
+            address,
+            values.len(),
+        )?;
+        self.inner.write_0f(slave_id, address, values).await
+    }
+
+    async fn write_10(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &[u16],
+    ) -> ModbusResult<()> {
+        self.authorize(
+            slave_id,
+            ModbusFunction::WriteMultipleRegisters,
+            address,
+            values.len(),
+        )?;
+        self.inner.write_10(slave_id, address, values).await
+    }
+
+    async fn read_exception_status(&mut self, slave_id: SlaveId) -> ModbusResult<u8> {
+        self.inner.read_exception_status(slave_id).await
+    }
+
+    async fn diagnostic_echo(&mut self, slave_id: SlaveId, data: u16) -> ModbusResult<u16> {
+        self.inner.diagnostic_echo(slave_id, data).await
+    }
+
+    async fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> ModbusResult<DeviceIdentification> {
+        self.inner
+            .read_device_id(slave_id, read_device_id_code, object_id)
+            .await
+    }
+
+    async fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> ModbusResult<()> {
+        self.authorize(slave_id, ModbusFunction::MaskWriteRegister, address, 1)?;
+        self.inner
+            .mask_write_register(slave_id, address, and_mask, or_mask)
+            .await
+    }
+
+    async fn read_write_registers(
+        &mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &[u16],
+    ) -> ModbusResult<Vec<u16>> {
+        self.authorize(
+            slave_id,
+            ModbusFunction::ReadWriteMultipleRegisters,
+            write_address,
+            write_values.len(),
+        )?;
+        self.inner
+            .read_write_registers(
+                slave_id,
+                read_address,
+                read_quantity,
+                write_address,
+                write_values,
+            )
+            .await
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.inner.close().await
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.inner.get_stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GenericModbusClient;
+    use crate::testing::EchoTransport;
+
+    fn guarded() -> WriteAuthorizationMiddleware<GenericModbusClient<EchoTransport>> {
+        let mut client =
+            WriteAuthorizationMiddleware::new(GenericModbusClient::new(EchoTransport::new()));
+        client.set_authorizer(
+            AllowedRanges::new()
+                .allow(1, 100, 109)
+                .allow(2, 0, 0)
+                .into_authorizer(),
+        );
+        client
+    }
+
+    #[tokio::test]
+    async fn allowed_writes_proceed() {
+        let mut client = guarded();
+        client.write_06(1, 100, 1).await.unwrap();
+        client.write_10(1, 105, &[1, 2, 3, 4, 5]).await.unwrap();
+        client.write_05(2, 0, true).await.unwrap();
+        assert_eq!(client.get_stats().requests_sent, 3);
+    }
+
+    #[tokio::test]
+    async fn forbidden_writes_are_not_sent() {
+        let mut client = guarded();
+        assert_eq!(
+            client.write_06(1, 99, 1).await,
+            Err(ModbusError::unauthorized(1, 99))
+        );
+        // Straddles the end of 100..=109: rejected at the first forbidden address.
+        assert_eq!(
+            client.write_10(1, 108, &[1, 2, 3]).await,
+            Err(ModbusError::unauthorized(1, 110))
+        );
+        assert_eq!(
+            client.write_0f(3, 0, &[true]).await,
+            Err(ModbusError::unauthorized(3, 0))
+        );
+        assert_eq!(client.get_stats().requests_sent, 0);
+
+        client.clear_authorizer();
+        client.write_06(1, 99, 1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reads_are_never_blocked() {
+        let mut client = guarded();
+        client.set_authorizer(|_, _, _| false);
+        assert_eq!(client.read_03(9, 0, 3).await.unwrap(), vec![0, 1, 2]);
+        assert_eq!(client.read_04(9, 0, 2).await.unwrap(), vec![0, 1]);
+        assert_eq!(client.read_01(9, 0, 4).await.unwrap().len(), 4);
+        assert_eq!(client.get_stats().requests_sent, 3);
+    }
+}
@@ -29,6 +29,9 @@
 //!     register_address: 100,
 //!     data_type: "uint16",
 //!     byte_order: ByteOrder::BigEndian,
+//!     scaling: None,
+//!     ttl: None,
+//!     priority: 0,
 //! });
 //!
 //! // Check if batch should execute
@@ -37,12 +40,48 @@
 //!     // Process commands...
 //! }
 //! ```
+//!
+//! ## Executing a Batch
+//!
+//! [`CommandBatcher::flush`] writes every pending command through one
+//! client, merging register writes to consecutive addresses into single
+//! FC16 requests, and reports a [`BatchResult`] per command.
+//!
+//! Commands carry a `priority` (0 first). Every execution path writes the
+//! commands of a group in priority order, and groups holding more urgent
+//! commands run first, so setpoint writes can overtake diagnostics queued
+//! before them. [`CommandBatcher::take_commands_sorted`] hands out the same
+//! order as a flat list.
+//!
+//! [`execute_batch_sequential`] writes every pending command through one
+//! client, group by group. [`execute_batch_parallel`] takes one client per
+//! slave and writes to all slaves at once. Both return one result per
+//! command, keyed by `point_id`.
+//!
+//! ```rust,no_run
+//! use voltage_modbus::batcher::execute_batch_sequential;
+//! use voltage_modbus::{CommandBatcher, DeviceLimits, ModbusClient};
+//!
+//! # async fn example(batcher: &mut CommandBatcher, client: &mut impl ModbusClient) {
+//! for (point_id, result) in execute_batch_sequential(batcher, client, &DeviceLimits::new()).await {
+//!     if let Err(e) = result {
+//!         eprintln!("point {} failed: {}", point_id, e);
+//!     }
+//! }
+//! # }
+//! ```
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::time::{Duration, Instant};
 
+use tokio::task::JoinSet;
+
 use crate::bytes::ByteOrder;
-use crate::codec::registers_for_type;
+use crate::client::ModbusClient;
+use crate::codec::{encode_f64_as_type, encode_value, is_float_type, registers_for_type};
+use crate::constants::MAX_WRITE_REGISTERS;
+use crate::device_limits::DeviceLimits;
+use crate::error::{ModbusError, ModbusResult};
 use crate::value::ModbusValue;
 
 /// Default batch window in milliseconds.
@@ -69,6 +108,53 @@ pub struct BatchCommand {
     pub data_type: &'static str,
     /// Byte order for multi-register types.
     pub byte_order: ByteOrder,
+    /// Optional `(gain, offset)`. When set, `value` is in engineering units
+    /// and is converted to `raw = (value - offset) / gain` in `data_type`
+    /// before writing.
+    pub scaling: Option<(f64, f64)>,
+    /// Drop the command instead of writing it once it has been pending this
+    /// long; `None` keeps it until taken.
+    pub ttl: Option<Duration>,
+    /// Execution priority, 0 = highest. Commands of equal priority keep the
+    /// order they were added in.
+    pub priority: u8,
+}
+
+impl BatchCommand {
+    /// Registers to write for this command, applying `scaling` if set.
+    ///
+    /// Scaled values are rounded to the nearest count for integer types.
+    pub fn encode_registers(&self) -> ModbusResult<Vec<u16>> {
+        let Some((gain, offset)) = self.scaling else {
+            return encode_value(&self.value, self.byte_order);
+        };
+        let raw = (self.value.as_f64() - offset) / gain;
+        let raw = if is_float_type(self.data_type) {
+            raw
+        } else {
+            raw.round()
+        };
+        encode_f64_as_type(raw, self.data_type, self.byte_order)
+    }
+}
+
+/// A queued command with the time it was added.
+#[derive(Debug)]
+struct PendingCommand {
+    command: BatchCommand,
+    enqueued_at: Instant,
+    /// Insertion order across all groups
+    sequence: u64,
+    /// Priority the command is written at, set when it is taken
+    effective_priority: u8,
+}
+
+impl PendingCommand {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.command
+            .ttl
+            .is_some_and(|ttl| now.saturating_duration_since(self.enqueued_at) > ttl)
+    }
 }
 
 /// Command batcher for optimizing Modbus write communications.
@@ -78,11 +164,15 @@ pub struct BatchCommand {
 #[derive(Debug)]
 pub struct CommandBatcher {
     /// Pending commands grouped by (slave_id, function_code).
-    pending_commands: HashMap<(u8, u8), Vec<BatchCommand>>,
+    pending_commands: HashMap<(u8, u8), Vec<PendingCommand>>,
     /// Last batch execution time.
     last_batch_time: Instant,
     /// Total pending commands count.
     total_pending: usize,
+    /// Commands dropped as expired by the last `take_commands`.
+    expired_count: usize,
+    /// Sequence number of the next added command.
+    next_sequence: u64,
     /// Batch window duration.
     batch_window: Duration,
     /// Maximum batch size.
@@ -96,6 +186,8 @@ impl CommandBatcher {
             pending_commands: HashMap::new(),
             last_batch_time: Instant::now(),
             total_pending: 0,
+            expired_count: 0,
+            next_sequence: 0,
             batch_window: Duration::from_millis(DEFAULT_BATCH_WINDOW_MS),
             max_batch_size: DEFAULT_MAX_BATCH_SIZE,
         }
@@ -111,6 +203,8 @@ impl CommandBatcher {
             pending_commands: HashMap::new(),
             last_batch_time: Instant::now(),
             total_pending: 0,
+            expired_count: 0,
+            next_sequence: 0,
             batch_window: Duration::from_millis(batch_window_ms),
             max_batch_size,
         }
@@ -140,17 +234,121 @@ impl CommandBatcher {
 
     /// Take all pending commands and reset the batcher.
     ///
-    /// Returns commands grouped by (slave_id, function_code).
+    /// Returns commands grouped by (slave_id, function_code), each group in
+    /// `priority` order. A command moves up to the priority of a later, more
+    /// urgent command to the same register, so writes to one register keep
+    /// the order they were added. Commands whose `ttl` has run out are
+    /// dropped and counted in [`expired_commands`](Self::expired_commands).
     pub fn take_commands(&mut self) -> HashMap<(u8, u8), Vec<BatchCommand>> {
-        self.last_batch_time = Instant::now();
+        self.take_live()
+            .into_iter()
+            .map(|(key, pending)| (key, pending.into_iter().map(|p| p.command).collect()))
+            .collect()
+    }
+
+    /// Take all pending commands as one list, ordered by `priority` and then
+    /// by the order they were added.
+    ///
+    /// Priorities and expired commands are handled as in
+    /// [`take_commands`](Self::take_commands).
+    pub fn take_commands_sorted(&mut self) -> Vec<BatchCommand> {
+        let mut pending: Vec<PendingCommand> = self.take_live().into_values().flatten().collect();
+        pending.sort_unstable_by_key(|p| (p.effective_priority, p.sequence));
+        pending.into_iter().map(|p| p.command).collect()
+    }
+
+    /// Drain the live commands, each group sorted by priority, and reset the
+    /// batcher
+    fn take_live(&mut self) -> HashMap<(u8, u8), Vec<PendingCommand>> {
+        let now = Instant::now();
+        self.last_batch_time = now;
         self.total_pending = 0;
-        std::mem::take(&mut self.pending_commands)
+        self.expired_count = 0;
+
+        let mut taken = HashMap::with_capacity(self.pending_commands.len());
+        for (key, mut pending) in std::mem::take(&mut self.pending_commands) {
+            let before = pending.len();
+            pending.retain(|entry| !entry.is_expired(now));
+            self.expired_count += before - pending.len();
+            if !pending.is_empty() {
+                let priorities = effective_priorities(pending.iter().map(|p| &p.command));
+                for (entry, priority) in pending.iter_mut().zip(priorities) {
+                    entry.effective_priority = priority;
+                }
+                // Groups are in insertion order, so a stable sort keeps it per priority
+                pending.sort_by_key(|p| p.effective_priority);
+                taken.insert(key, pending);
+            }
+        }
+        taken
+    }
+
+    /// Number of commands the last [`take_commands`](Self::take_commands)
+    /// (or [`flush`](Self::flush)) dropped because their `ttl` had run out.
+    #[inline]
+    pub fn expired_commands(&self) -> usize {
+        self.expired_count
     }
 
-    /// Add a command to the pending batch.
+    /// Add a command to the pending batch, recording when it was added for
+    /// its `ttl`.
     pub fn add_command(&mut self, command: BatchCommand) {
         let key = (command.slave_id, command.function_code);
-        self.pending_commands.entry(key).or_default().push(command);
+        self.pending_commands
+            .entry(key)
+            .or_default()
+            .push(PendingCommand {
+                command,
+                enqueued_at: Instant::now(),
+                sequence: self.next_sequence,
+                effective_priority: 0,
+            });
+        self.next_sequence += 1;
+        self.total_pending += 1;
+    }
+
+    /// Add a command, replacing any pending command for the same
+    /// `(slave_id, register_address)`.
+    ///
+    /// Only the latest value of a register is written, at the back of its
+    /// group. The replacement keeps the enqueue time of the command it
+    /// replaces, so a register rewritten faster than its `ttl` still
+    /// expires. Use [`add_command`](Self::add_command) when every write
+    /// must reach the device.
+    pub fn add_command_dedup(&mut self, command: BatchCommand) {
+        let mut enqueued_at: Option<Instant> = None;
+        let mut replaced = 0;
+        for ((slave_id, _), pending) in self.pending_commands.iter_mut() {
+            if *slave_id != command.slave_id {
+                continue;
+            }
+            pending.retain(|entry| {
+                if entry.command.register_address != command.register_address {
+                    return true;
+                }
+                enqueued_at =
+                    Some(enqueued_at.map_or(entry.enqueued_at, |t| t.min(entry.enqueued_at)));
+                replaced += 1;
+                false
+            });
+        }
+        if replaced > 0 {
+            self.pending_commands
+                .retain(|_, pending| !pending.is_empty());
+            self.total_pending -= replaced;
+        }
+
+        let key = (command.slave_id, command.function_code);
+        self.pending_commands
+            .entry(key)
+            .or_default()
+            .push(PendingCommand {
+                command,
+                enqueued_at: enqueued_at.unwrap_or_else(Instant::now),
+                sequence: self.next_sequence,
+                effective_priority: 0,
+            });
+        self.next_sequence += 1;
         self.total_pending += 1;
     }
 
@@ -204,6 +402,336 @@ impl Default for CommandBatcher {
     }
 }
 
+// ============================================================================
+// Execution
+// ============================================================================
+
+/// Per-command results of an executed batch: `(point_id, result)`.
+pub type BatchResults = Vec<(u32, ModbusResult<()>)>;
+
+/// Outcome of one command written by [`CommandBatcher::flush`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchResult {
+    /// `point_id` of the command.
+    pub point_id: u32,
+    /// Whether the write succeeded.
+    pub result: ModbusResult<()>,
+}
+
+impl BatchResult {
+    /// `true` if the write succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+impl CommandBatcher {
+    /// Take all pending commands and write them through `client`, merging
+    /// register writes to consecutive addresses.
+    ///
+    /// Within each `(slave_id, function_code)` group of FC06/FC16 commands,
+    /// runs of [strictly consecutive](Self::are_strictly_consecutive)
+    /// registers are encoded with [`BatchCommand::encode_registers`] and
+    /// written by one `write_10` of at most 123 registers. Every other
+    /// command is written on its own with its function code, so lone FC06
+    /// commands stay `write_06` calls. Groups run in the order of their
+    /// most urgent `priority`, then `(slave_id, function_code)`; within a
+    /// group, runs go out in priority order and commands to the same address
+    /// in the order they were added, at the priority of the most urgent one
+    /// (see [`take_commands`](Self::take_commands)).
+    ///
+    /// A failed write fails every command it carried and does not stop the
+    /// batch. Expired commands are dropped without a result, as in
+    /// [`take_commands`](Self::take_commands).
+    ///
+    /// # Errors
+    ///
+    /// Fails without taking any commands if `client` is not connected.
+    pub async fn flush(
+        &mut self,
+        client: &mut impl ModbusClient,
+    ) -> ModbusResult<Vec<BatchResult>> {
+        if !client.is_connected() {
+            return Err(ModbusError::connection(
+                "cannot flush batch: client is not connected",
+            ));
+        }
+        let mut results = Vec::with_capacity(self.total_pending);
+        for ((_, function_code), commands) in sorted_groups(self.take_commands()) {
+            if !matches!(function_code, 0x06 | 0x10) {
+                for command in &commands {
+                    let result = execute_command(client, command).await;
+                    results.push(BatchResult {
+                        point_id: command.point_id,
+                        result,
+                    });
+                }
+                continue;
+            }
+            let priorities = effective_priorities(commands.iter());
+            let mut ordered: Vec<(u8, BatchCommand)> =
+                priorities.into_iter().zip(commands).collect();
+            ordered.sort_by_key(|(priority, c)| (*priority, c.register_address));
+            let commands: Vec<BatchCommand> = ordered.into_iter().map(|(_, c)| c).collect();
+            for run in consecutive_runs(&commands) {
+                flush_run(client, run, &mut results).await;
+            }
+        }
+        Ok(results)
+    }
+}
+
+/// Take all pending commands and write them through `client`, one at a time.
+///
+/// Groups run in the order of their most urgent `priority`, then
+/// `(slave_id, function_code)`, with `limits.inter_request_delay_ms` between
+/// groups; commands within a group run by priority, then in the order they
+/// were added. A failed command does not stop the batch. Results are in
+/// execution order.
+pub async fn execute_batch_sequential(
+    batcher: &mut CommandBatcher,
+    client: &mut impl ModbusClient,
+    limits: &DeviceLimits,
+) -> BatchResults {
+    let groups = sorted_groups(batcher.take_commands())
+        .into_iter()
+        .map(|(_, commands)| commands)
+        .collect();
+    execute_groups(client, groups, inter_group_delay(limits)).await
+}
+
+/// Take all pending commands and write to every slave concurrently.
+///
+/// Each slave with pending commands gets one task on a [`JoinSet`], which
+/// borrows that slave's client from `clients` and puts it back when done.
+/// Within a slave, commands run as in [`execute_batch_sequential`].
+/// Commands for a slave without a client fail with a configuration error.
+/// Results are grouped by slave, in the order the slaves finish.
+pub async fn execute_batch_parallel<C>(
+    batcher: &mut CommandBatcher,
+    clients: &mut HashMap<u8, C>,
+    limits: &DeviceLimits,
+) -> BatchResults
+where
+    C: ModbusClient + 'static,
+{
+    let mut by_slave: BTreeMap<u8, Vec<Vec<BatchCommand>>> = BTreeMap::new();
+    for ((slave_id, _), commands) in sorted_groups(batcher.take_commands()) {
+        by_slave.entry(slave_id).or_default().push(commands);
+    }
+
+    let delay = inter_group_delay(limits);
+    let mut results = Vec::new();
+    let mut tasks = JoinSet::new();
+    let mut in_flight = HashMap::new();
+
+    for (slave_id, groups) in by_slave {
+        let point_ids: Vec<u32> = groups.iter().flatten().map(|c| c.point_id).collect();
+        let Some(mut client) = clients.remove(&slave_id) else {
+            results.extend(point_ids.into_iter().map(|point_id| {
+                let error = ModbusError::configuration(format!("no client for slave {}", slave_id));
+                (point_id, Err(error))
+            }));
+            continue;
+        };
+        let handle = tasks.spawn(async move {
+            let results = execute_groups(&mut client, groups, delay).await;
+            (slave_id, client, results)
+        });
+        in_flight.insert(handle.id(), (slave_id, point_ids));
+    }
+
+    while let Some(joined) = tasks.join_next_with_id().await {
+        match joined {
+            Ok((id, (slave_id, client, slave_results))) => {
+                in_flight.remove(&id);
+                clients.insert(slave_id, client);
+                results.extend(slave_results);
+            }
+            Err(e) => {
+                // The client went down with the task
+                if let Some((slave_id, point_ids)) = in_flight.remove(&e.id()) {
+                    results.extend(point_ids.into_iter().map(|point_id| {
+                        let error = ModbusError::internal(format!(
+                            "batch task for slave {} failed: {}",
+                            slave_id, e
+                        ));
+                        (point_id, Err(error))
+                    }));
+                }
+            }
+        }
+    }
+
+    results
+}
+
+/// Split address-sorted `commands` into runs of consecutive registers that
+/// fit one FC16 request.
+fn consecutive_runs(commands: &[BatchCommand]) -> Vec<&[BatchCommand]> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    while start < commands.len() {
+        let mut end = start + 1;
+        let mut registers = u32::from(CommandBatcher::get_register_count(
+            commands[start].data_type,
+        ));
+        while let Some(next) = commands.get(end) {
+            let prev = &commands[end - 1];
+            let prev_count = CommandBatcher::get_register_count(prev.data_type);
+            let count = u32::from(CommandBatcher::get_register_count(next.data_type));
+            let adjacent = prev_count > 0
+                && u32::from(next.register_address)
+                    == u32::from(prev.register_address) + u32::from(prev_count);
+            if !adjacent || registers + count > MAX_WRITE_REGISTERS as u32 {
+                break;
+            }
+            registers += count;
+            end += 1;
+        }
+        runs.push(&commands[start..end]);
+        start = end;
+    }
+    runs
+}
+
+/// Write `run` with one FC16 request if it is strictly consecutive and
+/// encodes to its registers, otherwise command by command.
+async fn flush_run<C: ModbusClient>(
+    client: &mut C,
+    run: &[BatchCommand],
+    results: &mut Vec<BatchResult>,
+) {
+    let expected: usize = run
+        .iter()
+        .map(|c| usize::from(CommandBatcher::get_register_count(c.data_type)))
+        .sum();
+    let encoded: Option<Vec<u16>> = CommandBatcher::are_strictly_consecutive(run)
+        .then(|| {
+            run.iter()
+                .map(|c| c.encode_registers().ok())
+                .collect::<Option<Vec<_>>>()
+        })
+        .flatten()
+        .map(|parts| parts.concat())
+        .filter(|registers| registers.len() == expected);
+
+    let Some(registers) = encoded else {
+        for command in run {
+            let result = execute_command(client, command).await;
+            results.push(BatchResult {
+                point_id: command.point_id,
+                result,
+            });
+        }
+        return;
+    };
+    let first = &run[0];
+    let result = client
+        .write_10(first.slave_id, first.register_address, &registers)
+        .await;
+    results.extend(run.iter().map(|command| BatchResult {
+        point_id: command.point_id,
+        result: result.clone(),
+    }));
+}
+
+/// Priority each of `commands` (in insertion order) is written at: its own,
+/// or that of a later, more urgent command to the same register
+///
+/// Sorting stably by it never writes an older value after a newer one.
+fn effective_priorities<'a>(
+    commands: impl DoubleEndedIterator<Item = &'a BatchCommand>,
+) -> Vec<u8> {
+    let mut most_urgent: HashMap<u16, u8> = HashMap::new();
+    let mut priorities: Vec<u8> = commands
+        .rev()
+        .map(|c| {
+            let priority = most_urgent
+                .entry(c.register_address)
+                .and_modify(|p| *p = (*p).min(c.priority))
+                .or_insert(c.priority);
+            *priority
+        })
+        .collect();
+    priorities.reverse();
+    priorities
+}
+
+/// Batch groups ordered by their most urgent command, then by
+/// `(slave_id, function_code)`
+fn sorted_groups(
+    commands: HashMap<(u8, u8), Vec<BatchCommand>>,
+) -> Vec<((u8, u8), Vec<BatchCommand>)> {
+    let mut groups: Vec<_> = commands.into_iter().collect();
+    groups.sort_unstable_by_key(|(key, commands)| {
+        let priority = commands.iter().map(|c| c.priority).min();
+        (priority, *key)
+    });
+    groups
+}
+
+fn inter_group_delay(limits: &DeviceLimits) -> Duration {
+    Duration::from_millis(limits.inter_request_delay_ms)
+}
+
+/// Write `groups` in order, pausing `delay` between groups
+async fn execute_groups<C: ModbusClient>(
+    client: &mut C,
+    groups: Vec<Vec<BatchCommand>>,
+    delay: Duration,
+) -> BatchResults {
+    let mut results = Vec::with_capacity(groups.iter().map(Vec::len).sum());
+    for (i, commands) in groups.into_iter().enumerate() {
+        if i > 0 && !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        for command in commands {
+            let result = execute_command(client, &command).await;
+            results.push((command.point_id, result));
+        }
+    }
+    results
+}
+
+/// Write one command with its own function code
+///
+/// FC06 writes multi-register values one register at a time, since the
+/// device was configured for single-register writes.
+async fn execute_command<C: ModbusClient>(
+    client: &mut C,
+    command: &BatchCommand,
+) -> ModbusResult<()> {
+    let slave_id = command.slave_id;
+    let address = command.register_address;
+    match command.function_code {
+        0x05 => {
+            client
+                .write_05(slave_id, address, !command.value.is_zero())
+                .await
+        }
+        0x0F => {
+            client
+                .write_0f(slave_id, address, &[!command.value.is_zero()])
+                .await
+        }
+        0x06 => {
+            let registers = command.encode_registers()?;
+            for (offset, register) in registers.into_iter().enumerate() {
+                client
+                    .write_06(slave_id, address.wrapping_add(offset as u16), register)
+                    .await?;
+            }
+            Ok(())
+        }
+        0x10 => {
+            let registers = command.encode_registers()?;
+            client.write_10(slave_id, address, &registers).await
+        }
+        code => Err(ModbusError::invalid_function(code)),
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -227,6 +755,9 @@ mod tests {
             register_address,
             data_type,
             byte_order: ByteOrder::BigEndian,
+            scaling: None,
+            ttl: None,
+            priority: 0,
         }
     }
 
@@ -419,6 +950,315 @@ mod tests {
         assert!(batcher.is_empty());
     }
 
+    fn write_command(point_id: u32, slave_id: u8, function_code: u8, address: u16) -> BatchCommand {
+        BatchCommand {
+            point_id,
+            value: ModbusValue::U16(point_id as u16),
+            slave_id,
+            function_code,
+            register_address: address,
+            data_type: "uint16",
+            byte_order: ByteOrder::BigEndian,
+            scaling: None,
+            ttl: None,
+            priority: 0,
+        }
+    }
+
+    #[test]
+    fn test_scaling_converts_engineering_value_before_encoding() {
+        let mut command = write_command(1, 1, 6, 0);
+        command.value = ModbusValue::F64(25.05);
+        command.data_type = "int16";
+        command.scaling = Some((0.1, -273.15));
+        assert_eq!(command.encode_registers().unwrap(), [2982]);
+
+        command.scaling = None;
+        command.value = ModbusValue::I16(-5);
+        assert_eq!(command.encode_registers().unwrap(), [0xFFFB]);
+    }
+
+    #[tokio::test]
+    async fn test_execute_sequential_runs_groups_in_order() {
+        use crate::client::GenericModbusClient;
+        use crate::protocol::{ModbusFunction, ModbusResponse};
+        use crate::testing::ScriptedTransport;
+
+        let mut batcher = CommandBatcher::new();
+        batcher.add_command(write_command(1, 2, 6, 20));
+        batcher.add_command(write_command(2, 1, 16, 10));
+        batcher.add_command(write_command(3, 1, 6, 30));
+        batcher.add_command(write_command(4, 1, 6, 31));
+        batcher.add_command(write_command(5, 1, 3, 0));
+
+        let echo = |slave, function, address: u16, value: u16| {
+            let mut data = address.to_be_bytes().to_vec();
+            data.extend_from_slice(&value.to_be_bytes());
+            Ok(ModbusResponse::new_success(slave, function, data))
+        };
+        let transport = ScriptedTransport::new(vec![
+            echo(1, ModbusFunction::WriteSingleRegister, 30, 3),
+            Err(ModbusError::timeout("write", 100)),
+            echo(1, ModbusFunction::WriteMultipleRegisters, 10, 1),
+            echo(2, ModbusFunction::WriteSingleRegister, 20, 1),
+        ]);
+        let mut client = GenericModbusClient::new(transport);
+        let limits = DeviceLimits::new().with_inter_request_delay_ms(0);
+
+        let results = execute_batch_sequential(&mut batcher, &mut client, &limits).await;
+        assert!(batcher.is_empty());
+
+        let order: Vec<u32> = results.iter().map(|(id, _)| *id).collect();
+        assert_eq!(order, [5, 3, 4, 2, 1]);
+        assert!(matches!(
+            results[0].1,
+            Err(ModbusError::InvalidFunction { code: 3 })
+        ));
+        assert!(results[1].1.is_ok());
+        assert!(matches!(results[2].1, Err(ModbusError::Timeout { .. })));
+        assert!(results[3].1.is_ok() && results[4].1.is_ok());
+
+        let addresses: Vec<(u8, u16)> = client
+            .transport()
+            .requests()
+            .iter()
+            .map(|r| (r.slave_id, r.address))
+            .collect();
+        assert_eq!(addresses, [(1, 30), (1, 31), (1, 10), (2, 20)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execute_parallel_writes_slaves_concurrently() {
+        use crate::client::GenericModbusClient;
+        use crate::testing::{EchoTransport, LatencyTransport};
+
+        let mut batcher = CommandBatcher::new();
+        for slave_id in 1..=3u8 {
+            let base = u32::from(slave_id) * 10;
+            batcher.add_command(write_command(base, slave_id, 6, 0));
+            batcher.add_command(write_command(base + 1, slave_id, 16, 100));
+        }
+        batcher.add_command(write_command(99, 9, 6, 0));
+
+        let mut clients: HashMap<u8, _> = (1..=3u8)
+            .map(|slave_id| {
+                let transport = LatencyTransport::new(
+                    EchoTransport::new(),
+                    Duration::from_millis(100),
+                    Duration::ZERO,
+                );
+                (slave_id, GenericModbusClient::new(transport))
+            })
+            .collect();
+        let limits = DeviceLimits::new().with_inter_request_delay_ms(10);
+
+        let start = tokio::time::Instant::now();
+        let results = execute_batch_parallel(&mut batcher, &mut clients, &limits).await;
+        // Two writes plus one inter-group delay per slave, all slaves at once
+        assert_eq!(start.elapsed(), Duration::from_millis(210));
+
+        assert_eq!(clients.len(), 3);
+        assert_eq!(results.len(), 7);
+        let mut ok: Vec<u32> = results
+            .iter()
+            .filter(|(_, r)| r.is_ok())
+            .map(|(id, _)| *id)
+            .collect();
+        ok.sort_unstable();
+        assert_eq!(ok, [10, 11, 20, 21, 30, 31]);
+        assert!(results
+            .iter()
+            .any(|(id, r)| *id == 99 && matches!(r, Err(ModbusError::Configuration { .. }))));
+    }
+
+    #[tokio::test]
+    async fn test_flush_merges_consecutive_registers() {
+        use crate::client::GenericModbusClient;
+        use crate::testing::EchoTransport;
+
+        let mut batcher = CommandBatcher::new();
+        batcher.add_command(write_command(3, 1, 6, 13));
+        batcher.add_command(write_command(1, 1, 6, 10));
+        batcher.add_command(write_command(7, 1, 6, 40));
+        let mut float = write_command(2, 1, 6, 11);
+        float.value = ModbusValue::F32(1.5);
+        float.data_type = "float32";
+        batcher.add_command(float);
+        batcher.add_command(write_command(4, 1, 6, 20));
+        batcher.add_command(write_command(5, 1, 5, 0));
+
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+        let results = batcher.flush(&mut client).await.unwrap();
+        assert!(batcher.is_empty());
+        assert!(results.iter().all(BatchResult::is_ok));
+        let order: Vec<u32> = results.iter().map(|r| r.point_id).collect();
+        assert_eq!(order, [5, 1, 2, 3, 4, 7]);
+        // Coil, then 10..=13 in one FC16, then 20 and 40 alone
+        assert_eq!(client.get_stats().requests_sent, 4);
+    }
+
+    #[tokio::test]
+    async fn test_flush_reports_failures_per_point() {
+        use crate::client::GenericModbusClient;
+        use crate::protocol::ModbusFunction;
+        use crate::testing::ScriptedTransport;
+
+        let mut batcher = CommandBatcher::new();
+        for (point_id, address) in [(1, 0), (2, 1), (3, 5)] {
+            batcher.add_command(write_command(point_id, 1, 6, address));
+        }
+        let transport = ScriptedTransport::new(vec![
+            Err(ModbusError::timeout("write", 100)),
+            Ok(crate::protocol::ModbusResponse::new_success(
+                1,
+                ModbusFunction::WriteSingleRegister,
+                vec![0, 5, 0, 3],
+            )),
+        ]);
+        let mut client = GenericModbusClient::new(transport);
+        let results = batcher.flush(&mut client).await.unwrap();
+
+        let requests = client.transport().requests();
+        assert_eq!(requests[0].function, ModbusFunction::WriteMultipleRegisters);
+        assert_eq!((requests[0].address, requests[0].quantity), (0, 2));
+        assert_eq!(requests[1].function, ModbusFunction::WriteSingleRegister);
+        assert!(matches!(
+            results[0].result,
+            Err(ModbusError::Timeout { .. })
+        ));
+        assert_eq!(results[0].result, results[1].result);
+        assert!(results[2].is_ok());
+
+        client.close().await.unwrap();
+        batcher.add_command(write_command(4, 1, 6, 0));
+        assert!(batcher.flush(&mut client).await.is_err());
+        assert_eq!(batcher.pending_count(), 1);
+    }
+
+    #[test]
+    fn test_take_commands_sorted_orders_by_priority_then_insertion() {
+        let mut batcher = CommandBatcher::new();
+        for (point_id, slave_id, priority) in
+            [(1, 1, 5), (2, 2, 0), (3, 1, 5), (4, 1, 0), (5, 3, 9)]
+        {
+            let mut command = write_command(point_id, slave_id, 6, point_id as u16);
+            command.priority = priority;
+            batcher.add_command(command);
+        }
+
+        let order: Vec<u32> = batcher
+            .take_commands_sorted()
+            .iter()
+            .map(|c| c.point_id)
+            .collect();
+        assert_eq!(order, [2, 4, 1, 3, 5]);
+        assert!(batcher.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_flush_writes_urgent_groups_and_runs_first() {
+        use crate::client::GenericModbusClient;
+        use crate::testing::EchoTransport;
+
+        let mut batcher = CommandBatcher::new();
+        let mut diagnostic = write_command(1, 1, 6, 0);
+        diagnostic.priority = 9;
+        batcher.add_command(diagnostic);
+        let mut setpoint = write_command(2, 2, 6, 50);
+        setpoint.priority = 0;
+        batcher.add_command(setpoint);
+        let mut later = write_command(3, 1, 6, 20);
+        later.priority = 1;
+        batcher.add_command(later);
+
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+        let results = batcher.flush(&mut client).await.unwrap();
+        let order: Vec<u32> = results.iter().map(|r| r.point_id).collect();
+        assert_eq!(order, [2, 3, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_priority_never_reorders_writes_to_one_register() {
+        use crate::client::GenericModbusClient;
+        use crate::testing::EchoTransport;
+
+        // An urgent write to register 10 queued after a routine one
+        let queue = |batcher: &mut CommandBatcher| {
+            for (point_id, address, priority) in [(1, 10, 9), (2, 30, 5), (3, 10, 0)] {
+                let mut command = write_command(point_id, 1, 6, address);
+                command.priority = priority;
+                batcher.add_command(command);
+            }
+        };
+        let mut batcher = CommandBatcher::new();
+        queue(&mut batcher);
+
+        let order: Vec<u32> = batcher.take_commands()[&(1, 6)]
+            .iter()
+            .map(|c| c.point_id)
+            .collect();
+        assert_eq!(order, [1, 3, 2]);
+
+        queue(&mut batcher);
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+        let results = batcher.flush(&mut client).await.unwrap();
+        let order: Vec<u32> = results.iter().map(|r| r.point_id).collect();
+        assert_eq!(
+            order,
+            [1, 3, 2],
+            "the newer value for register 10 goes out last"
+        );
+    }
+
+    #[test]
+    fn test_expired_commands_are_dropped_on_take() {
+        let mut batcher = CommandBatcher::new();
+        let mut stale = create_test_command(1, 1, 6, 100, "uint16");
+        stale.ttl = Some(Duration::from_millis(1));
+        batcher.add_command(stale);
+        let mut fresh = create_test_command(2, 1, 6, 101, "uint16");
+        fresh.ttl = Some(Duration::from_secs(60));
+        batcher.add_command(fresh);
+        batcher.add_command(create_test_command(3, 2, 16, 0, "uint16"));
+        let mut lone = create_test_command(4, 3, 6, 0, "uint16");
+        lone.ttl = Some(Duration::ZERO);
+        batcher.add_command(lone);
+
+        std::thread::sleep(Duration::from_millis(5));
+        let commands = batcher.take_commands();
+        assert_eq!(batcher.expired_commands(), 2);
+        assert_eq!(commands.len(), 2, "emptied groups are removed");
+        assert_eq!(commands[&(1, 6)][0].point_id, 2);
+        assert!(batcher.is_empty());
+
+        batcher.take_commands();
+        assert_eq!(batcher.expired_commands(), 0);
+    }
+
+    #[test]
+    fn test_add_command_dedup_keeps_latest_value() {
+        let mut batcher = CommandBatcher::new();
+        let mut first = create_test_command(1, 1, 6, 100, "uint16");
+        first.ttl = Some(Duration::from_millis(1));
+        batcher.add_command(first);
+        batcher.add_command(create_test_command(2, 1, 6, 101, "uint16"));
+        batcher.add_command(create_test_command(3, 2, 6, 100, "uint16"));
+        std::thread::sleep(Duration::from_millis(5));
+
+        let mut latest = create_test_command(4, 1, 16, 100, "uint16");
+        latest.ttl = Some(Duration::from_millis(1));
+        batcher.add_command_dedup(latest);
+        batcher.add_command_dedup(create_test_command(5, 1, 6, 102, "uint16"));
+        assert_eq!(batcher.pending_count(), 4);
+
+        let commands = batcher.take_commands();
+        assert_eq!(batcher.expired_commands(), 1, "original enqueue time kept");
+        assert!(!commands.contains_key(&(1, 16)));
+        let ids: Vec<u32> = commands[&(1, 6)].iter().map(|c| c.point_id).collect();
+        assert_eq!(ids, [2, 5]);
+        assert_eq!(commands[&(2, 6)].len(), 1);
+    }
+
     #[test]
     fn test_batch_workflow() {
         let mut batcher = CommandBatcher::new();
@@ -0,0 +1,210 @@
+//! # Compressed Block Writes
+//!
+//! Bulk configuration uploads (hundreds of registers at start-up) are slow on
+//! serial RTU links. [`CompressedBlockWrite`] run-length encodes a register
+//! block and turns it into the shortest sequence of standard FC16 (Write
+//! Multiple Registers) requests that restores it, so no server support is
+//! needed.
+//!
+//! Modbus has no run-length write, so every register that is written still
+//! travels on the wire. The saving comes from runs that do not need writing
+//! at all: with [`with_skip_value`](CompressedBlockWrite::with_skip_value),
+//! runs equal to a value the device is known to hold already (typically `0`
+//! after a factory reset) are skipped, and the remaining spans are packed into
+//! as few FC16 requests as the register limit allows.
+//!
+//! ```rust
+//! use voltage_modbus::block_write::CompressedBlockWrite;
+//!
+//! let mut config = vec![0u16; 400];
+//! config[10] = 1500;
+//! config[11] = 1500;
+//! config[300] = 7;
+//!
+//! let block = CompressedBlockWrite::new(1000, &config).with_skip_value(0);
+//! assert_eq!(block.uncompressed_request_count(), 4);
+//! assert_eq!(block.compressed_request_count(), 2);
+//!
+//! for request in block.requests(1) {
+//!     // client.execute_request(request).await?;
+//!     # let _ = request;
+//! }
+//! ```
+
+use crate::constants::MAX_WRITE_REGISTERS;
+use crate::protocol::{ModbusFunction, ModbusRequest, SlaveId};
+
+/// A run of `len` consecutive registers holding `value`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    /// Register value repeated across the run.
+    pub value: u16,
+    /// Number of registers in the run.
+    pub len: u16,
+}
+
+/// Run-length encoded register block, written as a sequence of FC16 requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedBlockWrite {
+    start_address: u16,
+    runs: Vec<Run>,
+    skip_value: Option<u16>,
+    max_registers: u16,
+}
+
+impl CompressedBlockWrite {
+    /// Encode `values`, to be written starting at `start_address`.
+    pub fn new(start_address: u16, values: &[u16]) -> Self {
+        let mut runs: Vec<Run> = Vec::new();
+        for &value in values {
+            match runs.last_mut() {
+                Some(run) if run.value == value && run.len < u16::MAX => run.len += 1,
+                _ => runs.push(Run { value, len: 1 }),
+            }
+        }
+        Self {
+            start_address,
+            runs,
+            skip_value: None,
+            max_registers: MAX_WRITE_REGISTERS as u16,
+        }
+    }
+
+    /// Skip runs equal to `value`, which the device already holds.
+    pub fn with_skip_value(mut self, value: u16) -> Self {
+        self.skip_value = Some(value);
+        self
+    }
+
+    /// Cap registers per FC16 request (default 123, the protocol maximum).
+    pub fn with_max_registers(mut self, max_registers: u16) -> Self {
+        self.max_registers = max_registers.clamp(1, MAX_WRITE_REGISTERS as u16);
+        self
+    }
+
+    /// The run-length encoding of the block.
+    pub fn runs(&self) -> &[Run] {
+        &self.runs
+    }
+
+    /// Total number of registers in the block.
+    pub fn register_count(&self) -> usize {
+        self.runs.iter().map(|run| usize::from(run.len)).sum()
+    }
+
+    /// Contiguous `(address, values)` spans that must be written.
+    pub fn spans(&self) -> Vec<(u16, Vec<u16>)> {
+        let mut spans: Vec<(u16, Vec<u16>)> = Vec::new();
+        let mut address = self.start_address;
+        let mut open = false;
+        for run in &self.runs {
+            if Some(run.value) == self.skip_value {
+                open = false;
+            } else {
+                if !open {
+                    spans.push((address, Vec::new()));
+                    open = true;
+                }
+                if let Some((_, values)) = spans.last_mut() {
+                    values.extend(std::iter::repeat_n(run.value, usize::from(run.len)));
+                }
+            }
+            address = address.wrapping_add(run.len);
+        }
+        spans
+    }
+
+    /// FC16 requests that restore the block on `slave_id`.
+    pub fn requests(&self, slave_id: SlaveId) -> Vec<ModbusRequest> {
+        let mut requests = Vec::new();
+        for (address, values) in self.spans() {
+            for (i, chunk) in values.chunks(usize::from(self.max_registers)).enumerate() {
+                let offset = (i * usize::from(self.max_registers)) as u16;
+                let data = chunk.iter().flat_map(|v| v.to_be_bytes()).collect();
+                requests.push(ModbusRequest::new_write(
+                    slave_id,
+                    ModbusFunction::WriteMultipleRegisters,
+                    address.wrapping_add(offset),
+                    data,
+                ));
+            }
+        }
+        requests
+    }
+
+    /// Number of FC16 requests [`requests`](Self::requests) produces.
+    pub fn compressed_request_count(&self) -> usize {
+        let max = usize::from(self.max_registers);
+        self.spans()
+            .iter()
+            .map(|(_, values)| values.len().div_ceil(max))
+            .sum()
+    }
+
+    /// Number of FC16 requests needed to write the block without compression.
+    pub fn uncompressed_request_count(&self) -> usize {
+        self.register_count()
+            .div_ceil(usize::from(self.max_registers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registers(request: &ModbusRequest) -> Vec<u16> {
+        request
+            .data
+            .chunks(2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+            .collect()
+    }
+
+    #[test]
+    fn all_zero_block_is_a_single_write() {
+        let block = CompressedBlockWrite::new(0, &[0; 100]);
+        assert_eq!(block.runs(), &[Run { value: 0, len: 100 }]);
+        assert_eq!(block.compressed_request_count(), 1);
+
+        let requests = block.requests(1);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].quantity, 100);
+        assert_eq!(registers(&requests[0]), vec![0; 100]);
+    }
+
+    #[test]
+    fn alternating_block_is_written_uncompressed() {
+        let values: Vec<u16> = (0..300)
+            .map(|i| if i % 2 == 0 { 0xAAAA } else { 0x5555 })
+            .collect();
+        let block = CompressedBlockWrite::new(100, &values);
+        assert_eq!(block.runs().len(), 300);
+        assert_eq!(
+            block.compressed_request_count(),
+            block.uncompressed_request_count()
+        );
+
+        let requests = block.requests(1);
+        let layout: Vec<(u16, u16)> = requests.iter().map(|r| (r.address, r.quantity)).collect();
+        assert_eq!(layout, vec![(100, 123), (223, 123), (346, 54)]);
+        let restored: Vec<u16> = requests.iter().flat_map(registers).collect();
+        assert_eq!(restored, values);
+        assert!(requests
+            .iter()
+            .all(|r| r.function == ModbusFunction::WriteMultipleRegisters && r.validate().is_ok()));
+    }
+
+    #[test]
+    fn skip_value_drops_runs_and_splits_spans() {
+        let mut values = vec![0u16; 400];
+        values[10] = 7;
+        values[11] = 7;
+        values[12] = 8;
+        values[399] = 9;
+
+        let block = CompressedBlockWrite::new(0, &values).with_skip_value(0);
+        assert_eq!(block.uncompressed_request_count(), 4);
+        assert_eq!(block.compressed_request_count(), 2);
+        assert_eq!(block.spans(), vec![(10, vec![7, 7, 8]), (399, vec![9])]);
+    }
+}
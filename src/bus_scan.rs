@@ -0,0 +1,370 @@
+//! # Slave Discovery and Address Conflict Detection
+//!
+//! During commissioning the first question is which slave IDs are alive at
+//! all. [`scan_slaves`] tries a one-register read on each ID and yields every
+//! outcome as it arrives; [`scan_slaves_blocking`] waits for the whole scan
+//! and keeps the IDs that answered.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use tokio_stream::StreamExt;
+//! use voltage_modbus::bus_scan::scan_slaves;
+//! use voltage_modbus::ModbusTcpClient;
+//!
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! let mut client = ModbusTcpClient::from_address("192.168.1.50:502", Duration::from_secs(1)).await?;
+//! let mut scan = std::pin::pin!(scan_slaves(&mut client, 1..=247, Duration::from_millis(100)));
+//! while let Some((slave_id, result)) = scan.next().await {
+//!     println!("slave {}: {:?}", slave_id, result);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## RS-485 address conflicts
+//!
+//! Two slaves configured with the same address on one multi-drop bus both
+//! answer, and their frames collide on the wire. The master sees a response
+//! arrive, but it fails its CRC (or is otherwise malformed). A clean answer,
+//! an exception included, means exactly one device owns the address.
+//!
+//! [`RtuBusScan`] polls each address with a one-register read under a short
+//! timeout and classifies the outcome as a [`ConflictResult`].
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "rtu")]
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! use voltage_modbus::bus_scan::{BusScanReport, RtuBusScan};
+//! use voltage_modbus::client::ModbusRtuClient;
+//!
+//! let mut scan = RtuBusScan::new(ModbusRtuClient::new("/dev/ttyUSB0", 9600)?);
+//! let report = BusScanReport::from(scan.scan_for_conflicts(1..=32).await?);
+//! for address in report.potential_conflicts() {
+//!     eprintln!("address {} is used by more than one slave", address);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::ops::RangeInclusive;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use tokio_stream::StreamExt;
+
+use crate::client::ModbusClient;
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::SlaveId;
+
+/// Default time to wait for each address.
+pub const DEFAULT_SCAN_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Read holding register 0 from each ID in `id_range`, one at a time,
+/// waiting at most `timeout` for each.
+///
+/// The timeout goes to the transport through
+/// [`ModbusClient::read_03_with_timeout`] rather than cancelling the read, so
+/// a late reply cannot be mistaken for the next ID's answer. Transports that
+/// ignore per-request timeouts wait for their own.
+///
+/// Yields `(slave_id, Ok(()))` for an ID that answered with data and the
+/// error otherwise; a scan timeout is a [`ModbusError::Timeout`]. An
+/// exception response still proves a device owns the ID; it is yielded as
+/// the error so callers can tell the two apart.
+pub fn scan_slaves<C: ModbusClient + Send>(
+    client: &mut C,
+    id_range: RangeInclusive<u8>,
+    timeout: Duration,
+) -> impl Stream<Item = (SlaveId, ModbusResult<()>)> + Send + '_ {
+    SlaveScan {
+        ids: id_range,
+        timeout,
+        state: ScanState::Idle(client),
+    }
+}
+
+/// Run [`scan_slaves`] to completion and return the IDs that responded,
+/// exception responses included.
+pub async fn scan_slaves_blocking<C: ModbusClient + Send>(
+    client: &mut C,
+    id_range: RangeInclusive<u8>,
+    timeout: Duration,
+) -> Vec<SlaveId> {
+    let mut scan = std::pin::pin!(scan_slaves(client, id_range, timeout));
+    let mut alive = Vec::new();
+    while let Some((slave_id, result)) = scan.next().await {
+        if result
+            .as_ref()
+            .map_or_else(ModbusError::is_device_exception, |_| true)
+        {
+            alive.push(slave_id);
+        }
+    }
+    alive
+}
+
+/// A scan read in flight; holds the client borrow until it completes
+type ScanFuture<'a, C> = Pin<Box<dyn Future<Output = (&'a mut C, ModbusResult<()>)> + Send + 'a>>;
+
+enum ScanState<'a, C> {
+    Idle(&'a mut C),
+    Reading(SlaveId, ScanFuture<'a, C>),
+    Done,
+}
+
+struct SlaveScan<'a, C> {
+    ids: RangeInclusive<u8>,
+    timeout: Duration,
+    state: ScanState<'a, C>,
+}
+
+impl<'a, C: ModbusClient + Send> Stream for SlaveScan<'a, C> {
+    type Item = (SlaveId, ModbusResult<()>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match std::mem::replace(&mut this.state, ScanState::Done) {
+                ScanState::Done => return Poll::Ready(None),
+                ScanState::Idle(client) => match this.ids.next() {
+                    Some(slave_id) => {
+                        let read = probe_slave(client, slave_id, this.timeout);
+                        this.state = ScanState::Reading(slave_id, read);
+                    }
+                    None => return Poll::Ready(None),
+                },
+                ScanState::Reading(slave_id, mut read) => match read.as_mut().poll(cx) {
+                    Poll::Ready((client, result)) => {
+                        this.state = ScanState::Idle(client);
+                        return Poll::Ready(Some((slave_id, result)));
+                    }
+                    Poll::Pending => {
+                        this.state = ScanState::Reading(slave_id, read);
+                        return Poll::Pending;
+                    }
+                },
+            }
+        }
+    }
+}
+
+fn probe_slave<C: ModbusClient + Send>(
+    client: &mut C,
+    slave_id: SlaveId,
+    timeout: Duration,
+) -> ScanFuture<'_, C> {
+    Box::pin(async move {
+        let result = client
+            .read_03_with_timeout(slave_id, 0, 1, timeout)
+            .await
+            .map(drop);
+        (client, result)
+    })
+}
+
+/// How one bus address responded to a scan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictResult {
+    /// A well-formed response (data or exception) from a single device.
+    Unique,
+    /// A response arrived but was corrupt; likely two slaves answering.
+    Conflict(String),
+    /// Nothing answered within the scan timeout.
+    NoResponse,
+}
+
+/// Scanner for duplicate slave addresses on an RTU bus.
+///
+/// `C` is normally a [`ModbusRtuClient`](crate::client::ModbusRtuClient); any
+/// client that reports corrupt responses as CRC or frame errors works.
+pub struct RtuBusScan<C> {
+    /// Client attached to the bus.
+    pub transport: C,
+    timeout: Duration,
+}
+
+impl<C: ModbusClient> RtuBusScan<C> {
+    /// Scan through `transport` with [`DEFAULT_SCAN_TIMEOUT`] per address.
+    pub fn new(transport: C) -> Self {
+        Self {
+            transport,
+            timeout: DEFAULT_SCAN_TIMEOUT,
+        }
+    }
+
+    /// Wait `timeout` for each address instead of the default.
+    ///
+    /// As in [`scan_slaves`], the transport applies it; a
+    /// [`ModbusRtuClient`](crate::client::ModbusRtuClient) honours it.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Read holding register 0 from every address in `range` and classify
+    /// the outcome.
+    ///
+    /// CRC and frame errors count as conflicts. Connection and I/O failures
+    /// abort the scan, since they say nothing about the addresses.
+    pub async fn scan_for_conflicts(
+        &mut self,
+        range: RangeInclusive<u8>,
+    ) -> ModbusResult<Vec<(u8, ConflictResult)>> {
+        let mut results = Vec::with_capacity(range.clone().count());
+        for address in range {
+            let read = self
+                .transport
+                .read_03_with_timeout(address, 0, 1, self.timeout)
+                .await;
+            results.push((address, classify(read)?));
+        }
+        Ok(results)
+    }
+}
+
+fn classify<T>(result: ModbusResult<T>) -> ModbusResult<ConflictResult> {
+    match result {
+        Ok(_) | Err(ModbusError::Exception { .. }) => Ok(ConflictResult::Unique),
+        Err(e @ (ModbusError::CrcMismatch { .. } | ModbusError::Frame { .. })) => {
+            Ok(ConflictResult::Conflict(e.to_string()))
+        }
+        Err(ModbusError::Timeout { .. } | ModbusError::DeviceNotResponding { .. }) => {
+            Ok(ConflictResult::NoResponse)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Results of a bus scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BusScanReport {
+    /// `(address, result)` per scanned address, in scan order.
+    pub results: Vec<(u8, ConflictResult)>,
+}
+
+impl BusScanReport {
+    /// Addresses whose response looked like a collision.
+    pub fn potential_conflicts(&self) -> Vec<u8> {
+        self.addresses(|result| matches!(result, ConflictResult::Conflict(_)))
+    }
+
+    /// Addresses answered by exactly one device.
+    pub fn unique(&self) -> Vec<u8> {
+        self.addresses(|result| *result == ConflictResult::Unique)
+    }
+
+    fn addresses(&self, keep: impl Fn(&ConflictResult) -> bool) -> Vec<u8> {
+        self.results
+            .iter()
+            .filter(|(_, result)| keep(result))
+            .map(|(address, _)| *address)
+            .collect()
+    }
+}
+
+impl From<Vec<(u8, ConflictResult)>> for BusScanReport {
+    fn from(results: Vec<(u8, ConflictResult)>) -> Self {
+        Self { results }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GenericModbusClient;
+    use crate::protocol::{ModbusRequest, ModbusResponse};
+    use crate::testing::EchoTransport;
+    use crate::transport::{ModbusTransport, TransportStats};
+    use tokio_stream::StreamExt;
+
+    /// Bus with slaves at 1-3; two devices share address 2, 4 hangs, 5 is
+    /// a gateway that rejects the register.
+    struct SimulatedBus {
+        echo: EchoTransport,
+    }
+
+    impl ModbusTransport for SimulatedBus {
+        async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+            match request.slave_id {
+                1 | 3 => self.echo.request(request).await,
+                2 => Err(ModbusError::crc_mismatch(0x1D2C, 0x93F1)),
+                4 => {
+                    // Hangs until the request's own timeout
+                    let wait = request.timeout.expect("scan sets a response timeout");
+                    tokio::time::sleep(wait).await;
+                    Err(ModbusError::timeout(
+                        "read response",
+                        wait.as_millis() as u64,
+                    ))
+                }
+                5 => Err(ModbusError::exception(0x03, 0x02)),
+                _ => Err(ModbusError::timeout("read response", 50)),
+            }
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        async fn close(&mut self) -> ModbusResult<()> {
+            Ok(())
+        }
+
+        fn get_stats(&self) -> TransportStats {
+            TransportStats::default()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn crc_failure_is_reported_as_conflict() {
+        let client = GenericModbusClient::new(SimulatedBus {
+            echo: EchoTransport::new(),
+        });
+        let mut scan = RtuBusScan::new(client).with_timeout(Duration::from_millis(20));
+
+        let start = tokio::time::Instant::now();
+        let results = scan.scan_for_conflicts(1..=6).await.unwrap();
+        assert_eq!(start.elapsed(), Duration::from_millis(20));
+
+        assert!(
+            matches!(&results[1], (2, ConflictResult::Conflict(detail)) if detail.contains("CRC"))
+        );
+        let report = BusScanReport::from(results);
+        assert_eq!(report.potential_conflicts(), [2]);
+        assert_eq!(report.unique(), [1, 3, 5]);
+        assert_eq!(report.results[3], (4, ConflictResult::NoResponse));
+        assert_eq!(report.results[5], (6, ConflictResult::NoResponse));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scan_slaves_yields_each_id_in_turn() {
+        let mut client = GenericModbusClient::new(SimulatedBus {
+            echo: EchoTransport::new(),
+        });
+        let timeout = Duration::from_millis(20);
+
+        {
+            let mut scan = std::pin::pin!(scan_slaves(&mut client, 3..=4, timeout));
+            assert!(matches!(scan.next().await, Some((3, Ok(())))));
+            assert!(matches!(
+                scan.next().await,
+                Some((4, Err(ModbusError::Timeout { .. })))
+            ));
+            assert!(scan.next().await.is_none());
+        }
+
+        let alive = scan_slaves_blocking(&mut client, 1..=6, timeout).await;
+        assert_eq!(alive, [1, 3, 5]);
+    }
+
+    #[test]
+    fn frame_errors_count_and_link_errors_abort() {
+        let garbled: ModbusResult<()> = Err(ModbusError::frame("CRC mismatch: expected 0x1234"));
+        assert!(matches!(classify(garbled), Ok(ConflictResult::Conflict(_))));
+        let closed: ModbusResult<()> = Err(ModbusError::connection("port closed"));
+        assert!(classify(closed).is_err());
+    }
+}
@@ -21,9 +21,29 @@
 //! - `LittleEndian (DCBA)`: \[0x78, 0x56, 0x34, 0x12\]
 //! - `BigEndianSwap (CDAB)`: \[0x56, 0x78, 0x12, 0x34\] (Modbus common)
 //! - `LittleEndianSwap (BADC)`: \[0x34, 0x12, 0x78, 0x56\]
+//!
+//! The "middle-endian" / PDP-11 layout of legacy VAX/PDP-era systems (words
+//! in big-endian order, bytes within each word swapped) is BADC, i.e.
+//! [`ByteOrder::LittleEndianSwap`]; [`ByteOrder::MIDDLE_ENDIAN`] names it.
 
 use core::fmt;
 
+use crate::client::ModbusClient;
+use crate::error::ModbusResult;
+use crate::protocol::SlaveId;
+use crate::snapshot::RegisterDescriptor;
+
+/// The 32/64-bit orders, in the order auto-detection tries them.
+const MULTI_REGISTER_ORDERS: [ByteOrder; 4] = [
+    ByteOrder::BigEndian,
+    ByteOrder::LittleEndian,
+    ByteOrder::BigEndianSwap,
+    ByteOrder::LittleEndianSwap,
+];
+
+/// Relative tolerance for matching a known f32 in [`ByteOrder::auto_detect`].
+const AUTO_DETECT_TOLERANCE: f32 = 0.001;
+
 /// Unified byte/word order representation for 16/32/64-bit values.
 ///
 /// # Example
@@ -57,8 +77,10 @@ pub enum ByteOrder {
 
     /// Little-endian with swapped words: BADC
     ///
-    /// Rare, but exists in some devices.
-    /// Example: 0x12345678 → \[0x34, 0x12, 0x78, 0x56\]
+    /// Also known as middle-endian or PDP-endian: words in big-endian order,
+    /// bytes within each word swapped. Found in legacy VAX/PDP-era systems.
+    /// Example: 0x12345678 → \[0x34, 0x12, 0x78, 0x56\];
+    /// 64-bit values use BADCFEHG.
     LittleEndianSwap,
 
     /// 16-bit big-endian: AB
@@ -75,13 +97,24 @@ pub enum ByteOrder {
 }
 
 impl ByteOrder {
+    /// Middle-endian (PDP-endian) byte order, BADC.
+    ///
+    /// ```rust
+    /// use voltage_modbus::bytes::u32_to_regs;
+    /// use voltage_modbus::ByteOrder;
+    ///
+    /// // 0x12345678 is transmitted as bytes 34 12 78 56.
+    /// assert_eq!(u32_to_regs(0x12345678, ByteOrder::MIDDLE_ENDIAN), [0x3412, 0x7856]);
+    /// ```
+    pub const MIDDLE_ENDIAN: ByteOrder = ByteOrder::LittleEndianSwap;
+
     /// Convert from legacy string formats.
     ///
     /// Supports various common string representations:
     /// - "ABCD", "AB-CD" → BigEndian
     /// - "DCBA", "DC-BA" → LittleEndian
     /// - "CDAB", "CD-AB" → BigEndianSwap
-    /// - "BADC", "BA-DC" → LittleEndianSwap
+    /// - "BADC", "BA-DC", "PDP", "MIDDLE_ENDIAN" → LittleEndianSwap
     /// - "BE", "BIG_ENDIAN" → BigEndian
     /// - "LE", "LITTLE_ENDIAN" → LittleEndian
     /// - "AB" → BigEndian16
@@ -101,7 +134,8 @@ impl ByteOrder {
                 Some(Self::LittleEndian)
             }
             "CDAB" | "BIG_ENDIAN_SWAP" | "BIGENDIANSWAP" => Some(Self::BigEndianSwap),
-            "BADC" | "LITTLE_ENDIAN_SWAP" | "LITTLEENDIANSWAP" => Some(Self::LittleEndianSwap),
+            "BADC" | "LITTLE_ENDIAN_SWAP" | "LITTLEENDIANSWAP" | "BADCFEHG" | "PDP"
+            | "PDPENDIAN" | "MIDDLEENDIAN" => Some(Self::LittleEndianSwap),
 
             // 16-bit patterns
             "AB" => Some(Self::BigEndian16),
@@ -152,6 +186,142 @@ impl ByteOrder {
     pub fn has_word_swap(&self) -> bool {
         matches!(self, Self::BigEndianSwap | Self::LittleEndianSwap)
     }
+
+    /// The order used for multi-register values: the 16-bit-only orders act
+    /// as ABCD and DCBA there.
+    #[inline]
+    pub fn multi_register_order(&self) -> ByteOrder {
+        match self {
+            Self::BigEndian16 => Self::BigEndian,
+            Self::LittleEndian16 => Self::LittleEndian,
+            other => *other,
+        }
+    }
+
+    /// `true` if all multi-register descriptors use the same 32-bit order.
+    ///
+    /// Single-register and bool descriptors are order-agnostic and ignored.
+    ///
+    /// ```rust
+    /// use voltage_modbus::snapshot::RegisterDescriptor;
+    /// use voltage_modbus::ByteOrder;
+    ///
+    /// let a = RegisterDescriptor::new(0, "float32").with_byte_order(ByteOrder::BigEndianSwap);
+    /// let b = RegisterDescriptor::new(2, "uint16").with_byte_order(ByteOrder::LittleEndian16);
+    /// let c = RegisterDescriptor::new(3, "int32");
+    /// assert!(ByteOrder::is_consistent(&[&a, &b]));
+    /// assert!(!ByteOrder::is_consistent(&[&a, &b, &c]));
+    /// ```
+    pub fn is_consistent(descriptors: &[&RegisterDescriptor]) -> bool {
+        let mut orders = descriptors
+            .iter()
+            .filter(|d| d.register_count() > 1)
+            .map(|d| d.byte_order.multi_register_order());
+        match orders.next() {
+            Some(first) => orders.all(|order| order == first),
+            None => true,
+        }
+    }
+
+    /// Find the 32-bit order under which the first two `registers` decode to
+    /// `known_value` (within a relative tolerance of 0.001).
+    ///
+    /// Orders are tried as ABCD, DCBA, CDAB, BADC; the first match wins.
+    /// Returns `None` if fewer than two registers are given or nothing matches.
+    ///
+    /// ```rust
+    /// use voltage_modbus::ByteOrder;
+    ///
+    /// // 25.0f32 = 0x41C80000, read from a CDAB device
+    /// assert_eq!(
+    ///     ByteOrder::auto_detect(&[0x0000, 0x41C8], 25.0),
+    ///     Some(ByteOrder::BigEndianSwap)
+    /// );
+    /// ```
+    pub fn auto_detect(registers: &[u16], known_value: f32) -> Option<ByteOrder> {
+        let regs: &[u16; 2] = registers.get(..2)?.try_into().ok()?;
+        let tolerance = known_value.abs() * AUTO_DETECT_TOLERANCE;
+        MULTI_REGISTER_ORDERS.into_iter().find(|&order| {
+            let decoded = regs_to_f32(regs, order);
+            decoded == known_value || (decoded - known_value).abs() <= tolerance
+        })
+    }
+
+    /// Find the 32-bit order under which `registers` decode to exactly
+    /// `known_value`. Orders are tried as in [`auto_detect`](Self::auto_detect).
+    pub fn detect_for_u32(registers: &[u16; 2], known_value: u32) -> Option<ByteOrder> {
+        MULTI_REGISTER_ORDERS
+            .into_iter()
+            .find(|&order| regs_to_u32(registers, order) == known_value)
+    }
+}
+
+/// Reference value whose encoding reveals a device's byte order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReferenceValue {
+    F32(f32),
+    U32(u32),
+}
+
+/// Detects a device's 32-bit byte order from a holding register pair with a
+/// known content (a nameplate constant, serial number, firmware version, ...).
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use voltage_modbus::bytes::AutoDetectSession;
+/// use voltage_modbus::ModbusTcpClient;
+///
+/// # async fn example() -> voltage_modbus::ModbusResult<()> {
+/// let mut client = ModbusTcpClient::from_address("10.0.0.5:502", Duration::from_secs(1)).await?;
+/// // The meter's serial number 20240117 is stored at 40100.
+/// let session = AutoDetectSession::for_u32(1, 100, 20_240_117);
+/// if let Some(order) = session.detect(&mut client).await? {
+///     println!("device uses {}", order);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoDetectSession {
+    slave_id: SlaveId,
+    address: u16,
+    reference: ReferenceValue,
+}
+
+impl AutoDetectSession {
+    /// Reference register pair at `address` holding the float `known_value`.
+    pub fn for_f32(slave_id: SlaveId, address: u16, known_value: f32) -> Self {
+        Self {
+            slave_id,
+            address,
+            reference: ReferenceValue::F32(known_value),
+        }
+    }
+
+    /// Reference register pair at `address` holding the integer `known_value`.
+    pub fn for_u32(slave_id: SlaveId, address: u16, known_value: u32) -> Self {
+        Self {
+            slave_id,
+            address,
+            reference: ReferenceValue::U32(known_value),
+        }
+    }
+
+    /// Read the reference registers (FC03) and return the matching order,
+    /// or `None` if no order decodes to the known value.
+    pub async fn detect<C: ModbusClient>(&self, client: &mut C) -> ModbusResult<Option<ByteOrder>> {
+        let registers = client.read_03(self.slave_id, self.address, 2).await?;
+        Ok(match self.reference {
+            ReferenceValue::F32(value) => ByteOrder::auto_detect(&registers, value),
+            ReferenceValue::U32(value) => {
+                let pair: [u16; 2] = match registers.as_slice().try_into() {
+                    Ok(pair) => pair,
+                    Err(_) => return Ok(None),
+                };
+                ByteOrder::detect_for_u32(&pair, value)
+            }
+        })
+    }
 }
 
 impl fmt::Display for ByteOrder {
@@ -167,6 +337,27 @@ impl Default for ByteOrder {
     }
 }
 
+/// Serialized as the pattern code that leads [`ByteOrder::as_str`] (`"CDAB"`).
+#[cfg(feature = "serde")]
+impl serde::Serialize for ByteOrder {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = self.as_str();
+        serializer.serialize_str(name.split(' ').next().unwrap_or(name))
+    }
+}
+
+/// Accepts every spelling [`ByteOrder::from_str`] does, and [`ByteOrder::as_str`]
+/// descriptions.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ByteOrder {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        ByteOrder::from_str(&s)
+            .or_else(|| s.split(' ').next().and_then(ByteOrder::from_str))
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown byte order: {:?}", s)))
+    }
+}
+
 // ============================================================================
 // Register to Bytes Conversions
 // ============================================================================
@@ -471,6 +662,72 @@ mod tests {
         );
     }
 
+    /// Wire bytes and registers of 0x12345678 for every variant.
+    #[test]
+    fn test_layout_of_0x12345678_for_every_variant() {
+        let cases: [(ByteOrder, [u8; 4], [u16; 2]); 6] = [
+            (
+                ByteOrder::BigEndian,
+                [0x12, 0x34, 0x56, 0x78],
+                [0x1234, 0x5678],
+            ),
+            (
+                ByteOrder::LittleEndian,
+                [0x78, 0x56, 0x34, 0x12],
+                [0x7856, 0x3412],
+            ),
+            (
+                ByteOrder::BigEndianSwap,
+                [0x56, 0x78, 0x12, 0x34],
+                [0x5678, 0x1234],
+            ),
+            (
+                ByteOrder::LittleEndianSwap,
+                [0x34, 0x12, 0x78, 0x56],
+                [0x3412, 0x7856],
+            ),
+            // 16-bit orders applied to 32-bit values fall back to ABCD / DCBA.
+            (
+                ByteOrder::BigEndian16,
+                [0x12, 0x34, 0x56, 0x78],
+                [0x1234, 0x5678],
+            ),
+            (
+                ByteOrder::LittleEndian16,
+                [0x78, 0x56, 0x34, 0x12],
+                [0x7856, 0x3412],
+            ),
+        ];
+        for (order, wire, regs) in cases {
+            let encoded = u32_to_regs(0x12345678, order);
+            assert_eq!(encoded, regs, "{:?}", order);
+            let on_wire: Vec<u8> = encoded.iter().flat_map(|r| r.to_be_bytes()).collect();
+            assert_eq!(on_wire, wire, "{:?}", order);
+            assert_eq!(regs_to_u32(&regs, order), 0x12345678, "{:?}", order);
+            assert_eq!(
+                bytes_4_to_regs(&regs_to_bytes_4(&regs, order), order),
+                regs,
+                "{:?}",
+                order
+            );
+        }
+
+        // Middle-endian (PDP) is BADC: no separate variant is needed.
+        assert_eq!(ByteOrder::MIDDLE_ENDIAN, ByteOrder::LittleEndianSwap);
+        assert_eq!(
+            ByteOrder::from_str("PDP"),
+            Some(ByteOrder::LittleEndianSwap)
+        );
+        assert_eq!(
+            ByteOrder::from_str("middle-endian"),
+            Some(ByteOrder::LittleEndianSwap)
+        );
+        assert_eq!(
+            u64_to_regs(0x1122_3344_5566_7788, ByteOrder::MIDDLE_ENDIAN),
+            [0x2211, 0x4433, 0x6655, 0x8877]
+        );
+    }
+
     #[test]
     fn test_regs_to_bytes_8_all_orders() {
         let regs = [0x1234, 0x5678, 0x9ABC, 0xDEF0];
@@ -493,6 +750,46 @@ mod tests {
         assert!((value - 25.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_auto_detect_all_orders() {
+        for order in MULTI_REGISTER_ORDERS {
+            let regs = f32_to_regs(25.0, order);
+            assert_eq!(ByteOrder::auto_detect(&regs, 25.0), Some(order));
+            // Within tolerance of a slightly rounded nameplate value.
+            assert_eq!(ByteOrder::auto_detect(&regs, 25.01), Some(order));
+
+            let regs = u32_to_regs(0x0134_5678, order);
+            assert_eq!(ByteOrder::detect_for_u32(&regs, 0x0134_5678), Some(order));
+        }
+        assert_eq!(ByteOrder::auto_detect(&[0x41C8, 0x0000], 30.0), None);
+        assert_eq!(ByteOrder::auto_detect(&[0x41C8], 25.0), None);
+    }
+
+    #[tokio::test]
+    async fn test_auto_detect_session() {
+        use crate::client::GenericModbusClient;
+        use crate::protocol::{ModbusFunction, ModbusResponse};
+        use crate::testing::ScriptedTransport;
+
+        let [a, b] = f32_to_regs(25.0, ByteOrder::LittleEndianSwap);
+        let mut data = vec![0x04];
+        data.extend_from_slice(&a.to_be_bytes());
+        data.extend_from_slice(&b.to_be_bytes());
+        let reply = Ok(ModbusResponse::new_success(
+            1,
+            ModbusFunction::ReadHoldingRegisters,
+            data,
+        ));
+        let mut client = GenericModbusClient::new(ScriptedTransport::new(vec![reply]));
+
+        let session = AutoDetectSession::for_f32(1, 500, 25.0);
+        assert_eq!(
+            session.detect(&mut client).await.unwrap(),
+            Some(ByteOrder::LittleEndianSwap)
+        );
+        assert_eq!(client.transport().requests()[0].address, 500);
+    }
+
     #[test]
     fn test_regs_to_u32() {
         let regs = [0x1234, 0x5678];
@@ -552,4 +849,18 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_uses_pattern_code() {
+        assert_eq!(
+            serde_json::to_string(&ByteOrder::BigEndianSwap).unwrap(),
+            r#""CDAB""#
+        );
+        for json in [r#""CDAB""#, r#""cd-ab""#, r#""CDAB (Big-Endian Swap)""#] {
+            let order: ByteOrder = serde_json::from_str(json).unwrap();
+            assert_eq!(order, ByteOrder::BigEndianSwap);
+        }
+        assert!(serde_json::from_str::<ByteOrder>(r#""XYZW""#).is_err());
+    }
 }
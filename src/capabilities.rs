@@ -0,0 +1,375 @@
+//! # Device Capability Negotiation
+//!
+//! The first connection to an unfamiliar device can find out what it
+//! supports instead of relying on documentation. [`DeviceCapabilityProber`]
+//! runs three probes against one slave:
+//!
+//! 1. Read Device Identification (FC 0x2B / MEI 0x0E) for vendor, product
+//!    and firmware strings.
+//! 2. A Diagnostics echo (FC 0x08 / sub-function 0x0000).
+//! 3. Holding-register reads of growing size, then a binary search, to find
+//!    the largest read the device accepts before answering with exception
+//!    0x03 (illegal data value).
+//!
+//! The resulting [`DeviceCapabilities`] turn into a [`DeviceLimits`] for the
+//! batch read APIs.
+//!
+//! ```rust,no_run
+//! use voltage_modbus::capabilities::DeviceCapabilityProber;
+//! use voltage_modbus::{ModbusClient, ModbusTcpClient};
+//! use std::time::Duration;
+//!
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! let mut client = ModbusTcpClient::from_address("192.168.1.50:502", Duration::from_secs(1)).await?;
+//! let capabilities = DeviceCapabilityProber::probe(&mut client, 1).await?;
+//! let limits = capabilities.device_limits();
+//! let values = client.read_03_batch(1, 0, 500, &limits).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::client::ModbusClient;
+use crate::constants::MAX_READ_REGISTERS;
+use crate::device_limits::DeviceLimits;
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::SlaveId;
+
+/// Objects returned by Read Device Identification (FC 0x2B / MEI 0x0E).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceIdentification {
+    /// Object 0x00, VendorName.
+    pub vendor_name: String,
+    /// Object 0x01, ProductCode.
+    pub product_code: String,
+    /// Object 0x02, MajorMinorRevision.
+    pub revision: String,
+    /// Regular and extended objects (0x03 and up), raw bytes by object ID.
+    pub extended: HashMap<u8, Vec<u8>>,
+}
+
+impl DeviceIdentification {
+    /// Store one object from a response: the three basic objects as
+    /// (lossy) UTF-8 text, everything else raw in `extended`.
+    pub fn insert_object(&mut self, object_id: u8, value: Vec<u8>) {
+        let text = || String::from_utf8_lossy(&value).into_owned();
+        match object_id {
+            0x00 => self.vendor_name = text(),
+            0x01 => self.product_code = text(),
+            0x02 => self.revision = text(),
+            _ => {
+                self.extended.insert(object_id, value);
+            }
+        }
+    }
+}
+
+/// What a device turned out to support.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceCapabilities {
+    /// Vendor name from device identification.
+    pub vendor: Option<String>,
+    /// Product code from device identification.
+    pub product: Option<String>,
+    /// Firmware revision from device identification.
+    pub firmware: Option<String>,
+    /// The device echoed an FC 0x08 diagnostics request.
+    pub supports_fc08: bool,
+    /// The device answered Read Device Identification.
+    pub supports_fc2b: bool,
+    /// Largest holding-register read the device accepted, if any read worked.
+    pub max_registers_per_read: Option<u16>,
+}
+
+impl DeviceCapabilities {
+    /// `limits` with the detected read size applied.
+    pub fn apply_to(&self, limits: DeviceLimits) -> DeviceLimits {
+        match self.max_registers_per_read {
+            Some(max) => limits.with_max_read_registers(max),
+            None => limits,
+        }
+    }
+
+    /// Spec-default [`DeviceLimits`] with the detected read size applied.
+    pub fn device_limits(&self) -> DeviceLimits {
+        self.apply_to(DeviceLimits::new())
+    }
+}
+
+/// Runs the capability probes described in the [module docs](self).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeviceCapabilityProber;
+
+impl DeviceCapabilityProber {
+    /// Data word sent in the diagnostics echo.
+    pub const ECHO_PATTERN: u16 = 0xA55A;
+
+    /// Probe `slave_id`, sizing reads from holding register 0.
+    pub async fn probe(
+        client: &mut impl ModbusClient,
+        slave_id: SlaveId,
+    ) -> ModbusResult<DeviceCapabilities> {
+        Self::probe_at(client, slave_id, 0).await
+    }
+
+    /// Probe `slave_id`, sizing reads from holding register `address`.
+    ///
+    /// Functions the device rejects or ignores (exception or timeout) are
+    /// reported as unsupported; connection failures abort the probe. The
+    /// read-size search only trusts exception 0x03: any other failure stops
+    /// it at the largest read that succeeded, which may be below the true
+    /// limit when the register map ends first.
+    pub async fn probe_at(
+        client: &mut impl ModbusClient,
+        slave_id: SlaveId,
+        address: u16,
+    ) -> ModbusResult<DeviceCapabilities> {
+        let mut capabilities = DeviceCapabilities::default();
+
+        match client.read_device_id(slave_id, 0x01, 0x00).await {
+            Ok(id) => {
+                capabilities.supports_fc2b = true;
+                capabilities.vendor = Some(id.vendor_name);
+                capabilities.product = Some(id.product_code);
+                capabilities.firmware = Some(id.revision);
+            }
+            Err(e) => unsupported(e)?,
+        }
+
+        match client.diagnostic_echo(slave_id, Self::ECHO_PATTERN).await {
+            Ok(echo) => capabilities.supports_fc08 = echo == Self::ECHO_PATTERN,
+            Err(e) => unsupported(e)?,
+        }
+
+        capabilities.max_registers_per_read = max_read_size(client, slave_id, address).await?;
+        Ok(capabilities)
+    }
+}
+
+/// Swallow errors that mean "the device does not do this"
+fn unsupported(error: ModbusError) -> ModbusResult<()> {
+    match error {
+        ModbusError::Exception { .. }
+        | ModbusError::InvalidFunction { .. }
+        | ModbusError::Timeout { .. }
+        | ModbusError::DeviceNotResponding { .. } => Ok(()),
+        other => Err(other),
+    }
+}
+
+/// Outcome of one sizing read
+enum ReadSize {
+    Accepted,
+    TooLarge,
+    Stop,
+}
+
+async fn try_read(
+    client: &mut impl ModbusClient,
+    slave_id: SlaveId,
+    address: u16,
+    quantity: u16,
+) -> ModbusResult<ReadSize> {
+    match client.read_03(slave_id, address, quantity).await {
+        Ok(_) => Ok(ReadSize::Accepted),
+        Err(e) if e.exception_code() == Some(0x03) => Ok(ReadSize::TooLarge),
+        Err(e) => unsupported(e).map(|()| ReadSize::Stop),
+    }
+}
+
+/// Double the read size until the device refuses, then binary-search the edge
+async fn max_read_size(
+    client: &mut impl ModbusClient,
+    slave_id: SlaveId,
+    address: u16,
+) -> ModbusResult<Option<u16>> {
+    let limit = (MAX_READ_REGISTERS as u16).min(u16::MAX - address).max(1);
+    let mut accepted = 0u16;
+    let mut size = 1u16;
+    let mut refused = None;
+
+    loop {
+        match try_read(client, slave_id, address, size).await? {
+            ReadSize::Accepted => {
+                accepted = size;
+                if size == limit {
+                    break;
+                }
+                size = size.saturating_mul(2).min(limit);
+            }
+            ReadSize::TooLarge => {
+                refused = Some(size);
+                break;
+            }
+            ReadSize::Stop => break,
+        }
+    }
+
+    if let Some(mut refused) = refused {
+        while refused - accepted > 1 {
+            let mid = accepted + (refused - accepted) / 2;
+            match try_read(client, slave_id, address, mid).await? {
+                ReadSize::Accepted => accepted = mid,
+                ReadSize::TooLarge | ReadSize::Stop => refused = mid,
+            }
+        }
+    }
+
+    Ok((accepted > 0).then_some(accepted))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::TransportStats;
+
+    /// Device that answers reads of up to `max_read` registers, with
+    /// optional FC08 and FC2B support.
+    struct MockDevice {
+        max_read: u16,
+        identity: Option<DeviceIdentification>,
+        echo: Option<u16>,
+        read_sizes: Vec<u16>,
+    }
+
+    impl MockDevice {
+        fn new(max_read: u16) -> Self {
+            Self {
+                max_read,
+                identity: None,
+                echo: None,
+                read_sizes: Vec::new(),
+            }
+        }
+    }
+
+    impl ModbusClient for MockDevice {
+        async fn read_01(&mut self, _: SlaveId, _: u16, _: u16) -> ModbusResult<Vec<bool>> {
+            Err(ModbusError::invalid_function(0x01))
+        }
+        async fn read_02(&mut self, _: SlaveId, _: u16, _: u16) -> ModbusResult<Vec<bool>> {
+            Err(ModbusError::invalid_function(0x02))
+        }
+        async fn read_03(&mut self, _: SlaveId, _: u16, quantity: u16) -> ModbusResult<Vec<u16>> {
+            self.read_sizes.push(quantity);
+            if quantity > self.max_read {
+                return Err(ModbusError::exception(0x03, 0x03));
+            }
+            Ok(vec![0; usize::from(quantity)])
+        }
+        async fn read_04(&mut self, _: SlaveId, _: u16, _: u16) -> ModbusResult<Vec<u16>> {
+            Err(ModbusError::invalid_function(0x04))
+        }
+        async fn write_05(&mut self, _: SlaveId, _: u16, _: bool) -> ModbusResult<()> {
+            Err(ModbusError::invalid_function(0x05))
+        }
+        async fn write_06(&mut self, _: SlaveId, _: u16, _: u16) -> ModbusResult<()> {
+            Err(ModbusError::invalid_function(0x06))
+        }
+        async fn write_0f(&mut self, _: SlaveId, _: u16, _: &[bool]) -> ModbusResult<()> {
+            Err(ModbusError::invalid_function(0x0F))
+        }
+        async fn write_10(&mut self, _: SlaveId, _: u16, _: &[u16]) -> ModbusResult<()> {
+            Err(ModbusError::invalid_function(0x10))
+        }
+        fn is_connected(&self) -> bool {
+            true
+        }
+        async fn close(&mut self) -> ModbusResult<()> {
+            Ok(())
+        }
+        fn get_stats(&self) -> TransportStats {
+            TransportStats::default()
+        }
+
+        async fn diagnostic_echo(&mut self, _: SlaveId, data: u16) -> ModbusResult<u16> {
+            match self.echo {
+                Some(mask) => Ok(data ^ mask),
+                None => Err(ModbusError::exception(0x08, 0x01)),
+            }
+        }
+
+        async fn read_device_id(
+            &mut self,
+            _: SlaveId,
+            read_device_id_code: u8,
+            object_id: u8,
+        ) -> ModbusResult<DeviceIdentification> {
+            assert_eq!((read_device_id_code, object_id), (0x01, 0x00));
+            self.identity
+                .clone()
+                .ok_or_else(|| ModbusError::timeout("read response header", 100))
+        }
+    }
+
+    #[tokio::test]
+    async fn full_featured_device() {
+        let mut device = MockDevice::new(60);
+        device.echo = Some(0);
+        device.identity = Some(DeviceIdentification {
+            vendor_name: "Voltage".to_string(),
+            product_code: "PM-3".to_string(),
+            revision: "2.1.0".to_string(),
+            extended: HashMap::new(),
+        });
+
+        let capabilities = DeviceCapabilityProber::probe(&mut device, 1).await.unwrap();
+        assert_eq!(
+            capabilities,
+            DeviceCapabilities {
+                vendor: Some("Voltage".to_string()),
+                product: Some("PM-3".to_string()),
+                firmware: Some("2.1.0".to_string()),
+                supports_fc08: true,
+                supports_fc2b: true,
+                max_registers_per_read: Some(60),
+            }
+        );
+        // 1, 2, 4, ... 64 refused, then bisect 32..64
+        assert_eq!(device.read_sizes[..7], [1, 2, 4, 8, 16, 32, 64]);
+        assert!(device.read_sizes.len() <= 7 + 5);
+
+        let limits = capabilities.device_limits();
+        assert_eq!(limits.max_read_registers, 60);
+        assert_eq!(
+            limits.max_write_registers,
+            DeviceLimits::new().max_write_registers
+        );
+    }
+
+    #[tokio::test]
+    async fn bare_device_keeps_default_limits() {
+        let mut device = MockDevice::new(125);
+        // A wrong echo does not count as diagnostics support
+        device.echo = Some(0x00FF);
+
+        let capabilities = DeviceCapabilityProber::probe(&mut device, 1).await.unwrap();
+        assert!(!capabilities.supports_fc2b && !capabilities.supports_fc08);
+        assert_eq!(capabilities.vendor, None);
+        assert_eq!(capabilities.max_registers_per_read, Some(125));
+        assert_eq!(capabilities.device_limits(), DeviceLimits::new());
+
+        let mut none = MockDevice::new(0);
+        let capabilities = DeviceCapabilityProber::probe(&mut none, 1).await.unwrap();
+        assert_eq!(capabilities.max_registers_per_read, None);
+        let conservative = DeviceLimits::conservative();
+        assert_eq!(capabilities.apply_to(conservative), conservative);
+    }
+
+    #[tokio::test]
+    async fn generic_client_probes_over_the_wire() {
+        use crate::client::GenericModbusClient;
+        use crate::testing::EchoTransport;
+
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+        assert_eq!(client.diagnostic_echo(1, 0x1234).await.unwrap(), 0x1234);
+        assert_eq!(
+            client.read_device_id(1, 0x01, 0x00).await.unwrap(),
+            DeviceIdentification::default()
+        );
+        let capabilities = DeviceCapabilityProber::probe(&mut client, 1).await.unwrap();
+        assert!(capabilities.supports_fc08 && capabilities.supports_fc2b);
+        assert_eq!(capabilities.max_registers_per_read, Some(125));
+    }
+}
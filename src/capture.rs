@@ -0,0 +1,492 @@
+//! # Capture and Replay
+//!
+//! Intermittent device problems are hard to chase without a record of what
+//! was actually exchanged. [`CaptureTransport`] wraps any transport and keeps
+//! a [`CaptureFrame`] per request: when it was sent, a transaction number,
+//! the request and what came back, errors included.
+//! [`save_to_file`](CaptureTransport::save_to_file) writes the frames in a
+//! compact binary format, and [`ReplayTransport`] reads that file back and
+//! answers the same requests with the recorded results, in order. A client
+//! bug seen against a real device can then be reproduced in a unit test.
+//!
+//! ```rust
+//! use voltage_modbus::capture::{CaptureTransport, ReplayTransport};
+//! use voltage_modbus::testing::EchoTransport;
+//! use voltage_modbus::{GenericModbusClient, ModbusClient};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut client = GenericModbusClient::new(CaptureTransport::new(EchoTransport::new()));
+//! let live = client.read_03(1, 0, 4).await?;
+//!
+//! let path = std::env::temp_dir().join("voltage_modbus_capture_doc.bin");
+//! client.transport().save_to_file(&path)?;
+//!
+//! let mut replay = GenericModbusClient::new(ReplayTransport::from_file(&path)?);
+//! assert_eq!(replay.read_03(1, 0, 4).await?, live);
+//! assert!(replay.read_03(1, 0, 4).await.is_err(), "capture exhausted");
+//! # std::fs::remove_file(&path)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## File format
+//!
+//! The file starts with the magic `VMCAP` and a format version byte (1),
+//! followed by the frames back to back. All integers are big-endian, byte
+//! strings are prefixed with their `u16` length:
+//!
+//! | Field | Encoding |
+//! |-------|----------|
+//! | timestamp | `u64` microseconds since the Unix epoch |
+//! | transaction ID | `u16` |
+//! | request | slave, function code, `u16` address, `u16` quantity, data, `u32` timeout in ms (0 = none) |
+//! | outcome | tag `0` + slave, function code, exception code (0 = none), data; tag `1` + timeout operation, `u64` timeout in ms; tag `2` + message of any other error |
+//!
+//! Errors other than timeouts keep only their message and are replayed as
+//! connection errors.
+
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::{ModbusFunction, ModbusRequest, ModbusResponse};
+use crate::transport::{ModbusTransport, TransportStats};
+
+/// Sequence number of a captured exchange.
+///
+/// Counted by the capture itself, starting at 1 and wrapping like an MBAP
+/// transaction ID; it need not match the ID the inner transport put on the wire.
+pub type TransactionId = u16;
+
+const MAGIC: &[u8; 5] = b"VMCAP";
+const FORMAT_VERSION: u8 = 1;
+
+const OUTCOME_RESPONSE: u8 = 0;
+const OUTCOME_TIMEOUT: u8 = 1;
+const OUTCOME_ERROR: u8 = 2;
+
+/// One request and its outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptureFrame {
+    /// When the request was sent
+    pub timestamp: SystemTime,
+    /// Sequence number of the exchange
+    pub transaction_id: TransactionId,
+    /// The request as handed to the transport
+    pub request: ModbusRequest,
+    /// What the transport returned
+    pub response: ModbusResult<ModbusResponse>,
+}
+
+/// Transport wrapper that records every exchange; see the [module docs](self).
+#[derive(Debug)]
+pub struct CaptureTransport<T: ModbusTransport> {
+    inner: T,
+    frames: Vec<CaptureFrame>,
+    last_transaction_id: TransactionId,
+}
+
+impl<T: ModbusTransport> CaptureTransport<T> {
+    /// Record every exchange with `inner`.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            frames: Vec::new(),
+            last_transaction_id: 0,
+        }
+    }
+
+    /// The exchanges recorded so far, oldest first.
+    pub fn frames(&self) -> &[CaptureFrame] {
+        &self.frames
+    }
+
+    /// Take the recorded exchanges, leaving the capture empty.
+    pub fn take_frames(&mut self) -> Vec<CaptureFrame> {
+        std::mem::take(&mut self.frames)
+    }
+
+    /// Write the recorded exchanges to `path` for [`ReplayTransport::from_file`].
+    pub fn save_to_file(&self, path: &Path) -> io::Result<()> {
+        let mut file = io::BufWriter::new(std::fs::File::create(path)?);
+        write_capture(&mut file, &self.frames)?;
+        file.flush()
+    }
+
+    /// Access the wrapped transport.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped transport.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap and return the inner transport.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: ModbusTransport> ModbusTransport for CaptureTransport<T> {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        let timestamp = SystemTime::now();
+        self.last_transaction_id = self.last_transaction_id.wrapping_add(1);
+        let response = self.inner.request(request).await;
+        self.frames.push(CaptureFrame {
+            timestamp,
+            transaction_id: self.last_transaction_id,
+            request: request.clone(),
+            response: response.clone(),
+        });
+        response
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.inner.close().await
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.inner.get_stats()
+    }
+}
+
+/// Transport that answers with the results of a capture, in order.
+///
+/// Each request must match the next captured one (slave, function, address,
+/// quantity and data); a mismatch fails with a protocol error without
+/// consuming the frame. Once the capture runs out, requests fail with a
+/// connection error.
+#[derive(Debug, Default)]
+pub struct ReplayTransport {
+    frames: VecDeque<CaptureFrame>,
+    stats: TransportStats,
+}
+
+impl ReplayTransport {
+    /// Replay `frames` in order.
+    pub fn new(frames: Vec<CaptureFrame>) -> Self {
+        Self {
+            frames: frames.into(),
+            stats: TransportStats::default(),
+        }
+    }
+
+    /// Replay a capture written by [`CaptureTransport::save_to_file`].
+    pub fn from_file(path: &Path) -> io::Result<Self> {
+        let mut file = io::BufReader::new(std::fs::File::open(path)?);
+        Ok(Self::new(read_capture(&mut file)?))
+    }
+
+    /// Number of captured exchanges not yet replayed.
+    pub fn remaining(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+impl ModbusTransport for ReplayTransport {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        let Some(frame) = self.frames.front() else {
+            self.stats.errors += 1;
+            return Err(ModbusError::connection("Replay capture exhausted"));
+        };
+        if !same_request(&frame.request, request) {
+            self.stats.errors += 1;
+            return Err(ModbusError::protocol(format!(
+                "Replay of transaction {} expected {:?}, got {:?}",
+                frame.transaction_id, frame.request, request
+            )));
+        }
+
+        let result = self.frames.pop_front().map(|f| f.response).unwrap();
+        self.stats.requests_sent += 1;
+        match &result {
+            Ok(response) => {
+                self.stats.responses_received += 1;
+                self.stats.bytes_received += response.data_len() as u64;
+            }
+            Err(e) => {
+                self.stats.errors += 1;
+                if matches!(e, ModbusError::Timeout { .. }) {
+                    self.stats.timeouts += 1;
+                }
+            }
+        }
+        result
+    }
+
+    fn is_connected(&self) -> bool {
+        !self.frames.is_empty()
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        Ok(())
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.stats.clone()
+    }
+}
+
+/// Whether two requests put the same PDU on the wire
+fn same_request(expected: &ModbusRequest, actual: &ModbusRequest) -> bool {
+    expected.slave_id == actual.slave_id
+        && expected.function == actual.function
+        && expected.address == actual.address
+        && expected.quantity == actual.quantity
+        && expected.data == actual.data
+}
+
+/// Write `frames` in the capture file format
+pub fn write_capture<W: Write>(writer: &mut W, frames: &[CaptureFrame]) -> io::Result<()> {
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[FORMAT_VERSION])?;
+    for frame in frames {
+        let micros = frame
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| u64::try_from(d.as_micros()).unwrap_or(u64::MAX));
+        writer.write_all(&micros.to_be_bytes())?;
+        writer.write_all(&frame.transaction_id.to_be_bytes())?;
+
+        let request = &frame.request;
+        writer.write_all(&[request.slave_id, request.function.to_u8()])?;
+        writer.write_all(&request.address.to_be_bytes())?;
+        writer.write_all(&request.quantity.to_be_bytes())?;
+        write_bytes(writer, &request.data)?;
+        let timeout_ms = request
+            .timeout
+            .map_or(0, |t| u32::try_from(t.as_millis()).unwrap_or(u32::MAX));
+        writer.write_all(&timeout_ms.to_be_bytes())?;
+
+        match &frame.response {
+            Ok(response) => {
+                let exception = response.exception.map_or(0, |e| e.to_u8());
+                writer.write_all(&[
+                    OUTCOME_RESPONSE,
+                    response.slave_id,
+                    response.function.to_u8(),
+                    exception,
+                ])?;
+                write_bytes(writer, response.data())?;
+            }
+            Err(ModbusError::Timeout {
+                operation,
+                timeout_ms,
+                ..
+            }) => {
+                writer.write_all(&[OUTCOME_TIMEOUT])?;
+                write_bytes(writer, operation.as_bytes())?;
+                writer.write_all(&timeout_ms.to_be_bytes())?;
+            }
+            Err(e) => {
+                writer.write_all(&[OUTCOME_ERROR])?;
+                write_bytes(writer, e.to_string().as_bytes())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Read frames written by [`write_capture`]
+pub fn read_capture<R: Read>(reader: &mut R) -> io::Result<Vec<CaptureFrame>> {
+    let mut header = [0u8; 6];
+    reader.read_exact(&mut header)?;
+    if &header[..5] != MAGIC {
+        return Err(invalid("not a Modbus capture file"));
+    }
+    if header[5] != FORMAT_VERSION {
+        return Err(invalid(format!(
+            "unsupported capture format version {}",
+            header[5]
+        )));
+    }
+
+    let mut frames = Vec::new();
+    loop {
+        let mut micros = [0u8; 8];
+        match reader.read_exact(&mut micros) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(frames),
+            Err(e) => return Err(e),
+        }
+        let timestamp = UNIX_EPOCH + Duration::from_micros(u64::from_be_bytes(micros));
+        let transaction_id = read_u16(reader)?;
+
+        let [slave_id, function] = read_array(reader)?;
+        let request = ModbusRequest {
+            slave_id,
+            function: read_function(function)?,
+            address: read_u16(reader)?,
+            quantity: read_u16(reader)?,
+            data: read_bytes(reader)?,
+            timeout: match u32::from_be_bytes(read_array(reader)?) {
+                0 => None,
+                ms => Some(Duration::from_millis(u64::from(ms))),
+            },
+        };
+
+        let response = match read_array::<1, _>(reader)?[0] {
+            OUTCOME_RESPONSE => {
+                let [slave_id, function, exception] = read_array(reader)?;
+                let function = read_function(function)?;
+                let data = read_bytes(reader)?;
+                Ok(match exception {
+                    0 => ModbusResponse::new_success(slave_id, function, data),
+                    code => ModbusResponse::new_exception(slave_id, function, code),
+                })
+            }
+            OUTCOME_TIMEOUT => {
+                let operation = read_string(reader)?;
+                let timeout_ms = u64::from_be_bytes(read_array(reader)?);
+                Err(ModbusError::timeout(operation, timeout_ms))
+            }
+            OUTCOME_ERROR => Err(ModbusError::connection(read_string(reader)?)),
+            tag => return Err(invalid(format!("unknown capture outcome tag {}", tag))),
+        };
+
+        frames.push(CaptureFrame {
+            timestamp,
+            transaction_id,
+            request,
+            response,
+        });
+    }
+}
+
+fn write_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> io::Result<()> {
+    let len = u16::try_from(bytes.len()).map_err(|_| invalid("capture field too long"))?;
+    writer.write_all(&len.to_be_bytes())?;
+    writer.write_all(bytes)
+}
+
+fn read_array<const N: usize, R: Read>(reader: &mut R) -> io::Result<[u8; N]> {
+    let mut buf = [0u8; N];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn read_u16<R: Read>(reader: &mut R) -> io::Result<u16> {
+    read_array(reader).map(u16::from_be_bytes)
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![0u8; usize::from(read_u16(reader)?)];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_string<R: Read>(reader: &mut R) -> io::Result<String> {
+    String::from_utf8(read_bytes(reader)?).map_err(|e| invalid(e.to_string()))
+}
+
+fn read_function(code: u8) -> io::Result<ModbusFunction> {
+    ModbusFunction::from_u8(code).map_err(|e| invalid(e.to_string()))
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ScriptedTransport;
+
+    fn read(address: u16) -> ModbusRequest {
+        ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, address, 1)
+    }
+
+    #[tokio::test]
+    async fn capture_round_trips_through_the_file_format() {
+        let mut capture = CaptureTransport::new(ScriptedTransport::new(vec![
+            Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::ReadHoldingRegisters,
+                vec![2, 0x12, 0x34],
+            )),
+            Ok(ModbusResponse::new_exception(
+                1,
+                ModbusFunction::ReadHoldingRegisters,
+                0x02,
+            )),
+            Err(ModbusError::timeout("read response", 500)),
+            Err(ModbusError::connection("reset by peer")),
+        ]));
+        let mut timed = read(3);
+        timed.timeout = Some(Duration::from_millis(250));
+        for request in [read(0), read(1), read(2), timed] {
+            let _ = capture.request(&request).await;
+        }
+        let ids: Vec<_> = capture.frames().iter().map(|f| f.transaction_id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+
+        let mut file = Vec::new();
+        write_capture(&mut file, capture.frames()).unwrap();
+        let frames = read_capture(&mut file.as_slice()).unwrap();
+        assert_eq!(frames.len(), 4);
+        for (loaded, captured) in frames.iter().zip(capture.frames()) {
+            assert_eq!(loaded.transaction_id, captured.transaction_id);
+            assert_eq!(loaded.request, captured.request);
+            assert_eq!(
+                loaded
+                    .timestamp
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_micros(),
+                captured
+                    .timestamp
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_micros()
+            );
+        }
+        for (loaded, captured) in frames.iter().zip(capture.frames()).take(3) {
+            assert_eq!(loaded.response, captured.response);
+        }
+        assert!(
+            matches!(&frames[3].response, Err(ModbusError::Connection { message, .. })
+            if message.contains("reset by peer"))
+        );
+
+        assert!(read_capture(&mut &b"VMCAP\x09"[..]).is_err());
+        assert!(read_capture(&mut &b"PCAP00"[..]).is_err());
+    }
+
+    #[tokio::test]
+    async fn replay_answers_in_order_then_runs_dry() {
+        let frames = vec![CaptureFrame {
+            timestamp: SystemTime::now(),
+            transaction_id: 1,
+            request: read(0),
+            response: Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::ReadHoldingRegisters,
+                vec![2, 0, 7],
+            )),
+        }];
+        let mut replay = ReplayTransport::new(frames);
+
+        let err = replay.request(&read(5)).await.unwrap_err();
+        assert!(matches!(err, ModbusError::Protocol { .. }));
+        assert_eq!(
+            replay.remaining(),
+            1,
+            "a mismatch does not consume the frame"
+        );
+
+        let response = replay.request(&read(0)).await.unwrap();
+        assert_eq!(response.parse_registers().unwrap(), vec![7]);
+        let err = replay.request(&read(0)).await.unwrap_err();
+        assert!(matches!(err, ModbusError::Connection { .. }));
+        assert_eq!(replay.get_stats().requests_sent, 1);
+    }
+}
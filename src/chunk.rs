@@ -0,0 +1,223 @@
+//! Lazy chunking of large address ranges
+//!
+//! A single Modbus read is capped at 125 registers or 2000 coils (often less on
+//! real devices). [`RegisterRangeChunker`] and [`CoilRangeChunker`] split a
+//! larger range into consecutive request-sized pieces one at a time, so callers
+//! can process each chunk as it arrives instead of waiting for the whole
+//! batch. The `_batch` methods of [`ModbusClient`](crate::client::ModbusClient)
+//! are built on them.
+//!
+//! ```rust
+//! use voltage_modbus::chunk::{RegisterRange, RegisterRangeChunker};
+//!
+//! let chunks: Vec<RegisterRange> = RegisterRangeChunker::new(0, 120, 50).collect();
+//! assert_eq!(
+//!     chunks,
+//!     [
+//!         RegisterRange { start: 0, count: 50 },
+//!         RegisterRange { start: 50, count: 50 },
+//!         RegisterRange { start: 100, count: 20 },
+//!     ]
+//! );
+//! ```
+//!
+//! Driving a batch by hand:
+//!
+//! ```rust,no_run
+//! use voltage_modbus::chunk::RegisterRangeChunker;
+//! use voltage_modbus::ModbusClient;
+//!
+//! # async fn example(client: &mut impl ModbusClient) -> voltage_modbus::ModbusResult<()> {
+//! for range in RegisterRangeChunker::new(0, 500, 50) {
+//!     let values = client.read_03(1, range.start, range.count).await?;
+//!     println!("{}..: {:?}", range.start, values);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::iter::FusedIterator;
+
+/// A contiguous run of registers for one read request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegisterRange {
+    /// First register address
+    pub start: u16,
+    /// Number of registers
+    pub count: u16,
+}
+
+/// A contiguous run of coils or discrete inputs for one read request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CoilRange {
+    /// First coil address
+    pub start: u16,
+    /// Number of coils
+    pub count: u16,
+}
+
+/// Shared chunking state
+#[derive(Debug, Clone)]
+struct Chunker {
+    start: u16,
+    remaining: u16,
+    chunk_size: u16,
+}
+
+impl Chunker {
+    fn new(start: u16, quantity: u16, chunk_size: u16) -> Self {
+        Self {
+            start,
+            remaining: quantity,
+            // A zero chunk size would never make progress
+            chunk_size: chunk_size.max(1),
+        }
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<(u16, u16)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let count = self.remaining.min(self.chunk_size);
+        let start = self.start;
+        self.start = self.start.saturating_add(count);
+        self.remaining -= count;
+        Some((start, count))
+    }
+
+    #[inline]
+    fn chunks_left(&self) -> usize {
+        usize::from(self.remaining).div_ceil(usize::from(self.chunk_size))
+    }
+}
+
+/// Iterator over request-sized [`RegisterRange`]s of a larger range
+#[derive(Debug, Clone)]
+pub struct RegisterRangeChunker {
+    inner: Chunker,
+}
+
+impl RegisterRangeChunker {
+    /// Split `quantity` registers from `start` into chunks of at most `chunk_size`
+    ///
+    /// A `chunk_size` of 0 is treated as 1.
+    pub fn new(start: u16, quantity: u16, chunk_size: u16) -> Self {
+        Self {
+            inner: Chunker::new(start, quantity, chunk_size),
+        }
+    }
+}
+
+impl Iterator for RegisterRangeChunker {
+    type Item = RegisterRange;
+
+    fn next(&mut self) -> Option<RegisterRange> {
+        self.inner
+            .next()
+            .map(|(start, count)| RegisterRange { start, count })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.inner.chunks_left();
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for RegisterRangeChunker {}
+impl FusedIterator for RegisterRangeChunker {}
+
+/// Iterator over request-sized [`CoilRange`]s of a larger range
+#[derive(Debug, Clone)]
+pub struct CoilRangeChunker {
+    inner: Chunker,
+}
+
+impl CoilRangeChunker {
+    /// Split `quantity` coils from `start` into chunks of at most `chunk_size`
+    ///
+    /// A `chunk_size` of 0 is treated as 1.
+    pub fn new(start: u16, quantity: u16, chunk_size: u16) -> Self {
+        Self {
+            inner: Chunker::new(start, quantity, chunk_size),
+        }
+    }
+}
+
+impl Iterator for CoilRangeChunker {
+    type Item = CoilRange;
+
+    fn next(&mut self) -> Option<CoilRange> {
+        self.inner
+            .next()
+            .map(|(start, count)| CoilRange { start, count })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.inner.chunks_left();
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for CoilRangeChunker {}
+impl FusedIterator for CoilRangeChunker {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registers(start: u16, quantity: u16, chunk_size: u16) -> Vec<(u16, u16)> {
+        RegisterRangeChunker::new(start, quantity, chunk_size)
+            .map(|r| (r.start, r.count))
+            .collect()
+    }
+
+    #[test]
+    fn exact_multiple() {
+        assert_eq!(
+            registers(100, 375, 125),
+            [(100, 125), (225, 125), (350, 125)]
+        );
+    }
+
+    #[test]
+    fn remainder_chunk() {
+        assert_eq!(registers(0, 120, 50), [(0, 50), (50, 50), (100, 20)]);
+        let coils: Vec<_> = CoilRangeChunker::new(10, 4500, 2000).collect();
+        assert_eq!(
+            coils,
+            [
+                CoilRange {
+                    start: 10,
+                    count: 2000
+                },
+                CoilRange {
+                    start: 2010,
+                    count: 2000
+                },
+                CoilRange {
+                    start: 4010,
+                    count: 500
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn single_chunk_and_empty() {
+        assert_eq!(registers(7, 10, 125), [(7, 10)]);
+        assert_eq!(registers(7, 0, 125), []);
+        assert_eq!(registers(0, 3, 0), [(0, 1), (1, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn exact_size_tracks_progress() {
+        let mut chunks = RegisterRangeChunker::new(0, 251, 125);
+        assert_eq!(chunks.len(), 3);
+        chunks.next();
+        assert_eq!(chunks.len(), 2);
+        chunks.by_ref().for_each(drop);
+        assert_eq!(chunks.len(), 0);
+        assert_eq!(chunks.next(), None);
+    }
+}
@@ -53,15 +53,26 @@
 //!     Ok(())
 //! }
 //! ```
+use std::collections::{BTreeMap, HashMap};
 use std::net::SocketAddr;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use tracing::Instrument;
+
+use crate::capabilities::DeviceIdentification;
+use crate::chunk::{CoilRangeChunker, RegisterRangeChunker};
 use crate::coalescer::ReadCoalescer;
-use crate::device_limits::DeviceLimits;
+use crate::codec::ModbusCodec;
+use crate::device_limits::{DeviceLimits, InterRequestDelay};
 use crate::error::{ModbusError, ModbusResult};
 use crate::logging::CallbackLogger;
+use crate::poller::RegisterPoller;
 use crate::protocol::{ModbusFunction, ModbusRequest, ModbusResponse, SlaveId};
+use crate::reliability::CircuitBreaker;
+use crate::snapshot::{plan_blocks, RegisterDescriptor, RegisterSnapshot};
 use crate::transport::{ModbusTransport, TcpTransport, TransportStats};
+use crate::utils::{PerformanceMetrics, RegisterBlockCrc};
+use crate::value::ModbusValue;
 
 #[cfg(feature = "rtu")]
 use crate::transport::RtuTransport;
@@ -147,6 +158,24 @@ pub trait ModbusClient: Send + Sync {
         quantity: u16,
     ) -> impl std::future::Future<Output = ModbusResult<Vec<u16>>> + Send;
 
+    /// Read holding registers (function code 0x03), waiting at most
+    /// `timeout` for the response instead of the client's own timeout.
+    ///
+    /// The transport applies the timeout, so a slow read is never cancelled
+    /// halfway through a frame; see [`ModbusRequest::timeout`] for the
+    /// transports that honour it. The default implementation ignores
+    /// `timeout` and calls [`read_03`](Self::read_03).
+    fn read_03_with_timeout(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        timeout: Duration,
+    ) -> impl std::future::Future<Output = ModbusResult<Vec<u16>>> + Send {
+        let _ = timeout;
+        self.read_03(slave_id, address, quantity)
+    }
+
     /// Read input registers (function code 0x04).
     ///
     /// Reads the contents of a contiguous block of input registers.
@@ -228,6 +257,75 @@ pub trait ModbusClient: Send + Sync {
         values: &[u16],
     ) -> impl std::future::Future<Output = ModbusResult<()>> + Send;
 
+    /// Read exception status (function code 0x07).
+    ///
+    /// Returns the eight device-defined status bits in one byte, a cheap way
+    /// to check for a fault before a full register read. Clients without FC07
+    /// support fail with [`ModbusError::InvalidFunction`] for code 0x07.
+    fn read_exception_status(
+        &mut self,
+        slave_id: SlaveId,
+    ) -> impl std::future::Future<Output = ModbusResult<u8>> + Send {
+        let _ = slave_id;
+        std::future::ready(Err(ModbusError::invalid_function(0x07)))
+    }
+
+    /// Mask write register (function code 0x16).
+    ///
+    /// The device sets the register to
+    /// `(current & and_mask) | (or_mask & !and_mask)` in one step, so single
+    /// bits can be changed without a read-modify-write race. Clients without
+    /// FC22 support fail with [`ModbusError::InvalidFunction`] for code 0x16.
+    ///
+    /// # Arguments
+    ///
+    /// * `slave_id` - The Modbus slave/unit ID (1-247)
+    /// * `address` - Register address (0-65535)
+    /// * `and_mask` - Bits to keep from the current value
+    /// * `or_mask` - Bits to set among those cleared by `and_mask`
+    fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> impl std::future::Future<Output = ModbusResult<()>> + Send {
+        let _ = (slave_id, address, and_mask, or_mask);
+        std::future::ready(Err(ModbusError::invalid_function(0x16)))
+    }
+
+    /// Read/write multiple registers (function code 0x17).
+    ///
+    /// Writes `write_values` starting at `write_address`, then reads
+    /// `read_quantity` holding registers from `read_address`, in a single
+    /// round trip. The device performs the write first. Clients without FC23
+    /// support fail with [`ModbusError::InvalidFunction`] for code 0x17.
+    ///
+    /// # Arguments
+    ///
+    /// * `slave_id` - The Modbus slave/unit ID (1-247)
+    /// * `read_address` - Starting register address to read (0-65535)
+    /// * `read_quantity` - Number of registers to read (1-125)
+    /// * `write_address` - Starting register address to write (0-65535)
+    /// * `write_values` - Values to write (1-121 registers)
+    fn read_write_registers(
+        &mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &[u16],
+    ) -> impl std::future::Future<Output = ModbusResult<Vec<u16>>> + Send {
+        let _ = (
+            slave_id,
+            read_address,
+            read_quantity,
+            write_address,
+            write_values,
+        );
+        std::future::ready(Err(ModbusError::invalid_function(0x17)))
+    }
+
     // ===== Batch read operations =====
 
     /// Batch read coils (function code 0x01) with automatic chunking.
@@ -272,26 +370,19 @@ pub trait ModbusClient: Send + Sync {
         Self: Sized,
     {
         let max_read_coils = limits.max_read_coils;
-        let inter_request_delay_ms = limits.inter_request_delay_ms;
+        let mut delay = InterRequestDelay::new(limits);
         async move {
-            if quantity == 0 {
-                return Ok(Vec::new());
-            }
-
             let mut result = Vec::with_capacity(quantity as usize);
-            let mut current_address = address;
-            let mut remaining = quantity;
+            let mut chunks = CoilRangeChunker::new(address, quantity, max_read_coils);
 
-            while remaining > 0 {
-                let count = remaining.min(max_read_coils);
-                let chunk = self.read_01(slave_id, current_address, count).await?;
+            while let Some(range) = chunks.next() {
+                let started = Instant::now();
+                let chunk = self.read_01(slave_id, range.start, range.count).await?;
+                delay.observe(started.elapsed());
                 result.extend_from_slice(&chunk);
 
-                current_address = current_address.saturating_add(count);
-                remaining -= count;
-
-                if inter_request_delay_ms > 0 && remaining > 0 {
-                    tokio::time::sleep(Duration::from_millis(inter_request_delay_ms)).await;
+                if !delay.current().is_zero() && chunks.len() > 0 {
+                    tokio::time::sleep(delay.current()).await;
                 }
             }
 
@@ -321,26 +412,19 @@ pub trait ModbusClient: Send + Sync {
         Self: Sized,
     {
         let max_read_coils = limits.max_read_coils;
-        let inter_request_delay_ms = limits.inter_request_delay_ms;
+        let mut delay = InterRequestDelay::new(limits);
         async move {
-            if quantity == 0 {
-                return Ok(Vec::new());
-            }
-
             let mut result = Vec::with_capacity(quantity as usize);
-            let mut current_address = address;
-            let mut remaining = quantity;
+            let mut chunks = CoilRangeChunker::new(address, quantity, max_read_coils);
 
-            while remaining > 0 {
-                let count = remaining.min(max_read_coils);
-                let chunk = self.read_02(slave_id, current_address, count).await?;
+            while let Some(range) = chunks.next() {
+                let started = Instant::now();
+                let chunk = self.read_02(slave_id, range.start, range.count).await?;
+                delay.observe(started.elapsed());
                 result.extend_from_slice(&chunk);
 
-                current_address = current_address.saturating_add(count);
-                remaining -= count;
-
-                if inter_request_delay_ms > 0 && remaining > 0 {
-                    tokio::time::sleep(Duration::from_millis(inter_request_delay_ms)).await;
+                if !delay.current().is_zero() && chunks.len() > 0 {
+                    tokio::time::sleep(delay.current()).await;
                 }
             }
 
@@ -353,6 +437,11 @@ pub trait ModbusClient: Send + Sync {
     /// Reads a large range of holding registers by automatically splitting the request
     /// into smaller chunks according to device limits.
     ///
+    /// Chunks are `limits.inter_request_delay_ms` apart; with
+    /// `limits.adaptive_delay_enabled` the delay follows the device's response
+    /// time instead (see [`device_limits`](crate::device_limits#adaptive-delay)).
+    /// The other batch methods space their chunks the same way.
+    ///
     /// # Arguments
     ///
     /// * `slave_id` - The Modbus slave/unit ID (1-247)
@@ -390,26 +479,19 @@ pub trait ModbusClient: Send + Sync {
         Self: Sized,
     {
         let max_read_registers = limits.max_read_registers;
-        let inter_request_delay_ms = limits.inter_request_delay_ms;
+        let mut delay = InterRequestDelay::new(limits);
         async move {
-            if quantity == 0 {
-                return Ok(Vec::new());
-            }
-
             let mut result = Vec::with_capacity(quantity as usize);
-            let mut current_address = address;
-            let mut remaining = quantity;
+            let mut chunks = RegisterRangeChunker::new(address, quantity, max_read_registers);
 
-            while remaining > 0 {
-                let count = remaining.min(max_read_registers);
-                let chunk = self.read_03(slave_id, current_address, count).await?;
+            while let Some(range) = chunks.next() {
+                let started = Instant::now();
+                let chunk = self.read_03(slave_id, range.start, range.count).await?;
+                delay.observe(started.elapsed());
                 result.extend_from_slice(&chunk);
 
-                current_address = current_address.saturating_add(count);
-                remaining -= count;
-
-                if inter_request_delay_ms > 0 && remaining > 0 {
-                    tokio::time::sleep(Duration::from_millis(inter_request_delay_ms)).await;
+                if !delay.current().is_zero() && chunks.len() > 0 {
+                    tokio::time::sleep(delay.current()).await;
                 }
             }
 
@@ -439,26 +521,19 @@ pub trait ModbusClient: Send + Sync {
         Self: Sized,
     {
         let max_read_registers = limits.max_read_registers;
-        let inter_request_delay_ms = limits.inter_request_delay_ms;
+        let mut delay = InterRequestDelay::new(limits);
         async move {
-            if quantity == 0 {
-                return Ok(Vec::new());
-            }
-
             let mut result = Vec::with_capacity(quantity as usize);
-            let mut current_address = address;
-            let mut remaining = quantity;
+            let mut chunks = RegisterRangeChunker::new(address, quantity, max_read_registers);
 
-            while remaining > 0 {
-                let count = remaining.min(max_read_registers);
-                let chunk = self.read_04(slave_id, current_address, count).await?;
+            while let Some(range) = chunks.next() {
+                let started = Instant::now();
+                let chunk = self.read_04(slave_id, range.start, range.count).await?;
+                delay.observe(started.elapsed());
                 result.extend_from_slice(&chunk);
 
-                current_address = current_address.saturating_add(count);
-                remaining -= count;
-
-                if inter_request_delay_ms > 0 && remaining > 0 {
-                    tokio::time::sleep(Duration::from_millis(inter_request_delay_ms)).await;
+                if !delay.current().is_zero() && chunks.len() > 0 {
+                    tokio::time::sleep(delay.current()).await;
                 }
             }
 
@@ -466,6 +541,335 @@ pub trait ModbusClient: Send + Sync {
         }
     }
 
+    /// Batch write coils (function code 0x0F) with automatic chunking.
+    ///
+    /// Writes `values` from `address` in consecutive requests of at most
+    /// `limits.max_write_coils` coils. Stops at the first failed request;
+    /// chunks written before it stay written.
+    ///
+    /// # Arguments
+    ///
+    /// * `slave_id` - The Modbus slave/unit ID (1-247)
+    /// * `address` - Starting coil address (0-65535)
+    /// * `values` - Coil values to write (can exceed 1968)
+    /// * `limits` - Device-specific limits configuration
+    fn write_0f_batch(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &[bool],
+        limits: &DeviceLimits,
+    ) -> impl std::future::Future<Output = ModbusResult<()>> + Send
+    where
+        Self: Sized,
+    {
+        let max_write_coils = limits.max_write_coils;
+        let mut delay = InterRequestDelay::new(limits);
+        async move {
+            let quantity = batch_quantity(values.len())?;
+            let mut chunks = CoilRangeChunker::new(address, quantity, max_write_coils);
+            let mut written = 0;
+
+            while let Some(range) = chunks.next() {
+                let count = usize::from(range.count);
+                let started = Instant::now();
+                self.write_0f(slave_id, range.start, &values[written..written + count])
+                    .await?;
+                delay.observe(started.elapsed());
+                written += count;
+
+                if !delay.current().is_zero() && chunks.len() > 0 {
+                    tokio::time::sleep(delay.current()).await;
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Batch write holding registers (function code 0x10) with automatic chunking.
+    ///
+    /// Writes `values` from `address` in consecutive requests of at most
+    /// `limits.max_write_registers` registers. Stops at the first failed
+    /// request; chunks written before it stay written.
+    ///
+    /// # Arguments
+    ///
+    /// * `slave_id` - The Modbus slave/unit ID (1-247)
+    /// * `address` - Starting register address (0-65535)
+    /// * `values` - Register values to write (can exceed 123)
+    /// * `limits` - Device-specific limits configuration
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use voltage_modbus::{ModbusTcpClient, ModbusClient, DeviceLimits};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> voltage_modbus::ModbusResult<()> {
+    /// let mut client = ModbusTcpClient::from_address("127.0.0.1:502", Duration::from_secs(5)).await?;
+    /// let limits = DeviceLimits::new();
+    ///
+    /// // Write 200 registers (split into 4 requests of 50 each)
+    /// let setpoints = vec![0u16; 200];
+    /// client.write_10_batch(1, 1000, &setpoints, &limits).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn write_10_batch(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &[u16],
+        limits: &DeviceLimits,
+    ) -> impl std::future::Future<Output = ModbusResult<()>> + Send
+    where
+        Self: Sized,
+    {
+        let max_write_registers = limits.max_write_registers;
+        let mut delay = InterRequestDelay::new(limits);
+        async move {
+            let quantity = batch_quantity(values.len())?;
+            let mut chunks = RegisterRangeChunker::new(address, quantity, max_write_registers);
+            let mut written = 0;
+
+            while let Some(range) = chunks.next() {
+                let count = usize::from(range.count);
+                let started = Instant::now();
+                self.write_10(slave_id, range.start, &values[written..written + count])
+                    .await?;
+                delay.observe(started.elapsed());
+                written += count;
+
+                if !delay.current().is_zero() && chunks.len() > 0 {
+                    tokio::time::sleep(delay.current()).await;
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    // ===== Verified writes =====
+
+    /// Write a single register (FC06), then read it back (FC03) to confirm.
+    ///
+    /// Waits `verification_delay` between the write and the read-back, for
+    /// devices that apply writes asynchronously; pass `Duration::ZERO` to
+    /// read back immediately. Fails with [`ModbusError::VerificationFailed`]
+    /// if the register does not hold `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use voltage_modbus::{ModbusTcpClient, ModbusClient};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> voltage_modbus::ModbusResult<()> {
+    /// let mut client = ModbusTcpClient::from_address("127.0.0.1:502", Duration::from_secs(5)).await?;
+    /// // Open the valve and make sure the PLC took the command
+    /// client.write_06_verify(1, 200, 1, Duration::from_millis(50)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn write_06_verify(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        value: u16,
+        verification_delay: Duration,
+    ) -> impl std::future::Future<Output = ModbusResult<()>> + Send
+    where
+        Self: Sized,
+    {
+        async move {
+            self.write_06(slave_id, address, value).await?;
+            if !verification_delay.is_zero() {
+                tokio::time::sleep(verification_delay).await;
+            }
+            let actual = self.read_03(slave_id, address, 1).await?;
+            verify_readback(address, &[value], &actual)
+        }
+    }
+
+    /// Write multiple registers (FC16), then read them back (FC03) to confirm.
+    ///
+    /// As [`write_06_verify`](Self::write_06_verify); the error names the
+    /// first register whose read-back differs.
+    fn write_10_verify(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &[u16],
+        verification_delay: Duration,
+    ) -> impl std::future::Future<Output = ModbusResult<()>> + Send
+    where
+        Self: Sized,
+    {
+        async move {
+            self.write_10(slave_id, address, values).await?;
+            if !verification_delay.is_zero() {
+                tokio::time::sleep(verification_delay).await;
+            }
+            let quantity = batch_quantity(values.len())?;
+            let actual = self.read_03(slave_id, address, quantity).await?;
+            verify_readback(address, values, &actual)
+        }
+    }
+
+    /// Write `new_value` to a holding register only if it currently holds
+    /// `expected`; returns whether the register now holds `new_value`.
+    ///
+    /// The register is read first (FC03); on a mismatch nothing is written
+    /// and `Ok(false)` is returned. Modbus has no true compare-and-swap, so
+    /// another master can still write between the read and the write:
+    ///
+    /// - [`AtomicMode::Hardware`] writes with Mask Write Register (FC22),
+    ///   changing only the bits in which `expected` and `new_value` differ.
+    ///   The device applies the masks to the register's value at write time,
+    ///   so concurrent changes to the other bits are preserved. Because of
+    ///   that, the register is read back afterwards: if another master
+    ///   changed it in between, the result differs from `new_value` and
+    ///   `Ok(false)` is returned even though the masks were applied.
+    /// - [`AtomicMode::Software`] writes the whole register with FC06.
+    /// - [`AtomicMode::Auto`] uses FC22 (with the read-back) and falls back
+    ///   to FC06 when the device or client does not support it (illegal
+    ///   function).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use voltage_modbus::{AtomicMode, ModbusTcpClient, ModbusClient};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> voltage_modbus::ModbusResult<()> {
+    /// let mut client = ModbusTcpClient::from_address("127.0.0.1:502", Duration::from_secs(5)).await?;
+    /// // Take the semaphore in register 500: 0 = free, 1 = held by us
+    /// if client.conditional_write(1, 500, 0, 1, AtomicMode::Auto).await? {
+    ///     // ... critical section ...
+    ///     client.write_06(1, 500, 0).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn conditional_write(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        expected: u16,
+        new_value: u16,
+        mode: AtomicMode,
+    ) -> impl std::future::Future<Output = ModbusResult<bool>> + Send
+    where
+        Self: Sized,
+    {
+        async move {
+            let current = self.read_03(slave_id, address, 1).await?;
+            if current.first() != Some(&expected) {
+                return Ok(false);
+            }
+            if expected == new_value {
+                return Ok(true);
+            }
+
+            let changed = expected ^ new_value;
+            let and_mask = !changed;
+            let or_mask = new_value & changed;
+            let masked = match mode {
+                AtomicMode::Software => false,
+                AtomicMode::Hardware => {
+                    self.mask_write_register(slave_id, address, and_mask, or_mask)
+                        .await?;
+                    true
+                }
+                AtomicMode::Auto => {
+                    match self
+                        .mask_write_register(slave_id, address, and_mask, or_mask)
+                        .await
+                    {
+                        Err(e) if is_unsupported_function(&e) => false,
+                        result => {
+                            result?;
+                            true
+                        }
+                    }
+                }
+            };
+            if !masked {
+                self.write_06(slave_id, address, new_value).await?;
+                return Ok(true);
+            }
+            // FC22 only touched the changed bits; confirm the whole register
+            let actual = self.read_03(slave_id, address, 1).await?;
+            Ok(actual.first() == Some(&new_value))
+        }
+    }
+
+    // ===== Bits within holding registers =====
+
+    /// Read bit `bit_position` (0–15, LSB = 0) of a holding register (FC03).
+    fn read_bit(
+        &mut self,
+        slave_id: SlaveId,
+        register_address: u16,
+        bit_position: u8,
+    ) -> impl std::future::Future<Output = ModbusResult<bool>> + Send
+    where
+        Self: Sized,
+    {
+        async move {
+            check_bit_position(bit_position)?;
+            let registers = self.read_03(slave_id, register_address, 1).await?;
+            let value = crate::codec::decode_register_value(
+                &registers,
+                "bool",
+                bit_position,
+                crate::bytes::ByteOrder::BigEndian,
+                None,
+            )?;
+            Ok(matches!(value, ModbusValue::Bool(true)))
+        }
+    }
+
+    /// Set or clear bit `bit_position` (0–15, LSB = 0) of a holding register,
+    /// leaving the other 15 bits unchanged.
+    ///
+    /// Uses Mask Write Register (FC22), which the device applies atomically.
+    /// If the client does not implement FC22 or the device answers with an
+    /// illegal function exception, falls back to reading the register (FC03)
+    /// and writing it back (FC06); another master writing the same register
+    /// in between loses its change.
+    fn write_bit(
+        &mut self,
+        slave_id: SlaveId,
+        register_address: u16,
+        bit_position: u8,
+        value: bool,
+    ) -> impl std::future::Future<Output = ModbusResult<()>> + Send
+    where
+        Self: Sized,
+    {
+        async move {
+            check_bit_position(bit_position)?;
+            let bit = 1u16 << bit_position;
+            let or_mask = if value { bit } else { 0 };
+            match self
+                .mask_write_register(slave_id, register_address, !bit, or_mask)
+                .await
+            {
+                Err(e) if is_unsupported_function(&e) => {
+                    let registers = self.read_03(slave_id, register_address, 1).await?;
+                    let current = registers.first().copied().ok_or_else(|| {
+                        ModbusError::invalid_data("Empty response to single register read")
+                    })?;
+                    self.write_06(slave_id, register_address, (current & !bit) | or_mask)
+                        .await
+                }
+                result => result,
+            }
+        }
+    }
+
     /// Check if the client is connected.
     ///
     /// Returns `true` if the underlying transport is connected and ready.
@@ -632,6 +1036,164 @@ pub trait ModbusClient: Send + Sync {
     {
         self.read_04_batch(slave_id, address, quantity, limits)
     }
+
+    // ===== Diagnostics and identification =====
+
+    /// Diagnostics echo (function code 0x08, sub-function 0x0000).
+    ///
+    /// The device mirrors `data` back, which makes a cheap liveness probe;
+    /// time it with [`OperationTimer`](crate::utils::OperationTimer) to feed
+    /// [`PerformanceMetrics`](crate::utils::PerformanceMetrics). Clients
+    /// without FC08 support fail with [`ModbusError::InvalidFunction`] for
+    /// code 0x08.
+    fn diagnostic_echo(
+        &mut self,
+        slave_id: SlaveId,
+        data: u16,
+    ) -> impl std::future::Future<Output = ModbusResult<u16>> + Send {
+        let _ = (slave_id, data);
+        std::future::ready(Err(ModbusError::invalid_function(0x08)))
+    }
+
+    /// Read Device Identification (function code 0x2B, MEI type 0x0E).
+    ///
+    /// `read_device_id_code` selects the object category (0x01 basic,
+    /// 0x02 regular, 0x03 extended, 0x04 one object) and `object_id` the
+    /// first object to read. Clients without FC43/14 support fail with
+    /// [`ModbusError::InvalidFunction`] for code 0x2B.
+    fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> impl std::future::Future<Output = ModbusResult<DeviceIdentification>> + Send {
+        let _ = (slave_id, read_device_id_code, object_id);
+        std::future::ready(Err(ModbusError::invalid_function(0x2B)))
+    }
+}
+
+/// How [`ModbusClient::conditional_write`] writes the new value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AtomicMode {
+    /// Mask Write Register (FC22), touching only the changed bits.
+    Hardware,
+    /// Write Single Register (FC06).
+    Software,
+    /// FC22, falling back to FC06 if it is not supported.
+    #[default]
+    Auto,
+}
+
+/// Which failed requests a [`RetryPolicy`] sends again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryCondition {
+    /// `Timeout` and `DeviceNotResponding`.
+    OnTimeout,
+    /// `Connection` and `Io` errors.
+    OnConnectionError,
+    /// Any [retryable](ModbusError::is_retryable) error plus corrupt or
+    /// mismatched frames. Invalid requests and device exceptions other than
+    /// busy/acknowledge still fail at once.
+    OnAnyError,
+}
+
+impl RetryCondition {
+    /// Whether `error` is retried under this condition
+    pub fn matches(self, error: &ModbusError) -> bool {
+        match self {
+            Self::OnTimeout => matches!(
+                error,
+                ModbusError::Timeout { .. } | ModbusError::DeviceNotResponding { .. }
+            ),
+            Self::OnConnectionError => {
+                matches!(
+                    error,
+                    ModbusError::Connection { .. } | ModbusError::Io { .. }
+                )
+            }
+            Self::OnAnyError => {
+                error.is_retryable()
+                    || matches!(
+                        error,
+                        ModbusError::Frame { .. }
+                            | ModbusError::CrcMismatch { .. }
+                            | ModbusError::TransactionIdMismatch { .. }
+                    )
+            }
+        }
+    }
+}
+
+/// Automatic retries of failed requests in [`GenericModbusClient`].
+///
+/// Retry `n` (0-based) waits `min(base_delay * 2^n, max_delay)`. Busy and
+/// acknowledge exceptions (codes 0x06 and 0x05) are retried under every
+/// condition after a short, fixed `base_delay`: the device asked to be polled
+/// again later.
+///
+/// Writes are retried like reads; a write whose response timed out may have
+/// been applied already.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use voltage_modbus::client::{RetryCondition, RetryPolicy};
+/// use voltage_modbus::ModbusError;
+///
+/// let policy = RetryPolicy {
+///     max_retries: 3,
+///     retry_on: RetryCondition::OnTimeout,
+///     base_delay: Duration::from_millis(50),
+///     max_delay: Duration::from_millis(150),
+/// };
+/// let timeout = ModbusError::timeout("read", 500);
+/// assert_eq!(policy.retry_delay(&timeout, 1), Some(Duration::from_millis(100)));
+/// assert_eq!(policy.retry_delay(&timeout, 2), Some(Duration::from_millis(150)));
+/// assert_eq!(policy.retry_delay(&ModbusError::exception(0x03, 0x06), 2), Some(Duration::from_millis(50)));
+/// assert_eq!(policy.retry_delay(&ModbusError::connection("reset"), 0), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt.
+    pub max_retries: u32,
+    /// Errors worth retrying.
+    pub retry_on: RetryCondition,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound on the delay between retries.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Delay before retry `retry` (0-based) after `error`, or `None` if the
+    /// error is not retried. Does not check `max_retries`.
+    pub fn retry_delay(&self, error: &ModbusError, retry: u32) -> Option<Duration> {
+        if matches!(error.exception_code(), Some(0x05 | 0x06)) {
+            return Some(self.base_delay.min(self.max_delay));
+        }
+        if !self.retry_on.matches(error) {
+            return None;
+        }
+        let factor = 2u32.checked_pow(retry).unwrap_or(u32::MAX);
+        Some(
+            self.base_delay
+                .checked_mul(factor)
+                .map_or(self.max_delay, |delay| delay.min(self.max_delay)),
+        )
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three retries on timeout, 100 ms doubling up to 2 s.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            retry_on: RetryCondition::OnTimeout,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(2),
+        }
+    }
 }
 
 /// Generic Modbus client that works with any transport
@@ -639,9 +1201,48 @@ pub trait ModbusClient: Send + Sync {
 /// This client implements the common application layer logic (PDU construction and parsing)
 /// while delegating transport-specific concerns to the underlying transport implementation.
 /// This eliminates code duplication between TCP and RTU clients since the PDU is identical.
+///
+/// Transport counters are also attributed to the slave each request was sent
+/// to; see [`get_stats_for_slave`](Self::get_stats_for_slave).
 pub struct GenericModbusClient<T: ModbusTransport> {
     transport: T,
     logger: Option<CallbackLogger>,
+    slave_stats: HashMap<SlaveId, TransportStats>,
+    retry_policy: Option<RetryPolicy>,
+    /// Template for new per-slave breakers; `None` disables circuit breaking
+    breaker_template: Option<CircuitBreaker>,
+    breakers: HashMap<SlaveId, CircuitBreaker>,
+    /// Slave of a guarded request whose outcome is not recorded yet; still
+    /// set on the next call if that request's future was dropped
+    unsettled: Option<SlaveId>,
+    /// Failures per slave and error variant, for `most_frequent_error`
+    error_tallies: HashMap<SlaveId, ErrorTally>,
+    metrics: PerformanceMetrics,
+    /// Reporting through the `metrics` facade; `None` until `with_metrics`
+    #[cfg(feature = "metrics")]
+    telemetry: Option<crate::telemetry::ClientMetrics>,
+}
+
+/// Failure count per error variant, with the latest error of each variant
+type ErrorTally = Vec<(ModbusError, u64)>;
+
+/// Count `count` failures with `error`, which becomes its variant's latest
+fn tally_error(tally: &mut ErrorTally, error: &ModbusError, count: u64) {
+    let kind = std::mem::discriminant(error);
+    match tally
+        .iter_mut()
+        .find(|(seen, _)| std::mem::discriminant(seen) == kind)
+    {
+        Some(entry) => *entry = (error.clone(), entry.1 + count),
+        None => tally.push((error.clone(), count)),
+    }
+}
+
+fn most_frequent(tally: &ErrorTally) -> Option<ModbusError> {
+    tally
+        .iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(error, _)| error.clone())
 }
 
 impl<T: ModbusTransport> GenericModbusClient<T> {
@@ -650,40 +1251,244 @@ impl<T: ModbusTransport> GenericModbusClient<T> {
         Self {
             transport,
             logger: None,
+            slave_stats: HashMap::new(),
+            retry_policy: None,
+            breaker_template: None,
+            breakers: HashMap::new(),
+            unsettled: None,
+            error_tallies: HashMap::new(),
+            metrics: PerformanceMetrics::new(),
+            #[cfg(feature = "metrics")]
+            telemetry: None,
+        }
+    }
+
+    /// Create a client that retries failed requests under `policy`
+    pub fn with_retry_policy(transport: T, policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy: Some(policy),
+            ..Self::new(transport)
+        }
+    }
+
+    /// Create a new generic client with logging
+    pub fn with_logger(transport: T, logger: CallbackLogger) -> Self {
+        Self {
+            logger: Some(logger),
+            ..Self::new(transport)
+        }
+    }
+
+    /// Get a reference to the underlying transport
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Get a mutable reference to the underlying transport
+    pub fn transport_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    /// Give every slave its own copy of `breaker`.
+    ///
+    /// Requests to a slave whose circuit is open fail with
+    /// [`ModbusError::CircuitOpen`] without reaching the transport. Timeouts,
+    /// connection and I/O errors count as failures; any answer from the
+    /// device, exceptions included, counts as a success. A request whose
+    /// future is dropped (e.g. by `tokio::time::timeout`) is recorded with
+    /// [`CircuitBreaker::record_cancelled`] on the next call, so a dropped
+    /// probe does not keep the circuit half-open.
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.breaker_template = Some(breaker);
+        self.breakers.clear();
+        self
+    }
+
+    /// Report every request through the `metrics` facade, named by `config`.
+    ///
+    /// See [`crate::telemetry`] for the metrics and their labels.
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics(mut self, config: crate::telemetry::MetricsConfig) -> Self {
+        self.telemetry = Some(crate::telemetry::ClientMetrics::new(config));
+        self
+    }
+
+    /// The circuit breaker of `slave_id`, once a request has been sent to it
+    pub fn circuit_breaker(&self, slave_id: SlaveId) -> Option<&CircuitBreaker> {
+        self.breakers.get(&slave_id)
+    }
+
+    /// Counters of the requests sent to `slave_id` through this client.
+    ///
+    /// Each request is charged with what the transport counted while it was
+    /// in flight, plus `error_count`/`last_error_at` if it failed. A transport
+    /// shared with other clients may charge their concurrent traffic too.
+    pub fn get_stats_for_slave(&self, slave_id: SlaveId) -> TransportStats {
+        self.slave_stats.get(&slave_id).cloned().unwrap_or_default()
+    }
+
+    /// Latency and success counters of every request executed by this
+    /// client, overall and per function code.
+    pub fn metrics(&self) -> &PerformanceMetrics {
+        &self.metrics
+    }
+
+    /// Execute a raw request
+    ///
+    /// Failed requests are sent again as the client's [`RetryPolicy`] allows;
+    /// the last error is returned once retries are exhausted. Each call is
+    /// recorded once in [`metrics`](Self::metrics), retries included in its
+    /// latency.
+    ///
+    /// Each call runs inside a `modbus_request` tracing span carrying
+    /// `slave_id`, `function_code`, `address`, `quantity` and, once done,
+    /// `duration_ms`; a failure also emits a warning event with
+    /// `exception_code` and `error_type`. A JSON subscriber turns these into
+    /// one structured record per request.
+    pub async fn execute_request(
+        &mut self,
+        request: ModbusRequest,
+    ) -> ModbusResult<ModbusResponse> {
+        let span = tracing::info_span!(
+            "modbus_request",
+            slave_id = request.slave_id,
+            function_code = request.function.to_u8(),
+            address = request.address,
+            quantity = request.quantity,
+            duration_ms = tracing::field::Empty,
+        );
+        let before = self.transport.get_stats();
+        let started = Instant::now();
+        let (result, retries) = match check_outgoing_request(&request) {
+            Ok(()) => self.send_guarded(&request).instrument(span.clone()).await,
+            Err(e) => (Err(e), 0),
+        };
+        let elapsed = started.elapsed();
+        span.record("duration_ms", elapsed.as_secs_f64() * 1000.0);
+        if let Err(e) = &result {
+            tracing::warn!(
+                parent: &span,
+                exception_code = e.exception_code(),
+                error_type = e.kind(),
+                error = %e,
+                "Modbus request failed"
+            );
         }
+        self.metrics
+            .record_function(request.function.to_u8(), elapsed, result.is_ok());
+        #[cfg(feature = "metrics")]
+        if let Some(telemetry) = &self.telemetry {
+            telemetry.record_request(request.slave_id, request.function.to_u8(), elapsed, &result);
+            telemetry.record_connection(self.transport.is_connected());
+        }
+
+        let mut stats = self.transport.get_stats().since(&before);
+        stats.retry_count = u64::from(retries);
+        if let Err(error) = &result {
+            let tally = self.error_tallies.entry(request.slave_id).or_default();
+            tally_error(tally, error, 1);
+            stats.error_count = 1;
+            stats.last_error_at = Some(Instant::now());
+            stats.most_frequent_error = most_frequent(tally);
+        }
+        let slave = self.slave_stats.entry(request.slave_id).or_default();
+        slave.merge(&stats);
+        if stats.most_frequent_error.is_some() {
+            slave.most_frequent_error = stats.most_frequent_error;
+        }
+        result
+    }
+
+    /// Execute a raw request, waiting at most `timeout` for each response
+    /// instead of the transport's default.
+    ///
+    /// See [`ModbusRequest::timeout`] for the transports that honour it.
+    pub async fn execute_request_with_timeout(
+        &mut self,
+        request: ModbusRequest,
+        timeout: Duration,
+    ) -> ModbusResult<ModbusResponse> {
+        self.execute_request(request.with_timeout(timeout)).await
+    }
+
+    /// FC03 with an optional per-request response timeout
+    async fn read_holding_registers_within(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        timeout: Option<Duration>,
+    ) -> ModbusResult<Vec<u16>> {
+        if quantity == 0 || quantity > 125 {
+            return Err(ModbusError::invalid_data("Invalid quantity"));
+        }
+
+        let request = ModbusRequest {
+            slave_id,
+            function: ModbusFunction::ReadHoldingRegisters,
+            address,
+            quantity,
+            data: vec![],
+            timeout,
+        };
+
+        let response = self.execute_request(request).await?;
+        // Use parse_registers() which correctly skips byte_count prefix
+        response.parse_registers()
     }
 
-    /// Create a new generic client with logging
-    pub fn with_logger(transport: T, logger: CallbackLogger) -> Self {
-        Self {
-            transport,
-            logger: Some(logger),
+    /// Send `request` past the slave's circuit breaker and retry it under the
+    /// retry policy; returns the final result and the number of retries.
+    async fn send_guarded(
+        &mut self,
+        request: &ModbusRequest,
+    ) -> (ModbusResult<ModbusResponse>, u32) {
+        // The previous request was cancelled mid-flight (e.g. by
+        // `tokio::time::timeout`); free its probe slot.
+        if let Some(slave_id) = self.unsettled.take() {
+            if let Some(breaker) = self.breakers.get_mut(&slave_id) {
+                breaker.record_cancelled();
+            }
+        }
+        if let Some(template) = &self.breaker_template {
+            let breaker = self
+                .breakers
+                .entry(request.slave_id)
+                .or_insert_with(|| template.clone());
+            if !breaker.allow_request() {
+                return (Err(ModbusError::circuit_open(request.slave_id)), 0);
+            }
+            self.unsettled = Some(request.slave_id);
         }
-    }
-
-    /// Get a reference to the underlying transport
-    pub fn transport(&self) -> &T {
-        &self.transport
-    }
 
-    /// Get a mutable reference to the underlying transport
-    pub fn transport_mut(&mut self) -> &mut T {
-        &mut self.transport
-    }
+        let mut retries = 0;
+        let result = loop {
+            let result = self.send_request(request).await;
+            let delay = match (&result, self.retry_policy) {
+                (Err(error), Some(policy)) if retries < policy.max_retries => {
+                    policy.retry_delay(error, retries)
+                }
+                _ => None,
+            };
+            let Some(delay) = delay else {
+                break result;
+            };
+            tokio::time::sleep(delay).await;
+            retries += 1;
+        };
 
-    /// Execute a raw request
-    pub async fn execute_request(
-        &mut self,
-        request: ModbusRequest,
-    ) -> ModbusResult<ModbusResponse> {
-        // Reject broadcast reads early — no response would ever arrive.
-        if request.slave_id == 0 && request.function.is_read_function() {
-            return Err(ModbusError::invalid_data(
-                "Broadcast (slave_id=0) is only valid for write operations",
-            ));
+        self.unsettled = None;
+        if let Some(breaker) = self.breakers.get_mut(&request.slave_id) {
+            match &result {
+                // A busy device answered, so only link failures count.
+                Err(e) if e.is_retryable() && !e.is_device_exception() => breaker.record_failure(),
+                _ => breaker.record_success(),
+            }
         }
-        request.validate()?;
+        (result, retries)
+    }
 
+    async fn send_request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
         // Log request if logger is available
         // Note: For accurate packet logging with real TID, use transport.set_packet_callback()
         if let Some(ref logger) = self.logger {
@@ -700,8 +1505,8 @@ impl<T: ModbusTransport> GenericModbusClient<T> {
         // For broadcast writes (slave_id = 0) the transport layer returns a synthetic
         // ack immediately without waiting for a response (Modbus spec: no reply expected).
         // Regular unicast requests wait for the real device response.
-        let response = self.transport.request(&request).await?;
-        validate_response_matches_request(&request, &response)?;
+        let response = self.transport.request(request).await?;
+        validate_response_matches_request(request, &response)?;
 
         // Log response if logger is available
         if let Some(ref logger) = self.logger {
@@ -717,7 +1522,61 @@ impl<T: ModbusTransport> GenericModbusClient<T> {
     }
 }
 
-fn validate_response_matches_request(
+/// Reject requests that cannot be sent, before they reach the transport.
+fn check_outgoing_request(request: &ModbusRequest) -> ModbusResult<()> {
+    // Reject broadcast reads early — no response would ever arrive.
+    if request.slave_id == 0 && request.function.is_read_function() {
+        return Err(ModbusError::invalid_data(
+            "Broadcast (slave_id=0) is only valid for write operations",
+        ));
+    }
+    request.validate()
+}
+
+/// Quantity of a batch write; a batch cannot cover more than the address space.
+fn batch_quantity(len: usize) -> ModbusResult<u16> {
+    u16::try_from(len).map_err(|_| {
+        ModbusError::invalid_data(format!("Batch of {} values exceeds the address space", len))
+    })
+}
+
+/// Reject bit positions outside a 16-bit register.
+fn check_bit_position(bit_position: u8) -> ModbusResult<()> {
+    if bit_position > 15 {
+        return Err(ModbusError::invalid_data(format!(
+            "Invalid bit position: {} (must be 0-15)",
+            bit_position
+        )));
+    }
+    Ok(())
+}
+
+/// Whether `error` says the function code is not supported, by the client
+/// or by the device (illegal function exception).
+fn is_unsupported_function(error: &ModbusError) -> bool {
+    matches!(error, ModbusError::InvalidFunction { .. }) || error.exception_code() == Some(0x01)
+}
+
+/// Compare registers read back from `address` with the values written there.
+fn verify_readback(address: u16, expected: &[u16], actual: &[u16]) -> ModbusResult<()> {
+    if actual.len() != expected.len() {
+        return Err(ModbusError::invalid_data(format!(
+            "Read back {} registers, expected {}",
+            actual.len(),
+            expected.len()
+        )));
+    }
+    match expected.iter().zip(actual).position(|(e, a)| e != a) {
+        Some(i) => Err(ModbusError::verification_failed(
+            address.wrapping_add(i as u16),
+            expected[i],
+            actual[i],
+        )),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn validate_response_matches_request(
     request: &ModbusRequest,
     response: &ModbusResponse,
 ) -> ModbusResult<()> {
@@ -748,7 +1607,9 @@ fn validate_response_matches_request(
         ModbusFunction::ReadCoils | ModbusFunction::ReadDiscreteInputs => {
             validate_read_byte_count(request, response, usize::from(request.quantity.div_ceil(8)))
         }
-        ModbusFunction::ReadHoldingRegisters | ModbusFunction::ReadInputRegisters => {
+        ModbusFunction::ReadHoldingRegisters
+        | ModbusFunction::ReadInputRegisters
+        | ModbusFunction::ReadWriteMultipleRegisters => {
             validate_read_byte_count(request, response, usize::from(request.quantity) * 2)
         }
         ModbusFunction::WriteSingleCoil => validate_write_echo(
@@ -772,6 +1633,31 @@ fn validate_response_matches_request(
         ModbusFunction::WriteMultipleCoils | ModbusFunction::WriteMultipleRegisters => {
             validate_write_echo(response, request.address, request.quantity)
         }
+        ModbusFunction::MaskWriteRegister => validate_mask_write_echo(request, response),
+        ModbusFunction::Diagnostics if response.data() != request.data.as_slice() => {
+            Err(ModbusError::protocol(format!(
+                "Diagnostics echo mismatch: expected {:02X?}, got {:02X?}",
+                request.data,
+                response.data()
+            )))
+        }
+        ModbusFunction::Diagnostics => Ok(()),
+        ModbusFunction::EncapsulatedInterfaceTransport => {
+            match (request.data.first(), response.data().first()) {
+                (Some(expected), Some(actual)) if expected == actual => Ok(()),
+                (expected, actual) => Err(ModbusError::protocol(format!(
+                    "MEI type mismatch: expected {:02X?}, got {:02X?}",
+                    expected, actual
+                ))),
+            }
+        }
+        ModbusFunction::ReadExceptionStatus => match response.data().len() {
+            1 => Ok(()),
+            len => Err(ModbusError::frame(format!(
+                "Invalid exception status response length: expected 1, got {}",
+                len
+            ))),
+        },
     }
 }
 
@@ -826,6 +1712,30 @@ fn validate_write_echo(
     Ok(())
 }
 
+fn validate_mask_write_echo(
+    request: &ModbusRequest,
+    response: &ModbusResponse,
+) -> ModbusResult<()> {
+    let data = response.data();
+    if data.len() != 6 {
+        return Err(ModbusError::frame(format!(
+            "Invalid mask write response length: expected 6, got {}",
+            data.len()
+        )));
+    }
+
+    if data[..2] != request.address.to_be_bytes() || data[2..] != request.data[..] {
+        return Err(ModbusError::protocol(format!(
+            "Mask write echo mismatch: expected {:02X?}{:02X?}, got {:02X?}",
+            request.address.to_be_bytes(),
+            request.data,
+            data
+        )));
+    }
+
+    Ok(())
+}
+
 fn expected_single_coil_value(request: &ModbusRequest) -> u16 {
     if !request.data.is_empty() && request.data[0] != 0 {
         0xFF00
@@ -851,6 +1761,7 @@ impl<T: ModbusTransport + Send + Sync> ModbusClient for GenericModbusClient<T> {
             address,
             quantity,
             data: vec![],
+            timeout: None,
         };
 
         let response = self.execute_request(request).await?;
@@ -876,6 +1787,7 @@ impl<T: ModbusTransport + Send + Sync> ModbusClient for GenericModbusClient<T> {
             address,
             quantity,
             data: vec![],
+            timeout: None,
         };
 
         let response = self.execute_request(request).await?;
@@ -891,21 +1803,19 @@ impl<T: ModbusTransport + Send + Sync> ModbusClient for GenericModbusClient<T> {
         address: u16,
         quantity: u16,
     ) -> ModbusResult<Vec<u16>> {
-        if quantity == 0 || quantity > 125 {
-            return Err(ModbusError::invalid_data("Invalid quantity"));
-        }
-
-        let request = ModbusRequest {
-            slave_id,
-            function: ModbusFunction::ReadHoldingRegisters,
-            address,
-            quantity,
-            data: vec![],
-        };
+        self.read_holding_registers_within(slave_id, address, quantity, None)
+            .await
+    }
 
-        let response = self.execute_request(request).await?;
-        // Use parse_registers() which correctly skips byte_count prefix
-        response.parse_registers()
+    async fn read_03_with_timeout(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        timeout: Duration,
+    ) -> ModbusResult<Vec<u16>> {
+        self.read_holding_registers_within(slave_id, address, quantity, Some(timeout))
+            .await
     }
 
     async fn read_04(
@@ -924,6 +1834,7 @@ impl<T: ModbusTransport + Send + Sync> ModbusClient for GenericModbusClient<T> {
             address,
             quantity,
             data: vec![],
+            timeout: None,
         };
 
         let response = self.execute_request(request).await?;
@@ -942,6 +1853,7 @@ impl<T: ModbusTransport + Send + Sync> ModbusClient for GenericModbusClient<T> {
             } else {
                 vec![0x00, 0x00]
             },
+            timeout: None,
         };
 
         self.execute_request(request).await?;
@@ -956,6 +1868,7 @@ impl<T: ModbusTransport + Send + Sync> ModbusClient for GenericModbusClient<T> {
             address,
             quantity: 1,
             data: vec![hi, lo],
+            timeout: None,
         };
 
         self.execute_request(request).await?;
@@ -992,6 +1905,7 @@ impl<T: ModbusTransport + Send + Sync> ModbusClient for GenericModbusClient<T> {
             address,
             quantity: values.len() as u16,
             data,
+            timeout: None,
         };
 
         self.execute_request(request).await?;
@@ -1020,22 +1934,133 @@ impl<T: ModbusTransport + Send + Sync> ModbusClient for GenericModbusClient<T> {
             address,
             quantity: values.len() as u16,
             data,
+            timeout: None,
         };
 
         self.execute_request(request).await?;
         Ok(())
     }
 
+    async fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> ModbusResult<()> {
+        let request = ModbusRequest::new_mask_write(slave_id, address, and_mask, or_mask);
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    async fn read_exception_status(&mut self, slave_id: SlaveId) -> ModbusResult<u8> {
+        let request = ModbusRequest::new_read_exception_status(slave_id);
+        let response = self.execute_request(request).await?;
+        Ok(response.data()[0])
+    }
+
+    async fn diagnostic_echo(&mut self, slave_id: SlaveId, data: u16) -> ModbusResult<u16> {
+        let request = ModbusRequest::new_diagnostics(slave_id, 0x0000, &data.to_be_bytes());
+        self.execute_request(request).await?;
+        // The response was checked to echo the sub-function and data exactly
+        Ok(data)
+    }
+
+    async fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> ModbusResult<DeviceIdentification> {
+        let mut identification = DeviceIdentification::default();
+        let mut next_object_id = object_id;
+        loop {
+            let request =
+                ModbusRequest::new_read_device_id(slave_id, read_device_id_code, next_object_id);
+            let response = self.execute_request(request).await?;
+            let page = ModbusCodec::parse_fc2b_response(response.data())?;
+            for (id, value) in page.objects {
+                identification.insert_object(id, value);
+            }
+
+            // Individual access (0x04) always answers with exactly one object
+            if !page.more_follows || read_device_id_code == 0x04 {
+                return Ok(identification);
+            }
+            // Continuation must move forward, or a faulty device loops forever
+            if page.next_object_id <= next_object_id {
+                return Err(ModbusError::protocol(format!(
+                    "Device identification did not advance: next object 0x{:02X} after 0x{:02X}",
+                    page.next_object_id, next_object_id
+                )));
+            }
+            next_object_id = page.next_object_id;
+        }
+    }
+
+    async fn read_write_registers(
+        &mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &[u16],
+    ) -> ModbusResult<Vec<u16>> {
+        if read_quantity == 0 || usize::from(read_quantity) > crate::MAX_READ_REGISTERS {
+            return Err(ModbusError::invalid_data("Invalid read quantity"));
+        }
+        if write_values.is_empty() || write_values.len() > crate::MAX_READ_WRITE_REGISTERS {
+            return Err(ModbusError::invalid_data("Invalid write quantity"));
+        }
+
+        let request = ModbusRequest::new_read_write(
+            slave_id,
+            read_address,
+            read_quantity,
+            write_address,
+            write_values,
+        );
+        let response = self.execute_request(request).await?;
+        response.parse_registers()
+    }
+
     fn is_connected(&self) -> bool {
         self.transport.is_connected()
     }
 
     async fn close(&mut self) -> ModbusResult<()> {
-        self.transport.close().await
+        let result = self.transport.close().await;
+        #[cfg(feature = "metrics")]
+        if let Some(telemetry) = &self.telemetry {
+            telemetry.record_connection(false);
+        }
+        result
     }
 
+    /// Transport counters plus the client's `error_count`, `last_error_at` and `retry_count`.
+    ///
+    /// Equal to the sum of [`get_stats_for_slave`](GenericModbusClient::get_stats_for_slave)
+    /// over all slaves, as long as every request went through this client.
     fn get_stats(&self) -> TransportStats {
-        self.transport.get_stats()
+        let mut stats = self.transport.get_stats();
+        for slave in self.slave_stats.values() {
+            stats.error_count += slave.error_count;
+            stats.last_error_at = stats.last_error_at.max(slave.last_error_at);
+            stats.retry_count += slave.retry_count;
+        }
+        let mut overall = ErrorTally::new();
+        for (error, count) in self.error_tallies.values().flatten() {
+            tally_error(&mut overall, error, *count);
+        }
+        stats.most_frequent_error = most_frequent(&overall);
+        stats
+    }
+}
+
+impl<T: ModbusTransport + Send + Sync + 'static> GenericModbusClient<T> {
+    /// Box this client as a [`DynModbusClient`](crate::dyn_client::DynModbusClient) trait object
+    pub fn into_dyn(self) -> Box<dyn crate::dyn_client::DynModbusClient> {
+        crate::dyn_client::boxed(self)
     }
 }
 
@@ -1142,6 +2167,170 @@ impl<T: ModbusTransport + Send + Sync> GenericModbusClient<T> {
     }
 }
 
+/// Snapshot reads available on any `GenericModbusClient<T>`
+impl<T: ModbusTransport + Send + Sync> GenericModbusClient<T> {
+    /// Read a set of typed holding-register values as a [`RegisterSnapshot`].
+    ///
+    /// The descriptors are grouped into as few FC03 requests as
+    /// `limits.max_read_registers` allows, with `limits.inter_request_delay_ms`
+    /// between requests. `is_consistent` is `true` only when a single request
+    /// covered every descriptor; values are returned in descriptor order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use voltage_modbus::snapshot::RegisterDescriptor;
+    /// use voltage_modbus::{DeviceLimits, GenericModbusClient, TcpTransport};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> voltage_modbus::ModbusResult<()> {
+    /// let addr = "127.0.0.1:502".parse().unwrap();
+    /// let transport = TcpTransport::new(addr, Duration::from_secs(5)).await?;
+    /// let mut client = GenericModbusClient::new(transport);
+    /// let voltage = RegisterDescriptor::new(0, "float32");
+    /// let current = RegisterDescriptor::new(2, "float32");
+    ///
+    /// let snapshot = client
+    ///     .read_snapshot(1, &[&voltage, &current], &DeviceLimits::new())
+    ///     .await?;
+    /// if snapshot.is_consistent {
+    ///     let power = snapshot.values[0].1.as_f64() * snapshot.values[1].1.as_f64();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_snapshot(
+        &mut self,
+        slave_id: SlaveId,
+        descriptors: &[&RegisterDescriptor],
+        limits: &DeviceLimits,
+    ) -> ModbusResult<RegisterSnapshot> {
+        let captured_at = Instant::now();
+        let blocks = plan_blocks(descriptors, limits.max_read_registers);
+        if let Some((start, quantity, _)) = blocks
+            .iter()
+            .find(|(_, quantity, _)| *quantity > limits.max_read_registers)
+        {
+            return Err(ModbusError::invalid_data(format!(
+                "Register value at {} spans {} registers, above the device limit of {}",
+                start, quantity, limits.max_read_registers
+            )));
+        }
+
+        let mut values: Vec<Option<(u16, ModbusValue)>> = vec![None; descriptors.len()];
+        let mut raw = BTreeMap::new();
+        for (i, (start, quantity, members)) in blocks.iter().enumerate() {
+            if i > 0 && limits.inter_request_delay_ms > 0 {
+                tokio::time::sleep(Duration::from_millis(limits.inter_request_delay_ms)).await;
+            }
+            let registers = self.read_03(slave_id, *start, *quantity).await?;
+            for &idx in members {
+                let desc = descriptors[idx];
+                values[idx] = Some((desc.address, desc.decode(*start, &registers)?));
+                let offset = usize::from(desc.address - start);
+                for (i, &word) in registers[offset..]
+                    .iter()
+                    .take(usize::from(desc.register_count()))
+                    .enumerate()
+                {
+                    raw.insert(desc.address + i as u16, word);
+                }
+            }
+        }
+
+        Ok(RegisterSnapshot {
+            values: values.into_iter().flatten().collect(),
+            registers: raw.into_iter().collect(),
+            captured_at,
+            slave_id,
+            is_consistent: blocks.len() == 1,
+            read_duration: captured_at.elapsed(),
+        })
+    }
+
+    /// Read one holding-register value and convert it to engineering units.
+    ///
+    /// Returns the value and the scaler's unit label. Descriptors without a
+    /// scaler return the decoded value unchanged with an empty unit.
+    pub async fn read_engineering(
+        &mut self,
+        slave_id: SlaveId,
+        descriptor: &RegisterDescriptor,
+    ) -> ModbusResult<(f64, &'static str)> {
+        let registers = self
+            .read_03(slave_id, descriptor.address, descriptor.register_count())
+            .await?;
+        let value = descriptor.decode(descriptor.address, &registers)?.as_f64();
+        Ok(match &descriptor.scaler {
+            Some(scaler) => (scaler.to_engineering(value), scaler.unit),
+            None => (value, ""),
+        })
+    }
+}
+
+/// Integrity-checked reads available on any `GenericModbusClient<T>`
+impl<T: ModbusTransport + Send + Sync> GenericModbusClient<T> {
+    /// Read `quantity` holding registers followed by a block CRC register.
+    ///
+    /// Reads `quantity + 1` registers, checks the last one against
+    /// [`RegisterBlockCrc::compute`] of the others and returns the data
+    /// registers without the CRC. Fails with `InvalidData("CRC mismatch")`
+    /// if the block does not verify.
+    pub async fn read_with_block_crc(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<u16>> {
+        let total = quantity
+            .checked_add(1)
+            .ok_or_else(|| ModbusError::invalid_data("Invalid quantity"))?;
+        let mut registers = self.read_03(slave_id, address, total).await?;
+        if !RegisterBlockCrc::verify(&registers) {
+            return Err(ModbusError::invalid_data("CRC mismatch"));
+        }
+        registers.pop();
+        Ok(registers)
+    }
+}
+
+/// Polling streams available on any `GenericModbusClient<T>`
+impl<T: ModbusTransport + Send + Sync + Unpin + 'static> GenericModbusClient<T> {
+    /// Read `quantity` holding registers from `address` every `interval` as a
+    /// stream; see [`RegisterPoller`].
+    ///
+    /// The client moves into the stream, and dropping the stream cancels
+    /// polling. Failed reads are yielded as `Err` items and polling goes on.
+    /// Add [`with_limits`](RegisterPoller::with_limits) to split large ranges
+    /// and keep polls `inter_request_delay_ms` apart.
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use tokio_stream::StreamExt;
+    /// use voltage_modbus::{DeviceLimits, GenericModbusClient, TcpTransport};
+    ///
+    /// # async fn example() -> voltage_modbus::ModbusResult<()> {
+    /// let transport = TcpTransport::new("127.0.0.1:502".parse().unwrap(), Duration::from_secs(1)).await?;
+    /// let mut readings = GenericModbusClient::new(transport)
+    ///     .poll_stream(1, 0, 10, Duration::from_secs(1))
+    ///     .with_limits(DeviceLimits::new().with_inter_request_delay_ms(50));
+    /// while let Some(reading) = readings.next().await {
+    ///     println!("{:?}", reading);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn poll_stream(
+        self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        interval: Duration,
+    ) -> RegisterPoller<Self> {
+        RegisterPoller::new(self, slave_id, address, quantity, interval)
+    }
+}
+
 /// Modbus TCP client implementation using the generic client
 pub struct ModbusTcpClient {
     inner: GenericModbusClient<TcpTransport>,
@@ -1180,6 +2369,19 @@ impl ModbusTcpClient {
         Self::new(addr, timeout).await
     }
 
+    /// Create a TCP client that talks Modbus over TLS
+    ///
+    /// See [`crate::tls`] for the accepted protocol versions and cipher suites.
+    #[cfg(feature = "tls")]
+    pub async fn with_tls(
+        addr: SocketAddr,
+        timeout: Duration,
+        tls_config: &crate::tls::TlsClientConfig<'_>,
+    ) -> ModbusResult<Self> {
+        let transport = crate::tls::TlsTcpTransport::connect(addr, timeout, tls_config).await?;
+        Ok(Self::from_transport(transport.into_inner()))
+    }
+
     /// Create a new TCP client from transport
     pub fn from_transport(transport: TcpTransport) -> Self {
         Self {
@@ -1187,6 +2389,25 @@ impl ModbusTcpClient {
         }
     }
 
+    /// Box this client as a [`DynModbusClient`](crate::dyn_client::DynModbusClient) trait object
+    pub fn into_dyn(self) -> Box<dyn crate::dyn_client::DynModbusClient> {
+        crate::dyn_client::boxed(self)
+    }
+
+    /// Report every request through the `metrics` facade.
+    ///
+    /// The connection gauge is labelled with the server address unless
+    /// `config` already names one.
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics(self, mut config: crate::telemetry::MetricsConfig) -> Self {
+        if config.address.is_none() {
+            config.address = Some(self.server_address().to_string());
+        }
+        Self {
+            inner: self.inner.with_metrics(config),
+        }
+    }
+
     /// Get the server address
     pub fn server_address(&self) -> SocketAddr {
         self.inner.transport().address
@@ -1376,6 +2597,18 @@ impl ModbusClient for ModbusTcpClient {
         self.inner.read_03(slave_id, address, quantity).await
     }
 
+    async fn read_03_with_timeout(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        timeout: Duration,
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner
+            .read_03_with_timeout(slave_id, address, quantity, timeout)
+            .await
+    }
+
     async fn read_04(
         &mut self,
         slave_id: SlaveId,
@@ -1411,6 +2644,56 @@ impl ModbusClient for ModbusTcpClient {
         self.inner.write_10(slave_id, address, values).await
     }
 
+    async fn read_exception_status(&mut self, slave_id: SlaveId) -> ModbusResult<u8> {
+        self.inner.read_exception_status(slave_id).await
+    }
+
+    async fn diagnostic_echo(&mut self, slave_id: SlaveId, data: u16) -> ModbusResult<u16> {
+        self.inner.diagnostic_echo(slave_id, data).await
+    }
+
+    async fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> ModbusResult<DeviceIdentification> {
+        self.inner
+            .read_device_id(slave_id, read_device_id_code, object_id)
+            .await
+    }
+
+    async fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> ModbusResult<()> {
+        self.inner
+            .mask_write_register(slave_id, address, and_mask, or_mask)
+            .await
+    }
+
+    async fn read_write_registers(
+        &mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &[u16],
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner
+            .read_write_registers(
+                slave_id,
+                read_address,
+                read_quantity,
+                write_address,
+                write_values,
+            )
+            .await
+    }
+
     fn is_connected(&self) -> bool {
         self.inner.is_connected()
     }
@@ -1478,6 +2761,11 @@ impl ModbusRtuClient {
         }
     }
 
+    /// Box this client as a [`DynModbusClient`](crate::dyn_client::DynModbusClient) trait object
+    pub fn into_dyn(self) -> Box<dyn crate::dyn_client::DynModbusClient> {
+        crate::dyn_client::boxed(self)
+    }
+
     /// Get the transport reference
     pub fn transport(&self) -> &RtuTransport {
         self.inner.transport()
@@ -1488,6 +2776,16 @@ impl ModbusRtuClient {
         self.inner.transport_mut().set_packet_logging(enabled);
     }
 
+    /// See [`RtuTransport::set_inter_frame_delay`]
+    pub fn set_inter_frame_delay(&mut self, delay: Duration) {
+        self.inner.transport_mut().set_inter_frame_delay(delay);
+    }
+
+    /// See [`RtuTransport::set_turnaround_delay`]
+    pub fn set_turnaround_delay(&mut self, delay: Duration) {
+        self.inner.transport_mut().set_turnaround_delay(delay);
+    }
+
     /// Execute a raw request
     pub async fn execute_request(
         &mut self,
@@ -1556,7 +2854,19 @@ impl ModbusClient for ModbusRtuOverTcpClient {
         address: u16,
         quantity: u16,
     ) -> ModbusResult<Vec<u16>> {
-        self.inner.read_03(slave_id, address, quantity).await
+        self.inner.read_03(slave_id, address, quantity).await
+    }
+
+    async fn read_03_with_timeout(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        timeout: Duration,
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner
+            .read_03_with_timeout(slave_id, address, quantity, timeout)
+            .await
     }
     async fn read_04(
         &mut self,
@@ -1588,6 +2898,51 @@ impl ModbusClient for ModbusRtuOverTcpClient {
     ) -> ModbusResult<()> {
         self.inner.write_10(slave_id, address, values).await
     }
+    async fn read_exception_status(&mut self, slave_id: SlaveId) -> ModbusResult<u8> {
+        self.inner.read_exception_status(slave_id).await
+    }
+    async fn diagnostic_echo(&mut self, slave_id: SlaveId, data: u16) -> ModbusResult<u16> {
+        self.inner.diagnostic_echo(slave_id, data).await
+    }
+    async fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> ModbusResult<DeviceIdentification> {
+        self.inner
+            .read_device_id(slave_id, read_device_id_code, object_id)
+            .await
+    }
+    async fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> ModbusResult<()> {
+        self.inner
+            .mask_write_register(slave_id, address, and_mask, or_mask)
+            .await
+    }
+    async fn read_write_registers(
+        &mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &[u16],
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner
+            .read_write_registers(
+                slave_id,
+                read_address,
+                read_quantity,
+                write_address,
+                write_values,
+            )
+            .await
+    }
     fn is_connected(&self) -> bool {
         self.inner.is_connected()
     }
@@ -1603,12 +2958,12 @@ impl ModbusClient for ModbusRtuOverTcpClient {
 ///
 /// Thin wrapper over [`GenericModbusClient`]`<`[`AsciiTransport`]`>` — all
 /// protocol logic is shared with TCP and RTU; only the framing differs.
-#[cfg(feature = "rtu")]
+#[cfg(feature = "ascii")]
 pub struct ModbusAsciiClient {
     inner: GenericModbusClient<crate::transport::AsciiTransport>,
 }
 
-#[cfg(feature = "rtu")]
+#[cfg(feature = "ascii")]
 impl ModbusAsciiClient {
     /// Create a new ASCII client with default settings (7E1, 1s timeouts).
     pub fn new(port: &str, baud_rate: u32) -> ModbusResult<Self> {
@@ -1639,7 +2994,7 @@ impl ModbusAsciiClient {
     }
 }
 
-#[cfg(feature = "rtu")]
+#[cfg(feature = "ascii")]
 impl ModbusClient for ModbusAsciiClient {
     async fn read_01(
         &mut self,
@@ -1665,6 +3020,18 @@ impl ModbusClient for ModbusAsciiClient {
     ) -> ModbusResult<Vec<u16>> {
         self.inner.read_03(slave_id, address, quantity).await
     }
+
+    async fn read_03_with_timeout(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        timeout: Duration,
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner
+            .read_03_with_timeout(slave_id, address, quantity, timeout)
+            .await
+    }
     async fn read_04(
         &mut self,
         slave_id: SlaveId,
@@ -1695,6 +3062,51 @@ impl ModbusClient for ModbusAsciiClient {
     ) -> ModbusResult<()> {
         self.inner.write_10(slave_id, address, values).await
     }
+    async fn read_exception_status(&mut self, slave_id: SlaveId) -> ModbusResult<u8> {
+        self.inner.read_exception_status(slave_id).await
+    }
+    async fn diagnostic_echo(&mut self, slave_id: SlaveId, data: u16) -> ModbusResult<u16> {
+        self.inner.diagnostic_echo(slave_id, data).await
+    }
+    async fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> ModbusResult<DeviceIdentification> {
+        self.inner
+            .read_device_id(slave_id, read_device_id_code, object_id)
+            .await
+    }
+    async fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> ModbusResult<()> {
+        self.inner
+            .mask_write_register(slave_id, address, and_mask, or_mask)
+            .await
+    }
+    async fn read_write_registers(
+        &mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &[u16],
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner
+            .read_write_registers(
+                slave_id,
+                read_address,
+                read_quantity,
+                write_address,
+                write_values,
+            )
+            .await
+    }
     fn is_connected(&self) -> bool {
         self.inner.is_connected()
     }
@@ -1735,6 +3147,18 @@ impl ModbusClient for ModbusRtuClient {
         self.inner.read_03(slave_id, address, quantity).await
     }
 
+    async fn read_03_with_timeout(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        timeout: Duration,
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner
+            .read_03_with_timeout(slave_id, address, quantity, timeout)
+            .await
+    }
+
     async fn read_04(
         &mut self,
         slave_id: SlaveId,
@@ -1770,6 +3194,56 @@ impl ModbusClient for ModbusRtuClient {
         self.inner.write_10(slave_id, address, values).await
     }
 
+    async fn read_exception_status(&mut self, slave_id: SlaveId) -> ModbusResult<u8> {
+        self.inner.read_exception_status(slave_id).await
+    }
+
+    async fn diagnostic_echo(&mut self, slave_id: SlaveId, data: u16) -> ModbusResult<u16> {
+        self.inner.diagnostic_echo(slave_id, data).await
+    }
+
+    async fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> ModbusResult<DeviceIdentification> {
+        self.inner
+            .read_device_id(slave_id, read_device_id_code, object_id)
+            .await
+    }
+
+    async fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> ModbusResult<()> {
+        self.inner
+            .mask_write_register(slave_id, address, and_mask, or_mask)
+            .await
+    }
+
+    async fn read_write_registers(
+        &mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &[u16],
+    ) -> ModbusResult<Vec<u16>> {
+        self.inner
+            .read_write_registers(
+                slave_id,
+                read_address,
+                read_quantity,
+                write_address,
+                write_values,
+            )
+            .await
+    }
+
     fn is_connected(&self) -> bool {
         self.inner.is_connected()
     }
@@ -1786,6 +3260,127 @@ impl ModbusClient for ModbusRtuClient {
 /// High-level utility functions for common operations
 pub mod utils {
     use super::*;
+    use std::future::Future;
+    use std::sync::Arc;
+    use tokio::sync::Semaphore;
+    use tokio::task::JoinSet;
+
+    /// Run per-slave reads concurrently, at most `max_concurrent` at a time
+    ///
+    /// Each `F` is only called once it holds a permit, so a closure that opens
+    /// its own connection keeps the number of open connections within the
+    /// limit. Results are returned in input order. A `max_concurrent` of 0 is
+    /// treated as 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use voltage_modbus::client::utils::read_slaves_concurrent;
+    /// use voltage_modbus::{ModbusClient, ModbusTcpClient};
+    ///
+    /// # async fn example() {
+    /// let tasks = (1..=50u8)
+    ///     .map(|slave_id| {
+    ///         let address = format!("10.0.0.{}:502", slave_id);
+    ///         (slave_id, move || async move {
+    ///             let mut client =
+    ///                 ModbusTcpClient::from_address(&address, Duration::from_secs(1)).await?;
+    ///             client.read_03(slave_id, 0, 10).await
+    ///         })
+    ///     })
+    ///     .collect();
+    /// for (slave_id, result) in read_slaves_concurrent(tasks, 8).await {
+    ///     println!("{}: {:?}", slave_id, result);
+    /// }
+    /// # }
+    /// ```
+    pub async fn read_slaves_concurrent<F, Fut>(
+        tasks: Vec<(SlaveId, F)>,
+        max_concurrent: usize,
+    ) -> Vec<(SlaveId, ModbusResult<Vec<u16>>)>
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = ModbusResult<Vec<u16>>> + Send + 'static,
+    {
+        let permits = Arc::new(Semaphore::new(max_concurrent.max(1)));
+        let mut results: Vec<(SlaveId, Option<ModbusResult<Vec<u16>>>)> = tasks
+            .iter()
+            .map(|(slave_id, _)| (*slave_id, None))
+            .collect();
+
+        let mut running = JoinSet::new();
+        let mut positions = std::collections::HashMap::new();
+        for (index, (_, task)) in tasks.into_iter().enumerate() {
+            let permits = Arc::clone(&permits);
+            let handle = running.spawn(async move {
+                let _permit = permits
+                    .acquire_owned()
+                    .await
+                    .map_err(|_| ModbusError::internal("read semaphore closed"))?;
+                task().await
+            });
+            positions.insert(handle.id(), index);
+        }
+
+        while let Some(joined) = running.join_next_with_id().await {
+            let (id, result) = match joined {
+                Ok((id, result)) => (id, result),
+                Err(e) => (
+                    e.id(),
+                    Err(ModbusError::internal(format!("read task failed: {}", e))),
+                ),
+            };
+            if let Some(&index) = positions.get(&id) {
+                results[index].1 = Some(result);
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|(slave_id, result)| {
+                let result = result
+                    .unwrap_or_else(|| Err(ModbusError::internal("read task did not finish")));
+                (slave_id, result)
+            })
+            .collect()
+    }
+
+    /// One holding-register read (FC03) for [`read_tasks_concurrent`]
+    pub struct ReadTask {
+        /// Slave to read from
+        pub slave_id: SlaveId,
+        /// First register address
+        pub addr: u16,
+        /// Number of registers
+        pub qty: u16,
+        /// Connected client for the slave
+        pub client: ModbusTcpClient,
+    }
+
+    impl ReadTask {
+        /// Read the registers, consuming the task and its client
+        pub async fn run(mut self) -> ModbusResult<Vec<u16>> {
+            self.client
+                .read_03(self.slave_id, self.addr, self.qty)
+                .await
+        }
+    }
+
+    /// [`read_slaves_concurrent`] over already connected [`ReadTask`]s
+    ///
+    /// The connections are open before the call; only the reads are limited
+    /// to `max_concurrent`.
+    pub async fn read_tasks_concurrent(
+        tasks: Vec<ReadTask>,
+        max_concurrent: usize,
+    ) -> Vec<(SlaveId, ModbusResult<Vec<u16>>)> {
+        let tasks = tasks
+            .into_iter()
+            .map(|task| (task.slave_id, move || task.run()))
+            .collect();
+        read_slaves_concurrent(tasks, max_concurrent).await
+    }
 
     /// Read multiple register types in a single operation
     pub async fn read_mixed_registers<T: ModbusClient>(
@@ -2090,6 +3685,129 @@ mod tests {
         assert!(err.to_string().contains("Write echo mismatch"));
     }
 
+    #[tokio::test]
+    async fn test_read_exception_status() {
+        let mock = MockTransport::new();
+        mock.add_response(Ok(ModbusResponse::new_success(
+            1,
+            ModbusFunction::ReadExceptionStatus,
+            vec![0x6D],
+        )));
+        mock.add_response(Ok(ModbusResponse::new_success(
+            1,
+            ModbusFunction::ReadExceptionStatus,
+            vec![],
+        )));
+        let mut client = GenericModbusClient::new(mock);
+        assert_eq!(client.read_exception_status(1).await.unwrap(), 0x6D);
+        assert!(client.read_exception_status(1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_diagnostic_echo() {
+        let mut client = GenericModbusClient::new(crate::testing::EchoTransport::new());
+        assert_eq!(client.diagnostic_echo(1, 0xA537).await.unwrap(), 0xA537);
+
+        let mock = MockTransport::new();
+        mock.add_response(Ok(ModbusResponse::new_success(
+            1,
+            ModbusFunction::Diagnostics,
+            vec![0x00, 0x00, 0xA5, 0x00],
+        )));
+        let mut client = GenericModbusClient::new(mock);
+        let err = client.diagnostic_echo(1, 0xA537).await.unwrap_err();
+        assert!(err.to_string().contains("Diagnostics echo mismatch"));
+    }
+
+    #[tokio::test]
+    async fn test_read_device_id_follows_continuation() {
+        let mock = MockTransport::new();
+        let mut first = vec![0x0E, 0x01, 0x01, 0xFF, 0x02, 0x02, 0x00, 0x07];
+        first.extend_from_slice(b"Voltage");
+        first.extend_from_slice(&[0x01, 0x04]);
+        first.extend_from_slice(b"PM-3");
+        let mut second = vec![0x0E, 0x01, 0x01, 0x00, 0x00, 0x01, 0x02, 0x05];
+        second.extend_from_slice(b"2.1.0");
+        for data in [first, second] {
+            mock.add_response(Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::EncapsulatedInterfaceTransport,
+                data,
+            )));
+        }
+        // Next object does not advance past the one just requested
+        mock.add_response(Ok(ModbusResponse::new_success(
+            1,
+            ModbusFunction::EncapsulatedInterfaceTransport,
+            vec![0x0E, 0x03, 0x83, 0xFF, 0x80, 0x00],
+        )));
+
+        let mut client = GenericModbusClient::new(mock);
+        let id = client.read_device_id(1, 0x01, 0x00).await.unwrap();
+        assert_eq!(id.vendor_name, "Voltage");
+        assert_eq!(id.product_code, "PM-3");
+        assert_eq!(id.revision, "2.1.0");
+        assert!(id.extended.is_empty());
+
+        let err = client.read_device_id(1, 0x03, 0x80).await.unwrap_err();
+        assert!(err.to_string().contains("did not advance"));
+    }
+
+    #[tokio::test]
+    async fn test_mask_write_register_checks_echo() {
+        let mut client = GenericModbusClient::new(crate::testing::EchoTransport::new());
+        client
+            .mask_write_register(1, 4, 0x00F2, 0x0025)
+            .await
+            .unwrap();
+
+        let mock = MockTransport::new();
+        mock.add_response(Ok(ModbusResponse::new_success(
+            1,
+            ModbusFunction::MaskWriteRegister,
+            vec![0x00, 0x04, 0x00, 0xF2, 0x00, 0x00],
+        )));
+        let mut client = GenericModbusClient::new(mock);
+        let err = client
+            .mask_write_register(1, 4, 0x00F2, 0x0025)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Mask write echo mismatch"));
+    }
+
+    #[tokio::test]
+    async fn test_read_write_registers() {
+        let mut client = GenericModbusClient::new(crate::testing::EchoTransport::new());
+        let values = client
+            .read_write_registers(1, 0, 4, 10, &[7, 8])
+            .await
+            .unwrap();
+        assert_eq!(values, vec![0, 1, 2, 3]);
+
+        let mock = MockTransport::new();
+        mock.add_response(Ok(ModbusResponse::new_success(
+            1,
+            ModbusFunction::ReadWriteMultipleRegisters,
+            vec![2, 0x12, 0x34],
+        )));
+        let mut client = GenericModbusClient::new(mock);
+        let err = client
+            .read_write_registers(1, 0, 2, 10, &[7])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("read response"));
+
+        assert!(client
+            .read_write_registers(1, 0, 126, 10, &[7])
+            .await
+            .is_err());
+        assert!(client
+            .read_write_registers(1, 0, 1, 10, &[0; 122])
+            .await
+            .is_err());
+        assert!(client.read_write_registers(1, 0, 1, 10, &[]).await.is_err());
+    }
+
     // =========================================================================
     // Batch read tests
     // =========================================================================
@@ -2235,6 +3953,371 @@ mod tests {
         assert_eq!(requests[1].quantity, 100);
     }
 
+    #[tokio::test]
+    async fn test_stats_are_tracked_per_slave() {
+        let mut client = GenericModbusClient::new(crate::testing::EchoTransport::new());
+        client.read_03(1, 0, 4).await.unwrap();
+        client.read_03(2, 0, 2).await.unwrap();
+        client.read_03(2, 10, 1).await.unwrap();
+        let invalid = ModbusRequest::new_read(2, ModbusFunction::ReadHoldingRegisters, 0, 0);
+        assert!(client.execute_request(invalid).await.is_err());
+
+        let one = client.get_stats_for_slave(1);
+        let two = client.get_stats_for_slave(2);
+        assert_eq!((one.requests_sent, one.error_count), (1, 0));
+        assert!(one.last_error_at.is_none());
+        assert_eq!((two.requests_sent, two.error_count), (2, 1));
+        assert!(two.last_error_at.is_some());
+        assert_eq!(client.get_stats_for_slave(3).requests_sent, 0);
+
+        let mut sum = one;
+        sum.merge(&two);
+        let total = client.get_stats();
+        assert_eq!(sum.requests_sent, total.requests_sent);
+        assert_eq!(sum.bytes_received, total.bytes_received);
+        assert_eq!(sum.error_count, total.error_count);
+        assert_eq!(sum.last_error_at, total.last_error_at);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_per_function_code_and_most_frequent_error() {
+        let mut client = GenericModbusClient::new(crate::testing::EchoTransport::new());
+        let invalid = |slave_id| {
+            ModbusRequest::new_read(slave_id, ModbusFunction::ReadHoldingRegisters, 0, 0)
+        };
+        client.read_03(1, 0, 4).await.unwrap();
+        client.read_03(1, 0, 4).await.unwrap();
+        client.write_06(1, 0, 7).await.unwrap();
+        let rejected = client.execute_request(invalid(1)).await.unwrap_err();
+        client.execute_request(invalid(2)).await.unwrap_err();
+        client.close().await.unwrap();
+        let closed = client.read_04(2, 0, 1).await.unwrap_err();
+        assert!(matches!(closed, ModbusError::Connection { .. }));
+        client.execute_request(invalid(2)).await.unwrap_err();
+
+        let metrics = client.metrics();
+        assert_eq!(metrics.total_requests, 7);
+        assert_eq!(metrics.function_codes[&0x03].request_count, 5);
+        assert_eq!(metrics.success_rate_for(0x03), 0.4);
+        assert_eq!(metrics.success_rate_for(0x06), 1.0);
+        assert_eq!(metrics.success_rate_for(0x04), 0.0);
+
+        let kind = std::mem::discriminant(&rejected);
+        let most_frequent = |stats: TransportStats| {
+            stats
+                .most_frequent_error
+                .map(|e| std::mem::discriminant(&e))
+        };
+        assert_eq!(most_frequent(client.get_stats_for_slave(2)), Some(kind));
+        assert_eq!(most_frequent(client.get_stats()), Some(kind));
+        assert_eq!(most_frequent(client.get_stats_for_slave(3)), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_policy_backs_off_and_counts_retries() {
+        let response = ModbusResponse::new_success(
+            1,
+            ModbusFunction::ReadHoldingRegisters,
+            vec![2, 0x00, 0x2A],
+        );
+        let transport = crate::testing::ScriptedTransport::new(vec![
+            Err(ModbusError::timeout("read", 100)),
+            Err(ModbusError::exception(0x03, 0x06)),
+            Err(ModbusError::timeout("read", 100)),
+            Ok(response),
+            Err(ModbusError::connection("reset")),
+            Err(ModbusError::timeout("read", 100)),
+            Err(ModbusError::timeout("read", 100)),
+        ]);
+        let policy = RetryPolicy {
+            max_retries: 3,
+            retry_on: RetryCondition::OnTimeout,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(250),
+        };
+        let mut client = GenericModbusClient::with_retry_policy(transport, policy);
+
+        let start = tokio::time::Instant::now();
+        assert_eq!(client.read_03(1, 0, 1).await.unwrap(), [42]);
+        // 100 ms after the timeout, a fixed 100 ms for busy, then 250 (capped)
+        assert_eq!(start.elapsed(), Duration::from_millis(450));
+        assert_eq!(client.get_stats().retry_count, 3);
+
+        assert!(matches!(
+            client.read_03(1, 0, 1).await,
+            Err(ModbusError::Connection { .. })
+        ));
+        let policy = RetryPolicy {
+            max_retries: 1,
+            ..policy
+        };
+        client.retry_policy = Some(policy);
+        assert!(matches!(
+            client.read_03(1, 0, 1).await,
+            Err(ModbusError::Timeout { .. })
+        ));
+        client.transport().assert_all_consumed();
+
+        let stats = client.get_stats_for_slave(1);
+        assert_eq!((stats.retry_count, stats.error_count), (4, 2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_breaker_short_circuits_open_slaves() {
+        use crate::reliability::{CircuitBreaker, CircuitState};
+
+        let response = ModbusResponse::new_success(
+            1,
+            ModbusFunction::ReadHoldingRegisters,
+            vec![2, 0x00, 0x07],
+        );
+        let transport = crate::testing::ScriptedTransport::new(vec![
+            Err(ModbusError::timeout("read", 100)),
+            Err(ModbusError::exception(0x03, 0x02)),
+            Err(ModbusError::timeout("read", 100)),
+            Err(ModbusError::timeout("read", 100)),
+            Ok(response),
+        ]);
+        let mut client = GenericModbusClient::new(transport)
+            .with_circuit_breaker(CircuitBreaker::new(2, 1, Duration::from_secs(10)));
+
+        for _ in 0..4 {
+            let _ = client.read_03(1, 0, 1).await;
+        }
+        assert_eq!(client.transport().remaining(), 1);
+        assert!(matches!(
+            client.read_03(1, 0, 1).await,
+            Err(ModbusError::CircuitOpen { slave_id: 1 })
+        ));
+        assert_eq!(client.transport().remaining(), 1);
+        assert!(client.circuit_breaker(2).is_none());
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert_eq!(client.read_03(1, 0, 1).await.unwrap(), [7]);
+        let breaker = client.circuit_breaker(1).unwrap();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        client.transport().assert_all_consumed();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dropped_probe_does_not_lock_out_slave() {
+        use crate::reliability::{CircuitBreaker, CircuitState};
+        use crate::testing::{LatencyTransport, ScriptedTransport};
+
+        let response = ModbusResponse::new_success(
+            1,
+            ModbusFunction::ReadHoldingRegisters,
+            vec![2, 0x00, 0x07],
+        );
+        let script =
+            ScriptedTransport::new(vec![Err(ModbusError::timeout("read", 100)), Ok(response)]);
+        let transport = LatencyTransport::new(script, Duration::from_secs(1), Duration::ZERO);
+        let mut client = GenericModbusClient::new(transport)
+            .with_circuit_breaker(CircuitBreaker::new(1, 1, Duration::from_secs(10)));
+
+        assert!(client.read_03(1, 0, 1).await.is_err());
+        tokio::time::advance(Duration::from_secs(10)).await;
+
+        // The probe is dropped before the device answers
+        let probe = tokio::time::timeout(Duration::from_millis(500), client.read_03(1, 0, 1)).await;
+        assert!(probe.is_err());
+        assert_eq!(
+            client.circuit_breaker(1).unwrap().state(),
+            CircuitState::HalfOpen
+        );
+
+        assert_eq!(client.read_03(1, 0, 1).await.unwrap(), [7]);
+        assert_eq!(
+            client.circuit_breaker(1).unwrap().state(),
+            CircuitState::Closed
+        );
+        client.transport().inner().assert_all_consumed();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_verified_writes_compare_read_back() {
+        let fc06 = ModbusFunction::WriteSingleRegister;
+        let fc16 = ModbusFunction::WriteMultipleRegisters;
+        let fc03 = ModbusFunction::ReadHoldingRegisters;
+        let transport = crate::testing::ScriptedTransport::new(vec![
+            Ok(ModbusResponse::new_success(1, fc06, vec![0, 10, 0, 1])),
+            Ok(ModbusResponse::new_success(1, fc03, vec![2, 0, 1])),
+            Ok(ModbusResponse::new_success(1, fc16, vec![0, 20, 0, 3])),
+            Ok(ModbusResponse::new_success(
+                1,
+                fc03,
+                vec![6, 0, 5, 0, 6, 0, 9],
+            )),
+        ]);
+        let mut client = GenericModbusClient::new(transport);
+
+        let start = tokio::time::Instant::now();
+        client
+            .write_06_verify(1, 10, 1, Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert_eq!(start.elapsed(), Duration::from_millis(50));
+
+        let err = client
+            .write_10_verify(1, 20, &[5, 6, 7], Duration::ZERO)
+            .await
+            .unwrap_err();
+        assert_eq!(err, ModbusError::verification_failed(22, 7, 9));
+        let requests = client.transport().requests();
+        assert_eq!((requests[3].address, requests[3].quantity), (20, 3));
+        client.transport().assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn test_conditional_write_modes() {
+        let fc03 = ModbusFunction::ReadHoldingRegisters;
+        let fc06 = ModbusFunction::WriteSingleRegister;
+        let fc22 = ModbusFunction::MaskWriteRegister;
+        let read = |value: u16| {
+            let [hi, lo] = value.to_be_bytes();
+            Ok(ModbusResponse::new_success(1, fc03, vec![2, hi, lo]))
+        };
+        let transport = crate::testing::ScriptedTransport::new(vec![
+            read(0x00F0),
+            read(0x00F0),
+            Ok(ModbusResponse::new_success(
+                1,
+                fc22,
+                vec![0, 5, 0xFF, 0xFE, 0x00, 0x01],
+            )),
+            read(0x00F1),
+            read(0x00F0),
+            Ok(ModbusResponse::new_success(
+                1,
+                fc22,
+                vec![0, 5, 0xFF, 0xFE, 0x00, 0x01],
+            )),
+            // Another master set bit 8 between the check and the mask write
+            read(0x01F1),
+            read(0x00F0),
+            Err(ModbusError::exception(0x16, 0x01)),
+            Ok(ModbusResponse::new_success(1, fc06, vec![0, 5, 0x00, 0xF1])),
+        ]);
+        let mut client = GenericModbusClient::new(transport);
+
+        // Mismatch: nothing is written
+        assert!(!client
+            .conditional_write(1, 5, 0, 1, AtomicMode::Hardware)
+            .await
+            .unwrap());
+        // 0x00F0 -> 0x00F1 only changes bit 0, so FC22 leaves the others alone
+        assert!(client
+            .conditional_write(1, 5, 0x00F0, 0x00F1, AtomicMode::Hardware)
+            .await
+            .unwrap());
+        // The masks were applied, but the read-back is not `new_value`
+        assert!(!client
+            .conditional_write(1, 5, 0x00F0, 0x00F1, AtomicMode::Auto)
+            .await
+            .unwrap());
+        // Auto falls back to FC06 on an illegal function exception
+        assert!(client
+            .conditional_write(1, 5, 0x00F0, 0x00F1, AtomicMode::Auto)
+            .await
+            .unwrap());
+
+        let transport = client.transport();
+        let functions: Vec<_> = transport.requests().iter().map(|r| r.function).collect();
+        assert_eq!(
+            functions,
+            [fc03, fc03, fc22, fc03, fc03, fc22, fc03, fc03, fc22, fc06]
+        );
+        assert_eq!(transport.requests()[2].data, [0xFF, 0xFE, 0x00, 0x01]);
+        transport.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn test_read_and_write_bits() {
+        let fc03 = ModbusFunction::ReadHoldingRegisters;
+        let fc06 = ModbusFunction::WriteSingleRegister;
+        let fc22 = ModbusFunction::MaskWriteRegister;
+        let transport = crate::testing::ScriptedTransport::new(vec![
+            Ok(ModbusResponse::new_success(1, fc03, vec![2, 0x80, 0x04])),
+            Ok(ModbusResponse::new_success(
+                1,
+                fc22,
+                vec![0, 9, 0xFF, 0xF7, 0x00, 0x08],
+            )),
+            Err(ModbusError::exception(0x16, 0x01)),
+            Ok(ModbusResponse::new_success(1, fc03, vec![2, 0x80, 0x04])),
+            Ok(ModbusResponse::new_success(1, fc06, vec![0, 9, 0x00, 0x04])),
+        ]);
+        let mut client = GenericModbusClient::new(transport);
+
+        assert!(client.read_bit(1, 9, 15).await.unwrap());
+        client.write_bit(1, 9, 3, true).await.unwrap();
+        client.write_bit(1, 9, 15, false).await.unwrap();
+        assert!(matches!(
+            client.read_bit(1, 9, 16).await,
+            Err(ModbusError::InvalidData { .. })
+        ));
+        assert!(client.write_bit(1, 9, 16, true).await.is_err());
+
+        let requests = client.transport().requests();
+        assert_eq!(requests[1].data, [0xFF, 0xF7, 0x00, 0x08]);
+        assert_eq!(requests[4].data, [0x00, 0x04]);
+        client.transport().assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn test_write_10_batch_splits_values() {
+        let mock = MockTransport::new();
+        for (address, quantity) in [(10, 50), (60, 50), (110, 20)] {
+            mock.add_response(Ok(create_write_response(
+                1,
+                ModbusFunction::WriteMultipleRegisters,
+                address,
+                quantity,
+            )));
+        }
+
+        let mut client = GenericModbusClient::new(mock);
+        let limits = DeviceLimits::new()
+            .with_max_write_registers(50)
+            .with_inter_request_delay_ms(0);
+        let values: Vec<u16> = (0..120).collect();
+        client
+            .write_10_batch(1, 10, &values, &limits)
+            .await
+            .unwrap();
+
+        let requests = client.transport().get_requests();
+        let chunks: Vec<(u16, u16)> = requests.iter().map(|r| (r.address, r.quantity)).collect();
+        assert_eq!(chunks, [(10, 50), (60, 50), (110, 20)]);
+        assert_eq!(&requests[2].data[..2], &100u16.to_be_bytes());
+
+        client.write_10_batch(1, 0, &[], &limits).await.unwrap();
+        assert_eq!(client.transport().get_requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_write_0f_batch_stops_at_first_error() {
+        let mock = MockTransport::new();
+        mock.add_response(Ok(create_write_response(
+            1,
+            ModbusFunction::WriteMultipleCoils,
+            0,
+            800,
+        )));
+        mock.add_response(Err(ModbusError::timeout("Simulated timeout", 1000)));
+
+        let mut client = GenericModbusClient::new(mock);
+        let limits = DeviceLimits::new()
+            .with_max_write_coils(800)
+            .with_inter_request_delay_ms(0);
+        let result = client.write_0f_batch(1, 0, &[true; 2000], &limits).await;
+
+        assert!(matches!(result, Err(ModbusError::Timeout { .. })));
+        let requests = client.transport().get_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!((requests[1].address, requests[1].quantity), (800, 800));
+    }
+
     // =========================================================================
     // Broadcast (slave_id = 0) tests
     // =========================================================================
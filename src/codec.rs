@@ -16,16 +16,40 @@
 //! | u64 | 4 | uint64, qword |
 //! | i64 | 4 | int64, longlong |
 //! | f64 | 4 | float64, double, lreal |
+//! | bcd16 | 1 | |
+//! | bcd32 | 2 | |
+//! | str8 | 4 | |
+//! | str16 | 8 | |
+//!
+//! BCD registers hold one decimal digit per nibble and decode to
+//! [`ModbusValue::Bcd32`]; a nibble above 9 is rejected as invalid data.
+//! Strings are ASCII, two characters per register with the first one in the
+//! high byte, independent of the byte order.
 
 use crate::bytes::{bytes_4_to_regs, bytes_8_to_regs, regs_to_bytes_4, regs_to_bytes_8, ByteOrder};
 use crate::constants;
 use crate::error::{ModbusError, ModbusResult};
 use crate::pdu::{ModbusPdu, PduBuilder};
-use crate::value::ModbusValue;
+use crate::value::{pack_bits, ModbusValue, ScaledValue, BCD32_MAX};
 
 /// Modbus codec for data encoding/decoding.
 pub struct ModbusCodec;
 
+/// One page of a Read Device Identification (FC43 / MEI 0x0E) response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceIdResponse {
+    /// Read Device ID code echoed by the device.
+    pub read_device_id_code: u8,
+    /// Conformity level reported by the device.
+    pub conformity_level: u8,
+    /// Whether more objects remain; request again from `next_object_id`.
+    pub more_follows: bool,
+    /// First object ID of the next page (0 when nothing follows).
+    pub next_object_id: u8,
+    /// `(object_id, value)` pairs in response order.
+    pub objects: Vec<(u8, Vec<u8>)>,
+}
+
 // ============================================================================
 // Decoding Functions
 // ============================================================================
@@ -35,14 +59,21 @@ pub struct ModbusCodec;
 /// Supports multiple data types with configurable byte ordering:
 /// - `bool`: Single bit extraction from register (0-15 bit position)
 /// - `uint16`, `int16`: Single 16-bit register
+/// - `bits16`: All 16 bits of one register as [`ModbusValue::Bits`], LSB first
 /// - `uint32`, `int32`, `float32`: Two 16-bit registers
 /// - `uint64`, `int64`, `float64`: Four 16-bit registers
+/// - `bcd16`, `bcd32`: 4 or 8 BCD digits in one or two registers
+/// - `str8`, `str16`: 8 or 16 ASCII characters in 4 or 8 registers, with
+///   trailing NUL and space padding removed
 ///
 /// # Arguments
 /// * `registers` - Raw register values from Modbus response
 /// * `data_type` - Data type string (e.g., "uint16", "float32", "bool")
 /// * `bit_position` - For bool type: which bit to extract (0-15, LSB=0)
 /// * `byte_order` - Byte ordering for multi-register types
+/// * `scaling` - Optional `(gain, offset)`; when given, the decoded value is
+///   converted to engineering units (see [`ScaledValue`]) and returned as
+///   `F64`. Strings cannot be scaled.
 ///
 /// # Example
 ///
@@ -51,14 +82,40 @@ pub struct ModbusCodec;
 ///
 /// // Decode a 32-bit unsigned integer from 2 registers
 /// let registers = [0x1234, 0x5678];
-/// let value = decode_register_value(&registers, "uint32", 0, ByteOrder::BigEndian).unwrap();
+/// let value = decode_register_value(&registers, "uint32", 0, ByteOrder::BigEndian, None).unwrap();
 /// assert_eq!(value, ModbusValue::U32(0x12345678));
+///
+/// // 0.1 °C per count
+/// let value = decode_register_value(&[215], "int16", 0, ByteOrder::BigEndian, Some((0.1, 0.0)))
+///     .unwrap();
+/// assert!((value.as_f64() - 21.5).abs() < 1e-9);
 /// ```
 pub fn decode_register_value(
     registers: &[u16],
     data_type: &str,
     bit_position: u8,
     byte_order: ByteOrder,
+    scaling: Option<(f64, f64)>,
+) -> ModbusResult<ModbusValue> {
+    let value = decode_raw_value(registers, data_type, bit_position, byte_order)?;
+    match scaling {
+        None => Ok(value),
+        Some(_) if matches!(value, ModbusValue::Str(_)) => Err(ModbusError::invalid_data(format!(
+            "Cannot scale {} value",
+            data_type
+        ))),
+        Some((gain, offset)) => Ok(ModbusValue::F64(
+            ScaledValue::new(value, gain, offset).to_engineering(),
+        )),
+    }
+}
+
+/// Decode `registers` as `data_type` without scaling.
+fn decode_raw_value(
+    registers: &[u16],
+    data_type: &str,
+    bit_position: u8,
+    byte_order: ByteOrder,
 ) -> ModbusResult<ModbusValue> {
     let dt = data_type;
     if dt.eq_ignore_ascii_case("bool")
@@ -106,6 +163,15 @@ pub fn decode_register_value(
         return Ok(ModbusValue::I16(registers[0] as i16));
     }
 
+    if dt.eq_ignore_ascii_case("bits16") {
+        if registers.is_empty() {
+            return Err(ModbusError::InvalidData {
+                message: "No registers for bits16".to_string(),
+            });
+        }
+        return Ok(ModbusValue::bits_from_u16(registers[0]));
+    }
+
     if dt.eq_ignore_ascii_case("uint32")
         || dt.eq_ignore_ascii_case("u32")
         || dt.eq_ignore_ascii_case("dword")
@@ -192,6 +258,34 @@ pub fn decode_register_value(
         return Ok(ModbusValue::F64(f64::from_be_bytes(bytes)));
     }
 
+    if dt.eq_ignore_ascii_case("bcd16") {
+        if registers.is_empty() {
+            return Err(ModbusError::InvalidData {
+                message: "No registers for bcd16".to_string(),
+            });
+        }
+        return Ok(ModbusValue::Bcd32(bcd_to_u32(u32::from(registers[0]))?));
+    }
+
+    if dt.eq_ignore_ascii_case("bcd32") {
+        if registers.len() < 2 {
+            return Err(ModbusError::InvalidData {
+                message: "Not enough registers for bcd32".to_string(),
+            });
+        }
+        let bytes = regs_to_bytes_4(&[registers[0], registers[1]], byte_order);
+        return Ok(ModbusValue::Bcd32(bcd_to_u32(u32::from_be_bytes(bytes))?));
+    }
+
+    if let Some(count) = string_registers(dt) {
+        if registers.len() < count {
+            return Err(ModbusError::InvalidData {
+                message: format!("Not enough registers for {}", dt.to_ascii_lowercase()),
+            });
+        }
+        return Ok(ModbusValue::Str(regs_to_string(&registers[..count])));
+    }
+
     Err(ModbusError::InvalidData {
         message: format!("Unsupported data type: {}", data_type),
     })
@@ -210,27 +304,33 @@ pub fn decode_register_value(
 /// The clamped value, or the original value if the type is unknown/boolean
 pub fn clamp_to_data_type(value: f64, data_type: &str) -> f64 {
     let dt = data_type;
-    let (min, max): (f64, f64) =
-        if dt.eq_ignore_ascii_case("uint16") || dt.eq_ignore_ascii_case("u16") {
-            (0.0, 65535.0)
-        } else if dt.eq_ignore_ascii_case("int16") || dt.eq_ignore_ascii_case("i16") {
-            (-32768.0, 32767.0)
-        } else if dt.eq_ignore_ascii_case("uint32") || dt.eq_ignore_ascii_case("u32") {
-            (0.0, 4294967295.0)
-        } else if dt.eq_ignore_ascii_case("int32") || dt.eq_ignore_ascii_case("i32") {
-            (-2147483648.0, 2147483647.0)
-        } else if dt.eq_ignore_ascii_case("uint64") || dt.eq_ignore_ascii_case("u64") {
-            (0.0, u64::MAX as f64)
-        } else if dt.eq_ignore_ascii_case("int64") || dt.eq_ignore_ascii_case("i64") {
-            (i64::MIN as f64, i64::MAX as f64)
-        } else if dt.eq_ignore_ascii_case("float32") || dt.eq_ignore_ascii_case("f32") {
-            (f32::MIN as f64, f32::MAX as f64)
-        } else if dt.eq_ignore_ascii_case("float64") || dt.eq_ignore_ascii_case("f64") {
-            (f64::MIN, f64::MAX)
-        } else {
-            // Boolean types and unknown types — return as-is
-            return value;
-        };
+    let (min, max): (f64, f64) = if dt.eq_ignore_ascii_case("uint16")
+        || dt.eq_ignore_ascii_case("u16")
+        || dt.eq_ignore_ascii_case("bits16")
+    {
+        (0.0, 65535.0)
+    } else if dt.eq_ignore_ascii_case("int16") || dt.eq_ignore_ascii_case("i16") {
+        (-32768.0, 32767.0)
+    } else if dt.eq_ignore_ascii_case("uint32") || dt.eq_ignore_ascii_case("u32") {
+        (0.0, 4294967295.0)
+    } else if dt.eq_ignore_ascii_case("int32") || dt.eq_ignore_ascii_case("i32") {
+        (-2147483648.0, 2147483647.0)
+    } else if dt.eq_ignore_ascii_case("uint64") || dt.eq_ignore_ascii_case("u64") {
+        (0.0, u64::MAX as f64)
+    } else if dt.eq_ignore_ascii_case("int64") || dt.eq_ignore_ascii_case("i64") {
+        (i64::MIN as f64, i64::MAX as f64)
+    } else if dt.eq_ignore_ascii_case("float32") || dt.eq_ignore_ascii_case("f32") {
+        (f32::MIN as f64, f32::MAX as f64)
+    } else if dt.eq_ignore_ascii_case("float64") || dt.eq_ignore_ascii_case("f64") {
+        (f64::MIN, f64::MAX)
+    } else if dt.eq_ignore_ascii_case("bcd16") {
+        (0.0, 9999.0)
+    } else if dt.eq_ignore_ascii_case("bcd32") {
+        (0.0, f64::from(BCD32_MAX))
+    } else {
+        // Boolean types and unknown types — return as-is
+        return value;
+    };
 
     value.clamp(min, max)
 }
@@ -328,6 +428,7 @@ pub fn encode_value(value: &ModbusValue, byte_order: ByteOrder) -> ModbusResult<
         ModbusValue::Bool(b) => Ok(vec![if *b { 1 } else { 0 }]),
         ModbusValue::U16(v) => Ok(vec![*v]),
         ModbusValue::I16(v) => Ok(vec![*v as u16]),
+        ModbusValue::Bits(bits) => Ok(vec![pack_bits(bits)]),
         ModbusValue::U32(v) => {
             let bytes = v.to_be_bytes();
             Ok(bytes_4_to_regs(&bytes, byte_order).to_vec())
@@ -352,9 +453,103 @@ pub fn encode_value(value: &ModbusValue, byte_order: ByteOrder) -> ModbusResult<
             let bytes = v.to_be_bytes();
             Ok(bytes_8_to_regs(&bytes, byte_order).to_vec())
         }
+        ModbusValue::Bcd32(v) => {
+            let bytes = u32_to_bcd(*v, BCD32_MAX)?.to_be_bytes();
+            Ok(bytes_4_to_regs(&bytes, byte_order).to_vec())
+        }
+        ModbusValue::Str(s) => string_to_regs(s),
+    }
+}
+
+/// Decimal value of packed BCD digits, rejecting nibbles above 9.
+fn bcd_to_u32(bcd: u32) -> ModbusResult<u32> {
+    let mut value = 0;
+    for shift in (0..32).step_by(4).rev() {
+        let digit = (bcd >> shift) & 0xF;
+        if digit > 9 {
+            return Err(ModbusError::invalid_data(format!(
+                "Invalid BCD value 0x{:X}: nibble 0x{:X} is not a decimal digit",
+                bcd, digit
+            )));
+        }
+        value = value * 10 + digit;
+    }
+    Ok(value)
+}
+
+/// Packed BCD digits of `value`, which must not exceed `max`.
+fn u32_to_bcd(mut value: u32, max: u32) -> ModbusResult<u32> {
+    if value > max {
+        return Err(ModbusError::invalid_data(format!(
+            "Value {} does not fit in BCD (max {})",
+            value, max
+        )));
+    }
+    let mut bcd = 0;
+    for shift in (0..32).step_by(4) {
+        bcd |= (value % 10) << shift;
+        value /= 10;
+    }
+    Ok(bcd)
+}
+
+/// Whether `data_type` names a floating-point type.
+pub(crate) fn is_float_type(data_type: &str) -> bool {
+    [
+        "float32", "f32", "float", "real", "float64", "f64", "double", "lreal",
+    ]
+    .iter()
+    .any(|name| data_type.eq_ignore_ascii_case(name))
+}
+
+/// Register count of a `str8` / `str16` type name, `None` for other types.
+fn string_registers(data_type: &str) -> Option<usize> {
+    if data_type.eq_ignore_ascii_case("str8") {
+        Some(4)
+    } else if data_type.eq_ignore_ascii_case("str16") {
+        Some(8)
+    } else {
+        None
     }
 }
 
+/// Unpack two characters per register, dropping trailing NULs and spaces.
+fn regs_to_string(registers: &[u16]) -> String {
+    let bytes: Vec<u8> = registers.iter().flat_map(|r| r.to_be_bytes()).collect();
+    let len = bytes
+        .iter()
+        .rposition(|&b| b != 0 && b != b' ')
+        .map_or(0, |i| i + 1);
+    String::from_utf8_lossy(&bytes[..len]).into_owned()
+}
+
+/// Pack an ASCII string into 4 (`str8`) or 8 (`str16`) registers,
+/// space-padded.
+fn string_to_regs(s: &str) -> ModbusResult<Vec<u16>> {
+    if !s.is_ascii() {
+        return Err(ModbusError::invalid_data(format!(
+            "String value {:?} is not ASCII",
+            s
+        )));
+    }
+    let width = match s.len() {
+        0..=8 => 8,
+        9..=16 => 16,
+        len => {
+            return Err(ModbusError::invalid_data(format!(
+                "String value of {} characters exceeds str16",
+                len
+            )))
+        }
+    };
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.resize(width, b' ');
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+        .collect())
+}
+
 /// Encode a value from f64 with specified data type for Modbus transmission.
 ///
 /// This is useful when you have a generic numeric value and need to encode
@@ -385,6 +580,7 @@ pub fn encode_f64_as_type(
     if dt.eq_ignore_ascii_case("uint16")
         || dt.eq_ignore_ascii_case("u16")
         || dt.eq_ignore_ascii_case("word")
+        || dt.eq_ignore_ascii_case("bits16")
     {
         return Ok(vec![clamped as u16]);
     }
@@ -438,6 +634,13 @@ pub fn encode_f64_as_type(
         let bytes = clamped.to_be_bytes();
         return Ok(bytes_8_to_regs(&bytes, byte_order).to_vec());
     }
+    if dt.eq_ignore_ascii_case("bcd16") {
+        return Ok(vec![u32_to_bcd(clamped.round() as u32, 9999)? as u16]);
+    }
+    if dt.eq_ignore_ascii_case("bcd32") {
+        let bytes = u32_to_bcd(clamped.round() as u32, BCD32_MAX)?.to_be_bytes();
+        return Ok(bytes_4_to_regs(&bytes, byte_order).to_vec());
+    }
 
     Err(ModbusError::InvalidData {
         message: format!("Unsupported data type: {}", data_type),
@@ -468,6 +671,91 @@ impl ModbusCodec {
             .build())
     }
 
+    /// Build write PDU for FC22 (Mask Write Register).
+    pub fn build_fc22_pdu(address: u16, and_mask: u16, or_mask: u16) -> ModbusResult<ModbusPdu> {
+        Ok(PduBuilder::new()
+            .function_code(0x16)?
+            .address(address)?
+            .quantity(and_mask)?
+            .quantity(or_mask)?
+            .build())
+    }
+
+    /// Build request PDU for FC43 / MEI 0x0E (Read Device Identification).
+    pub fn build_fc2b_pdu(read_device_id_code: u8, object_id: u8) -> ModbusResult<ModbusPdu> {
+        Ok(PduBuilder::new()
+            .function_code(0x2B)?
+            .byte(0x0E)?
+            .byte(read_device_id_code)?
+            .byte(object_id)?
+            .build())
+    }
+
+    /// Parse the data of a Read Device Identification response, starting at
+    /// the MEI type byte.
+    ///
+    /// Layout: MEI type, Read Device ID code, conformity level, more follows
+    /// (0x00 / 0xFF), next object ID, object count, then `count` objects of
+    /// `[id, length, value...]`.
+    pub fn parse_fc2b_response(data: &[u8]) -> ModbusResult<DeviceIdResponse> {
+        if data.len() < 6 {
+            return Err(ModbusError::frame(format!(
+                "Device identification response too short: {} bytes",
+                data.len()
+            )));
+        }
+        if data[0] != 0x0E {
+            return Err(ModbusError::protocol(format!(
+                "Unexpected MEI type 0x{:02X} in device identification response",
+                data[0]
+            )));
+        }
+        let more_follows = match data[3] {
+            0x00 => false,
+            0xFF => true,
+            other => {
+                return Err(ModbusError::invalid_data(format!(
+                    "Invalid more-follows flag 0x{:02X}",
+                    other
+                )))
+            }
+        };
+
+        let count = data[5];
+        let mut objects = Vec::with_capacity(usize::from(count));
+        let mut rest = &data[6..];
+        for _ in 0..count {
+            let [id, len, tail @ ..] = rest else {
+                return Err(ModbusError::frame("Truncated device identification object"));
+            };
+            let len = usize::from(*len);
+            if tail.len() < len {
+                return Err(ModbusError::frame(format!(
+                    "Device identification object 0x{:02X} truncated: expected {} bytes, got {}",
+                    id,
+                    len,
+                    tail.len()
+                )));
+            }
+            objects.push((*id, tail[..len].to_vec()));
+            rest = &tail[len..];
+        }
+        if !rest.is_empty() {
+            return Err(ModbusError::frame(format!(
+                "{} trailing bytes after device identification objects",
+                rest.len()
+            )));
+        }
+
+        Ok(DeviceIdResponse {
+            read_device_id_code: data[1],
+            conformity_level: data[2],
+            more_follows,
+            next_object_id: data[4],
+            objects,
+        })
+    }
+
     /// Build write PDU for FC15 (Write Multiple Coils).
     pub fn build_fc15_pdu(start_address: u16, values: &[bool]) -> ModbusResult<ModbusPdu> {
         if values.is_empty() || values.len() > constants::MAX_WRITE_COILS {
@@ -593,6 +881,8 @@ pub fn registers_for_type(data_type: &str) -> usize {
         || dt.eq_ignore_ascii_case("int16")
         || dt.eq_ignore_ascii_case("i16")
         || dt.eq_ignore_ascii_case("short")
+        || dt.eq_ignore_ascii_case("bcd16")
+        || dt.eq_ignore_ascii_case("bits16")
     {
         1
     } else if dt.eq_ignore_ascii_case("uint32")
@@ -605,6 +895,7 @@ pub fn registers_for_type(data_type: &str) -> usize {
         || dt.eq_ignore_ascii_case("f32")
         || dt.eq_ignore_ascii_case("float")
         || dt.eq_ignore_ascii_case("real")
+        || dt.eq_ignore_ascii_case("bcd32")
     {
         2
     } else if dt.eq_ignore_ascii_case("uint64")
@@ -617,8 +908,11 @@ pub fn registers_for_type(data_type: &str) -> usize {
         || dt.eq_ignore_ascii_case("f64")
         || dt.eq_ignore_ascii_case("double")
         || dt.eq_ignore_ascii_case("lreal")
+        || dt.eq_ignore_ascii_case("str8")
     {
         4
+    } else if dt.eq_ignore_ascii_case("str16") {
+        8
     } else {
         1 // Default to 1 register for unknown types
     }
@@ -635,21 +929,24 @@ mod tests {
     #[test]
     fn test_decode_uint16() {
         let registers = [0x1234];
-        let value = decode_register_value(&registers, "uint16", 0, ByteOrder::BigEndian).unwrap();
+        let value =
+            decode_register_value(&registers, "uint16", 0, ByteOrder::BigEndian, None).unwrap();
         assert_eq!(value, ModbusValue::U16(0x1234));
     }
 
     #[test]
     fn test_decode_int16() {
         let registers = [0xFFFF]; // -1 in two's complement
-        let value = decode_register_value(&registers, "int16", 0, ByteOrder::BigEndian).unwrap();
+        let value =
+            decode_register_value(&registers, "int16", 0, ByteOrder::BigEndian, None).unwrap();
         assert_eq!(value, ModbusValue::I16(-1));
     }
 
     #[test]
     fn test_decode_uint32_big_endian() {
         let registers = [0x1234, 0x5678];
-        let value = decode_register_value(&registers, "uint32", 0, ByteOrder::BigEndian).unwrap();
+        let value =
+            decode_register_value(&registers, "uint32", 0, ByteOrder::BigEndian, None).unwrap();
         assert_eq!(value, ModbusValue::U32(0x12345678));
     }
 
@@ -658,7 +955,7 @@ mod tests {
         // CDAB: Word-swapped big-endian (common in Modbus)
         let registers = [0x5678, 0x1234]; // Swapped order
         let value =
-            decode_register_value(&registers, "uint32", 0, ByteOrder::BigEndianSwap).unwrap();
+            decode_register_value(&registers, "uint32", 0, ByteOrder::BigEndianSwap, None).unwrap();
         assert_eq!(value, ModbusValue::U32(0x12345678));
     }
 
@@ -666,7 +963,8 @@ mod tests {
     fn test_decode_float32() {
         // 25.0 in IEEE 754: 0x41C80000
         let registers = [0x41C8, 0x0000];
-        let value = decode_register_value(&registers, "float32", 0, ByteOrder::BigEndian).unwrap();
+        let value =
+            decode_register_value(&registers, "float32", 0, ByteOrder::BigEndian, None).unwrap();
         if let ModbusValue::F32(f) = value {
             assert!((f - 25.0).abs() < f32::EPSILON);
         } else {
@@ -677,10 +975,12 @@ mod tests {
     #[test]
     fn test_decode_bool_bit_extraction() {
         let registers = [0b0000_0100]; // Bit 2 is set
-        let value = decode_register_value(&registers, "bool", 2, ByteOrder::BigEndian).unwrap();
+        let value =
+            decode_register_value(&registers, "bool", 2, ByteOrder::BigEndian, None).unwrap();
         assert_eq!(value, ModbusValue::Bool(true));
 
-        let value = decode_register_value(&registers, "bool", 0, ByteOrder::BigEndian).unwrap();
+        let value =
+            decode_register_value(&registers, "bool", 0, ByteOrder::BigEndian, None).unwrap();
         assert_eq!(value, ModbusValue::Bool(false));
     }
 
@@ -694,7 +994,7 @@ mod tests {
             ByteOrder::LittleEndianSwap,
         ] {
             let registers = encode_value(&original, order).unwrap();
-            let decoded = decode_register_value(&registers, "uint32", 0, order).unwrap();
+            let decoded = decode_register_value(&registers, "uint32", 0, order, None).unwrap();
             assert_eq!(decoded, original, "Roundtrip failed for {:?}", order);
         }
     }
@@ -709,7 +1009,7 @@ mod tests {
             ByteOrder::LittleEndianSwap,
         ] {
             let registers = encode_value(&original, order).unwrap();
-            let decoded = decode_register_value(&registers, "float32", 0, order).unwrap();
+            let decoded = decode_register_value(&registers, "float32", 0, order, None).unwrap();
             if let (ModbusValue::F32(orig), ModbusValue::F32(dec)) = (&original, &decoded) {
                 assert!(
                     (orig - dec).abs() < 0.001,
@@ -736,6 +1036,114 @@ mod tests {
         assert_eq!(registers_for_type("uint16"), 1);
         assert_eq!(registers_for_type("int32"), 2);
         assert_eq!(registers_for_type("float64"), 4);
+        assert_eq!(registers_for_type("str8"), 4);
+        assert_eq!(registers_for_type("STR16"), 8);
+        assert_eq!(registers_for_type("bcd16"), 1);
+        assert_eq!(registers_for_type("bcd32"), 2);
+        assert_eq!(registers_for_type("bits16"), 1);
+    }
+
+    #[test]
+    fn test_bits16_decode_and_encode() {
+        let mut value =
+            decode_register_value(&[0x8003], "BITS16", 0, ByteOrder::BigEndian, None).unwrap();
+        assert!(value[0] && value[1] && !value[2] && value[15]);
+        assert_eq!(value.register_count(), 1);
+
+        value[1] = false;
+        value[4] = true;
+        assert_eq!(
+            encode_value(&value, ByteOrder::LittleEndian).unwrap(),
+            vec![0x8011]
+        );
+        assert_eq!(value.to_string(), "1000000000010001");
+        assert_eq!(ModbusValue::from_bytes(&value.to_bytes()).unwrap(), value);
+        assert!(decode_register_value(&[], "bits16", 0, ByteOrder::BigEndian, None).is_err());
+    }
+
+    #[test]
+    fn test_bcd_decode_and_encode() {
+        let value =
+            decode_register_value(&[0x0123], "bcd16", 0, ByteOrder::BigEndian, None).unwrap();
+        assert_eq!(value, ModbusValue::Bcd32(123));
+        let value =
+            decode_register_value(&[0x1234, 0x5678], "bcd32", 0, ByteOrder::BigEndian, None)
+                .unwrap();
+        assert_eq!(value, ModbusValue::Bcd32(12_345_678));
+        let swapped = decode_register_value(
+            &[0x5678, 0x1234],
+            "bcd32",
+            0,
+            ByteOrder::BigEndianSwap,
+            None,
+        );
+        assert_eq!(swapped.unwrap(), value);
+
+        assert_eq!(
+            encode_value(&value, ByteOrder::BigEndian).unwrap(),
+            [0x1234, 0x5678]
+        );
+        assert_eq!(
+            encode_f64_as_type(987.0, "bcd16", ByteOrder::BigEndian).unwrap(),
+            [0x0987]
+        );
+        assert_eq!(
+            encode_f64_as_type(12_345.0, "bcd16", ByteOrder::BigEndian).unwrap(),
+            [0x9999]
+        );
+    }
+
+    #[test]
+    fn test_decode_with_scaling() {
+        let order = ByteOrder::BigEndian;
+        let value = decode_register_value(&[2982], "uint16", 0, order, Some((0.1, -273.15)));
+        assert!((value.unwrap().as_f64() - 25.05).abs() < 1e-9);
+        let scaled_bcd = decode_register_value(&[0x0150], "bcd16", 0, order, Some((0.01, 0.0)));
+        assert_eq!(scaled_bcd.unwrap(), ModbusValue::F64(1.5));
+        let text = decode_register_value(&[0x4142; 4], "str8", 0, order, Some((1.0, 0.0)));
+        assert!(matches!(text, Err(ModbusError::InvalidData { .. })));
+    }
+
+    #[test]
+    fn test_bcd_rejects_invalid_digits() {
+        for registers in [[0x012A, 0x0000], [0x0000, 0xF000]] {
+            let err = decode_register_value(&registers, "bcd32", 0, ByteOrder::BigEndian, None);
+            assert!(matches!(err, Err(ModbusError::InvalidData { .. })));
+        }
+        assert!(decode_register_value(&[0x00A0], "bcd16", 0, ByteOrder::BigEndian, None).is_err());
+        let too_big = ModbusValue::Bcd32(100_000_000);
+        assert!(encode_value(&too_big, ByteOrder::BigEndian).is_err());
+    }
+
+    #[test]
+    fn test_string_roundtrip() {
+        let registers = encode_value(&ModbusValue::from("PUMP-1"), ByteOrder::BigEndian).unwrap();
+        assert_eq!(registers, [0x5055, 0x4D50, 0x2D31, 0x2020]);
+        let decoded = decode_register_value(&registers, "str8", 0, ByteOrder::LittleEndian, None);
+        assert_eq!(decoded.unwrap(), ModbusValue::from("PUMP-1"));
+
+        let alarm = ModbusValue::from("HIGH TEMP ALARM");
+        let registers = encode_value(&alarm, ByteOrder::BigEndian).unwrap();
+        assert_eq!(registers.len(), alarm.register_count());
+        assert_eq!(registers.len(), 8);
+        let decoded = decode_register_value(&registers, "str16", 0, ByteOrder::BigEndian, None);
+        assert_eq!(decoded.unwrap(), alarm);
+    }
+
+    #[test]
+    fn test_string_padding_and_errors() {
+        let nul_padded = [0x4F4B, 0x0000, 0x2000, 0x0000];
+        let value =
+            decode_register_value(&nul_padded, "str8", 0, ByteOrder::BigEndian, None).unwrap();
+        assert_eq!(value, ModbusValue::Str("OK".to_string()));
+        assert!(
+            decode_register_value(&nul_padded, "str16", 0, ByteOrder::BigEndian, None).is_err()
+        );
+
+        let too_long = ModbusValue::from("ABCDEFGHIJKLMNOPQ");
+        assert!(encode_value(&too_long, ByteOrder::BigEndian).is_err());
+        let not_ascii = ModbusValue::from("température");
+        assert!(encode_value(&not_ascii, ByteOrder::BigEndian).is_err());
     }
 
     #[test]
@@ -750,6 +1158,12 @@ mod tests {
         assert_eq!(pdu.as_slice(), &[0x06, 0x01, 0x00, 0x12, 0x34]);
     }
 
+    #[test]
+    fn test_build_fc22_pdu() {
+        let pdu = ModbusCodec::build_fc22_pdu(0x0004, 0x00F2, 0x0025).unwrap();
+        assert_eq!(pdu.as_slice(), &[0x16, 0x00, 0x04, 0x00, 0xF2, 0x00, 0x25]);
+    }
+
     #[test]
     fn test_build_fc15_pdu() {
         let pdu = ModbusCodec::build_fc15_pdu(0x0100, &[true, false, true]).unwrap();
@@ -768,4 +1182,35 @@ mod tests {
             &[0x10, 0x01, 0x00, 0x00, 0x02, 0x04, 0x12, 0x34, 0x56, 0x78]
         );
     }
+
+    #[test]
+    fn test_build_fc2b_pdu() {
+        let pdu = ModbusCodec::build_fc2b_pdu(0x01, 0x00).unwrap();
+        assert_eq!(pdu.as_slice(), &[0x2B, 0x0E, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn test_parse_fc2b_response() {
+        let mut data = vec![0x0E, 0x01, 0x81, 0xFF, 0x02, 0x02];
+        data.extend_from_slice(&[0x00, 0x07]);
+        data.extend_from_slice(b"Voltage");
+        data.extend_from_slice(&[0x01, 0x04]);
+        data.extend_from_slice(b"PM-3");
+
+        let page = ModbusCodec::parse_fc2b_response(&data).unwrap();
+        assert_eq!(page.read_device_id_code, 0x01);
+        assert_eq!(page.conformity_level, 0x81);
+        assert!(page.more_follows);
+        assert_eq!(page.next_object_id, 0x02);
+        assert_eq!(
+            page.objects,
+            vec![(0x00, b"Voltage".to_vec()), (0x01, b"PM-3".to_vec())]
+        );
+
+        // Object length running past the end of the frame
+        assert!(ModbusCodec::parse_fc2b_response(&data[..data.len() - 1]).is_err());
+        data.push(0x00);
+        assert!(ModbusCodec::parse_fc2b_response(&data).is_err());
+        assert!(ModbusCodec::parse_fc2b_response(&[0x0D, 0x01, 0x01, 0x00, 0x00, 0x00]).is_err());
+    }
 }
@@ -58,6 +58,18 @@ pub const MAX_READ_REGISTERS: usize = 125;
 /// - Therefore: N ≤ (253 - 6) / 2 = 123.5 → 123 registers
 pub const MAX_WRITE_REGISTERS: usize = 123;
 
+/// Maximum number of registers written by FC23 (Read/Write Multiple Registers)
+///
+/// Calculation for request PDU:
+/// - Function Code: 1 byte
+/// - Read Starting Address + Quantity to Read: 4 bytes
+/// - Write Starting Address + Quantity to Write: 4 bytes
+/// - Write Byte Count: 1 byte
+/// - Write Register Values: N × 2 bytes
+/// - Total: 1 + 4 + 4 + 1 + (N × 2) ≤ 253
+/// - Therefore: N ≤ (253 - 10) / 2 = 121.5 → 121 registers
+pub const MAX_READ_WRITE_REGISTERS: usize = 121;
+
 // ============================================================================
 // Coil Operation Limits
 // ============================================================================
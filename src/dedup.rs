@@ -0,0 +1,376 @@
+//! # Read Deduplication
+//!
+//! [`DedupTransport`] collapses identical concurrent register reads into a
+//! single Modbus request. In a pub/sub gateway, ten subscribers polling the
+//! same register at roughly the same moment would otherwise queue ten
+//! identical requests on one serial link or TCP connection.
+//!
+//! A read of holding or input registers (FC03/FC04) is keyed by
+//! `(slave_id, function, address, quantity)`. The first caller (the *leader*)
+//! issues the request; any identical read that arrives while the leader is
+//! still in flight, and no later than `dedup_window` after the leader started,
+//! waits for the leader's result instead of issuing its own request. Writes,
+//! other function codes and non-identical reads pass straight through.
+//!
+//! Unlike the other transports, requests are issued through `&self`, so the
+//! transport can be shared between tasks behind an [`Arc`](std::sync::Arc):
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use voltage_modbus::dedup::DedupTransport;
+//! use voltage_modbus::testing::EchoTransport;
+//! use voltage_modbus::ModbusFunction;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> voltage_modbus::ModbusResult<()> {
+//! let transport = Arc::new(DedupTransport::new(
+//!     EchoTransport::new(),
+//!     Duration::from_millis(100),
+//! ));
+//!
+//! let fc = ModbusFunction::ReadHoldingRegisters;
+//! let (a, b) = tokio::join!(
+//!     transport.read_registers(1, fc, 0, 4),
+//!     transport.read_registers(1, fc, 0, 4),
+//! );
+//! assert_eq!(a?, b?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`DedupTransport`] also implements [`ModbusTransport`], so it can be handed
+//! to [`GenericModbusClient`](crate::GenericModbusClient); deduplication then
+//! only applies to requests issued concurrently through [`DedupTransport::send`].
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use tokio::sync::oneshot;
+
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::{ModbusFunction, ModbusRequest, ModbusResponse, SlaveId};
+use crate::transport::{ModbusTransport, TransportStats};
+
+/// Default window in which an identical read joins an in-flight one.
+pub const DEFAULT_DEDUP_WINDOW: Duration = Duration::from_millis(100);
+
+/// `(slave_id, function code, address, quantity)` of a register read.
+type DedupKey = (SlaveId, u8, u16, u16);
+
+type Waiter = oneshot::Sender<ModbusResult<Vec<u16>>>;
+
+/// A read currently being performed by a leader.
+struct InFlight {
+    started: Instant,
+    waiters: Vec<Waiter>,
+}
+
+/// How a caller takes part in a read.
+enum Role {
+    /// Issues the request and resolves the waiters.
+    Leader,
+    /// Waits for the leader's result.
+    Follower(oneshot::Receiver<ModbusResult<Vec<u16>>>),
+    /// Issues its own request without touching the in-flight map.
+    Independent,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Removes the leader's in-flight entry when the leader finishes or is dropped.
+///
+/// Dropping the entry without a result drops the waiters' senders, so a
+/// cancelled leader fails its followers instead of leaving them hanging.
+struct LeaderGuard<'a> {
+    in_flight: &'a Mutex<HashMap<DedupKey, InFlight>>,
+    key: DedupKey,
+}
+
+impl LeaderGuard<'_> {
+    fn finish(self, result: &ModbusResult<Vec<u16>>) {
+        let entry = lock(self.in_flight).remove(&self.key);
+        if let Some(entry) = entry {
+            for waiter in entry.waiters {
+                let _ = waiter.send(result.clone());
+            }
+        }
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for LeaderGuard<'_> {
+    fn drop(&mut self) {
+        lock(self.in_flight).remove(&self.key);
+    }
+}
+
+/// Transport wrapper that shares one in-flight read between identical callers.
+pub struct DedupTransport<T: ModbusTransport> {
+    inner: tokio::sync::Mutex<T>,
+    in_flight: Mutex<HashMap<DedupKey, InFlight>>,
+    dedup_window: Duration,
+    deduplicated: AtomicU64,
+    /// Inner stats as of the last completed request
+    stats: Mutex<TransportStats>,
+}
+
+impl<T: ModbusTransport> DedupTransport<T> {
+    /// Wrap `inner`; identical reads within `dedup_window` of each other share a request.
+    pub fn new(inner: T, dedup_window: Duration) -> Self {
+        let stats = inner.get_stats();
+        Self {
+            inner: tokio::sync::Mutex::new(inner),
+            in_flight: Mutex::new(HashMap::new()),
+            dedup_window,
+            deduplicated: AtomicU64::new(0),
+            stats: Mutex::new(stats),
+        }
+    }
+
+    /// Window in which an identical read joins an in-flight one.
+    pub fn dedup_window(&self) -> Duration {
+        self.dedup_window
+    }
+
+    /// Number of reads answered from another caller's request.
+    pub fn deduplicated(&self) -> u64 {
+        self.deduplicated.load(Ordering::Relaxed)
+    }
+
+    /// Number of distinct reads currently in flight.
+    pub fn in_flight_count(&self) -> usize {
+        lock(&self.in_flight).len()
+    }
+
+    /// Unwrap the inner transport.
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+
+    /// Read holding (FC03) or input (FC04) registers, sharing the request with
+    /// any identical read already in flight.
+    pub async fn read_registers(
+        &self,
+        slave_id: SlaveId,
+        function: ModbusFunction,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<u16>> {
+        if !matches!(
+            function,
+            ModbusFunction::ReadHoldingRegisters | ModbusFunction::ReadInputRegisters
+        ) {
+            return Err(ModbusError::invalid_function(function.to_u8()));
+        }
+
+        let key = (slave_id, function.to_u8(), address, quantity);
+        let role = {
+            let mut in_flight = lock(&self.in_flight);
+            match in_flight.get_mut(&key) {
+                Some(entry) if entry.started.elapsed() <= self.dedup_window => {
+                    let (tx, rx) = oneshot::channel();
+                    entry.waiters.push(tx);
+                    Role::Follower(rx)
+                }
+                // Leader started too long ago: issue an independent request.
+                Some(_) => Role::Independent,
+                None => {
+                    in_flight.insert(
+                        key,
+                        InFlight {
+                            started: Instant::now(),
+                            waiters: Vec::new(),
+                        },
+                    );
+                    Role::Leader
+                }
+            }
+        };
+
+        match role {
+            Role::Leader => self.lead(key).await,
+            Role::Follower(rx) => {
+                self.deduplicated.fetch_add(1, Ordering::Relaxed);
+                rx.await.map_err(|_| {
+                    ModbusError::connection("deduplicated read abandoned by its leader")
+                })?
+            }
+            Role::Independent => {
+                let request = ModbusRequest::new_read(slave_id, function, address, quantity);
+                self.forward(&request).await?.parse_registers()
+            }
+        }
+    }
+
+    /// Perform the read for `key` and hand the result to every waiter.
+    async fn lead(&self, key: DedupKey) -> ModbusResult<Vec<u16>> {
+        let guard = LeaderGuard {
+            in_flight: &self.in_flight,
+            key,
+        };
+        let (slave_id, function, address, quantity) = key;
+        let function = ModbusFunction::from_u8(function)?;
+        let request = ModbusRequest::new_read(slave_id, function, address, quantity);
+        let result = match self.forward(&request).await {
+            Ok(response) => response.parse_registers(),
+            Err(e) => Err(e),
+        };
+        guard.finish(&result);
+        result
+    }
+
+    /// Send `request`, deduplicating FC03/FC04 reads; everything else passes through.
+    pub async fn send(&self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        match request.function {
+            ModbusFunction::ReadHoldingRegisters | ModbusFunction::ReadInputRegisters
+                if request.slave_id != 0 =>
+            {
+                let registers = self
+                    .read_registers(
+                        request.slave_id,
+                        request.function,
+                        request.address,
+                        request.quantity,
+                    )
+                    .await?;
+                let mut data = Vec::with_capacity(1 + registers.len() * 2);
+                data.push((registers.len() * 2) as u8);
+                for register in registers {
+                    data.extend_from_slice(&register.to_be_bytes());
+                }
+                Ok(ModbusResponse::new_success(
+                    request.slave_id,
+                    request.function,
+                    data,
+                ))
+            }
+            _ => self.forward(request).await,
+        }
+    }
+
+    /// Issue `request` on the inner transport.
+    async fn forward(&self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        let mut inner = self.inner.lock().await;
+        let result = inner.request(request).await;
+        *lock(&self.stats) = inner.get_stats();
+        result
+    }
+}
+
+impl<T: ModbusTransport> ModbusTransport for DedupTransport<T> {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        self.send(request).await
+    }
+
+    fn is_connected(&self) -> bool {
+        match self.inner.try_lock() {
+            Ok(inner) => inner.is_connected(),
+            // A request is in progress, so the link was up when it started.
+            Err(_) => true,
+        }
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        let result = self.inner.get_mut().close().await;
+        *lock(&self.stats) = self.inner.get_mut().get_stats();
+        result
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        lock(&self.stats).clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{EchoTransport, LatencyTransport};
+
+    const FC03: ModbusFunction = ModbusFunction::ReadHoldingRegisters;
+
+    fn slow_echo() -> DedupTransport<LatencyTransport<EchoTransport>> {
+        DedupTransport::new(
+            LatencyTransport::new(
+                EchoTransport::new(),
+                Duration::from_millis(50),
+                Duration::ZERO,
+            ),
+            DEFAULT_DEDUP_WINDOW,
+        )
+    }
+
+    #[tokio::test]
+    async fn identical_concurrent_reads_share_one_request() {
+        let transport = slow_echo();
+
+        let (a, b, c) = tokio::join!(
+            transport.read_registers(1, FC03, 10, 3),
+            transport.read_registers(1, FC03, 10, 3),
+            transport.read_registers(1, FC03, 10, 3),
+        );
+        assert_eq!(a.unwrap(), vec![0, 1, 2]);
+        assert_eq!(b.unwrap(), vec![0, 1, 2]);
+        assert_eq!(c.unwrap(), vec![0, 1, 2]);
+
+        assert_eq!(transport.deduplicated(), 2);
+        assert_eq!(transport.in_flight_count(), 0);
+        assert_eq!(transport.get_stats().requests_sent, 1);
+    }
+
+    #[tokio::test]
+    async fn distinct_reads_and_writes_pass_through() {
+        let transport = slow_echo();
+        let write =
+            ModbusRequest::new_write(1, ModbusFunction::WriteSingleRegister, 10, vec![0x12, 0x34]);
+
+        let (a, b, c, w) = tokio::join!(
+            transport.read_registers(1, FC03, 10, 3),
+            transport.read_registers(1, FC03, 10, 4),
+            transport.read_registers(2, FC03, 10, 3),
+            transport.send(&write),
+        );
+        assert!(a.is_ok() && b.is_ok() && c.is_ok() && w.is_ok());
+
+        assert_eq!(transport.deduplicated(), 0);
+        assert_eq!(transport.get_stats().requests_sent, 4);
+    }
+
+    #[tokio::test]
+    async fn reads_outside_window_issue_their_own_request() {
+        let transport = DedupTransport::new(
+            LatencyTransport::new(
+                EchoTransport::new(),
+                Duration::from_millis(50),
+                Duration::ZERO,
+            ),
+            Duration::from_millis(5),
+        );
+
+        let late = async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            transport.read_registers(1, FC03, 0, 2).await
+        };
+        let (a, b) = tokio::join!(transport.read_registers(1, FC03, 0, 2), late);
+        assert_eq!(a.unwrap(), b.unwrap());
+
+        assert_eq!(transport.deduplicated(), 0);
+        assert_eq!(transport.get_stats().requests_sent, 2);
+    }
+
+    #[tokio::test]
+    async fn send_rebuilds_read_response_for_followers() {
+        let transport = slow_echo();
+        let request = ModbusRequest::new_read(1, FC03, 0, 2);
+
+        let (a, b) = tokio::join!(transport.send(&request), transport.send(&request));
+        let (a, b) = (a.unwrap(), b.unwrap());
+        assert_eq!(a.data(), &[0x04, 0x00, 0x00, 0x00, 0x01]);
+        assert_eq!(a.data(), b.data());
+        assert_eq!(transport.get_stats().requests_sent, 1);
+    }
+}
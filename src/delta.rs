@@ -0,0 +1,186 @@
+//! # Delta-Encoded Register Updates
+//!
+//! A gateway mirroring registers to a remote system over a metered link does
+//! not need to resend values that have not changed. [`DeltaEncoder`] turns
+//! successive [`RegisterSnapshot`]s into [`DeltaPayload`]s holding only the
+//! raw registers that differ from the previous snapshot of the same slave;
+//! [`DeltaDecoder`] applies them to a local shadow copy to rebuild the full
+//! register state on the receiving side.
+//!
+//! The first payload for a slave carries every register (there is nothing to
+//! diff against), so a decoder that starts with the encoder stays in sync.
+//!
+//! ```rust
+//! use std::time::{Duration, Instant};
+//! use voltage_modbus::delta::{DeltaDecoder, DeltaEncoder};
+//! use voltage_modbus::RegisterSnapshot;
+//!
+//! let snapshot = |registers: Vec<(u16, u16)>| RegisterSnapshot {
+//!     values: Vec::new(),
+//!     registers,
+//!     captured_at: Instant::now(),
+//!     slave_id: 1,
+//!     is_consistent: true,
+//!     read_duration: Duration::ZERO,
+//! };
+//!
+//! let mut encoder = DeltaEncoder::new();
+//! let mut decoder = DeltaDecoder::new();
+//!
+//! let first = encoder.encode(&snapshot(vec![(0, 10), (1, 20)]));
+//! assert_eq!(first.changed.len(), 2);
+//! decoder.apply(&first);
+//!
+//! let second = encoder.encode(&snapshot(vec![(0, 10), (1, 21)]));
+//! assert_eq!(second.changed, vec![(1, 21)]);
+//! assert_eq!(decoder.apply(&second), vec![(0, 10), (1, 21)]);
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::time::Instant;
+
+use crate::protocol::SlaveId;
+use crate::snapshot::RegisterSnapshot;
+
+/// Registers that changed since the previous snapshot of one slave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeltaPayload {
+    /// Slave the registers belong to.
+    pub slave_id: SlaveId,
+    /// Changed `(address, register)` pairs, in address order.
+    pub changed: Vec<(u16, u16)>,
+    /// Capture time of the snapshot the delta was computed from.
+    pub timestamp: Instant,
+}
+
+impl DeltaPayload {
+    /// `true` if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty()
+    }
+}
+
+/// Produces [`DeltaPayload`]s from successive snapshots.
+#[derive(Debug, Clone, Default)]
+pub struct DeltaEncoder {
+    /// Last register value sent, per slave and address
+    prev: HashMap<(SlaveId, u16), u16>,
+}
+
+impl DeltaEncoder {
+    /// Create an encoder with no previous state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers in `snapshot` that differ from (or were absent in) the last
+    /// snapshot of the same slave.
+    pub fn encode(&mut self, snapshot: &RegisterSnapshot) -> DeltaPayload {
+        let mut changed = Vec::new();
+        for &(address, value) in &snapshot.registers {
+            let key = (snapshot.slave_id, address);
+            if self.prev.insert(key, value) != Some(value) {
+                changed.push((address, value));
+            }
+        }
+        changed.sort_unstable_by_key(|&(address, _)| address);
+        DeltaPayload {
+            slave_id: snapshot.slave_id,
+            changed,
+            timestamp: snapshot.captured_at,
+        }
+    }
+
+    /// Forget all previous state; the next payload per slave is complete again.
+    pub fn reset(&mut self) {
+        self.prev.clear();
+    }
+}
+
+/// Rebuilds full register state from [`DeltaPayload`]s.
+#[derive(Debug, Clone, Default)]
+pub struct DeltaDecoder {
+    /// Shadow copy of each slave's registers
+    shadow: HashMap<SlaveId, BTreeMap<u16, u16>>,
+}
+
+impl DeltaDecoder {
+    /// Create a decoder with an empty shadow.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply `delta` and return the full `(address, register)` state of its
+    /// slave, in address order.
+    pub fn apply(&mut self, delta: &DeltaPayload) -> Vec<(u16, u16)> {
+        let shadow = self.shadow.entry(delta.slave_id).or_default();
+        shadow.extend(delta.changed.iter().copied());
+        shadow
+            .iter()
+            .map(|(&address, &value)| (address, value))
+            .collect()
+    }
+
+    /// Current shadow value of one register.
+    pub fn get(&self, slave_id: SlaveId, address: u16) -> Option<u16> {
+        self.shadow.get(&slave_id)?.get(&address).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn snapshot(slave_id: SlaveId, registers: &[(u16, u16)]) -> RegisterSnapshot {
+        RegisterSnapshot {
+            values: Vec::new(),
+            registers: registers.to_vec(),
+            captured_at: Instant::now(),
+            slave_id,
+            is_consistent: true,
+            read_duration: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn first_delta_is_complete_and_unchanged_is_empty() {
+        let mut encoder = DeltaEncoder::new();
+        let state = snapshot(1, &[(0, 1), (1, 2), (2, 3)]);
+
+        assert_eq!(encoder.encode(&state).changed, vec![(0, 1), (1, 2), (2, 3)]);
+        assert!(encoder.encode(&state).is_empty());
+    }
+
+    #[test]
+    fn successive_deltas_reconstruct_each_state() {
+        let states: Vec<Vec<(u16, u16)>> = vec![
+            vec![(0, 100), (1, 200), (2, 300)],
+            vec![(0, 100), (1, 201), (2, 300)],
+            vec![(0, 99), (1, 201), (2, 301)],
+            vec![(0, 99), (1, 201), (2, 301)],
+        ];
+        let mut encoder = DeltaEncoder::new();
+        let mut decoder = DeltaDecoder::new();
+
+        for state in &states {
+            let delta = encoder.encode(&snapshot(1, state));
+            assert_eq!(&decoder.apply(&delta), state);
+        }
+    }
+
+    #[test]
+    fn slaves_are_tracked_independently() {
+        let mut encoder = DeltaEncoder::new();
+        let mut decoder = DeltaDecoder::new();
+
+        decoder.apply(&encoder.encode(&snapshot(1, &[(0, 5)])));
+        let other = encoder.encode(&snapshot(2, &[(0, 5)]));
+        assert_eq!(other.changed, vec![(0, 5)]);
+        assert_eq!(decoder.apply(&other), vec![(0, 5)]);
+        assert_eq!(decoder.get(1, 0), Some(5));
+
+        encoder.reset();
+        assert_eq!(encoder.encode(&snapshot(1, &[(0, 5)])).changed.len(), 1);
+    }
+}
@@ -12,6 +12,47 @@
 //!
 //! Some devices may have lower limits. This module allows configuring
 //! per-device limits for optimal communication.
+//!
+//! ## Presets
+//!
+//! [`DeviceLimits`] has constructors for common PLCs and meters, e.g.
+//! [`DeviceLimits::siemens_s7`], and [`DeviceLimits::from_device_name`] to
+//! pick one from a configuration string. Each preset names the manual its
+//! limits come from; firmware and communication modules differ, so treat
+//! them as a starting point and check the manual for your hardware.
+//!
+//! ## Configuration Files
+//!
+//! With the `serde` feature `DeviceLimits` deserializes from any serde
+//! format, using the field names below; omitted fields keep their
+//! [`Default`] value and unknown fields are rejected. A `devices.toml` can
+//! hold one table per device type:
+//!
+//! ```toml
+//! [meter]
+//! max_read_registers = 80
+//! inter_request_delay_ms = 20
+//!
+//! [plc]
+//! max_read_registers = 100
+//! ```
+//!
+//! [`DeviceLimits::from_json_str`] (`serde` feature) and
+//! [`DeviceLimits::from_toml_str`] (`toml` feature) parse a single device.
+//!
+//! ## Adaptive Delay
+//!
+//! With [`DeviceLimits::adaptive_delay_enabled`] the batch methods
+//! (`read_03_batch` and friends) time every chunk against
+//! `response_timeout_ms`. Two responses in a row slower than 80% of it double
+//! the delay before the next chunk; a response faster than 20% halves it. The
+//! delay starts at `inter_request_delay_ms` and stays between
+//! `min_inter_request_delay_ms` and `max_inter_request_delay_ms`.
+
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use crate::error::{ModbusError, ModbusResult};
 
 /// Default maximum registers per read operation (Modbus specification).
 pub const DEFAULT_MAX_READ_REGISTERS: u16 = 125;
@@ -28,6 +69,12 @@ pub const DEFAULT_MAX_WRITE_COILS: u16 = 1968;
 /// Default inter-request delay in milliseconds.
 pub const DEFAULT_INTER_REQUEST_DELAY_MS: u64 = 0;
 
+/// Default lower bound of the adaptive inter-request delay in milliseconds.
+pub const DEFAULT_MIN_INTER_REQUEST_DELAY_MS: u64 = 0;
+
+/// Default upper bound of the adaptive inter-request delay in milliseconds.
+pub const DEFAULT_MAX_INTER_REQUEST_DELAY_MS: u64 = 1000;
+
 /// Device-specific Modbus protocol limits.
 ///
 /// Use this to configure limits for devices that don't support
@@ -46,6 +93,11 @@ pub const DEFAULT_INTER_REQUEST_DELAY_MS: u64 = 0;
 /// assert_eq!(limits.max_read_registers, 50);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
 pub struct DeviceLimits {
     /// Maximum registers per read request.
     pub max_read_registers: u16,
@@ -56,7 +108,18 @@ pub struct DeviceLimits {
     /// Maximum coils per write request.
     pub max_write_coils: u16,
     /// Minimum delay between requests (milliseconds).
+    ///
+    /// With `adaptive_delay_enabled` this is the starting delay.
     pub inter_request_delay_ms: u64,
+    /// Adjust the delay between batch chunks to the device's response time.
+    pub adaptive_delay_enabled: bool,
+    /// Lower bound of the adaptive delay (milliseconds).
+    pub min_inter_request_delay_ms: u64,
+    /// Upper bound of the adaptive delay (milliseconds).
+    pub max_inter_request_delay_ms: u64,
+    /// Response timeout the adaptive delay measures responses against
+    /// (milliseconds); set it to the client's timeout.
+    pub response_timeout_ms: u64,
 }
 
 impl DeviceLimits {
@@ -78,9 +141,159 @@ impl DeviceLimits {
             max_read_coils: 500,
             max_write_coils: 500,
             inter_request_delay_ms: 10,
+            ..Self::default()
         }
     }
 
+    /// Siemens S7-1200 / S7-1500 running the `MB_SERVER` instruction.
+    ///
+    /// 125 registers per read and 123 per write, the spec maximum.
+    /// Source: S7-1200 System Manual, "MB_SERVER" (V4.x instruction set).
+    pub fn siemens_s7() -> Self {
+        Self::default()
+    }
+
+    /// Schneider Modicon M340 (BMX P34 CPUs and BMX NOE/NOC modules).
+    ///
+    /// 100 registers per read/write and a 10 ms gap between requests.
+    /// Source: Modicon M340 for Ethernet, Communications Modules and
+    /// Processors User Manual, "Modbus/TCP server limits".
+    pub fn schneider_m340() -> Self {
+        Self::default()
+            .with_max_read_registers(100)
+            .with_max_write_registers(100)
+            .with_inter_request_delay_ms(10)
+    }
+
+    /// Omron CJ2M / CJ-series CPUs using the Modbus/TCP function blocks.
+    ///
+    /// 50 registers per read/write.
+    /// Source: Omron CJ-series Modbus/TCP Function Blocks manual (W505),
+    /// server block limits.
+    pub fn omron_cj() -> Self {
+        Self::default()
+            .with_max_read_registers(50)
+            .with_max_write_registers(50)
+    }
+
+    /// Mitsubishi MELSEC FX3 (FX3U-485ADP-MB) and iQ-F FX5 built-in Modbus.
+    ///
+    /// 125 registers per read and 123 per write, with a 10 ms gap that
+    /// covers the FX3 serial adapter's scan-synchronised replies.
+    /// Source: MELSEC iQ-F FX5 User's Manual (MODBUS Communication) and
+    /// FX3 Series User's Manual, MODBUS Serial Communication Edition.
+    pub fn mitsubishi_fx() -> Self {
+        Self::default().with_inter_request_delay_ms(10)
+    }
+
+    /// WAGO PFC100 / PFC200 controllers (750-81xx, 750-82xx).
+    ///
+    /// Full spec limits with no delay.
+    /// Source: WAGO PFC200 product manual, "Modbus TCP/UDP slave".
+    pub fn wago_pfc() -> Self {
+        Self::default()
+    }
+
+    /// Beckhoff TwinCAT with the TF6250 Modbus TCP server.
+    ///
+    /// Full spec limits with no delay.
+    /// Source: Beckhoff Information System, TF6250 TwinCAT 3 Modbus TCP.
+    pub fn beckhoff_twincat() -> Self {
+        Self::default()
+    }
+
+    /// Eastron SDM630 / SDM120 energy meters (RS-485).
+    ///
+    /// At most 40 float parameters (80 registers) per read. Writes set one
+    /// float parameter (2 registers), and a 20 ms gap lets the meter
+    /// finish measuring between requests.
+    /// Source: Eastron SDM630 Modbus Protocol, "Modbus Protocol General
+    /// Information".
+    pub fn eastron_sdm() -> Self {
+        Self::default()
+            .with_max_read_registers(80)
+            .with_max_write_registers(2)
+            .with_inter_request_delay_ms(20)
+    }
+
+    /// Delta DVP-series PLCs (DVP-SS2/SA2/SX2 COM ports).
+    ///
+    /// 100 registers per read/write and 256 coils per request.
+    /// Source: Delta DVP-ES2/EX2/SS2/SA2/SX2/SE Operation Manual –
+    /// Programming, "MODBUS communication".
+    pub fn delta_dvp() -> Self {
+        Self::default()
+            .with_max_read_registers(100)
+            .with_max_write_registers(100)
+            .with_max_read_coils(256)
+            .with_max_write_coils(256)
+    }
+
+    /// Look up a preset by device name.
+    ///
+    /// Case, spaces, `-` and `_` are ignored, and common model names are
+    /// accepted as aliases (`"S7-1200"`, `"m340"`, `"CJ2M"`, `"FX5U"`, ...).
+    /// `"default"` and `"conservative"` map to [`DeviceLimits::default`] and
+    /// [`DeviceLimits::conservative`].
+    ///
+    /// ```rust
+    /// use voltage_modbus::DeviceLimits;
+    ///
+    /// assert_eq!(
+    ///     DeviceLimits::from_device_name("Schneider M340"),
+    ///     Some(DeviceLimits::schneider_m340())
+    /// );
+    /// assert_eq!(DeviceLimits::from_device_name("unknown"), None);
+    /// ```
+    pub fn from_device_name(name: &str) -> Option<Self> {
+        let key: String = name
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        Some(match key.as_str() {
+            "default" | "spec" | "modbus" => Self::default(),
+            "conservative" => Self::conservative(),
+            "siemenss7" | "s7" | "s71200" | "s71500" => Self::siemens_s7(),
+            "schneiderm340" | "modiconm340" | "m340" => Self::schneider_m340(),
+            "omroncj" | "omroncj2m" | "cj" | "cj2m" => Self::omron_cj(),
+            "mitsubishifx" | "fx" | "fx3u" | "fx3g" | "fx5u" | "fx5uc" => Self::mitsubishi_fx(),
+            "wagopfc" | "pfc100" | "pfc200" => Self::wago_pfc(),
+            "beckhofftwincat" | "twincat" | "tf6250" => Self::beckhoff_twincat(),
+            "eastronsdm" | "sdm" | "sdm120" | "sdm630" => Self::eastron_sdm(),
+            "deltadvp" | "dvp" => Self::delta_dvp(),
+            _ => return None,
+        })
+    }
+
+    /// Parse limits from a JSON object such as
+    /// `{"max_read_registers": 50, "inter_request_delay_ms": 10}`.
+    ///
+    /// Returns a configuration error for malformed JSON, unknown fields or
+    /// out-of-range values.
+    #[cfg(feature = "serde")]
+    pub fn from_json_str(s: &str) -> ModbusResult<Self> {
+        serde_json::from_str(s)
+            .map_err(|e| ModbusError::configuration(format!("Invalid device limits JSON: {}", e)))
+    }
+
+    /// Parse limits from a TOML document with the fields at the top level.
+    ///
+    /// ```rust
+    /// use voltage_modbus::DeviceLimits;
+    ///
+    /// let limits = DeviceLimits::from_toml_str("max_read_registers = 50").unwrap();
+    /// assert_eq!(limits, DeviceLimits::new().with_max_read_registers(50));
+    /// ```
+    ///
+    /// Returns a configuration error for malformed TOML, unknown fields or
+    /// out-of-range values.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(s: &str) -> ModbusResult<Self> {
+        toml::from_str(s)
+            .map_err(|e| ModbusError::configuration(format!("Invalid device limits TOML: {}", e)))
+    }
+
     /// Set maximum read registers.
     pub fn with_max_read_registers(mut self, count: u16) -> Self {
         self.max_read_registers = count;
@@ -111,6 +324,20 @@ impl DeviceLimits {
         self
     }
 
+    /// Enable the adaptive delay, kept between `min_ms` and `max_ms`.
+    pub fn with_adaptive_delay(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.adaptive_delay_enabled = true;
+        self.min_inter_request_delay_ms = min_ms;
+        self.max_inter_request_delay_ms = max_ms;
+        self
+    }
+
+    /// Set the response timeout the adaptive delay compares against.
+    pub fn with_response_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.response_timeout_ms = timeout_ms;
+        self
+    }
+
     /// Calculate the number of read requests needed for a given register count.
     pub fn read_request_count(&self, total_registers: u16) -> u16 {
         if total_registers == 0 {
@@ -156,8 +383,74 @@ impl Default for DeviceLimits {
             max_read_coils: DEFAULT_MAX_READ_COILS,
             max_write_coils: DEFAULT_MAX_WRITE_COILS,
             inter_request_delay_ms: DEFAULT_INTER_REQUEST_DELAY_MS,
+            adaptive_delay_enabled: false,
+            min_inter_request_delay_ms: DEFAULT_MIN_INTER_REQUEST_DELAY_MS,
+            max_inter_request_delay_ms: DEFAULT_MAX_INTER_REQUEST_DELAY_MS,
+            response_timeout_ms: crate::DEFAULT_TIMEOUT_MS,
+        }
+    }
+}
+
+/// Delay between the chunks of one batch call.
+///
+/// Fixed at `inter_request_delay_ms` unless the limits enable the adaptive
+/// delay, in which case [`observe`](Self::observe) adjusts it.
+#[derive(Debug, Clone)]
+pub(crate) struct InterRequestDelay {
+    adaptive: bool,
+    delay_ms: u64,
+    min_ms: u64,
+    max_ms: u64,
+    timeout_ms: u64,
+    slow_responses: u8,
+}
+
+impl InterRequestDelay {
+    pub(crate) fn new(limits: &DeviceLimits) -> Self {
+        let min_ms = limits.min_inter_request_delay_ms;
+        let max_ms = limits.max_inter_request_delay_ms.max(min_ms);
+        let delay_ms = if limits.adaptive_delay_enabled {
+            limits.inter_request_delay_ms.clamp(min_ms, max_ms)
+        } else {
+            limits.inter_request_delay_ms
+        };
+        Self {
+            adaptive: limits.adaptive_delay_enabled,
+            delay_ms,
+            min_ms,
+            max_ms,
+            timeout_ms: limits.response_timeout_ms,
+            slow_responses: 0,
         }
     }
+
+    /// Delay before the next chunk
+    pub(crate) fn current(&self) -> Duration {
+        Duration::from_millis(self.delay_ms)
+    }
+
+    /// Account for one chunk that took `elapsed` to answer
+    pub(crate) fn observe(&mut self, elapsed: Duration) {
+        if !self.adaptive {
+            return;
+        }
+        let elapsed_ms = elapsed.as_millis();
+        let timeout_ms = u128::from(self.timeout_ms);
+        if elapsed_ms * 5 > timeout_ms * 4 {
+            self.slow_responses += 1;
+            if self.slow_responses >= 2 {
+                self.slow_responses = 0;
+                // Doubling zero would never leave it.
+                self.delay_ms = (self.delay_ms.saturating_mul(2)).max(1);
+            }
+        } else {
+            self.slow_responses = 0;
+            if elapsed_ms * 5 < timeout_ms {
+                self.delay_ms /= 2;
+            }
+        }
+        self.delay_ms = self.delay_ms.clamp(self.min_ms, self.max_ms);
+    }
 }
 
 // ============================================================================
@@ -189,6 +482,34 @@ mod tests {
         assert_eq!(limits.inter_request_delay_ms, 10);
     }
 
+    #[test]
+    fn test_presets_and_name_lookup() {
+        assert_eq!(DeviceLimits::schneider_m340().max_read_registers, 100);
+        assert_eq!(DeviceLimits::schneider_m340().inter_request_delay_ms, 10);
+        assert_eq!(DeviceLimits::omron_cj().max_read_registers, 50);
+        assert_eq!(DeviceLimits::siemens_s7().max_read_registers, 125);
+
+        for name in ["S7-1200", "siemens_s7", "Siemens S7"] {
+            assert_eq!(
+                DeviceLimits::from_device_name(name),
+                Some(DeviceLimits::siemens_s7())
+            );
+        }
+        assert_eq!(
+            DeviceLimits::from_device_name("CJ2M"),
+            Some(DeviceLimits::omron_cj())
+        );
+        assert_eq!(
+            DeviceLimits::from_device_name("SDM630"),
+            Some(DeviceLimits::eastron_sdm())
+        );
+        assert_eq!(
+            DeviceLimits::from_device_name("Conservative"),
+            Some(DeviceLimits::conservative())
+        );
+        assert_eq!(DeviceLimits::from_device_name("s7-300x"), None);
+    }
+
     #[test]
     fn test_builder_pattern() {
         let limits = DeviceLimits::new()
@@ -201,6 +522,44 @@ mod tests {
         assert_eq!(limits.inter_request_delay_ms, 5);
     }
 
+    #[test]
+    fn test_adaptive_delay() {
+        let limits = DeviceLimits::new()
+            .with_inter_request_delay_ms(10)
+            .with_adaptive_delay(5, 30)
+            .with_response_timeout_ms(1000);
+        let mut delay = InterRequestDelay::new(&limits);
+        let slow = Duration::from_millis(900);
+        let fast = Duration::from_millis(100);
+        let normal = Duration::from_millis(500);
+
+        // One slow response is not enough to back off.
+        delay.observe(slow);
+        assert_eq!(delay.current(), Duration::from_millis(10));
+        delay.observe(slow);
+        assert_eq!(delay.current(), Duration::from_millis(20));
+
+        // The streak restarts after a doubling and after a normal response.
+        delay.observe(slow);
+        delay.observe(normal);
+        delay.observe(slow);
+        assert_eq!(delay.current(), Duration::from_millis(20));
+        delay.observe(slow);
+        assert_eq!(delay.current(), Duration::from_millis(30), "clamped to max");
+
+        delay.observe(fast);
+        assert_eq!(delay.current(), Duration::from_millis(15));
+        delay.observe(fast);
+        delay.observe(fast);
+        assert_eq!(delay.current(), Duration::from_millis(5), "clamped to min");
+
+        let mut fixed =
+            InterRequestDelay::new(&DeviceLimits::new().with_inter_request_delay_ms(10));
+        fixed.observe(slow);
+        fixed.observe(slow);
+        assert_eq!(fixed.current(), Duration::from_millis(10));
+    }
+
     #[test]
     fn test_read_request_count() {
         let limits = DeviceLimits::new().with_max_read_registers(50);
@@ -250,4 +609,50 @@ mod tests {
         assert!(limits.is_coil_write_within_limits(500));
         assert!(!limits.is_coil_write_within_limits(501));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let limits = DeviceLimits::new()
+            .with_max_read_registers(50)
+            .with_inter_request_delay_ms(20);
+        let json = serde_json::to_string(&limits).unwrap();
+        assert!(json.contains(r#""max_read_registers":50"#));
+        assert_eq!(serde_json::from_str::<DeviceLimits>(&json).unwrap(), limits);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_json_str() {
+        let limits = DeviceLimits::from_json_str(r#"{"max_write_registers": 60}"#).unwrap();
+        assert_eq!(limits, DeviceLimits::new().with_max_write_registers(60));
+
+        for bad in [
+            r#"{"max_read_register": 50}"#,
+            r#"{"max_read_coils": -1}"#,
+            "[",
+        ] {
+            assert!(matches!(
+                DeviceLimits::from_json_str(bad),
+                Err(crate::error::ModbusError::Configuration { .. })
+            ));
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_str_and_device_tables() {
+        let limits =
+            DeviceLimits::from_toml_str("max_read_registers = 80\ninter_request_delay_ms = 20")
+                .unwrap();
+        assert_eq!(limits.max_read_registers, 80);
+        assert_eq!(limits.max_write_registers, DEFAULT_MAX_WRITE_REGISTERS);
+        assert_eq!(limits.inter_request_delay_ms, 20);
+        assert!(DeviceLimits::from_toml_str("max_read_registers = 'many'").is_err());
+
+        let devices: std::collections::HashMap<String, DeviceLimits> =
+            toml::from_str("[meter]\nmax_read_registers = 80\n\n[plc]\n").unwrap();
+        assert_eq!(devices["meter"].max_read_registers, 80);
+        assert_eq!(devices["plc"], DeviceLimits::default());
+    }
 }
@@ -0,0 +1,510 @@
+//! # Object-Safe Client
+//!
+//! [`ModbusClient`] returns `impl Future` from its methods, so it cannot be
+//! used as a trait object. [`DynModbusClient`] mirrors its request methods
+//! with boxed futures; [`boxed`](crate::dyn_client::boxed) (or `into_dyn()` on the concrete clients)
+//! wraps any `ModbusClient` into a `Box<dyn DynModbusClient>`, so clients
+//! over different transports can share a `Vec`.
+//!
+//! `Box<dyn DynModbusClient>` implements `ModbusClient` in turn, so a boxed
+//! client still works with generic helpers such as
+//! [`ReadMerger`](crate::optimizer::ReadMerger) and the `*_batch` methods.
+//!
+//! ```rust
+//! use voltage_modbus::dyn_client::DynModbusClient;
+//! use voltage_modbus::testing::EchoTransport;
+//! use voltage_modbus::GenericModbusClient;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> voltage_modbus::ModbusResult<()> {
+//! let mut clients: Vec<Box<dyn DynModbusClient>> = vec![
+//!     GenericModbusClient::new(EchoTransport::new()).into_dyn(),
+//!     GenericModbusClient::new(EchoTransport::new()).into_dyn(),
+//! ];
+//! for client in &mut clients {
+//!     assert_eq!(client.read_03(1, 10, 2).await?, [0, 1]);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::capabilities::DeviceIdentification;
+use crate::client::ModbusClient;
+use crate::error::ModbusResult;
+use crate::protocol::SlaveId;
+use crate::transport::TransportStats;
+
+/// Boxed future returned by [`DynModbusClient`] methods.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Object-safe counterpart of [`ModbusClient`]; see the [module docs](self).
+///
+/// Obtained through [`boxed`]; there is no need to implement it by hand.
+pub trait DynModbusClient: Send + Sync {
+    /// See [`ModbusClient::read_01`]
+    fn read_01(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> BoxFuture<'_, ModbusResult<Vec<bool>>>;
+
+    /// See [`ModbusClient::read_02`]
+    fn read_02(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> BoxFuture<'_, ModbusResult<Vec<bool>>>;
+
+    /// See [`ModbusClient::read_03`]
+    fn read_03(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> BoxFuture<'_, ModbusResult<Vec<u16>>>;
+
+    /// See [`ModbusClient::read_04`]
+    fn read_04(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> BoxFuture<'_, ModbusResult<Vec<u16>>>;
+
+    /// See [`ModbusClient::write_05`]
+    fn write_05(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        value: bool,
+    ) -> BoxFuture<'_, ModbusResult<()>>;
+
+    /// See [`ModbusClient::write_06`]
+    fn write_06(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        value: u16,
+    ) -> BoxFuture<'_, ModbusResult<()>>;
+
+    /// See [`ModbusClient::write_0f`]
+    fn write_0f<'a>(
+        &'a mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &'a [bool],
+    ) -> BoxFuture<'a, ModbusResult<()>>;
+
+    /// See [`ModbusClient::write_10`]
+    fn write_10<'a>(
+        &'a mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &'a [u16],
+    ) -> BoxFuture<'a, ModbusResult<()>>;
+
+    /// See [`ModbusClient::read_exception_status`]
+    fn read_exception_status(&mut self, slave_id: SlaveId) -> BoxFuture<'_, ModbusResult<u8>>;
+
+    /// See [`ModbusClient::mask_write_register`]
+    fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> BoxFuture<'_, ModbusResult<()>>;
+
+    /// See [`ModbusClient::read_write_registers`]
+    fn read_write_registers<'a>(
+        &'a mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &'a [u16],
+    ) -> BoxFuture<'a, ModbusResult<Vec<u16>>>;
+
+    /// See [`ModbusClient::diagnostic_echo`]
+    fn diagnostic_echo(&mut self, slave_id: SlaveId, data: u16)
+        -> BoxFuture<'_, ModbusResult<u16>>;
+
+    /// See [`ModbusClient::read_device_id`]
+    fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> BoxFuture<'_, ModbusResult<DeviceIdentification>>;
+
+    /// See [`ModbusClient::is_connected`]
+    fn is_connected(&self) -> bool;
+
+    /// See [`ModbusClient::close`]
+    fn close(&mut self) -> BoxFuture<'_, ModbusResult<()>>;
+
+    /// See [`ModbusClient::get_stats`]
+    fn get_stats(&self) -> TransportStats;
+}
+
+/// Box `client` as a [`DynModbusClient`].
+///
+/// Concrete clients also offer this as `into_dyn()`.
+pub fn boxed<C: ModbusClient + 'static>(client: C) -> Box<dyn DynModbusClient> {
+    Box::new(Adapter(client))
+}
+
+/// Forwards [`DynModbusClient`] calls to a [`ModbusClient`].
+///
+/// Kept private so that concrete clients implement only `ModbusClient` and
+/// glob imports of both traits stay unambiguous.
+struct Adapter<C>(C);
+
+impl<C: ModbusClient> DynModbusClient for Adapter<C> {
+    fn read_01(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> BoxFuture<'_, ModbusResult<Vec<bool>>> {
+        Box::pin(ModbusClient::read_01(
+            &mut self.0,
+            slave_id,
+            address,
+            quantity,
+        ))
+    }
+
+    fn read_02(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> BoxFuture<'_, ModbusResult<Vec<bool>>> {
+        Box::pin(ModbusClient::read_02(
+            &mut self.0,
+            slave_id,
+            address,
+            quantity,
+        ))
+    }
+
+    fn read_03(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> BoxFuture<'_, ModbusResult<Vec<u16>>> {
+        Box::pin(ModbusClient::read_03(
+            &mut self.0,
+            slave_id,
+            address,
+            quantity,
+        ))
+    }
+
+    fn read_04(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> BoxFuture<'_, ModbusResult<Vec<u16>>> {
+        Box::pin(ModbusClient::read_04(
+            &mut self.0,
+            slave_id,
+            address,
+            quantity,
+        ))
+    }
+
+    fn write_05(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        value: bool,
+    ) -> BoxFuture<'_, ModbusResult<()>> {
+        Box::pin(ModbusClient::write_05(
+            &mut self.0,
+            slave_id,
+            address,
+            value,
+        ))
+    }
+
+    fn write_06(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        value: u16,
+    ) -> BoxFuture<'_, ModbusResult<()>> {
+        Box::pin(ModbusClient::write_06(
+            &mut self.0,
+            slave_id,
+            address,
+            value,
+        ))
+    }
+
+    fn write_0f<'a>(
+        &'a mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &'a [bool],
+    ) -> BoxFuture<'a, ModbusResult<()>> {
+        Box::pin(ModbusClient::write_0f(
+            &mut self.0,
+            slave_id,
+            address,
+            values,
+        ))
+    }
+
+    fn write_10<'a>(
+        &'a mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &'a [u16],
+    ) -> BoxFuture<'a, ModbusResult<()>> {
+        Box::pin(ModbusClient::write_10(
+            &mut self.0,
+            slave_id,
+            address,
+            values,
+        ))
+    }
+
+    fn read_exception_status(&mut self, slave_id: SlaveId) -> BoxFuture<'_, ModbusResult<u8>> {
+        Box::pin(ModbusClient::read_exception_status(&mut self.0, slave_id))
+    }
+
+    fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> BoxFuture<'_, ModbusResult<()>> {
+        Box::pin(ModbusClient::mask_write_register(
+            &mut self.0,
+            slave_id,
+            address,
+            and_mask,
+            or_mask,
+        ))
+    }
+
+    fn read_write_registers<'a>(
+        &'a mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &'a [u16],
+    ) -> BoxFuture<'a, ModbusResult<Vec<u16>>> {
+        Box::pin(ModbusClient::read_write_registers(
+            &mut self.0,
+            slave_id,
+            read_address,
+            read_quantity,
+            write_address,
+            write_values,
+        ))
+    }
+
+    fn diagnostic_echo(
+        &mut self,
+        slave_id: SlaveId,
+        data: u16,
+    ) -> BoxFuture<'_, ModbusResult<u16>> {
+        Box::pin(ModbusClient::diagnostic_echo(&mut self.0, slave_id, data))
+    }
+
+    fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> BoxFuture<'_, ModbusResult<DeviceIdentification>> {
+        Box::pin(ModbusClient::read_device_id(
+            &mut self.0,
+            slave_id,
+            read_device_id_code,
+            object_id,
+        ))
+    }
+
+    fn is_connected(&self) -> bool {
+        ModbusClient::is_connected(&self.0)
+    }
+
+    fn close(&mut self) -> BoxFuture<'_, ModbusResult<()>> {
+        Box::pin(ModbusClient::close(&mut self.0))
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        ModbusClient::get_stats(&self.0)
+    }
+}
+
+impl ModbusClient for Box<dyn DynModbusClient> {
+    async fn read_01(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<bool>> {
+        DynModbusClient::read_01(&mut **self, slave_id, address, quantity).await
+    }
+
+    async fn read_02(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<bool>> {
+        DynModbusClient::read_02(&mut **self, slave_id, address, quantity).await
+    }
+
+    async fn read_03(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<u16>> {
+        DynModbusClient::read_03(&mut **self, slave_id, address, quantity).await
+    }
+
+    async fn read_04(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<Vec<u16>> {
+        DynModbusClient::read_04(&mut **self, slave_id, address, quantity).await
+    }
+
+    async fn write_05(&mut self, slave_id: SlaveId, address: u16, value: bool) -> ModbusResult<()> {
+        DynModbusClient::write_05(&mut **self, slave_id, address, value).await
+    }
+
+    async fn write_06(&mut self, slave_id: SlaveId, address: u16, value: u16) -> ModbusResult<()> {
+        DynModbusClient::write_06(&mut **self, slave_id, address, value).await
+    }
+
+    async fn write_0f(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &[bool],
+    ) -> ModbusResult<()> {
+        DynModbusClient::write_0f(&mut **self, slave_id, address, values).await
+    }
+
+    async fn write_10(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        values: &[u16],
+    ) -> ModbusResult<()> {
+        DynModbusClient::write_10(&mut **self, slave_id, address, values).await
+    }
+
+    async fn read_exception_status(&mut self, slave_id: SlaveId) -> ModbusResult<u8> {
+        DynModbusClient::read_exception_status(&mut **self, slave_id).await
+    }
+
+    async fn mask_write_register(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> ModbusResult<()> {
+        DynModbusClient::mask_write_register(&mut **self, slave_id, address, and_mask, or_mask)
+            .await
+    }
+
+    async fn read_write_registers(
+        &mut self,
+        slave_id: SlaveId,
+        read_address: u16,
+        read_quantity: u16,
+        write_address: u16,
+        write_values: &[u16],
+    ) -> ModbusResult<Vec<u16>> {
+        DynModbusClient::read_write_registers(
+            &mut **self,
+            slave_id,
+            read_address,
+            read_quantity,
+            write_address,
+            write_values,
+        )
+        .await
+    }
+
+    fn is_connected(&self) -> bool {
+        DynModbusClient::is_connected(&**self)
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        DynModbusClient::close(&mut **self).await
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        DynModbusClient::get_stats(&**self)
+    }
+
+    async fn diagnostic_echo(&mut self, slave_id: SlaveId, data: u16) -> ModbusResult<u16> {
+        DynModbusClient::diagnostic_echo(&mut **self, slave_id, data).await
+    }
+
+    async fn read_device_id(
+        &mut self,
+        slave_id: SlaveId,
+        read_device_id_code: u8,
+        object_id: u8,
+    ) -> ModbusResult<DeviceIdentification> {
+        DynModbusClient::read_device_id(&mut **self, slave_id, read_device_id_code, object_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GenericModbusClient;
+    use crate::optimizer::ReadMerger;
+    use crate::testing::EchoTransport;
+
+    #[tokio::test]
+    async fn boxed_clients_dispatch_to_their_transport() {
+        let mut clients: Vec<Box<dyn DynModbusClient>> = vec![
+            boxed(GenericModbusClient::new(EchoTransport::new())),
+            GenericModbusClient::new(EchoTransport::new()).into_dyn(),
+        ];
+        for client in &mut clients {
+            assert_eq!(client.read_03(1, 5, 3).await.unwrap(), [0, 1, 2]);
+            client.write_10(1, 0, &[1, 2]).await.unwrap();
+        }
+        assert_eq!(DynModbusClient::get_stats(&*clients[0]).requests_sent, 2);
+
+        DynModbusClient::close(&mut *clients[1]).await.unwrap();
+        assert!(!DynModbusClient::is_connected(&*clients[1]));
+    }
+
+    #[tokio::test]
+    async fn boxed_client_works_with_generic_helpers() {
+        let mut client = boxed(GenericModbusClient::new(EchoTransport::new()));
+        let results = ReadMerger::new(4)
+            .read_03(&mut client, 1, &[(0, 2), (4, 2)])
+            .await
+            .unwrap();
+        assert_eq!(ReadMerger::extract(&results, (4, 2)), Some(&[4, 5][..]));
+        assert_eq!(ModbusClient::get_stats(&client).requests_sent, 1);
+    }
+}
@@ -161,6 +161,23 @@ where
                 push(&mut frame, byte_count)?;
                 extend(&mut frame, &request.data)?;
             }
+
+            ModbusFunction::MaskWriteRegister => {
+                extend(&mut frame, &request.address.to_be_bytes())?;
+                extend(&mut frame, &request.data)?;
+            }
+
+            ModbusFunction::ReadWriteMultipleRegisters => {
+                extend(&mut frame, &request.address.to_be_bytes())?;
+                extend(&mut frame, &request.quantity.to_be_bytes())?;
+                extend(&mut frame, &request.data)?;
+            }
+
+            ModbusFunction::ReadExceptionStatus => {}
+
+            ModbusFunction::Diagnostics | ModbusFunction::EncapsulatedInterfaceTransport => {
+                extend(&mut frame, &request.data)?;
+            }
         }
 
         let crc = CRC_MODBUS.checksum(&frame);
@@ -257,7 +274,8 @@ where
             ModbusFunction::ReadCoils
             | ModbusFunction::ReadDiscreteInputs
             | ModbusFunction::ReadHoldingRegisters
-            | ModbusFunction::ReadInputRegisters => {
+            | ModbusFunction::ReadInputRegisters
+            | ModbusFunction::ReadWriteMultipleRegisters => {
                 let mut byte_count = [0u8; 1];
                 self.io
                     .read_exact(&mut byte_count)
@@ -275,7 +293,8 @@ where
             ModbusFunction::WriteSingleCoil
             | ModbusFunction::WriteSingleRegister
             | ModbusFunction::WriteMultipleCoils
-            | ModbusFunction::WriteMultipleRegisters => {
+            | ModbusFunction::WriteMultipleRegisters
+            | ModbusFunction::Diagnostics => {
                 let mut tail = [0u8; 6];
                 self.io
                     .read_exact(&mut tail)
@@ -283,6 +302,54 @@ where
                     .map_err(|_| ModbusError::io("embedded read error"))?;
                 frame.extend_from_slice(&tail);
             }
+            ModbusFunction::ReadExceptionStatus => {
+                let mut tail = [0u8; 3];
+                self.io
+                    .read_exact(&mut tail)
+                    .await
+                    .map_err(|_| ModbusError::io("embedded read error"))?;
+                frame.extend_from_slice(&tail);
+            }
+            ModbusFunction::EncapsulatedInterfaceTransport => {
+                // [mei, code, conformity, more_follows, next_object, object_count]
+                let mut fixed = [0u8; 6];
+                self.io
+                    .read_exact(&mut fixed)
+                    .await
+                    .map_err(|_| ModbusError::io("embedded read error"))?;
+                frame.extend_from_slice(&fixed);
+                for _ in 0..fixed[5] {
+                    let mut object = [0u8; 2]; // [id, length]
+                    self.io
+                        .read_exact(&mut object)
+                        .await
+                        .map_err(|_| ModbusError::io("embedded read error"))?;
+                    frame.extend_from_slice(&object);
+                    let mut value = vec![0u8; usize::from(object[1])];
+                    self.io
+                        .read_exact(&mut value)
+                        .await
+                        .map_err(|_| ModbusError::io("embedded read error"))?;
+                    frame.extend_from_slice(&value);
+                    if frame.len() > MAX_FRAME {
+                        return Err(ModbusError::frame("RTU frame buffer overflow"));
+                    }
+                }
+                let mut crc = [0u8; 2];
+                self.io
+                    .read_exact(&mut crc)
+                    .await
+                    .map_err(|_| ModbusError::io("embedded read error"))?;
+                frame.extend_from_slice(&crc);
+            }
+            ModbusFunction::MaskWriteRegister => {
+                let mut tail = [0u8; 8];
+                self.io
+                    .read_exact(&mut tail)
+                    .await
+                    .map_err(|_| ModbusError::io("embedded read error"))?;
+                frame.extend_from_slice(&tail);
+            }
         }
 
         Ok(frame)
@@ -82,7 +82,7 @@
 //!         Ok(registers) => {
 //!             println!("Read {} registers", registers.len());
 //!         },
-//!         Err(ModbusError::Timeout { operation, timeout_ms }) => {
+//!         Err(ModbusError::Timeout { operation, timeout_ms, .. }) => {
 //!             println!("Timeout during {}: {}ms", operation, timeout_ms);
 //!         },
 //!         Err(ModbusError::Exception { function, code, message }) => {
@@ -101,7 +101,7 @@
 //!     // Read holding registers using function code naming
 //!     match client.read_03(1, 0, 10).await {
 //!         Ok(registers) => println!("Values: {:?}", registers),
-//!         Err(ModbusError::Timeout { operation, timeout_ms }) => {
+//!         Err(ModbusError::Timeout { operation, timeout_ms, .. }) => {
 //!             println!("Request {} timed out after {}ms, retrying...", operation, timeout_ms);
 //!             // Implement retry logic
 //!         },
@@ -156,14 +156,15 @@
 //! ```
 
 #[cfg(not(feature = "std"))]
-use alloc::string::String;
+use alloc::{boxed::Box, format, string::String, string::ToString, sync::Arc};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use core::ops::Deref;
 
 #[cfg(feature = "std")]
 use thiserror::Error;
 
-// core::fmt is used by the manual Display impl in no_std mode.
-// In std mode thiserror generates all fmt code, so suppress the unused-import warning.
-#[cfg(not(feature = "std"))]
 use core::fmt;
 
 /// Result type alias for Modbus operations
@@ -185,6 +186,7 @@ pub type ModbusResult<T> = Result<T, ModbusError>;
 #[cfg_attr(feature = "std", derive(Error))]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "kind"))]
 pub enum ModbusError {
     /// I/O related errors (network, serial)
     #[cfg_attr(feature = "std", error("I/O error: {message}"))]
@@ -192,11 +194,22 @@ pub enum ModbusError {
 
     /// Connection errors
     #[cfg_attr(feature = "std", error("Connection error: {message}"))]
-    Connection { message: String },
+    Connection {
+        message: String,
+        /// Underlying error, e.g. the `io::Error` of a failed connect
+        #[cfg_attr(feature = "serde", serde(skip))]
+        source: Option<ErrorSource>,
+    },
 
     /// Timeout errors
     #[cfg_attr(feature = "std", error("Timeout after {timeout_ms}ms: {operation}"))]
-    Timeout { operation: String, timeout_ms: u64 },
+    Timeout {
+        operation: String,
+        timeout_ms: u64,
+        /// Underlying error, if the timeout was reported by another layer
+        #[cfg_attr(feature = "serde", serde(skip))]
+        source: Option<ErrorSource>,
+    },
 
     /// Protocol-level errors
     #[cfg_attr(feature = "std", error("Protocol error: {message}"))]
@@ -242,7 +255,12 @@ pub enum ModbusError {
 
     /// Configuration errors
     #[cfg_attr(feature = "std", error("Configuration error: {message}"))]
-    Configuration { message: String },
+    Configuration {
+        message: String,
+        /// Underlying error, e.g. a parse error of a configuration file
+        #[cfg_attr(feature = "serde", serde(skip))]
+        source: Option<ErrorSource>,
+    },
 
     /// Device not responding
     #[cfg_attr(feature = "std", error("Device {slave_id} not responding"))]
@@ -255,6 +273,43 @@ pub enum ModbusError {
     )]
     TransactionIdMismatch { expected: u16, actual: u16 },
 
+    /// Write rejected by a client-side authorization policy
+    #[cfg_attr(
+        feature = "std",
+        error("Unauthorized write: slave={slave_id}, address={address}")
+    )]
+    Unauthorized { slave_id: u8, address: u16 },
+
+    /// A bounded resource (connections, permits) has no capacity left
+    #[cfg_attr(feature = "std", error("Resource exhausted: {message}"))]
+    ResourceExhausted { message: String },
+
+    /// An operation is not defined for the operand types
+    #[cfg_attr(
+        feature = "std",
+        error("Type mismatch: cannot {operation} {left} and {right}")
+    )]
+    TypeMismatch {
+        operation: &'static str,
+        left: &'static str,
+        right: &'static str,
+    },
+
+    /// A register read back after a write does not hold the written value
+    #[cfg_attr(
+        feature = "std",
+        error("Verification failed at register {address}: wrote {expected}, read back {actual}")
+    )]
+    VerificationFailed {
+        address: u16,
+        expected: u16,
+        actual: u16,
+    },
+
+    /// The slave's circuit breaker is open; the request was not sent
+    #[cfg_attr(feature = "std", error("Circuit open for slave {slave_id}"))]
+    CircuitOpen { slave_id: u8 },
+
     /// Internal errors (should not occur in normal operation)
     #[cfg_attr(feature = "std", error("Internal error: {message}"))]
     Internal { message: String },
@@ -293,10 +348,11 @@ impl fmt::Display for ModbusError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Io { message } => write!(f, "I/O error: {}", message),
-            Self::Connection { message } => write!(f, "Connection error: {}", message),
+            Self::Connection { message, .. } => write!(f, "Connection error: {}", message),
             Self::Timeout {
                 operation,
                 timeout_ms,
+                ..
             } => write!(f, "Timeout after {}ms: {}", timeout_ms, operation),
             Self::Protocol { message } => write!(f, "Protocol error: {}", message),
             Self::InvalidFunction { code } => write!(f, "Invalid function code: {}", code),
@@ -319,7 +375,9 @@ impl fmt::Display for ModbusError {
                 function, code, message
             ),
             Self::Frame { message } => write!(f, "Frame error: {}", message),
-            Self::Configuration { message } => write!(f, "Configuration error: {}", message),
+            Self::Configuration { message, .. } => {
+                write!(f, "Configuration error: {}", message)
+            }
             Self::DeviceNotResponding { slave_id } => {
                 write!(f, "Device {} not responding", slave_id)
             }
@@ -328,6 +386,31 @@ impl fmt::Display for ModbusError {
                 "Transaction ID mismatch: expected={:04X}, actual={:04X}",
                 expected, actual
             ),
+            Self::Unauthorized { slave_id, address } => write!(
+                f,
+                "Unauthorized write: slave={}, address={}",
+                slave_id, address
+            ),
+            Self::ResourceExhausted { message } => write!(f, "Resource exhausted: {}", message),
+            Self::TypeMismatch {
+                operation,
+                left,
+                right,
+            } => write!(
+                f,
+                "Type mismatch: cannot {} {} and {}",
+                operation, left, right
+            ),
+            Self::VerificationFailed {
+                address,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Verification failed at register {}: wrote {}, read back {}",
+                address, expected, actual
+            ),
+            Self::CircuitOpen { slave_id } => write!(f, "Circuit open for slave {}", slave_id),
             Self::Internal { message } => write!(f, "Internal error: {}", message),
             #[allow(deprecated)]
             Self::TimeoutLegacy => write!(f, "Timeout"),
@@ -346,7 +429,67 @@ impl fmt::Display for ModbusError {
 // In std mode, thiserror already derives Display; we still need the core::error::Error
 // impl for no_std builds (core::error::Error was stabilised in Rust 1.81).
 #[cfg(not(feature = "std"))]
-impl core::error::Error for ModbusError {}
+impl core::error::Error for ModbusError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Connection { source, .. }
+            | Self::Timeout { source, .. }
+            | Self::Configuration { source, .. } => source
+                .as_deref()
+                .map(|e| e as &(dyn core::error::Error + 'static)),
+            _ => None,
+        }
+    }
+}
+
+/// The error that caused a [`ModbusError`], returned by its
+/// [`source`](core::error::Error::source)
+///
+/// Shared rather than boxed so that `ModbusError` stays `Clone`. Derefs to
+/// the original error, which can be downcast; two sources compare equal when
+/// their messages do.
+#[derive(Clone)]
+pub struct ErrorSource(Arc<dyn core::error::Error + Send + Sync>);
+
+impl ErrorSource {
+    /// Wrap an error (or a message, which becomes an error without a source)
+    pub fn new<E: Into<Box<dyn core::error::Error + Send + Sync>>>(error: E) -> Self {
+        Self(Arc::from(error.into()))
+    }
+}
+
+impl Deref for ErrorSource {
+    type Target = dyn core::error::Error + Send + Sync;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl fmt::Debug for ErrorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for ErrorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
+
+impl PartialEq for ErrorSource {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0.to_string() == other.0.to_string()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ErrorSource {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{}", defmt::Display2Format(&*self.0))
+    }
+}
 
 impl ModbusError {
     /// Create a new I/O error
@@ -360,6 +503,29 @@ impl ModbusError {
     pub fn connection<S: Into<String>>(message: S) -> Self {
         Self::Connection {
             message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Create a connection error caused by `source`
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use voltage_modbus::ModbusError;
+    ///
+    /// let io = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+    /// let error = ModbusError::connection_with_source("Failed to connect", io);
+    /// let source = error.source().unwrap().downcast_ref::<std::io::Error>().unwrap();
+    /// assert_eq!(source.kind(), std::io::ErrorKind::ConnectionRefused);
+    /// ```
+    pub fn connection_with_source<S, E>(message: S, source: E) -> Self
+    where
+        S: Into<String>,
+        E: Into<Box<dyn core::error::Error + Send + Sync>>,
+    {
+        Self::Connection {
+            message: message.into(),
+            source: Some(ErrorSource::new(source)),
         }
     }
 
@@ -368,6 +534,20 @@ impl ModbusError {
         Self::Timeout {
             operation: operation.into(),
             timeout_ms,
+            source: None,
+        }
+    }
+
+    /// Create a timeout error caused by `source`
+    pub fn timeout_with_source<S, E>(operation: S, timeout_ms: u64, source: E) -> Self
+    where
+        S: Into<String>,
+        E: Into<Box<dyn core::error::Error + Send + Sync>>,
+    {
+        Self::Timeout {
+            operation: operation.into(),
+            timeout_ms,
+            source: Some(ErrorSource::new(source)),
         }
     }
 
@@ -431,10 +611,21 @@ impl ModbusError {
         }
     }
 
-    /// Create a configuration error
-    pub fn configuration<S: Into<String>>(message: S) -> Self {
+    /// Create a configuration error from a message or an error
+    ///
+    /// An error (a TOML or JSON parse error, say) is kept as the
+    /// [`source`](core::error::Error::source) and its text becomes the
+    /// message; a plain message has no source.
+    pub fn configuration<E: Into<Box<dyn core::error::Error + Send + Sync>>>(error: E) -> Self {
+        let error = error.into();
+        let message = error.to_string();
+        // `&str` and `String` convert to a private error type that debug-prints
+        // as the quoted message; such an error adds nothing as a source.
+        let is_plain_message =
+            error.source().is_none() && format!("{:?}", error) == format!("{:?}", message);
         Self::Configuration {
-            message: message.into(),
+            message,
+            source: (!is_plain_message).then(|| ErrorSource::new(error)),
         }
     }
 
@@ -448,6 +639,41 @@ impl ModbusError {
         Self::TransactionIdMismatch { expected, actual }
     }
 
+    /// Create an unauthorized write error
+    pub fn unauthorized(slave_id: u8, address: u16) -> Self {
+        Self::Unauthorized { slave_id, address }
+    }
+
+    /// Create a resource exhausted error
+    pub fn resource_exhausted<S: Into<String>>(message: S) -> Self {
+        Self::ResourceExhausted {
+            message: message.into(),
+        }
+    }
+
+    /// Create a type mismatch error for `operation` on `left` and `right`
+    pub fn type_mismatch(operation: &'static str, left: &'static str, right: &'static str) -> Self {
+        Self::TypeMismatch {
+            operation,
+            left,
+            right,
+        }
+    }
+
+    /// Create a verification error for register `address`
+    pub fn verification_failed(address: u16, expected: u16, actual: u16) -> Self {
+        Self::VerificationFailed {
+            address,
+            expected,
+            actual,
+        }
+    }
+
+    /// Create a circuit open error
+    pub fn circuit_open(slave_id: u8) -> Self {
+        Self::CircuitOpen { slave_id }
+    }
+
     /// Create an internal error
     pub fn internal<S: Into<String>>(message: S) -> Self {
         Self::Internal {
@@ -472,7 +698,7 @@ impl ModbusError {
         match self {
             Self::Io { .. } => true,
             Self::Connection { .. } => true,
-            Self::Timeout { .. } => true,
+            Self::Timeout { .. } | Self::TimeoutLegacy => true,
             Self::DeviceNotResponding { .. } => true,
             Self::TransactionIdMismatch { .. } => true,
             Self::Exception { code, .. } => {
@@ -482,6 +708,105 @@ impl ModbusError {
         }
     }
 
+    /// Check if the failed request is worth sending again
+    ///
+    /// `true` for transient failures: `Io`, `Connection`, `Timeout` and
+    /// `DeviceNotResponding`, where the request may not have reached the
+    /// device or the response got lost, and the Slave Device Busy exception
+    /// (0x06). Everything else — other device exceptions, invalid requests,
+    /// configuration and framing problems — fails the same way on a retry.
+    /// Unlike [`is_recoverable`](Self::is_recoverable), Acknowledge (0x05) is
+    /// not retryable: the device accepted the request and is still working
+    /// on it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use voltage_modbus::ModbusError;
+    ///
+    /// assert!(ModbusError::timeout("read", 1000).is_retryable());
+    /// assert!(ModbusError::exception(0x03, 0x06).is_retryable());
+    /// assert!(!ModbusError::exception(0x03, 0x02).is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Io { .. }
+                | Self::Connection { .. }
+                | Self::Timeout { .. }
+                | Self::TimeoutLegacy
+                | Self::DeviceNotResponding { .. }
+                | Self::Exception { code: 0x06, .. }
+        )
+    }
+
+    /// Check if the error is an exception response from the device
+    pub fn is_device_exception(&self) -> bool {
+        matches!(self, Self::Exception { .. })
+    }
+
+    /// Exception code of a device exception response
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use voltage_modbus::ModbusError;
+    ///
+    /// assert_eq!(ModbusError::exception(0x03, 0x02).exception_code(), Some(0x02));
+    /// assert_eq!(ModbusError::timeout("read", 1000).exception_code(), None);
+    /// ```
+    pub fn exception_code(&self) -> Option<u8> {
+        match self {
+            Self::Exception { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// Variant name (`"Timeout"`, `"Exception"`, ...) for log fields and
+    /// metric labels; the same as the serde `kind` tag
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Io { .. } => "Io",
+            Self::Connection { .. } => "Connection",
+            Self::Timeout { .. } => "Timeout",
+            Self::Protocol { .. } => "Protocol",
+            Self::InvalidFunction { .. } => "InvalidFunction",
+            Self::InvalidAddress { .. } => "InvalidAddress",
+            Self::InvalidData { .. } => "InvalidData",
+            Self::CrcMismatch { .. } => "CrcMismatch",
+            Self::Exception { .. } => "Exception",
+            Self::Frame { .. } => "Frame",
+            Self::Configuration { .. } => "Configuration",
+            Self::DeviceNotResponding { .. } => "DeviceNotResponding",
+            Self::TransactionIdMismatch { .. } => "TransactionIdMismatch",
+            Self::Unauthorized { .. } => "Unauthorized",
+            Self::ResourceExhausted { .. } => "ResourceExhausted",
+            Self::TypeMismatch { .. } => "TypeMismatch",
+            Self::VerificationFailed { .. } => "VerificationFailed",
+            Self::CircuitOpen { .. } => "CircuitOpen",
+            Self::Internal { .. } => "Internal",
+            Self::TimeoutLegacy => "TimeoutLegacy",
+            Self::InvalidFrame => "InvalidFrame",
+            Self::InvalidDataValue => "InvalidDataValue",
+            Self::IllegalFunction => "IllegalFunction",
+            Self::InternalError => "InternalError",
+        }
+    }
+
+    /// Check if the peer dropped an established connection
+    ///
+    /// Matches `Connection` and `Io` errors whose message says the connection
+    /// was reset by the peer or the pipe is broken (case-insensitive).
+    pub fn is_connection_lost(&self) -> bool {
+        match self {
+            Self::Connection { message, .. } | Self::Io { message } => {
+                contains_ignore_ascii_case(message, "reset by peer")
+                    || contains_ignore_ascii_case(message, "broken pipe")
+            }
+            _ => false,
+        }
+    }
+
     /// Check if the error is a network/transport issue
     ///
     /// # Examples
@@ -498,7 +823,7 @@ impl ModbusError {
     pub fn is_transport_error(&self) -> bool {
         matches!(
             self,
-            Self::Io { .. } | Self::Connection { .. } | Self::Timeout { .. }
+            Self::Io { .. } | Self::Connection { .. } | Self::Timeout { .. } | Self::TimeoutLegacy
         )
     }
 
@@ -528,6 +853,13 @@ impl ModbusError {
     }
 }
 
+fn contains_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .any(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
 /// Convert from std::io::Error — only available with the `std` feature
 #[cfg(feature = "std")]
 impl From<std::io::Error> for ModbusError {
@@ -539,8 +871,8 @@ impl From<std::io::Error> for ModbusError {
 /// Convert from tokio timeout errors — only available with the `std` feature
 #[cfg(feature = "std")]
 impl From<tokio::time::error::Elapsed> for ModbusError {
-    fn from(_: tokio::time::error::Elapsed) -> Self {
-        Self::timeout("Operation timeout", 0)
+    fn from(elapsed: tokio::time::error::Elapsed) -> Self {
+        Self::timeout_with_source("Operation timeout", 0, elapsed)
     }
 }
 
@@ -562,6 +894,138 @@ mod tests {
         assert!(err.is_protocol_error());
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn test_classifiers_for_every_variant() {
+        // (error, retryable, device exception, exception code, connection lost)
+        let cases = [
+            (
+                ModbusError::io("Network unreachable"),
+                true,
+                false,
+                None,
+                false,
+            ),
+            (
+                ModbusError::io("Connection reset by peer (os error 104)"),
+                true,
+                false,
+                None,
+                true,
+            ),
+            (ModbusError::connection("refused"), true, false, None, false),
+            (
+                ModbusError::connection("Broken pipe"),
+                true,
+                false,
+                None,
+                true,
+            ),
+            (ModbusError::timeout("read", 100), true, false, None, false),
+            (ModbusError::protocol("bad"), false, false, None, false),
+            (
+                ModbusError::invalid_function(0x99),
+                false,
+                false,
+                None,
+                false,
+            ),
+            (
+                ModbusError::invalid_address(0, 0),
+                false,
+                false,
+                None,
+                false,
+            ),
+            (ModbusError::invalid_data("bad"), false, false, None, false),
+            (ModbusError::crc_mismatch(1, 2), false, false, None, false),
+            (
+                ModbusError::exception(0x03, 0x02),
+                false,
+                true,
+                Some(0x02),
+                false,
+            ),
+            (
+                ModbusError::exception(0x10, 0x06),
+                true,
+                true,
+                Some(0x06),
+                false,
+            ),
+            (
+                ModbusError::exception(0x10, 0x05),
+                false,
+                true,
+                Some(0x05),
+                false,
+            ),
+            (ModbusError::frame("short"), false, false, None, false),
+            (
+                ModbusError::configuration("broken pipe in config"),
+                false,
+                false,
+                None,
+                false,
+            ),
+            (
+                ModbusError::device_not_responding(1),
+                true,
+                false,
+                None,
+                false,
+            ),
+            (
+                ModbusError::TransactionIdMismatch {
+                    expected: 1,
+                    actual: 2,
+                },
+                false,
+                false,
+                None,
+                false,
+            ),
+            (ModbusError::unauthorized(1, 2), false, false, None, false),
+            (
+                ModbusError::resource_exhausted("pool"),
+                false,
+                false,
+                None,
+                false,
+            ),
+            (
+                ModbusError::type_mismatch("add", "bool", "bool"),
+                false,
+                false,
+                None,
+                false,
+            ),
+            (
+                ModbusError::verification_failed(10, 1, 0),
+                false,
+                false,
+                None,
+                false,
+            ),
+            (ModbusError::circuit_open(4), false, false, None, false),
+            (ModbusError::internal("bug"), false, false, None, false),
+            (ModbusError::TimeoutLegacy, true, false, None, false),
+            (ModbusError::InvalidFrame, false, false, None, false),
+            (ModbusError::InvalidDataValue, false, false, None, false),
+            (ModbusError::IllegalFunction, false, false, None, false),
+            (ModbusError::InternalError, false, false, None, false),
+        ];
+
+        for (err, retryable, device_exception, code, lost) in cases {
+            assert_eq!(err.is_retryable(), retryable, "{:?}", err);
+            assert_eq!(err.is_device_exception(), device_exception, "{:?}", err);
+            assert_eq!(err.exception_code(), code, "{:?}", err);
+            assert_eq!(err.is_connection_lost(), lost, "{:?}", err);
+        }
+        assert!(ModbusError::TimeoutLegacy.is_transport_error());
+        assert!(ModbusError::TimeoutLegacy.is_recoverable());
+    }
+
     #[test]
     fn test_error_display() {
         let err = ModbusError::crc_mismatch(0x1234, 0x5678);
@@ -570,4 +1034,55 @@ mod tests {
         assert!(msg.contains("1234"));
         assert!(msg.contains("5678"));
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_source_chain() {
+        use std::error::Error as _;
+
+        let io = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset by peer");
+        let error = ModbusError::connection_with_source("Connection lost", io);
+        let source = error.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<std::io::Error>().unwrap().kind(),
+            std::io::ErrorKind::ConnectionReset
+        );
+        assert_eq!(error.clone(), error);
+        assert_ne!(error, ModbusError::connection("Connection lost"));
+        assert!(ModbusError::connection("refused").source().is_none());
+        assert!(ModbusError::timeout("read", 100).source().is_none());
+
+        // Messages stay messages; errors become the source.
+        let plain = ModbusError::configuration(format!("bad port {}", 0));
+        assert_eq!(plain.to_string(), "Configuration error: bad port 0");
+        assert!(plain.source().is_none());
+        let parse = "x".parse::<u16>().unwrap_err();
+        let wrapped = ModbusError::configuration(parse.clone());
+        assert_eq!(
+            wrapped.to_string(),
+            format!("Configuration error: {}", parse)
+        );
+        assert!(wrapped.source().unwrap().is::<std::num::ParseIntError>());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_error_serializes_with_kind_tag() {
+        let json = serde_json::to_value(ModbusError::timeout("read", 500)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"kind": "Timeout", "operation": "read", "timeout_ms": 500})
+        );
+        let json = serde_json::to_value(ModbusError::exception(0x03, 0x02)).unwrap();
+        assert_eq!(json["kind"], "Exception");
+        assert_eq!(json["code"], 2);
+
+        for error in [
+            ModbusError::circuit_open(1),
+            ModbusError::type_mismatch("add", "bool", "bool"),
+            ModbusError::InvalidFrame,
+        ] {
+            assert_eq!(serde_json::to_value(&error).unwrap()["kind"], error.kind());
+        }
+    }
 }
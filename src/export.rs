@@ -0,0 +1,242 @@
+//! # Time-Series Export
+//!
+//! Formatters that turn register readings into the wire formats of common
+//! time-series databases. [`InfluxLineFormatter`] renders one reading as an
+//! InfluxDB line protocol record:
+//!
+//! ```text
+//! modbus,slave=1,address=100 value=3.14 1609459200000000000
+//! ```
+//!
+//! The field type follows the [`ModbusValue`] variant: integral variants are
+//! written as integers (`42i`, or `42u` for `u64` values beyond `i64::MAX`),
+//! `F32`/`F64` as floats, `Bool` as a boolean and `Str` as a quoted string.
+//! Line protocol has no NaN or infinity, so non-finite floats cannot be exported;
+//! [`InfluxBatchFormatter::add`] drops them.
+//!
+//! ```rust
+//! use voltage_modbus::export::{InfluxBatchFormatter, InfluxLineFormatter};
+//! use voltage_modbus::ModbusValue;
+//!
+//! let formatter = InfluxLineFormatter::new("modbus");
+//! let line = formatter.format_register(1, 100, &ModbusValue::F32(3.14), 1_609_459_200_000_000_000);
+//! assert_eq!(line, "modbus,slave=1,address=100 value=3.14 1609459200000000000");
+//!
+//! let mut batch = InfluxBatchFormatter::new(formatter);
+//! batch.add(1, 100, &ModbusValue::U16(7), 1);
+//! batch.add(1, 101, &ModbusValue::Bool(true), 1);
+//! assert_eq!(batch.flush().lines().count(), 2);
+//! assert!(batch.is_empty());
+//! ```
+
+use crate::protocol::SlaveId;
+use crate::snapshot::RegisterDescriptor;
+use crate::value::ModbusValue;
+
+/// Measurement used when a descriptor has no name.
+const DEFAULT_MEASUREMENT: &str = "modbus";
+
+/// Formats readings as InfluxDB line protocol records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InfluxLineFormatter {
+    /// Measurement name of every record.
+    pub measurement: String,
+    /// Extra tags added after `slave` and `address`.
+    pub tags: Vec<(String, String)>,
+}
+
+impl InfluxLineFormatter {
+    /// Create a formatter for `measurement` with no extra tags.
+    pub fn new(measurement: impl Into<String>) -> Self {
+        Self {
+            measurement: measurement.into(),
+            tags: Vec::new(),
+        }
+    }
+
+    /// Formatter named after `descriptor`, tagged with its engineering unit.
+    ///
+    /// Falls back to the `modbus` measurement for unnamed descriptors.
+    pub fn from_register_descriptor(descriptor: &RegisterDescriptor) -> Self {
+        let measurement = descriptor.name.as_deref().unwrap_or(DEFAULT_MEASUREMENT);
+        let mut formatter = Self::new(measurement);
+        if let Some(scaler) = &descriptor.scaler {
+            if !scaler.unit.is_empty() {
+                formatter = formatter.with_tag("unit", scaler.unit);
+            }
+        }
+        formatter
+    }
+
+    /// Add a tag to every record.
+    pub fn with_tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tags.push((key.into(), value.into()));
+        self
+    }
+
+    /// Format one reading; `timestamp_ns` is nanoseconds since the Unix epoch.
+    pub fn format_register(
+        &self,
+        slave_id: SlaveId,
+        address: u16,
+        value: &ModbusValue,
+        timestamp_ns: u64,
+    ) -> String {
+        let mut line = escape(&self.measurement, &[',', ' ']);
+        line.push_str(&format!(",slave={},address={}", slave_id, address));
+        for (key, value) in &self.tags {
+            line.push(',');
+            line.push_str(&escape(key, &[',', '=', ' ']));
+            line.push('=');
+            line.push_str(&escape(value, &[',', '=', ' ']));
+        }
+        line.push_str(" value=");
+        line.push_str(&field_value(value));
+        line.push(' ');
+        line.push_str(&timestamp_ns.to_string());
+        line
+    }
+}
+
+/// Accumulates line protocol records for a single write.
+#[derive(Debug, Clone)]
+pub struct InfluxBatchFormatter {
+    formatter: InfluxLineFormatter,
+    lines: Vec<String>,
+}
+
+impl InfluxBatchFormatter {
+    /// Create an empty batch formatting records with `formatter`.
+    pub fn new(formatter: InfluxLineFormatter) -> Self {
+        Self {
+            formatter,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Queue one reading. Returns `false` (and queues nothing) for NaN or
+    /// infinite floats, which line protocol cannot represent.
+    pub fn add(
+        &mut self,
+        slave_id: SlaveId,
+        address: u16,
+        value: &ModbusValue,
+        timestamp_ns: u64,
+    ) -> bool {
+        if !value.as_f64().is_finite() {
+            return false;
+        }
+        self.lines.push(
+            self.formatter
+                .format_register(slave_id, address, value, timestamp_ns),
+        );
+        true
+    }
+
+    /// Number of queued records.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// `true` if no records are queued.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Take all queued records as newline-separated line protocol.
+    pub fn flush(&mut self) -> String {
+        let body = self.lines.join("\n");
+        self.lines.clear();
+        body
+    }
+}
+
+/// Line protocol field value with the type suffix for `value`.
+fn field_value(value: &ModbusValue) -> String {
+    match *value {
+        ModbusValue::Bool(v) => v.to_string(),
+        ModbusValue::U64(v) if v > i64::MAX as u64 => format!("{}u", v),
+        ModbusValue::F32(v) => v.to_string(),
+        ModbusValue::F64(v) => v.to_string(),
+        ModbusValue::Str(ref s) => format!("\"{}\"", escape(s, &['"', '\\'])),
+        _ => format!("{}i", value.as_i64()),
+    }
+}
+
+/// Backslash-escape `special` characters.
+fn escape(s: &str, special: &[char]) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if special.contains(&c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::EngineeringScaler;
+
+    const TS: u64 = 1_609_459_200_000_000_000;
+
+    #[test]
+    fn field_type_follows_value_variant() {
+        let formatter = InfluxLineFormatter::new("modbus");
+        let cases = [
+            (ModbusValue::Bool(true), "true"),
+            (ModbusValue::U16(65535), "65535i"),
+            (ModbusValue::I16(-5), "-5i"),
+            (ModbusValue::U32(4_000_000_000), "4000000000i"),
+            (ModbusValue::I32(-70_000), "-70000i"),
+            (ModbusValue::F32(21.7), "21.7"),
+            (ModbusValue::U64(u64::MAX), "18446744073709551615u"),
+            (ModbusValue::I64(i64::MIN), "-9223372036854775808i"),
+            (ModbusValue::F64(-0.5), "-0.5"),
+            (ModbusValue::from(r#"say "hi""#), r#""say \"hi\"""#),
+        ];
+        for (value, field) in cases {
+            assert_eq!(
+                formatter.format_register(1, 100, &value, TS),
+                format!("modbus,slave=1,address=100 value={} {}", field, TS)
+            );
+        }
+    }
+
+    #[test]
+    fn descriptor_name_unit_and_escaping() {
+        let descriptor = RegisterDescriptor::new(10, "uint16")
+            .with_name("boiler pressure")
+            .with_scaler(EngineeringScaler::new(0.0, 1000.0, 0.0, 10.0, "bar"));
+        let formatter = InfluxLineFormatter::from_register_descriptor(&descriptor)
+            .with_tag("site", "plant=1, hall 2");
+
+        assert_eq!(
+            formatter.format_register(3, 10, &ModbusValue::F64(4.5), 7),
+            r"boiler\ pressure,slave=3,address=10,unit=bar,site=plant\=1\,\ hall\ 2 value=4.5 7"
+        );
+        let unnamed = RegisterDescriptor::new(0, "uint16");
+        assert_eq!(
+            InfluxLineFormatter::from_register_descriptor(&unnamed),
+            InfluxLineFormatter::new("modbus")
+        );
+    }
+
+    #[test]
+    fn batch_skips_non_finite_and_clears_on_flush() {
+        let mut batch = InfluxBatchFormatter::new(InfluxLineFormatter::new("m"));
+        assert!(batch.add(1, 0, &ModbusValue::U16(1), 1));
+        assert!(!batch.add(1, 1, &ModbusValue::F32(f32::NAN), 1));
+        assert!(batch.add(1, 2, &ModbusValue::Bool(false), 2));
+        assert_eq!(batch.len(), 2);
+
+        assert_eq!(
+            batch.flush(),
+            "m,slave=1,address=0 value=1i 1\nm,slave=1,address=2 value=false 2"
+        );
+        assert!(batch.is_empty());
+        assert_eq!(batch.flush(), "");
+    }
+}
@@ -0,0 +1,206 @@
+//! # Redundant Transports
+//!
+//! Plants with redundant PLCs expose the same register map on two devices.
+//! [`FailoverTransport`] sends every request to the primary; when the primary
+//! fails with a transport error (I/O, connection or timeout, see
+//! [`ModbusError::is_transport_error`](crate::ModbusError::is_transport_error))
+//! the same request is sent to the secondary, and later requests stay on the secondary. Device exceptions
+//! and other errors are returned as they are, since they prove the primary
+//! is reachable.
+//!
+//! After `primary_recovery_timeout` on the secondary, the next request tries
+//! the primary again. If it succeeds the primary is promoted back; if it fails
+//! with a transport error the request falls through to the secondary again.
+//!
+//! `FailoverTransport` is a [`ModbusTransport`], so it plugs into
+//! [`GenericModbusClient`](crate::GenericModbusClient) unchanged:
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use voltage_modbus::failover::FailoverTransport;
+//! use voltage_modbus::testing::EchoTransport;
+//! use voltage_modbus::{GenericModbusClient, ModbusClient};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> voltage_modbus::ModbusResult<()> {
+//! let transport = FailoverTransport::new(
+//!     EchoTransport::new(),
+//!     EchoTransport::new(),
+//!     Duration::from_secs(60),
+//! );
+//! let mut client = GenericModbusClient::new(transport);
+//! client.read_03(1, 0, 4).await?;
+//! assert_eq!(client.transport().failover_count(), 0);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::error::ModbusResult;
+use crate::protocol::{ModbusRequest, ModbusResponse};
+use crate::transport::{ModbusTransport, TransportStats};
+
+/// Transport pair that falls through from a primary to a secondary device.
+#[derive(Debug)]
+pub struct FailoverTransport<P: ModbusTransport, S: ModbusTransport> {
+    primary: P,
+    secondary: S,
+    /// Time on the secondary before the primary is tried again.
+    pub primary_recovery_timeout: Duration,
+    /// When requests last moved to the secondary; `None` while on the primary.
+    failed_over_at: Option<Instant>,
+    failover_count: u64,
+}
+
+impl<P: ModbusTransport, S: ModbusTransport> FailoverTransport<P, S> {
+    /// Send to `primary`, falling through to `secondary` on transport errors.
+    pub fn new(primary: P, secondary: S, primary_recovery_timeout: Duration) -> Self {
+        Self {
+            primary,
+            secondary,
+            primary_recovery_timeout,
+            failed_over_at: None,
+            failover_count: 0,
+        }
+    }
+
+    /// The primary transport
+    pub fn primary(&self) -> &P {
+        &self.primary
+    }
+
+    /// The secondary transport
+    pub fn secondary(&self) -> &S {
+        &self.secondary
+    }
+
+    /// `true` while requests are sent to the secondary.
+    pub fn is_failed_over(&self) -> bool {
+        self.failed_over_at.is_some()
+    }
+
+    /// Number of times a primary failure moved requests to the secondary.
+    pub fn failover_count(&self) -> u64 {
+        self.failover_count
+    }
+
+    /// Whether the next request should go to the primary.
+    fn use_primary(&self) -> bool {
+        self.failed_over_at
+            .is_none_or(|at| at.elapsed() >= self.primary_recovery_timeout)
+    }
+}
+
+impl<P: ModbusTransport, S: ModbusTransport> ModbusTransport for FailoverTransport<P, S> {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        if !self.use_primary() {
+            return self.secondary.request(request).await;
+        }
+
+        match self.primary.request(request).await {
+            Err(e) if e.is_transport_error() => {
+                tracing::warn!(error = %e, "primary transport failed, using secondary");
+                self.failover_count += 1;
+                self.failed_over_at = Some(Instant::now());
+                self.secondary.request(request).await
+            }
+            result => {
+                if self.failed_over_at.take().is_some() {
+                    tracing::info!("primary transport recovered");
+                }
+                result
+            }
+        }
+    }
+
+    /// Whether the next request can reach either device.
+    fn is_connected(&self) -> bool {
+        if self.use_primary() {
+            self.primary.is_connected() || self.secondary.is_connected()
+        } else {
+            self.secondary.is_connected()
+        }
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        let primary = self.primary.close().await;
+        let secondary = self.secondary.close().await;
+        primary.and(secondary)
+    }
+
+    /// Combined statistics of both transports.
+    fn get_stats(&self) -> TransportStats {
+        let mut stats = self.primary.get_stats();
+        stats.merge(&self.secondary.get_stats());
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ModbusError;
+    use crate::protocol::ModbusFunction;
+    use crate::testing::{EchoTransport, ScriptedTransport};
+
+    fn read() -> ModbusRequest {
+        ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1)
+    }
+
+    fn ok() -> ModbusResult<ModbusResponse> {
+        Ok(ModbusResponse::new_success(
+            1,
+            ModbusFunction::ReadHoldingRegisters,
+            vec![2, 0, 7],
+        ))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fails_over_and_promotes_primary_after_recovery_timeout() {
+        let primary = ScriptedTransport::new(vec![
+            ok(),
+            Err(ModbusError::connection("refused")),
+            Err(ModbusError::timeout("read response", 1000)),
+            ok(),
+        ]);
+        let mut transport =
+            FailoverTransport::new(primary, EchoTransport::new(), Duration::from_secs(30));
+
+        transport.request(&read()).await.unwrap();
+        assert!(!transport.is_failed_over());
+
+        transport.request(&read()).await.unwrap();
+        assert!(transport.is_failed_over());
+        assert_eq!(transport.failover_count(), 1);
+
+        // Still inside the recovery timeout: the primary is not touched.
+        transport.request(&read()).await.unwrap();
+        assert_eq!(transport.primary().requests().len(), 2);
+
+        tokio::time::advance(Duration::from_secs(30)).await;
+        transport.request(&read()).await.unwrap();
+        assert_eq!(transport.failover_count(), 2, "failed probe falls through");
+
+        tokio::time::advance(Duration::from_secs(30)).await;
+        transport.request(&read()).await.unwrap();
+        assert!(!transport.is_failed_over());
+        transport.primary().assert_all_consumed();
+        assert_eq!(transport.secondary().get_stats().requests_sent, 3);
+        assert_eq!(transport.get_stats().requests_sent, 7);
+    }
+
+    #[tokio::test]
+    async fn device_exceptions_do_not_fail_over() {
+        let primary = ScriptedTransport::new(vec![Err(ModbusError::exception(0x03, 0x02))]);
+        let mut transport =
+            FailoverTransport::new(primary, EchoTransport::new(), Duration::from_secs(30));
+
+        let err = transport.request(&read()).await.unwrap_err();
+        assert!(err.is_device_exception());
+        assert!(!transport.is_failed_over());
+        assert_eq!(transport.secondary().get_stats().requests_sent, 0);
+    }
+}
@@ -0,0 +1,334 @@
+//! # Fail-Safe Defaults
+//!
+//! In safety-related installations outputs must not hold their last commanded
+//! state forever once the master loses contact with a device: pumps should
+//! stop and valves should close. [`FailSafeWriter`] watches a client's
+//! [`TransportStats`] from a background task and, when no response has been
+//! received for [`FailSafeConfig::timeout`], writes the configured safe values
+//! (FC06 for registers, FC05 for coils) to the slave.
+//!
+//! The defaults are written once per outage. If a write fails (the link is
+//! still down) it is retried on the next check; once the device answers
+//! again the writer re-arms for the next outage. [`FailSafeWriter::disarm`]
+//! suspends the watchdog, e.g. during planned maintenance.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use voltage_modbus::failsafe::{FailSafeConfig, FailSafeWriter};
+//! use voltage_modbus::{ModbusClient, ModbusTcpClient};
+//!
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! let client = ModbusTcpClient::from_address("127.0.0.1:502", Duration::from_secs(1)).await?;
+//! let config = FailSafeConfig::new(Duration::from_secs(5))
+//!     .with_default(100, 0) // pump speed setpoint
+//!     .with_coil_default(0, false); // valve open command
+//!
+//! let mut failsafe = FailSafeWriter::new(client, 1, config);
+//! failsafe.start();
+//!
+//! // Use the shared client for normal polling.
+//! let client = failsafe.client();
+//! let speed = client.lock().await.read_03(1, 100, 1).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+use crate::client::ModbusClient;
+use crate::error::ModbusResult;
+use crate::protocol::SlaveId;
+
+/// Shortest interval between watchdog checks.
+const MIN_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Safe values written to a slave when communication is lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailSafeConfig {
+    /// `(address, value)` holding registers written with FC06.
+    pub defaults: Vec<(u16, u16)>,
+    /// `(address, state)` coils written with FC05.
+    pub coil_defaults: Vec<(u16, bool)>,
+    /// Time without a response after which the defaults are written.
+    pub timeout: Duration,
+}
+
+impl FailSafeConfig {
+    /// Create a config with no defaults.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            defaults: Vec::new(),
+            coil_defaults: Vec::new(),
+            timeout,
+        }
+    }
+
+    /// Add a holding-register default.
+    pub fn with_default(mut self, address: u16, value: u16) -> Self {
+        self.defaults.push((address, value));
+        self
+    }
+
+    /// Add a coil default.
+    pub fn with_coil_default(mut self, address: u16, state: bool) -> Self {
+        self.coil_defaults.push((address, state));
+        self
+    }
+
+    /// Interval between watchdog checks (a quarter of the timeout).
+    fn check_interval(&self) -> Duration {
+        (self.timeout / 4).max(MIN_CHECK_INTERVAL)
+    }
+}
+
+/// Writes [`FailSafeConfig`] defaults when a client stops receiving responses.
+///
+/// The watchdog is armed on construction and starts checking once
+/// [`start`](Self::start) is called.
+pub struct FailSafeWriter<C: ModbusClient + 'static> {
+    client: Arc<Mutex<C>>,
+    slave_id: SlaveId,
+    config: Arc<FailSafeConfig>,
+    armed: Arc<AtomicBool>,
+    activations: Arc<AtomicU64>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl<C: ModbusClient + 'static> FailSafeWriter<C> {
+    /// Watch `client`, writing `config` defaults to `slave_id` on timeout.
+    pub fn new(client: C, slave_id: SlaveId, config: FailSafeConfig) -> Self {
+        Self {
+            client: Arc::new(Mutex::new(client)),
+            slave_id,
+            config: Arc::new(config),
+            armed: Arc::new(AtomicBool::new(true)),
+            activations: Arc::new(AtomicU64::new(0)),
+            task: None,
+        }
+    }
+
+    /// Shared handle to the client, for normal application traffic.
+    pub fn client(&self) -> Arc<Mutex<C>> {
+        self.client.clone()
+    }
+
+    /// The fail-safe configuration.
+    pub fn config(&self) -> &FailSafeConfig {
+        &self.config
+    }
+
+    /// Enable writing defaults on timeout.
+    pub fn arm(&self) {
+        self.armed.store(true, Ordering::Relaxed);
+    }
+
+    /// Suspend the watchdog; no defaults are written until [`arm`](Self::arm).
+    pub fn disarm(&self) {
+        self.armed.store(false, Ordering::Relaxed);
+    }
+
+    /// Whether defaults will be written on timeout.
+    pub fn is_armed(&self) -> bool {
+        self.armed.load(Ordering::Relaxed)
+    }
+
+    /// Number of times the defaults have been written.
+    pub fn activations(&self) -> u64 {
+        self.activations.load(Ordering::Relaxed)
+    }
+
+    /// Spawn the watchdog task. Calling it again restarts the task.
+    pub fn start(&mut self) {
+        self.stop();
+        self.task = Some(tokio::spawn(watchdog(
+            self.client.clone(),
+            self.slave_id,
+            self.config.clone(),
+            self.armed.clone(),
+            self.activations.clone(),
+        )));
+    }
+
+    /// Stop the watchdog task.
+    pub fn stop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl<C: ModbusClient + 'static> Drop for FailSafeWriter<C> {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Write every default, stopping at the first failure.
+async fn write_defaults<C: ModbusClient>(
+    client: &mut C,
+    slave_id: SlaveId,
+    config: &FailSafeConfig,
+) -> ModbusResult<()> {
+    for &(address, value) in &config.defaults {
+        client.write_06(slave_id, address, value).await?;
+    }
+    for &(address, state) in &config.coil_defaults {
+        client.write_05(slave_id, address, state).await?;
+    }
+    Ok(())
+}
+
+async fn watchdog<C: ModbusClient>(
+    client: Arc<Mutex<C>>,
+    slave_id: SlaveId,
+    config: Arc<FailSafeConfig>,
+    armed: Arc<AtomicBool>,
+    activations: Arc<AtomicU64>,
+) {
+    let mut ticker = tokio::time::interval(config.check_interval());
+    let mut last_responses = client.lock().await.get_stats().responses_received;
+    let mut last_success = Instant::now();
+    let mut applied = false;
+
+    loop {
+        ticker.tick().await;
+        let mut client = client.lock().await;
+
+        let responses = client.get_stats().responses_received;
+        if responses != last_responses {
+            if applied {
+                info!(slave_id, "modbus.failsafe.recovered");
+            }
+            last_responses = responses;
+            last_success = Instant::now();
+            applied = false;
+            continue;
+        }
+        if applied || !armed.load(Ordering::Relaxed) || last_success.elapsed() < config.timeout {
+            continue;
+        }
+
+        match write_defaults(&mut *client, slave_id, &config).await {
+            Ok(()) => {
+                warn!(
+                    slave_id,
+                    registers = config.defaults.len(),
+                    coils = config.coil_defaults.len(),
+                    "modbus.failsafe.defaults_written"
+                );
+                applied = true;
+                activations.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) => warn!(slave_id, error = %e, "modbus.failsafe.write_failed"),
+        }
+        // Our own writes are not evidence that the application link recovered.
+        last_responses = client.get_stats().responses_received;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GenericModbusClient;
+    use crate::error::ModbusError;
+    use crate::protocol::{ModbusFunction, ModbusRequest, ModbusResponse};
+    use crate::transport::{ModbusTransport, TransportStats};
+
+    /// Transport whose reads fail with a connection error while writes succeed.
+    struct MockTransport {
+        writes: Arc<std::sync::Mutex<Vec<ModbusRequest>>>,
+        stats: TransportStats,
+    }
+
+    impl ModbusTransport for MockTransport {
+        async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+            self.stats.requests_sent += 1;
+            if !request.function.is_write_function() {
+                self.stats.errors += 1;
+                return Err(ModbusError::connection("device unreachable"));
+            }
+            self.writes.lock().unwrap().push(request.clone());
+            self.stats.responses_received += 1;
+            let mut echo = request.address.to_be_bytes().to_vec();
+            echo.extend_from_slice(&request.data);
+            Ok(ModbusResponse::new_success(
+                request.slave_id,
+                request.function,
+                echo,
+            ))
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        async fn close(&mut self) -> ModbusResult<()> {
+            Ok(())
+        }
+
+        fn get_stats(&self) -> TransportStats {
+            self.stats.clone()
+        }
+    }
+
+    type Writes = Arc<std::sync::Mutex<Vec<ModbusRequest>>>;
+
+    fn failsafe() -> (FailSafeWriter<GenericModbusClient<MockTransport>>, Writes) {
+        let writes = Writes::default();
+        let client = GenericModbusClient::new(MockTransport {
+            writes: writes.clone(),
+            stats: TransportStats::default(),
+        });
+        let config = FailSafeConfig::new(Duration::from_millis(40))
+            .with_default(100, 0)
+            .with_coil_default(7, false);
+        (FailSafeWriter::new(client, 1, config), writes)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn defaults_written_once_after_timeout() {
+        let (mut failsafe, writes) = failsafe();
+        failsafe.start();
+
+        let read = failsafe.client().lock().await.read_03(1, 100, 1).await;
+        assert!(matches!(read, Err(ModbusError::Connection { .. })));
+        assert!(writes.lock().unwrap().is_empty());
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let writes = writes.lock().unwrap().clone();
+        let written: Vec<(ModbusFunction, u16, Vec<u8>)> = writes
+            .into_iter()
+            .map(|r| (r.function, r.address, r.data))
+            .collect();
+        assert_eq!(
+            written,
+            vec![
+                (ModbusFunction::WriteSingleRegister, 100, vec![0x00, 0x00]),
+                (ModbusFunction::WriteSingleCoil, 7, vec![0x00, 0x00]),
+            ]
+        );
+        assert_eq!(failsafe.activations(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn disarm_prevents_writes() {
+        let (mut failsafe, writes) = failsafe();
+        failsafe.disarm();
+        failsafe.start();
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(writes.lock().unwrap().is_empty());
+        assert_eq!(failsafe.activations(), 0);
+
+        failsafe.arm();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(writes.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,133 @@
+//! # Deadband Filtering
+//!
+//! Analog sensors jitter within their measurement noise, and publishing every
+//! jitter as a change event floods downstream consumers. [`DeadbandFilter`]
+//! passes a reading only when it differs from the last *published* reading by
+//! more than `threshold` (compared via [`ModbusValue::as_f64`]), so slow
+//! drifts are still reported once they add up.
+//!
+//! ```rust
+//! use voltage_modbus::filter::DeadbandFilter;
+//! use voltage_modbus::ModbusValue;
+//!
+//! let mut filter = DeadbandFilter::new(0.5);
+//! assert!(filter.check(ModbusValue::F32(20.0)).is_some()); // first reading
+//! assert!(filter.check(ModbusValue::F32(20.3)).is_none());
+//! assert!(filter.check(ModbusValue::F32(20.4)).is_none());
+//! assert!(filter.check(ModbusValue::F32(20.6)).is_some()); // 0.6 from 20.0
+//! ```
+
+use crate::value::ModbusValue;
+
+/// Suppresses readings within `threshold` of the last published one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadbandFilter {
+    /// Largest change that is suppressed.
+    pub threshold: f64,
+    /// Publish every change of a `Bool` reading, whatever the threshold.
+    pub always_pass_bool: bool,
+    last_published: Option<ModbusValue>,
+}
+
+impl DeadbandFilter {
+    /// Filter with a deadband of `threshold`; bools are filtered like numbers.
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            threshold,
+            always_pass_bool: false,
+            last_published: None,
+        }
+    }
+
+    /// Publish every change of a `Bool` reading, even with `threshold >= 1`.
+    pub fn with_always_pass_bool(mut self, always_pass_bool: bool) -> Self {
+        self.always_pass_bool = always_pass_bool;
+        self
+    }
+
+    /// Last reading [`check`](Self::check) let through.
+    pub fn last_published(&self) -> Option<&ModbusValue> {
+        self.last_published.as_ref()
+    }
+
+    /// `Some(new_value)` if it should be published, `None` if it is within
+    /// the deadband of the last published reading.
+    ///
+    /// The first reading always passes. Strings pass when they differ, and
+    /// a change between NaN and a number always passes.
+    pub fn check(&mut self, new_value: ModbusValue) -> Option<ModbusValue> {
+        if let Some(last) = &self.last_published {
+            if !self.exceeds(last, &new_value) {
+                return None;
+            }
+        }
+        self.last_published = Some(new_value.clone());
+        Some(new_value)
+    }
+
+    /// Forget the last published reading, so the next one passes.
+    pub fn reset(&mut self) {
+        self.last_published = None;
+    }
+
+    fn exceeds(&self, last: &ModbusValue, new_value: &ModbusValue) -> bool {
+        match (last, new_value) {
+            (ModbusValue::Str(a), ModbusValue::Str(b)) => a != b,
+            (ModbusValue::Bool(a), ModbusValue::Bool(b)) if self.always_pass_bool => a != b,
+            _ => {
+                let (a, b) = (last.as_f64(), new_value.as_f64());
+                if a.is_nan() || b.is_nan() {
+                    a.is_nan() != b.is_nan()
+                } else {
+                    (b - a).abs() > self.threshold
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_against_last_published_value() {
+        let mut filter = DeadbandFilter::new(10.0);
+        assert_eq!(
+            filter.check(ModbusValue::U16(100)),
+            Some(ModbusValue::U16(100))
+        );
+        assert_eq!(filter.check(ModbusValue::U16(110)), None);
+        assert_eq!(
+            filter.check(ModbusValue::I32(89)),
+            Some(ModbusValue::I32(89))
+        );
+        assert_eq!(filter.last_published(), Some(&ModbusValue::I32(89)));
+
+        assert_eq!(
+            filter.check(ModbusValue::F32(f32::NAN)),
+            Some(ModbusValue::F32(f32::NAN))
+        );
+        assert_eq!(filter.check(ModbusValue::F32(f32::NAN)), None);
+        assert!(filter.check(ModbusValue::F32(89.0)).is_some());
+
+        filter.reset();
+        assert!(filter.check(ModbusValue::U16(89)).is_some());
+    }
+
+    #[test]
+    fn bools_bypass_threshold_when_enabled() {
+        let mut filter = DeadbandFilter::new(5.0);
+        assert!(filter.check(ModbusValue::Bool(false)).is_some());
+        assert_eq!(filter.check(ModbusValue::Bool(true)), None);
+
+        let mut filter = filter.with_always_pass_bool(true);
+        assert_eq!(
+            filter.check(ModbusValue::Bool(true)),
+            Some(ModbusValue::Bool(true))
+        );
+        assert_eq!(filter.check(ModbusValue::Bool(true)), None);
+        assert!(filter.check(ModbusValue::from("on")).is_some());
+        assert!(filter.check(ModbusValue::from("on")).is_none());
+    }
+}
@@ -0,0 +1,224 @@
+//! # Kafka Event Export
+//!
+//! [`KafkaEventProducer`] publishes register changes as JSON events to a
+//! Kafka topic, for event-driven architectures that use Kafka as their
+//! backbone. Enabled by the `kafka` feature (rdkafka with a bundled
+//! librdkafka).
+//!
+//! Every event is keyed `slave={slave_id}/address={address}`, so all updates
+//! of one register land in the same partition and keep their order. The
+//! payload looks like:
+//!
+//! ```json
+//! {"slave_id":1,"address":100,"type":"u16","old_value":7,"new_value":8,"timestamp_ms":1609459200000}
+//! ```
+//!
+//! Messages are queued and delivered by a background thread;
+//! [`flush`](KafkaEventProducer::flush) waits until the brokers have
+//! acknowledged everything queued so far.
+//!
+//! ```rust,no_run
+//! use std::time::{Duration, SystemTime};
+//! use voltage_modbus::kafka::KafkaEventProducer;
+//! use voltage_modbus::ModbusValue;
+//!
+//! # fn example() -> voltage_modbus::ModbusResult<()> {
+//! let producer = KafkaEventProducer::new("kafka-1:9092", "plant.registers")?;
+//! producer.publish_register_update(
+//!     1,
+//!     100,
+//!     &ModbusValue::U16(7),
+//!     &ModbusValue::U16(8),
+//!     SystemTime::now(),
+//! )?;
+//! producer.flush(Duration::from_secs(5))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rdkafka::config::ClientConfig;
+use rdkafka::producer::{BaseRecord, DefaultProducerContext, Producer, ThreadedProducer};
+use serde_json::json;
+
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::SlaveId;
+use crate::value::ModbusValue;
+
+/// Produces register change events to one Kafka topic.
+pub struct KafkaEventProducer {
+    topic: String,
+    producer: ThreadedProducer<DefaultProducerContext>,
+}
+
+impl KafkaEventProducer {
+    /// Connect to the comma-separated `brokers` list, producing to `topic`.
+    pub fn new(brokers: &str, topic: impl Into<String>) -> ModbusResult<Self> {
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", brokers);
+        Self::from_config(&config, topic)
+    }
+
+    /// Create a producer from a full rdkafka configuration (security, acks, ...).
+    pub fn from_config(config: &ClientConfig, topic: impl Into<String>) -> ModbusResult<Self> {
+        let producer = config.create().map_err(|e| {
+            ModbusError::configuration(format!("Kafka producer configuration: {}", e))
+        })?;
+        Ok(Self {
+            topic: topic.into(),
+            producer,
+        })
+    }
+
+    /// Topic events are produced to.
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Queue a change of register `address` on `slave_id` from `old_value` to
+    /// `new_value`.
+    pub fn publish_register_update(
+        &self,
+        slave_id: SlaveId,
+        address: u16,
+        old_value: &ModbusValue,
+        new_value: &ModbusValue,
+        timestamp: SystemTime,
+    ) -> ModbusResult<()> {
+        let timestamp_ms = timestamp
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as i64);
+        let key = event_key(slave_id, address);
+        let payload = register_update_json(slave_id, address, old_value, new_value, timestamp_ms);
+
+        let record = BaseRecord::to(&self.topic)
+            .key(&key)
+            .payload(&payload)
+            .timestamp(timestamp_ms);
+        self.producer
+            .send(record)
+            .map_err(|(e, _)| ModbusError::connection(format!("Kafka produce failed: {}", e)))
+    }
+
+    /// Block until every queued event is acknowledged, or `timeout` elapses.
+    pub fn flush(&self, timeout: Duration) -> ModbusResult<()> {
+        self.producer
+            .flush(timeout)
+            .map_err(|_| ModbusError::timeout("Kafka flush", timeout.as_millis() as u64))
+    }
+
+    /// Number of events queued but not yet acknowledged.
+    pub fn in_flight_count(&self) -> i32 {
+        self.producer.in_flight_count()
+    }
+}
+
+/// Message key for per-register partition ordering.
+pub fn event_key(slave_id: SlaveId, address: u16) -> String {
+    format!("slave={}/address={}", slave_id, address)
+}
+
+fn register_update_json(
+    slave_id: SlaveId,
+    address: u16,
+    old_value: &ModbusValue,
+    new_value: &ModbusValue,
+    timestamp_ms: i64,
+) -> String {
+    json!({
+        "slave_id": slave_id,
+        "address": address,
+        "type": new_value.type_name(),
+        "old_value": old_value.to_json(),
+        "new_value": new_value.to_json(),
+        "timestamp_ms": timestamp_ms,
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rdkafka::consumer::{BaseConsumer, Consumer};
+    use rdkafka::message::Message;
+    use rdkafka::mocking::MockCluster;
+
+    #[test]
+    fn event_payload_format() {
+        let payload = register_update_json(
+            3,
+            40001,
+            &ModbusValue::F32(1.5),
+            &ModbusValue::F32(f32::NAN),
+            1_609_459_200_000,
+        );
+        let parsed: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(
+            parsed,
+            json!({
+                "slave_id": 3,
+                "address": 40001,
+                "type": "f32",
+                "old_value": 1.5,
+                "new_value": null,
+                "timestamp_ms": 1_609_459_200_000i64,
+            })
+        );
+        assert_eq!(ModbusValue::Bool(true).to_json(), json!(true));
+        assert_eq!(ModbusValue::I16(-2).to_json(), json!(-2));
+        assert_eq!(ModbusValue::U64(u64::MAX).to_json(), json!(u64::MAX));
+    }
+
+    #[test]
+    fn events_reach_mock_cluster_with_register_keys() {
+        const TOPIC: &str = "modbus.registers";
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 4, 1).unwrap();
+
+        let producer = KafkaEventProducer::new(&cluster.bootstrap_servers(), TOPIC).unwrap();
+        let at = UNIX_EPOCH + Duration::from_millis(1_000);
+        producer
+            .publish_register_update(1, 100, &ModbusValue::U16(7), &ModbusValue::U16(8), at)
+            .unwrap();
+        producer
+            .publish_register_update(
+                2,
+                5,
+                &ModbusValue::Bool(false),
+                &ModbusValue::Bool(true),
+                at,
+            )
+            .unwrap();
+        producer.flush(Duration::from_secs(10)).unwrap();
+        assert_eq!(producer.in_flight_count(), 0);
+
+        let consumer: BaseConsumer = ClientConfig::new()
+            .set("bootstrap.servers", cluster.bootstrap_servers())
+            .set("group.id", "voltage-modbus-test")
+            .set("auto.offset.reset", "earliest")
+            .create()
+            .unwrap();
+        consumer.subscribe(&[TOPIC]).unwrap();
+
+        let mut received = Vec::new();
+        let deadline = std::time::Instant::now() + Duration::from_secs(20);
+        while received.len() < 2 && std::time::Instant::now() < deadline {
+            if let Some(message) = consumer.poll(Duration::from_millis(100)) {
+                let message = message.unwrap();
+                let key = String::from_utf8(message.key().unwrap().to_vec()).unwrap();
+                let payload: serde_json::Value =
+                    serde_json::from_slice(message.payload().unwrap()).unwrap();
+                received.push((key, payload));
+            }
+        }
+        received.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[0].0, "slave=1/address=100");
+        assert_eq!(received[0].1["new_value"], json!(8));
+        assert_eq!(received[0].1["timestamp_ms"], json!(1000));
+        assert_eq!(received[1].0, "slave=2/address=5");
+        assert_eq!(received[1].1["old_value"], json!(false));
+    }
+}
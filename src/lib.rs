@@ -99,6 +99,9 @@ pub mod error;
 /// High-performance PDU with stack-allocated fixed array
 pub mod pdu;
 
+/// Lazy chunking of large address ranges into request-sized pieces
+pub mod chunk;
+
 /// Modbus protocol definitions and message handling
 pub mod protocol;
 
@@ -114,6 +117,86 @@ pub mod transport;
 #[cfg(feature = "std")]
 pub mod client;
 
+/// Object-safe client trait for heterogeneous client collections
+#[cfg(feature = "std")]
+pub mod dyn_client;
+
+/// TLS-encrypted Modbus TCP transport
+#[cfg(feature = "tls")]
+pub mod tls;
+
+/// Pipelined Modbus TCP transport (multiple requests in flight per connection)
+#[cfg(feature = "std")]
+pub mod pipeline;
+
+/// Bounded pool of TCP connections for concurrent requests to one server
+#[cfg(feature = "std")]
+pub mod pool;
+
+/// Deduplication of identical concurrent register reads
+#[cfg(feature = "std")]
+pub mod dedup;
+
+/// Primary/secondary transport failover for redundant devices
+#[cfg(feature = "std")]
+pub mod failover;
+
+/// Recording of request/response exchanges and their replay
+#[cfg(feature = "std")]
+pub mod capture;
+
+/// Run-length compressed bulk register writes
+#[cfg(feature = "std")]
+pub mod block_write;
+
+/// Timestamped multi-register snapshots
+#[cfg(feature = "std")]
+pub mod snapshot;
+
+/// Delta encoding of register snapshots for bandwidth-limited mirroring
+#[cfg(feature = "std")]
+pub mod delta;
+
+/// Continuous register and coil polling as `Stream`s
+#[cfg(feature = "std")]
+pub mod poller;
+
+/// Register maps and configuration validation
+#[cfg(feature = "std")]
+pub mod map;
+
+/// Register map discovery by probing undocumented devices
+#[cfg(feature = "std")]
+pub mod probe;
+
+/// Device capability negotiation (identification, diagnostics, read size)
+#[cfg(feature = "std")]
+pub mod capabilities;
+
+/// Slave discovery and RS-485 address conflict detection
+#[cfg(feature = "std")]
+pub mod bus_scan;
+
+/// Time-series export formats (InfluxDB line protocol)
+#[cfg(feature = "std")]
+pub mod export;
+
+/// Register change events produced to Kafka
+#[cfg(feature = "kafka")]
+pub mod kafka;
+
+/// Value changes published to an MQTT broker
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+
+/// Fail-safe default values written on communication loss
+#[cfg(feature = "std")]
+pub mod failsafe;
+
+/// Per-address write authorization for client connections
+#[cfg(feature = "std")]
+pub mod authorization;
+
 /// Utility functions and performance monitoring
 #[cfg(feature = "std")]
 pub mod utils;
@@ -150,6 +233,18 @@ pub mod coalescer;
 #[cfg(feature = "std")]
 pub mod scheduler;
 
+/// Read merging for scattered register ranges on one slave
+#[cfg(feature = "std")]
+pub mod optimizer;
+
+/// Per-slave circuit breaker for devices that stop responding
+#[cfg(feature = "std")]
+pub mod reliability;
+
+/// Deadband filtering of noisy readings for change detection
+#[cfg(feature = "std")]
+pub mod filter;
+
 /// Device-specific protocol limits configuration
 #[cfg(feature = "std")]
 pub mod device_limits;
@@ -166,17 +261,31 @@ pub mod embedded;
 #[cfg(feature = "std")]
 pub mod register_bank;
 
+/// Request counters and latency histograms through the `metrics` facade
+#[cfg(feature = "metrics")]
+pub mod telemetry;
+
+/// In-process test transports for exercising client logic without a network
+#[cfg(feature = "std")]
+pub mod testing;
+
+/// Python bindings (pyo3) for the TCP client and value types
+#[cfg(feature = "python")]
+pub mod py_bindings;
+
 // ============================================================================
 // Re-exports for convenience
 // ============================================================================
 
 // === Core protocol — always available (no_std compatible) ===
+pub use chunk::{CoilRange, CoilRangeChunker, RegisterRange, RegisterRangeChunker};
 pub use constants::{
-    MAX_PDU_SIZE, MAX_READ_COILS, MAX_READ_REGISTERS, MAX_WRITE_COILS, MAX_WRITE_REGISTERS,
+    MAX_PDU_SIZE, MAX_READ_COILS, MAX_READ_REGISTERS, MAX_READ_WRITE_REGISTERS, MAX_WRITE_COILS,
+    MAX_WRITE_REGISTERS,
 };
-pub use error::{ModbusError, ModbusResult};
-pub use pdu::{ModbusPdu, PduBuilder};
-pub use protocol::{ModbusFunction, ModbusRequest, ModbusResponse, SlaveId};
+pub use error::{ErrorSource, ModbusError, ModbusResult};
+pub use pdu::{validate_pdu, validate_response_pdu, ModbusPdu, PduBuilder, PduViolation};
+pub use protocol::{ModbusFunction, ModbusRequest, ModbusRequestBuilder, ModbusResponse, SlaveId};
 
 // === std-only re-exports ===
 
@@ -184,16 +293,24 @@ pub use protocol::{ModbusFunction, ModbusRequest, ModbusResponse, SlaveId};
 pub use tokio;
 
 #[cfg(feature = "std")]
-pub use client::{GenericModbusClient, ModbusClient, ModbusTcpClient};
+pub use client::{
+    AtomicMode, GenericModbusClient, ModbusClient, ModbusTcpClient, RetryCondition, RetryPolicy,
+};
+
+#[cfg(feature = "std")]
+pub use dyn_client::DynModbusClient;
 
 #[cfg(feature = "std")]
 pub use bytes::ByteOrder;
 
 #[cfg(feature = "std")]
-pub use value::ModbusValue;
+pub use value::{ModbusValue, ModbusValueVec, ScaledValue};
 
 #[cfg(feature = "std")]
-pub use batcher::{BatchCommand, CommandBatcher};
+pub use batcher::{
+    execute_batch_parallel, execute_batch_sequential, BatchCommand, BatchResult, BatchResults,
+    CommandBatcher,
+};
 
 #[cfg(feature = "std")]
 pub use coalescer::{CoalescedRead, ReadCoalescer, ReadRequest};
@@ -202,7 +319,22 @@ pub use coalescer::{CoalescedRead, ReadCoalescer, ReadRequest};
 pub use scheduler::ScheduledRequest;
 
 #[cfg(feature = "std")]
-pub use codec::ModbusCodec;
+pub use optimizer::ReadMerger;
+
+#[cfg(feature = "std")]
+pub use reliability::{CircuitBreaker, CircuitState};
+
+#[cfg(feature = "std")]
+pub use filter::DeadbandFilter;
+
+#[cfg(feature = "std")]
+pub use failover::FailoverTransport;
+
+#[cfg(feature = "std")]
+pub use capture::{CaptureFrame, CaptureTransport, ReplayTransport};
+
+#[cfg(feature = "std")]
+pub use codec::{DeviceIdResponse, ModbusCodec};
 
 #[cfg(feature = "std")]
 pub use device_limits::DeviceLimits;
@@ -211,13 +343,74 @@ pub use device_limits::DeviceLimits;
 pub use client::ModbusRtuOverTcpClient;
 
 #[cfg(feature = "std")]
-pub use transport::{ModbusTransport, RtuOverTcpTransport, TcpTransport, TransportStats};
+pub use transport::{
+    AtomicTransportStats, EscalatingTimeout, ModbusTransport, ReconnectBackoff, ReconnectPolicy,
+    ResendStrategy, RoundRobinTransport, RtuOverTcpTransport, TcpKeepalive, TcpTransport,
+    TcpTransportConfig, TransportStats, WritePolicy, LATENCY_BUCKETS,
+};
+
+#[cfg(feature = "std")]
+pub use transport::{crc16_modbus, PacketCallback, PacketDirection};
+
+#[cfg(feature = "std")]
+pub use pipeline::{PipelineConfig, PipelinedTcpTransport};
+
+#[cfg(feature = "std")]
+pub use pool::{PooledConnection, TcpConnectionPool, TcpPoolConfig};
+
+#[cfg(feature = "tls")]
+pub use tls::{CertificateStore, TlsClientConfig, TlsTcpTransport};
+
+#[cfg(feature = "std")]
+pub use dedup::DedupTransport;
+
+#[cfg(feature = "std")]
+pub use block_write::CompressedBlockWrite;
+
+#[cfg(feature = "std")]
+pub use snapshot::{RegisterDescriptor, RegisterDiff, RegisterSnapshot, SnapshotStore};
+
+#[cfg(feature = "std")]
+pub use delta::{DeltaDecoder, DeltaEncoder, DeltaPayload};
+
+#[cfg(feature = "std")]
+pub use map::{Access, ByteOrderInconsistency, RegisterMap, RegisterType, TypedRegister};
+
+#[cfg(feature = "std")]
+pub use poller::{CoilPoller, RegisterPoller};
 
 #[cfg(feature = "std")]
-pub use transport::{PacketCallback, PacketDirection};
+pub use probe::{ProbeResult, RegisterMapProber, SmartProber};
 
 #[cfg(feature = "std")]
-pub use utils::PerformanceMetrics;
+pub use capabilities::{DeviceCapabilities, DeviceCapabilityProber, DeviceIdentification};
+
+#[cfg(feature = "std")]
+pub use bus_scan::{scan_slaves, scan_slaves_blocking, BusScanReport, ConflictResult, RtuBusScan};
+
+#[cfg(feature = "std")]
+pub use export::{InfluxBatchFormatter, InfluxLineFormatter};
+
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaEventProducer;
+
+#[cfg(feature = "mqtt")]
+pub use mqtt::{MqttGateway, MqttPublisher, RetainedPublisher, ValueChangePublisher};
+
+#[cfg(feature = "metrics")]
+pub use telemetry::MetricsConfig;
+
+#[cfg(feature = "std")]
+pub use failsafe::{FailSafeConfig, FailSafeWriter};
+
+#[cfg(feature = "std")]
+pub use authorization::{AllowedRanges, WriteAuthorizationMiddleware};
+
+#[cfg(feature = "std")]
+pub use utils::{
+    EngineeringScaler, FunctionCodeStats, HysteresisFilter, LinearizationTable, PerformanceMetrics,
+    PerformanceMetricsDiff, RegisterBlockCrc,
+};
 
 #[cfg(feature = "std")]
 pub use logging::{CallbackLogger, LogCallback, LogLevel, LoggingMode};
@@ -226,7 +419,9 @@ pub use logging::{CallbackLogger, LogCallback, LogLevel, LoggingMode};
 pub use register_bank::{ModbusRegisterBank, RegisterBankStats};
 
 #[cfg(feature = "std")]
-pub use server::{ModbusServer, ModbusTcpServer, ModbusTcpServerConfig, ServerStats};
+pub use server::{
+    DataStore, ModbusServer, ModbusTcpServer, ModbusTcpServerConfig, RequestHandler, ServerStats,
+};
 
 // === Hidden but preserved (backward compatibility, std-only) ===
 #[cfg(feature = "std")]
@@ -258,17 +453,26 @@ pub use device_limits::{
 pub use utils::OperationTimer;
 
 #[cfg(feature = "rtu")]
-pub use client::{ModbusAsciiClient, ModbusRtuClient};
+pub use client::ModbusRtuClient;
+
+#[cfg(feature = "ascii")]
+pub use client::ModbusAsciiClient;
 
 #[cfg(feature = "rtu")]
 pub use server::{ModbusRtuServer, ModbusRtuServerConfig};
 
 #[cfg(feature = "rtu")]
-pub use transport::{AsciiTransport, RtuTransport};
+pub use transport::RtuTransport;
+
+#[cfg(feature = "ascii")]
+pub use transport::AsciiTransport;
 
 #[cfg(feature = "embedded")]
 pub use embedded::EmbeddedRtuTransport;
 
+#[cfg(feature = "python")]
+pub use py_bindings::{PyModbusTcpClient, PyModbusValue};
+
 /// Default timeout for operations (5 seconds)
 pub const DEFAULT_TIMEOUT_MS: u64 = 5000;
 
@@ -370,81 +370,61 @@ impl CallbackLogger {
         }
 
         match function_code {
-            0x01 | 0x02 => {
+            0x01 | 0x02 if data.len() >= 2 => {
                 // Coils or discrete inputs
-                if data.len() >= 2 {
-                    let byte_count = data[0];
-                    let mut coils = Vec::new();
-                    for i in 1..=byte_count as usize {
-                        if i < data.len() {
-                            for bit in 0..8 {
-                                coils.push((data[i] & (1 << bit)) != 0);
-                            }
+                let byte_count = data[0];
+                let mut coils = Vec::new();
+                for i in 1..=byte_count as usize {
+                    if i < data.len() {
+                        for bit in 0..8 {
+                            coils.push((data[i] & (1 << bit)) != 0);
                         }
                     }
-                    format!(
-                        "Byte count: {}, Coils: {:?}",
-                        byte_count,
-                        &coils[..coils.len().min(16)]
-                    )
-                } else {
-                    format!("Data: {}", Self::hex_encode(data))
                 }
+                format!(
+                    "Byte count: {}, Coils: {:?}",
+                    byte_count,
+                    &coils[..coils.len().min(16)]
+                )
             }
-            0x03 | 0x04 => {
+            0x03 | 0x04 if data.len() >= 3 => {
                 // Holding registers or input registers
-                if data.len() >= 3 {
-                    let byte_count = data[0];
-                    let mut registers = Vec::new();
-                    for i in (1..data.len()).step_by(2) {
-                        if i + 1 < data.len() {
-                            let value = u16::from_be_bytes([data[i], data[i + 1]]);
-                            registers.push(value);
-                        }
+                let byte_count = data[0];
+                let mut registers = Vec::new();
+                for i in (1..data.len()).step_by(2) {
+                    if i + 1 < data.len() {
+                        let value = u16::from_be_bytes([data[i], data[i + 1]]);
+                        registers.push(value);
                     }
-                    format!(
-                        "Byte count: {}, Registers: {:?}",
-                        byte_count,
-                        &registers[..registers.len().min(8)]
-                    )
-                } else {
-                    format!("Data: {}", Self::hex_encode(data))
                 }
+                format!(
+                    "Byte count: {}, Registers: {:?}",
+                    byte_count,
+                    &registers[..registers.len().min(8)]
+                )
             }
-            0x05 => {
+            0x05 if data.len() >= 4 => {
                 // Write single coil response
-                if data.len() >= 4 {
-                    let address = u16::from_be_bytes([data[0], data[1]]);
-                    let value = u16::from_be_bytes([data[2], data[3]]);
-                    format!(
-                        "Address: {}, Value: 0x{:04X} ({})",
-                        address,
-                        value,
-                        if value == 0xFF00 { "ON" } else { "OFF" }
-                    )
-                } else {
-                    format!("Data: {}", Self::hex_encode(data))
-                }
+                let address = u16::from_be_bytes([data[0], data[1]]);
+                let value = u16::from_be_bytes([data[2], data[3]]);
+                format!(
+                    "Address: {}, Value: 0x{:04X} ({})",
+                    address,
+                    value,
+                    if value == 0xFF00 { "ON" } else { "OFF" }
+                )
             }
-            0x06 => {
+            0x06 if data.len() >= 4 => {
                 // Write single register response
-                if data.len() >= 4 {
-                    let address = u16::from_be_bytes([data[0], data[1]]);
-                    let value = u16::from_be_bytes([data[2], data[3]]);
-                    format!("Address: {}, Value: {} (0x{:04X})", address, value, value)
-                } else {
-                    format!("Data: {}", Self::hex_encode(data))
-                }
+                let address = u16::from_be_bytes([data[0], data[1]]);
+                let value = u16::from_be_bytes([data[2], data[3]]);
+                format!("Address: {}, Value: {} (0x{:04X})", address, value, value)
             }
-            0x0F | 0x10 => {
+            0x0F | 0x10 if data.len() >= 4 => {
                 // Write multiple coils/registers response
-                if data.len() >= 4 {
-                    let address = u16::from_be_bytes([data[0], data[1]]);
-                    let quantity = u16::from_be_bytes([data[2], data[3]]);
-                    format!("Address: {}, Quantity: {}", address, quantity)
-                } else {
-                    format!("Data: {}", Self::hex_encode(data))
-                }
+                let address = u16::from_be_bytes([data[0], data[1]]);
+                let quantity = u16::from_be_bytes([data[2], data[3]]);
+                format!("Address: {}, Quantity: {}", address, quantity)
             }
             _ => {
                 format!("Data: {}", Self::hex_encode(data))
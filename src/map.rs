@@ -0,0 +1,621 @@
+//! # Register Maps
+//!
+//! A [`RegisterMap`] collects the [`RegisterDescriptor`]s of every device an
+//! application talks to, so configuration mistakes can be caught before
+//! deployment.
+//!
+//! A physical device uses one byte order for all its multi-register values.
+//! [`RegisterMap::validate_byte_order_consistency`] reports every slave whose
+//! descriptors disagree, listing each multi-register descriptor involved.
+//!
+//! ```rust
+//! use voltage_modbus::map::RegisterMap;
+//! use voltage_modbus::snapshot::RegisterDescriptor;
+//! use voltage_modbus::ByteOrder;
+//!
+//! let map = RegisterMap::new()
+//!     .with_entry(1, RegisterDescriptor::new(0, "float32").with_name("voltage"))
+//!     .with_entry(
+//!         1,
+//!         RegisterDescriptor::new(2, "float32")
+//!             .with_name("current")
+//!             .with_byte_order(ByteOrder::BigEndianSwap),
+//!     );
+//!
+//! let problems = map.validate_byte_order_consistency();
+//! assert_eq!(problems.len(), 1);
+//! assert_eq!(problems[0].slave_id, 1);
+//! assert_eq!(problems[0].conflicting_orders.len(), 2);
+//! ```
+//!
+//! The map also does the I/O: [`RegisterMap::read_all`] reads every readable
+//! point of a slave in as few requests as [`ReadMerger`] can plan, and
+//! [`RegisterMap::write`] looks a point up by name and writes it in the
+//! point's data type and byte order.
+//!
+//! ```rust
+//! use voltage_modbus::map::{Access, RegisterMap};
+//! use voltage_modbus::snapshot::RegisterDescriptor;
+//! use voltage_modbus::testing::EchoTransport;
+//! use voltage_modbus::{GenericModbusClient, ModbusValue};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> voltage_modbus::ModbusResult<()> {
+//! let map = RegisterMap::new()
+//!     .with_entry(1, RegisterDescriptor::new(0, "uint16").with_name("status"))
+//!     .with_entry(1, RegisterDescriptor::new(1, "uint16").with_name("setpoint"))
+//!     .with_entry(
+//!         1,
+//!         RegisterDescriptor::new(2, "uint32")
+//!             .with_name("energy")
+//!             .with_access(Access::ReadOnly),
+//!     );
+//!
+//! let mut client = GenericModbusClient::new(EchoTransport::new());
+//! let values = map.read_all(&mut client, 1).await?;
+//! assert_eq!(values["setpoint"], ModbusValue::U16(1));
+//!
+//! map.write(&mut client, 1, "setpoint", &ModbusValue::U16(42)).await?;
+//! assert!(map.write(&mut client, 1, "energy", &ModbusValue::U32(0)).await.is_err());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A single point known at compile time is a [`TypedRegister`], whose Rust
+//! type fixes the data type and register count:
+//!
+//! ```rust
+//! use voltage_modbus::map::TypedRegister;
+//! use voltage_modbus::testing::EchoTransport;
+//! use voltage_modbus::{ByteOrder, GenericModbusClient};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> voltage_modbus::ModbusResult<()> {
+//! let energy = TypedRegister::<u32>::new(10).with_byte_order(ByteOrder::BigEndianSwap);
+//! let mut client = GenericModbusClient::new(EchoTransport::new());
+//! assert_eq!(energy.read(&mut client, 1).await?, 0x0001_0000);
+//! energy.write(&mut client, 1, 123_456).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+
+use crate::bytes::ByteOrder;
+use crate::client::ModbusClient;
+use crate::codec::{decode_register_value, encode_value};
+use crate::error::{ModbusError, ModbusResult};
+use crate::optimizer::ReadMerger;
+use crate::protocol::SlaveId;
+use crate::snapshot::RegisterDescriptor;
+use crate::value::ModbusValue;
+
+/// Directions a register may be accessed in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// Read only; [`RegisterMap::write`] refuses it.
+    ReadOnly,
+    /// Write only; [`RegisterMap::read_all`] skips it.
+    WriteOnly,
+    /// Read and write.
+    #[default]
+    ReadWrite,
+}
+
+impl Access {
+    /// `true` unless write-only.
+    pub fn is_readable(self) -> bool {
+        self != Access::WriteOnly
+    }
+
+    /// `true` unless read-only.
+    pub fn is_writable(self) -> bool {
+        self != Access::ReadOnly
+    }
+}
+
+/// Multi-register descriptors of one slave that do not share a byte order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteOrderInconsistency {
+    /// Slave the descriptors belong to.
+    pub slave_id: SlaveId,
+    /// `(name, byte_order)` of every multi-register descriptor on the slave.
+    pub conflicting_orders: Vec<(String, ByteOrder)>,
+}
+
+/// Register descriptors of one or more slaves.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegisterMap {
+    entries: Vec<(SlaveId, RegisterDescriptor)>,
+}
+
+impl RegisterMap {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `descriptor` for `slave_id`.
+    pub fn add(&mut self, slave_id: SlaveId, descriptor: RegisterDescriptor) -> &mut Self {
+        self.entries.push((slave_id, descriptor));
+        self
+    }
+
+    /// Builder form of [`add`](Self::add).
+    pub fn with_entry(mut self, slave_id: SlaveId, descriptor: RegisterDescriptor) -> Self {
+        self.add(slave_id, descriptor);
+        self
+    }
+
+    /// All `(slave_id, descriptor)` entries, in insertion order.
+    pub fn entries(&self) -> &[(SlaveId, RegisterDescriptor)] {
+        &self.entries
+    }
+
+    /// Descriptors of `slave_id`, in insertion order.
+    pub fn descriptors(&self, slave_id: SlaveId) -> Vec<&RegisterDescriptor> {
+        self.entries
+            .iter()
+            .filter(|(slave, _)| *slave == slave_id)
+            .map(|(_, descriptor)| descriptor)
+            .collect()
+    }
+
+    /// Number of descriptors.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// `true` if the map has no descriptors.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Slaves whose multi-register descriptors mix byte orders, in slave order.
+    ///
+    /// Unnamed descriptors are reported as `@{address}`.
+    pub fn validate_byte_order_consistency(&self) -> Vec<ByteOrderInconsistency> {
+        let mut by_slave: BTreeMap<SlaveId, Vec<&RegisterDescriptor>> = BTreeMap::new();
+        for (slave_id, descriptor) in &self.entries {
+            by_slave.entry(*slave_id).or_default().push(descriptor);
+        }
+
+        by_slave
+            .into_iter()
+            .filter(|(_, descriptors)| !ByteOrder::is_consistent(descriptors))
+            .map(|(slave_id, descriptors)| ByteOrderInconsistency {
+                slave_id,
+                conflicting_orders: descriptors
+                    .into_iter()
+                    .filter(|d| d.register_count() > 1)
+                    .map(|d| (display_name(d), d.byte_order))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Read every readable descriptor of `slave_id` with FC03, merging
+    /// neighbouring descriptors into as few requests as a default
+    /// [`ReadMerger`] plans.
+    ///
+    /// Values are keyed by name (`@{address}` if unnamed) and are not scaled.
+    pub async fn read_all<C: ModbusClient>(
+        &self,
+        client: &mut C,
+        slave_id: SlaveId,
+    ) -> ModbusResult<HashMap<String, ModbusValue>> {
+        let readable: Vec<&RegisterDescriptor> = self
+            .descriptors(slave_id)
+            .into_iter()
+            .filter(|d| d.access.is_readable())
+            .collect();
+        let ranges: Vec<(u16, u16)> = readable
+            .iter()
+            .map(|d| (d.address, d.register_count()))
+            .collect();
+        let blocks = ReadMerger::default()
+            .read_03(client, slave_id, &ranges)
+            .await?;
+
+        readable
+            .into_iter()
+            .zip(ranges)
+            .map(|(descriptor, range)| {
+                let registers = ReadMerger::extract(&blocks, range).ok_or_else(|| {
+                    ModbusError::invalid_data(format!(
+                        "No registers read for {}",
+                        display_name(descriptor)
+                    ))
+                })?;
+                let value = descriptor.decode(descriptor.address, registers)?;
+                Ok((display_name(descriptor), value))
+            })
+            .collect()
+    }
+
+    /// Write `value` to the descriptor of `slave_id` named `name`.
+    ///
+    /// The value is converted to the descriptor's data type (integers
+    /// saturate) and encoded with [`encode_value`] in its byte order. Single
+    /// registers are written with FC06 and longer values with FC16; `"bool"`
+    /// descriptors set their bit with FC22, keeping the rest of the register.
+    ///
+    /// # Errors
+    ///
+    /// [`ModbusError::Configuration`] if there is no such descriptor or it is
+    /// read-only, and [`ModbusError::TypeMismatch`] when writing a string to
+    /// a numeric descriptor or a number to a string one.
+    pub async fn write<C: ModbusClient>(
+        &self,
+        client: &mut C,
+        slave_id: SlaveId,
+        name: &str,
+        value: &ModbusValue,
+    ) -> ModbusResult<()> {
+        let descriptor = self
+            .entries
+            .iter()
+            .find(|(slave, d)| *slave == slave_id && d.name.as_deref() == Some(name))
+            .map(|(_, d)| d)
+            .ok_or_else(|| {
+                ModbusError::configuration(format!(
+                    "No register named {:?} on slave {}",
+                    name, slave_id
+                ))
+            })?;
+        if !descriptor.access.is_writable() {
+            return Err(ModbusError::configuration(format!(
+                "Register {:?} on slave {} is read-only",
+                name, slave_id
+            )));
+        }
+
+        let count = usize::from(descriptor.register_count());
+        let like = descriptor.decode(descriptor.address, &vec![0; count])?;
+        let value = match (&like, value) {
+            (ModbusValue::Str(_), ModbusValue::Str(_)) => value.clone(),
+            (ModbusValue::Str(_), _) | (_, ModbusValue::Str(_)) => {
+                return Err(ModbusError::type_mismatch(
+                    "write",
+                    value.type_name(),
+                    like.type_name(),
+                ));
+            }
+            _ => value.convert_like(&like),
+        };
+
+        if let ModbusValue::Bool(on) = value {
+            let bit = 1u16 << descriptor.bit_position;
+            let or_mask = if on { bit } else { 0 };
+            return client
+                .mask_write_register(slave_id, descriptor.address, !bit, or_mask)
+                .await;
+        }
+        let mut registers = encode_value(&value, descriptor.byte_order)?;
+        if registers.len() > count {
+            return Err(ModbusError::invalid_data(format!(
+                "{} does not fit the {} registers of {:?}",
+                value, count, name
+            )));
+        }
+        registers.resize(count, 0);
+        write_registers(client, slave_id, descriptor.address, &registers).await
+    }
+}
+
+/// Rust types stored in a fixed number of holding registers.
+pub trait RegisterType: Sized {
+    /// Codec data type name (`"uint16"`, `"float32"`, ...).
+    const DATA_TYPE: &'static str;
+    /// Number of registers the value occupies.
+    const REGISTER_COUNT: u16;
+
+    /// Extract the value from its decoded [`ModbusValue`].
+    fn from_value(value: ModbusValue) -> ModbusResult<Self>;
+
+    /// Wrap the value for [`encode_value`].
+    fn into_value(self) -> ModbusValue;
+}
+
+macro_rules! impl_register_type {
+    ($($ty:ty => $variant:ident, $data_type:literal, $count:literal;)*) => {$(
+        impl RegisterType for $ty {
+            const DATA_TYPE: &'static str = $data_type;
+            const REGISTER_COUNT: u16 = $count;
+
+            fn from_value(value: ModbusValue) -> ModbusResult<Self> {
+                match value {
+                    ModbusValue::$variant(v) => Ok(v),
+                    other => Err(ModbusError::type_mismatch(
+                        "read",
+                        other.type_name(),
+                        stringify!($ty),
+                    )),
+                }
+            }
+
+            fn into_value(self) -> ModbusValue {
+                ModbusValue::$variant(self)
+            }
+        }
+    )*};
+}
+
+impl_register_type! {
+    bool => Bool, "bool", 1;
+    u16 => U16, "uint16", 1;
+    i16 => I16, "int16", 1;
+    u32 => U32, "uint32", 2;
+    i32 => I32, "int32", 2;
+    f32 => F32, "float32", 2;
+    u64 => U64, "uint64", 4;
+    i64 => I64, "int64", 4;
+    f64 => F64, "float64", 4;
+}
+
+/// One holding-register point whose type is fixed by `T`.
+///
+/// `bool` reads bit 0 of its register and writes the register as 0 or 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TypedRegister<T> {
+    /// First register address.
+    pub address: u16,
+    /// Byte order for multi-register types.
+    pub byte_order: ByteOrder,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T: RegisterType> TypedRegister<T> {
+    /// Big-endian point starting at `address`.
+    pub fn new(address: u16) -> Self {
+        Self {
+            address,
+            byte_order: ByteOrder::default(),
+            _type: PhantomData,
+        }
+    }
+
+    /// Set the byte order.
+    pub fn with_byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    /// Read the value with FC03.
+    pub async fn read(&self, client: &mut impl ModbusClient, slave_id: SlaveId) -> ModbusResult<T> {
+        let registers = client
+            .read_03(slave_id, self.address, T::REGISTER_COUNT)
+            .await?;
+        let value = decode_register_value(&registers, T::DATA_TYPE, 0, self.byte_order, None)?;
+        T::from_value(value)
+    }
+
+    /// Write `value` with FC06, or FC16 if it spans several registers.
+    pub async fn write(
+        &self,
+        client: &mut impl ModbusClient,
+        slave_id: SlaveId,
+        value: T,
+    ) -> ModbusResult<()> {
+        let registers = encode_value(&value.into_value(), self.byte_order)?;
+        write_registers(client, slave_id, self.address, &registers).await
+    }
+}
+
+/// Write `registers` with FC06 if there is one, FC16 otherwise.
+async fn write_registers<C: ModbusClient>(
+    client: &mut C,
+    slave_id: SlaveId,
+    address: u16,
+    registers: &[u16],
+) -> ModbusResult<()> {
+    match *registers {
+        [register] => client.write_06(slave_id, address, register).await,
+        _ => client.write_10(slave_id, address, registers).await,
+    }
+}
+
+fn display_name(descriptor: &RegisterDescriptor) -> String {
+    descriptor
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("@{}", descriptor.address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GenericModbusClient;
+    use crate::protocol::{ModbusFunction, ModbusResponse};
+    use crate::testing::{EchoTransport, ScriptedTransport};
+
+    #[test]
+    fn mixed_orders_on_one_slave_are_reported() {
+        let map = RegisterMap::new()
+            .with_entry(
+                1,
+                RegisterDescriptor::new(0, "float32").with_name("voltage"),
+            )
+            .with_entry(1, RegisterDescriptor::new(2, "uint16").with_name("status"))
+            .with_entry(
+                1,
+                RegisterDescriptor::new(10, "uint32").with_byte_order(ByteOrder::LittleEndianSwap),
+            )
+            .with_entry(
+                2,
+                RegisterDescriptor::new(0, "float32").with_byte_order(ByteOrder::BigEndianSwap),
+            )
+            .with_entry(
+                2,
+                RegisterDescriptor::new(2, "float64").with_byte_order(ByteOrder::BigEndianSwap),
+            );
+
+        assert_eq!(
+            map.validate_byte_order_consistency(),
+            vec![ByteOrderInconsistency {
+                slave_id: 1,
+                conflicting_orders: vec![
+                    ("voltage".to_string(), ByteOrder::BigEndian),
+                    ("@10".to_string(), ByteOrder::LittleEndianSwap),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn sixteen_bit_descriptors_are_order_agnostic() {
+        let a = RegisterDescriptor::new(0, "int16").with_byte_order(ByteOrder::LittleEndian16);
+        let b = RegisterDescriptor::new(1, "float32");
+        let c = RegisterDescriptor::new(3, "uint32").with_byte_order(ByteOrder::BigEndian16);
+        let d = RegisterDescriptor::new(5, "bool").with_byte_order(ByteOrder::LittleEndian);
+        assert!(ByteOrder::is_consistent(&[&a, &b, &c, &d]));
+        assert!(ByteOrder::is_consistent(&[]));
+
+        let map = RegisterMap::new().with_entry(3, a).with_entry(3, b);
+        assert!(map.validate_byte_order_consistency().is_empty());
+        assert_eq!(map.descriptors(3).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn read_all_merges_reads_and_skips_write_only() {
+        let map = RegisterMap::new()
+            .with_entry(1, RegisterDescriptor::new(0, "uint16").with_name("a"))
+            .with_entry(1, RegisterDescriptor::new(2, "uint32").with_name("b"))
+            .with_entry(
+                1,
+                RegisterDescriptor::new(4, "uint16")
+                    .with_name("command")
+                    .with_access(Access::WriteOnly),
+            )
+            .with_entry(1, RegisterDescriptor::new(100, "int16"))
+            .with_entry(2, RegisterDescriptor::new(0, "uint16").with_name("other"));
+
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+        let values = map.read_all(&mut client, 1).await.unwrap();
+
+        assert_eq!(client.get_stats().requests_sent, 2);
+        assert_eq!(values.len(), 3);
+        assert_eq!(values["a"], ModbusValue::U16(0));
+        assert_eq!(values["b"], ModbusValue::U32(0x0002_0003));
+        assert_eq!(values["@100"], ModbusValue::I16(0));
+    }
+
+    #[tokio::test]
+    async fn typed_registers_round_trip_through_codec() {
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+        assert_eq!(
+            TypedRegister::<u16>::new(0)
+                .read(&mut client, 1)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            TypedRegister::<i32>::new(0)
+                .read(&mut client, 1)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            TypedRegister::<u64>::new(0)
+                .with_byte_order(ByteOrder::BigEndianSwap)
+                .read(&mut client, 1)
+                .await
+                .unwrap(),
+            0x0003_0002_0001_0000
+        );
+        assert!(!TypedRegister::<bool>::new(0)
+            .read(&mut client, 1)
+            .await
+            .unwrap());
+
+        let point = TypedRegister::<f32>::new(40).with_byte_order(ByteOrder::BigEndianSwap);
+        let transport = ScriptedTransport::new(vec![Ok(ModbusResponse::new_success(
+            1,
+            ModbusFunction::WriteMultipleRegisters,
+            vec![0, 40, 0, 2],
+        ))]);
+        let mut client = GenericModbusClient::new(transport);
+        point.write(&mut client, 1, 3.0).await.unwrap();
+        assert_eq!(
+            client.transport().requests()[0].data,
+            [0x00, 0x00, 0x40, 0x40]
+        );
+
+        assert_eq!(<f64 as RegisterType>::REGISTER_COUNT, 4);
+        assert!(matches!(
+            u16::from_value(ModbusValue::I16(1)),
+            Err(ModbusError::TypeMismatch { right: "u16", .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn write_encodes_by_descriptor_type() {
+        let map = RegisterMap::new()
+            .with_entry(1, RegisterDescriptor::new(10, "uint16").with_name("speed"))
+            .with_entry(1, RegisterDescriptor::new(12, "float32").with_name("limit"))
+            .with_entry(
+                1,
+                RegisterDescriptor::new(20, "bool")
+                    .with_name("run")
+                    .with_bit_position(3),
+            )
+            .with_entry(
+                1,
+                RegisterDescriptor::new(30, "uint16")
+                    .with_name("fault")
+                    .with_access(Access::ReadOnly),
+            );
+        let transport = ScriptedTransport::new(vec![
+            Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::WriteSingleRegister,
+                vec![0, 10, 0x01, 0xF4],
+            )),
+            Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::WriteMultipleRegisters,
+                vec![0, 12, 0, 2],
+            )),
+            Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::MaskWriteRegister,
+                vec![0, 20, 0xFF, 0xF7, 0x00, 0x08],
+            )),
+        ]);
+        let mut client = GenericModbusClient::new(transport);
+
+        map.write(&mut client, 1, "speed", &ModbusValue::F64(499.6))
+            .await
+            .unwrap();
+        map.write(&mut client, 1, "limit", &ModbusValue::U16(3))
+            .await
+            .unwrap();
+        map.write(&mut client, 1, "run", &ModbusValue::Bool(true))
+            .await
+            .unwrap();
+
+        let requests = client.transport().requests();
+        assert_eq!(requests[0].data, [0x01, 0xF4]);
+        assert_eq!(requests[1].data, [0x40, 0x40, 0x00, 0x00]);
+        assert_eq!(requests[2].data, [0xFF, 0xF7, 0x00, 0x08]);
+        client.transport().assert_all_consumed();
+
+        for (name, value) in [
+            ("fault", ModbusValue::U16(0)),
+            ("missing", ModbusValue::U16(0)),
+        ] {
+            assert!(matches!(
+                map.write(&mut client, 1, name, &value).await,
+                Err(ModbusError::Configuration { .. })
+            ));
+        }
+        assert!(matches!(
+            map.write(&mut client, 1, "speed", &ModbusValue::from("fast"))
+                .await,
+            Err(ModbusError::TypeMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,403 @@
+//! # MQTT Value Publishing
+//!
+//! Publishes Modbus values to an MQTT broker for edge-to-cloud telemetry.
+//! Enabled by the `mqtt` feature (rumqttc over plain TCP).
+//!
+//! [`MqttPublisher`] implements [`ValueChangePublisher`]: each value is
+//! published to `{topic_prefix}/{tag}` with its JSON representation as
+//! payload (`42`, `3.5`, `true`; NaN and infinity become `null`). Register
+//! tags have the form `{slave_id}/{address}` (see [`register_tag`]), giving
+//! topics like `plant/line1/1/100`. [`RetainedPublisher`] sets the retain flag
+//! so late subscribers immediately receive the last known good value.
+//!
+//! [`MqttGateway`] connects a publisher to a `tokio::sync::watch` channel of
+//! [`RegisterSnapshot`]s and publishes every value that changed each time a
+//! new snapshot is sent.
+//!
+//! ```rust,no_run
+//! use rumqttc::MqttOptions;
+//! use voltage_modbus::mqtt::{register_tag, MqttPublisher, ValueChangePublisher};
+//! use voltage_modbus::ModbusValue;
+//!
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! let options = MqttOptions::new("modbus-gateway", "broker.local", 1883);
+//! let mut publisher = MqttPublisher::connect(options, "plant/line1", 1)?;
+//! publisher
+//!     .publish(&register_tag(1, 100), &ModbusValue::F32(21.5))
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use rumqttc::{AsyncClient, MqttOptions, QoS};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::SlaveId;
+use crate::snapshot::RegisterSnapshot;
+use crate::value::ModbusValue;
+
+/// Capacity of the request queue between the client and its event loop.
+const REQUEST_QUEUE_CAPACITY: usize = 64;
+
+/// Delay before the event loop retries after a connection error.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Destination for value changes, keyed by a tag such as `"1/100"`.
+pub trait ValueChangePublisher: Send {
+    /// Publish the current `value` of `tag`.
+    fn publish(
+        &mut self,
+        tag: &str,
+        value: &ModbusValue,
+    ) -> impl std::future::Future<Output = ModbusResult<()>> + Send;
+}
+
+/// Tag for register `address` on `slave_id`: `"{slave_id}/{address}"`.
+pub fn register_tag(slave_id: SlaveId, address: u16) -> String {
+    format!("{}/{}", slave_id, address)
+}
+
+/// Publishes values to `{topic_prefix}/{tag}` on an MQTT broker.
+pub struct MqttPublisher {
+    client: AsyncClient,
+    topic_prefix: String,
+    qos: QoS,
+    event_loop: Option<JoinHandle<()>>,
+}
+
+impl MqttPublisher {
+    /// Connect with `options` and drive the connection from a background task.
+    ///
+    /// `qos` is the MQTT quality of service (0, 1 or 2). The connection is
+    /// established (and re-established after errors) asynchronously;
+    /// messages published meanwhile are queued.
+    pub fn connect(
+        options: MqttOptions,
+        topic_prefix: impl Into<String>,
+        qos: u8,
+    ) -> ModbusResult<Self> {
+        parse_qos(qos)?;
+        let (client, mut event_loop) = AsyncClient::new(options, REQUEST_QUEUE_CAPACITY);
+        let task = tokio::spawn(async move {
+            loop {
+                if let Err(e) = event_loop.poll().await {
+                    warn!(error = %e, "modbus.mqtt.connection_error");
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                }
+            }
+        });
+        let mut publisher = Self::new(client, topic_prefix, qos)?;
+        publisher.event_loop = Some(task);
+        Ok(publisher)
+    }
+
+    /// Wrap a client whose event loop is driven by the caller.
+    pub fn new(
+        client: AsyncClient,
+        topic_prefix: impl Into<String>,
+        qos: u8,
+    ) -> ModbusResult<Self> {
+        Ok(Self {
+            client,
+            topic_prefix: topic_prefix.into().trim_end_matches('/').to_string(),
+            qos: parse_qos(qos)?,
+            event_loop: None,
+        })
+    }
+
+    /// Topic prefix (without trailing `/`).
+    pub fn topic_prefix(&self) -> &str {
+        &self.topic_prefix
+    }
+
+    /// Full topic for `tag`.
+    pub fn topic(&self, tag: &str) -> String {
+        format!("{}/{}", self.topic_prefix, tag)
+    }
+
+    /// Publish `value` to the topic for `tag` with the given retain flag.
+    pub async fn publish_with_retain(
+        &mut self,
+        tag: &str,
+        value: &ModbusValue,
+        retain: bool,
+    ) -> ModbusResult<()> {
+        let payload = value.to_json().to_string();
+        self.client
+            .publish(self.topic(tag), self.qos, retain, payload)
+            .await
+            .map_err(|e| ModbusError::connection(format!("MQTT publish failed: {}", e)))
+    }
+
+    /// Send an MQTT DISCONNECT.
+    pub async fn disconnect(&mut self) -> ModbusResult<()> {
+        self.client
+            .disconnect()
+            .await
+            .map_err(|e| ModbusError::connection(format!("MQTT disconnect failed: {}", e)))
+    }
+}
+
+impl ValueChangePublisher for MqttPublisher {
+    async fn publish(&mut self, tag: &str, value: &ModbusValue) -> ModbusResult<()> {
+        self.publish_with_retain(tag, value, false).await
+    }
+}
+
+impl Drop for MqttPublisher {
+    fn drop(&mut self) {
+        if let Some(task) = self.event_loop.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Publishes with the MQTT retain flag for last-known-good semantics.
+pub struct RetainedPublisher {
+    inner: MqttPublisher,
+}
+
+impl RetainedPublisher {
+    /// Retain every value published through `inner`.
+    pub fn new(inner: MqttPublisher) -> Self {
+        Self { inner }
+    }
+
+    /// The wrapped publisher.
+    pub fn inner(&self) -> &MqttPublisher {
+        &self.inner
+    }
+
+    /// Unwrap the publisher.
+    pub fn into_inner(self) -> MqttPublisher {
+        self.inner
+    }
+}
+
+impl ValueChangePublisher for RetainedPublisher {
+    async fn publish(&mut self, tag: &str, value: &ModbusValue) -> ModbusResult<()> {
+        self.inner.publish_with_retain(tag, value, true).await
+    }
+}
+
+/// Publishes the values of each new [`RegisterSnapshot`] that changed.
+pub struct MqttGateway<P: ValueChangePublisher> {
+    publisher: P,
+    last: HashMap<(SlaveId, u16), ModbusValue>,
+}
+
+impl<P: ValueChangePublisher> MqttGateway<P> {
+    /// Create a gateway publishing through `publisher`.
+    pub fn new(publisher: P) -> Self {
+        Self {
+            publisher,
+            last: HashMap::new(),
+        }
+    }
+
+    /// Publish values of `snapshot` that differ from the last published ones.
+    ///
+    /// Returns the number of values published.
+    pub async fn publish_snapshot(&mut self, snapshot: &RegisterSnapshot) -> ModbusResult<usize> {
+        let mut published = 0;
+        for (address, value) in &snapshot.values {
+            let key = (snapshot.slave_id, *address);
+            if self.last.get(&key) == Some(value) {
+                continue;
+            }
+            self.publisher
+                .publish(&register_tag(snapshot.slave_id, *address), value)
+                .await?;
+            self.last.insert(key, value.clone());
+            published += 1;
+        }
+        Ok(published)
+    }
+
+    /// Publish on every `changed()` of `snapshots` until the sender is dropped.
+    ///
+    /// The snapshot present when the gateway starts is published first.
+    pub async fn run(
+        &mut self,
+        mut snapshots: watch::Receiver<RegisterSnapshot>,
+    ) -> ModbusResult<()> {
+        loop {
+            let snapshot = snapshots.borrow_and_update().clone();
+            self.publish_snapshot(&snapshot).await?;
+            if snapshots.changed().await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// The publisher.
+    pub fn publisher(&self) -> &P {
+        &self.publisher
+    }
+}
+
+fn parse_qos(qos: u8) -> ModbusResult<QoS> {
+    rumqttc::qos(qos)
+        .map_err(|_| ModbusError::configuration(format!("Invalid MQTT QoS {} (expected 0-2)", qos)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::sync::mpsc;
+
+    /// A PUBLISH packet as seen by the broker: `(topic, payload, retain, qos)`.
+    type Received = (String, String, bool, u8);
+
+    async fn read_packet(stream: &mut TcpStream) -> Option<(u8, Vec<u8>)> {
+        let header = stream.read_u8().await.ok()?;
+        let (mut len, mut shift) = (0usize, 0);
+        loop {
+            let byte = stream.read_u8().await.ok()?;
+            len |= usize::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        let mut body = vec![0u8; len];
+        stream.read_exact(&mut body).await.ok()?;
+        Some((header, body))
+    }
+
+    /// Minimal MQTT 3.1.1 broker forwarding every PUBLISH to a channel.
+    async fn test_broker() -> (u16, mpsc::UnboundedReceiver<Received>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            while let Some((header, body)) = read_packet(&mut stream).await {
+                match header >> 4 {
+                    1 => stream.write_all(&[0x20, 0x02, 0x00, 0x00]).await.unwrap(),
+                    3 => {
+                        let qos = (header >> 1) & 0x03;
+                        let topic_len = usize::from(u16::from_be_bytes([body[0], body[1]]));
+                        let topic = String::from_utf8(body[2..2 + topic_len].to_vec()).unwrap();
+                        let mut rest = &body[2 + topic_len..];
+                        if qos > 0 {
+                            stream
+                                .write_all(&[0x40, 0x02, rest[0], rest[1]])
+                                .await
+                                .unwrap();
+                            rest = &rest[2..];
+                        }
+                        let payload = String::from_utf8(rest.to_vec()).unwrap();
+                        let _ = tx.send((topic, payload, header & 0x01 != 0, qos));
+                    }
+                    12 => stream.write_all(&[0xD0, 0x00]).await.unwrap(),
+                    _ => {}
+                }
+            }
+        });
+        (port, rx)
+    }
+
+    async fn next(rx: &mut mpsc::UnboundedReceiver<Received>) -> Received {
+        tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("no publish within 5s")
+            .unwrap()
+    }
+
+    fn snapshot(values: Vec<(u16, ModbusValue)>) -> RegisterSnapshot {
+        RegisterSnapshot {
+            values,
+            registers: Vec::new(),
+            captured_at: Instant::now(),
+            slave_id: 7,
+            is_consistent: true,
+            read_duration: Duration::ZERO,
+        }
+    }
+
+    #[tokio::test]
+    async fn topic_payload_and_retain_flag() {
+        let (port, mut rx) = test_broker().await;
+        let options = MqttOptions::new("voltage-modbus-test", "127.0.0.1", port);
+        let mut publisher = MqttPublisher::connect(options, "plant/line1/", 1).unwrap();
+
+        publisher
+            .publish(&register_tag(1, 100), &ModbusValue::F32(21.5))
+            .await
+            .unwrap();
+        assert_eq!(
+            next(&mut rx).await,
+            (
+                "plant/line1/1/100".to_string(),
+                "21.5".to_string(),
+                false,
+                1
+            )
+        );
+
+        let mut retained = RetainedPublisher::new(publisher);
+        retained
+            .publish(&register_tag(2, 5), &ModbusValue::Bool(true))
+            .await
+            .unwrap();
+        assert_eq!(
+            next(&mut rx).await,
+            ("plant/line1/2/5".to_string(), "true".to_string(), true, 1)
+        );
+    }
+
+    #[tokio::test]
+    async fn gateway_publishes_changed_values_only() {
+        let (port, mut rx) = test_broker().await;
+        let options = MqttOptions::new("voltage-modbus-gateway", "127.0.0.1", port);
+        let publisher = MqttPublisher::connect(options, "site", 0).unwrap();
+        let mut gateway = MqttGateway::new(publisher);
+
+        let (tx, watch_rx) = watch::channel(snapshot(vec![
+            (0, ModbusValue::U16(1)),
+            (1, ModbusValue::I16(-3)),
+        ]));
+        let run = tokio::spawn(async move { gateway.run(watch_rx).await });
+
+        let mut first = [next(&mut rx).await, next(&mut rx).await];
+        first.sort();
+        assert_eq!(first[0].0, "site/7/0");
+        assert_eq!(
+            first[1],
+            ("site/7/1".to_string(), "-3".to_string(), false, 0)
+        );
+
+        tx.send(snapshot(vec![
+            (0, ModbusValue::U16(2)),
+            (1, ModbusValue::I16(-3)),
+        ]))
+        .unwrap();
+        assert_eq!(
+            next(&mut rx).await,
+            ("site/7/0".to_string(), "2".to_string(), false, 0)
+        );
+
+        drop(tx);
+        run.await.unwrap().unwrap();
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn invalid_qos_is_rejected() {
+        let (client, _event_loop) = AsyncClient::new(MqttOptions::new("id", "localhost", 1883), 1);
+        assert!(matches!(
+            MqttPublisher::new(client, "x", 3),
+            Err(ModbusError::Configuration { .. })
+        ));
+    }
+}
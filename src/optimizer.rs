@@ -0,0 +1,153 @@
+//! # Read Merging
+//!
+//! Register maps are usually scattered: floats at 0, 2, 4 and 6 cost four
+//! `read_03(addr, 2)` round trips, but one `read_03(0, 8)` returns the same
+//! data. [`ReadMerger`] turns a list of `(address, count)` ranges into the
+//! fewest reads that cover them all, bridging gaps of up to `max_gap` unused
+//! registers, and [`ReadMerger::extract`] slices each original range back out
+//! of the merged results.
+//!
+//! Merging is done by [`ReadCoalescer`]; this module is the single-slave,
+//! tuple-based front end with [`DeviceLimits`] integration.
+//!
+//! ```rust
+//! use voltage_modbus::optimizer::ReadMerger;
+//!
+//! let merger = ReadMerger::new(2);
+//! let merged = merger.merge(&[(0, 2), (2, 2), (4, 2), (6, 2), (40, 1)]);
+//! assert_eq!(merged, [(0, 8), (40, 1)]);
+//!
+//! let results = vec![(0, (100..108).collect::<Vec<u16>>()), (40, vec![7])];
+//! assert_eq!(ReadMerger::extract(&results, (4, 2)), Some(&[104, 105][..]));
+//! assert_eq!(ReadMerger::extract(&results, (40, 1)), Some(&[7][..]));
+//! ```
+
+use std::time::Duration;
+
+use crate::client::ModbusClient;
+use crate::coalescer::{ReadCoalescer, ReadRequest};
+use crate::device_limits::DeviceLimits;
+use crate::error::ModbusResult;
+use crate::protocol::SlaveId;
+
+/// Plans merged register reads for one slave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadMerger {
+    max_gap: u16,
+    limits: DeviceLimits,
+}
+
+impl ReadMerger {
+    /// Merge ranges separated by at most `max_gap` unused registers, up to
+    /// the default 125 registers per read.
+    pub fn new(max_gap: u16) -> Self {
+        Self {
+            max_gap,
+            limits: DeviceLimits::new(),
+        }
+    }
+
+    /// Cap merged reads at `limits.max_read_registers`, and pace
+    /// [`read_03`](Self::read_03) by `limits.inter_request_delay_ms`.
+    pub fn with_limits(mut self, limits: DeviceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Largest gap of unused registers bridged by one read.
+    pub fn max_gap(&self) -> u16 {
+        self.max_gap
+    }
+
+    /// Merged `(address, count)` reads covering every range in `ranges`,
+    /// in address order.
+    ///
+    /// A single range longer than `max_read_registers` is kept whole;
+    /// [`read_03`](Self::read_03) splits it when reading.
+    pub fn merge(&self, ranges: &[(u16, u16)]) -> Vec<(u16, u16)> {
+        let requests: Vec<ReadRequest> = ranges
+            .iter()
+            .filter(|&&(_, count)| count > 0)
+            .map(|&(address, count)| ReadRequest::new(0, 0x03, address, count))
+            .collect();
+        ReadCoalescer::with_config(self.max_gap, self.limits.max_read_registers)
+            .coalesce(&requests)
+            .into_iter()
+            .map(|read| (read.address, read.quantity))
+            .collect()
+    }
+
+    /// Read every merged range with FC03, returning `(address, registers)`
+    /// per merged read for [`extract`](Self::extract).
+    pub async fn read_03<C: ModbusClient>(
+        &self,
+        client: &mut C,
+        slave_id: SlaveId,
+        ranges: &[(u16, u16)],
+    ) -> ModbusResult<Vec<(u16, Vec<u16>)>> {
+        let merged = self.merge(ranges);
+        let mut results = Vec::with_capacity(merged.len());
+        for (i, &(address, count)) in merged.iter().enumerate() {
+            if i > 0 && self.limits.inter_request_delay_ms > 0 {
+                tokio::time::sleep(Duration::from_millis(self.limits.inter_request_delay_ms)).await;
+            }
+            let registers = client
+                .read_03_batch(slave_id, address, count, &self.limits)
+                .await?;
+            results.push((address, registers));
+        }
+        Ok(results)
+    }
+
+    /// The registers of `range` within `merged_results`, or `None` if no
+    /// merged read covers all of it.
+    pub fn extract(merged_results: &[(u16, Vec<u16>)], range: (u16, u16)) -> Option<&[u16]> {
+        let (address, count) = range;
+        merged_results.iter().find_map(|(start, registers)| {
+            let offset = usize::from(address.checked_sub(*start)?);
+            registers.get(offset..offset + usize::from(count))
+        })
+    }
+}
+
+impl Default for ReadMerger {
+    fn default() -> Self {
+        Self::new(crate::coalescer::DEFAULT_GAP_THRESHOLD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GenericModbusClient;
+    use crate::testing::EchoTransport;
+
+    #[test]
+    fn merge_respects_gap_and_read_limit() {
+        let merger = ReadMerger::new(3);
+        assert_eq!(merger.merge(&[(9, 2), (0, 2), (5, 1)]), [(0, 11)]);
+        assert_eq!(merger.merge(&[(0, 2), (6, 2)]), [(0, 2), (6, 2)]);
+        assert_eq!(merger.merge(&[(0, 4), (1, 2), (9, 0)]), [(0, 4)]);
+
+        let limited = merger.with_limits(DeviceLimits::new().with_max_read_registers(8));
+        assert_eq!(limited.merge(&[(0, 2), (4, 2), (8, 2)]), [(0, 6), (8, 2)]);
+        assert!(limited.merge(&[]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn read_03_issues_one_request_per_merged_range() {
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+        let merger =
+            ReadMerger::new(0).with_limits(DeviceLimits::new().with_inter_request_delay_ms(0));
+        let ranges = [(0, 2), (2, 2), (4, 2), (6, 2), (100, 3)];
+
+        let results = merger.read_03(&mut client, 1, &ranges).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(client.get_stats().requests_sent, 2);
+
+        assert_eq!(ReadMerger::extract(&results, (4, 2)), Some(&[4, 5][..]));
+        assert_eq!(ReadMerger::extract(&results, (101, 2)), Some(&[1, 2][..]));
+        assert_eq!(ReadMerger::extract(&results, (7, 2)), None);
+        assert_eq!(ReadMerger::extract(&results, (50, 1)), None);
+    }
+}
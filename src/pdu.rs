@@ -1,21 +1,70 @@
 //! Optimized Modbus PDU data structure
 //!
 //! Use a fixed-size stack array to avoid heap allocation and improve performance.
+//! PDUs of up to [`SMALL_PDU_SIZE`] bytes (every read request, single writes,
+//! exception responses) live in a small inline buffer, so building them only
+//! initialises 8 bytes instead of 253; a PDU moves to the full-size buffer
+//! transparently once it grows past that.
 //! This module is no_std compatible: it only uses `core` primitives and the
 //! `alloc` crate (for `format!` in error messages and `vec!` in write helpers).
 //! `tracing` diagnostics are compiled out in no_std builds.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::ToString, vec};
+use alloc::{format, string::ToString, vec, vec::Vec};
+use core::fmt;
 
-use crate::constants::{MAX_PDU_SIZE, MAX_WRITE_COILS, MAX_WRITE_REGISTERS};
+use crate::constants::{
+    MAX_PDU_SIZE, MAX_READ_COILS, MAX_READ_REGISTERS, MAX_WRITE_COILS, MAX_WRITE_REGISTERS,
+};
 use crate::error::{ModbusError, ModbusResult};
 
+/// Largest PDU kept in the small inline buffer
+pub const SMALL_PDU_SIZE: usize = 8;
+
+/// PDU storage, sized by payload
+///
+/// The enum is as large as its biggest variant; the saving is in how many bytes
+/// get initialised, so the large variant stays inline rather than boxed.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+enum PduBuffer {
+    /// Up to `SMALL_PDU_SIZE` bytes
+    Small([u8; SMALL_PDU_SIZE]),
+    /// Up to `MAX_PDU_SIZE` bytes
+    Large([u8; MAX_PDU_SIZE]),
+}
+
+impl PduBuffer {
+    #[inline]
+    fn capacity(&self) -> usize {
+        match self {
+            Self::Small(buf) => buf.len(),
+            Self::Large(buf) => buf.len(),
+        }
+    }
+
+    #[inline]
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Self::Small(buf) => buf,
+            Self::Large(buf) => buf,
+        }
+    }
+
+    #[inline]
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        match self {
+            Self::Small(buf) => buf,
+            Self::Large(buf) => buf,
+        }
+    }
+}
+
 /// High-performance PDU with stack-allocated fixed array
 #[derive(Debug, Clone)]
 pub struct ModbusPdu {
     /// Fixed-size buffer (stack)
-    data: [u8; MAX_PDU_SIZE],
+    data: PduBuffer,
     /// Actual data length
     len: usize,
 }
@@ -25,11 +74,27 @@ impl ModbusPdu {
     #[inline]
     pub fn new() -> Self {
         Self {
-            data: [0; MAX_PDU_SIZE],
+            data: PduBuffer::Small([0; SMALL_PDU_SIZE]),
             len: 0,
         }
     }
 
+    /// Whether the PDU is held in the small inline buffer
+    #[inline]
+    pub fn is_small(&self) -> bool {
+        matches!(self.data, PduBuffer::Small(_))
+    }
+
+    /// Make room for `len` bytes, moving to the large buffer if needed
+    #[inline]
+    fn reserve_for(&mut self, len: usize) {
+        if len > self.data.capacity() {
+            let mut large = [0; MAX_PDU_SIZE];
+            large[..self.len].copy_from_slice(self.as_slice());
+            self.data = PduBuffer::Large(large);
+        }
+    }
+
     /// Create a PDU from a byte slice
     #[inline]
     pub fn from_slice(data: &[u8]) -> ModbusResult<Self> {
@@ -43,7 +108,8 @@ impl ModbusPdu {
         }
 
         let mut pdu = Self::new();
-        pdu.data[..data.len()].copy_from_slice(data);
+        pdu.reserve_for(data.len());
+        pdu.data.bytes_mut()[..data.len()].copy_from_slice(data);
         pdu.len = data.len();
 
         // Log function code details (std only)
@@ -81,7 +147,8 @@ impl ModbusPdu {
                 message: "PDU buffer full".to_string(),
             });
         }
-        self.data[self.len] = byte;
+        self.reserve_for(self.len + 1);
+        self.data.bytes_mut()[self.len] = byte;
         self.len += 1;
         Ok(())
     }
@@ -107,7 +174,8 @@ impl ModbusPdu {
                 ),
             });
         }
-        self.data[self.len..self.len + data.len()].copy_from_slice(data);
+        self.reserve_for(self.len + data.len());
+        self.data.bytes_mut()[self.len..self.len + data.len()].copy_from_slice(data);
         self.len += data.len();
         Ok(())
     }
@@ -115,13 +183,13 @@ impl ModbusPdu {
     /// Get immutable data slice
     #[inline]
     pub fn as_slice(&self) -> &[u8] {
-        &self.data[..self.len]
+        &self.data.bytes()[..self.len]
     }
 
     /// Get mutable data slice
     #[inline]
     pub fn as_mut_slice(&mut self) -> &mut [u8] {
-        &mut self.data[..self.len]
+        &mut self.data.bytes_mut()[..self.len]
     }
 
     /// Get current length
@@ -145,11 +213,7 @@ impl ModbusPdu {
     /// Get function code (first byte)
     #[inline]
     pub fn function_code(&self) -> Option<u8> {
-        if self.len > 0 {
-            Some(self.data[0])
-        } else {
-            None
-        }
+        self.as_slice().first().copied()
     }
 
     /// Check if exception response
@@ -164,7 +228,7 @@ impl ModbusPdu {
     #[inline]
     pub fn exception_code(&self) -> Option<u8> {
         if self.is_exception() && self.len > 1 {
-            Some(self.data[1])
+            Some(self.as_slice()[1])
         } else {
             None
         }
@@ -181,6 +245,7 @@ impl ModbusPdu {
             0x06 => "Write Single Register",
             0x0F => "Write Multiple Coils",
             0x10 => "Write Multiple Registers",
+            0x16 => "Mask Write Register",
             0x17 => "Read/Write Multiple Registers",
             _ => "Unknown Function",
         }
@@ -377,6 +442,290 @@ impl PduBuilder {
     }
 }
 
+/// A way in which a PDU breaks the Modbus application protocol specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PduViolation {
+    /// The PDU has no function code.
+    Empty,
+    /// The function code is not the one expected.
+    FunctionCodeMismatch {
+        /// Function code the caller expected
+        expected: u8,
+        /// Function code found in the PDU
+        actual: u8,
+    },
+    /// The function code is not one of FC01-06, FC15 or FC16.
+    UnsupportedFunction {
+        /// Function code found in the PDU
+        code: u8,
+    },
+    /// The PDU length is wrong for its function code.
+    InvalidLength {
+        /// Length required by the spec
+        expected: usize,
+        /// Actual PDU length
+        actual: usize,
+    },
+    /// A FC05 coil value other than 0xFF00 (ON) or 0x0000 (OFF).
+    InvalidCoilValue {
+        /// Value found in the coil position
+        value: u16,
+    },
+    /// The quantity is outside the range the function code allows.
+    QuantityOutOfRange {
+        /// Quantity found in the PDU
+        quantity: u16,
+        /// Largest quantity the spec allows
+        max: u16,
+    },
+    /// The byte count field does not match the number of data bytes that follow.
+    ByteCountMismatch {
+        /// Value of the byte count field
+        byte_count: u8,
+        /// Number of data bytes after the byte count field
+        data_len: usize,
+    },
+    /// The byte count field does not match the quantity field (FC15/16).
+    QuantityMismatch {
+        /// Value of the quantity field
+        quantity: u16,
+        /// Value of the byte count field
+        byte_count: u8,
+    },
+    /// A register response byte count that is not a whole number of registers.
+    OddByteCount {
+        /// Value of the byte count field
+        byte_count: u8,
+    },
+}
+
+impl fmt::Display for PduViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Empty => write!(f, "empty PDU"),
+            Self::FunctionCodeMismatch { expected, actual } => write!(
+                f,
+                "function code 0x{:02X} does not match expected 0x{:02X}",
+                actual, expected
+            ),
+            Self::UnsupportedFunction { code } => {
+                write!(f, "unsupported function code 0x{:02X}", code)
+            }
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "PDU length {} (expected {})", actual, expected)
+            }
+            Self::InvalidCoilValue { value } => {
+                write!(f, "coil value 0x{:04X} is neither 0xFF00 nor 0x0000", value)
+            }
+            Self::QuantityOutOfRange { quantity, max } => {
+                write!(f, "quantity {} outside 1..={}", quantity, max)
+            }
+            Self::ByteCountMismatch {
+                byte_count,
+                data_len,
+            } => write!(
+                f,
+                "byte count {} but {} data bytes follow",
+                byte_count, data_len
+            ),
+            Self::QuantityMismatch {
+                quantity,
+                byte_count,
+            } => write!(
+                f,
+                "byte count {} does not fit quantity {}",
+                byte_count, quantity
+            ),
+            Self::OddByteCount { byte_count } => {
+                write!(f, "register byte count {} is odd", byte_count)
+            }
+        }
+    }
+}
+
+/// Check a request PDU for FC01-06, FC15 or FC16 against the spec.
+///
+/// Returns every violation found; an empty `Vec` means the PDU is well-formed.
+/// Checks stop at the first structural problem (wrong function code or
+/// length), since the remaining fields cannot be located reliably after it.
+///
+/// ```rust
+/// use voltage_modbus::pdu::{validate_pdu, ModbusPdu, PduBuilder, PduViolation};
+///
+/// let pdu = PduBuilder::build_write_single_coil(0x00AC, true).unwrap();
+/// assert!(validate_pdu(&pdu, 0x05).is_empty());
+///
+/// let bad = ModbusPdu::from_slice(&[0x05, 0x00, 0xAC, 0x12, 0x34]).unwrap();
+/// assert_eq!(
+///     validate_pdu(&bad, 0x05),
+///     vec![PduViolation::InvalidCoilValue { value: 0x1234 }]
+/// );
+/// ```
+pub fn validate_pdu(pdu: &ModbusPdu, expected_fc: u8) -> Vec<PduViolation> {
+    let data = pdu.as_slice();
+    let mut violations = Vec::new();
+    if let Err(violation) = check_function_code(data, expected_fc) {
+        violations.push(violation);
+        return violations;
+    }
+
+    match expected_fc {
+        0x01..=0x06 => {
+            if let Err(violation) = check_length(data, 5) {
+                violations.push(violation);
+                return violations;
+            }
+            let value = word(data, 3);
+            match expected_fc {
+                0x01 | 0x02 => check_quantity(value, MAX_READ_COILS, &mut violations),
+                0x03 | 0x04 => check_quantity(value, MAX_READ_REGISTERS, &mut violations),
+                0x05 if value != 0xFF00 && value != 0x0000 => {
+                    violations.push(PduViolation::InvalidCoilValue { value })
+                }
+                _ => {}
+            }
+        }
+        _ => {
+            if data.len() < 6 {
+                violations.push(PduViolation::InvalidLength {
+                    expected: 6,
+                    actual: data.len(),
+                });
+                return violations;
+            }
+            let quantity = word(data, 3);
+            let byte_count = data[5];
+            let (max, expected_bytes) = if expected_fc == 0x0F {
+                (MAX_WRITE_COILS, usize::from(quantity).div_ceil(8))
+            } else {
+                (MAX_WRITE_REGISTERS, usize::from(quantity) * 2)
+            };
+            check_quantity(quantity, max, &mut violations);
+            if usize::from(byte_count) != expected_bytes {
+                violations.push(PduViolation::QuantityMismatch {
+                    quantity,
+                    byte_count,
+                });
+            }
+            check_byte_count(byte_count, &data[6..], &mut violations);
+        }
+    }
+    violations
+}
+
+/// Check a response PDU for FC01-06, FC15 or FC16 against the spec.
+///
+/// Exception responses (`expected_fc | 0x80`) are well-formed when they carry
+/// exactly one exception code byte.
+///
+/// ```rust
+/// use voltage_modbus::pdu::{validate_response_pdu, ModbusPdu, PduViolation};
+///
+/// let ok = ModbusPdu::from_slice(&[0x03, 0x04, 0x00, 0x0A, 0x01, 0x02]).unwrap();
+/// assert!(validate_response_pdu(&ok, 0x03).is_empty());
+///
+/// let short = ModbusPdu::from_slice(&[0x03, 0x04, 0x00, 0x0A]).unwrap();
+/// assert_eq!(
+///     validate_response_pdu(&short, 0x03),
+///     vec![PduViolation::ByteCountMismatch { byte_count: 4, data_len: 2 }]
+/// );
+/// ```
+pub fn validate_response_pdu(pdu: &ModbusPdu, expected_fc: u8) -> Vec<PduViolation> {
+    let data = pdu.as_slice();
+    let mut violations = Vec::new();
+    if pdu.is_exception() && data[0] == expected_fc | 0x80 {
+        if let Err(violation) = check_length(data, 2) {
+            violations.push(violation);
+        }
+        return violations;
+    }
+    if let Err(violation) = check_function_code(data, expected_fc) {
+        violations.push(violation);
+        return violations;
+    }
+
+    match expected_fc {
+        0x01..=0x04 => {
+            if data.len() < 2 {
+                violations.push(PduViolation::InvalidLength {
+                    expected: 2,
+                    actual: data.len(),
+                });
+                return violations;
+            }
+            let byte_count = data[1];
+            if matches!(expected_fc, 0x03 | 0x04) && byte_count % 2 != 0 {
+                violations.push(PduViolation::OddByteCount { byte_count });
+            }
+            check_byte_count(byte_count, &data[2..], &mut violations);
+        }
+        _ => {
+            if let Err(violation) = check_length(data, 5) {
+                violations.push(violation);
+                return violations;
+            }
+            let value = word(data, 3);
+            match expected_fc {
+                0x05 if value != 0xFF00 && value != 0x0000 => {
+                    violations.push(PduViolation::InvalidCoilValue { value })
+                }
+                0x0F => check_quantity(value, MAX_WRITE_COILS, &mut violations),
+                0x10 => check_quantity(value, MAX_WRITE_REGISTERS, &mut violations),
+                _ => {}
+            }
+        }
+    }
+    violations
+}
+
+fn check_function_code(data: &[u8], expected_fc: u8) -> Result<(), PduViolation> {
+    if !matches!(expected_fc, 0x01..=0x06 | 0x0F | 0x10) {
+        return Err(PduViolation::UnsupportedFunction { code: expected_fc });
+    }
+    match data.first() {
+        None => Err(PduViolation::Empty),
+        Some(&actual) if actual != expected_fc => Err(PduViolation::FunctionCodeMismatch {
+            expected: expected_fc,
+            actual,
+        }),
+        Some(_) => Ok(()),
+    }
+}
+
+fn check_length(data: &[u8], expected: usize) -> Result<(), PduViolation> {
+    if data.len() == expected {
+        Ok(())
+    } else {
+        Err(PduViolation::InvalidLength {
+            expected,
+            actual: data.len(),
+        })
+    }
+}
+
+fn check_quantity(quantity: u16, max: usize, violations: &mut Vec<PduViolation>) {
+    if quantity == 0 || usize::from(quantity) > max {
+        violations.push(PduViolation::QuantityOutOfRange {
+            quantity,
+            max: max as u16,
+        });
+    }
+}
+
+fn check_byte_count(byte_count: u8, rest: &[u8], violations: &mut Vec<PduViolation>) {
+    if usize::from(byte_count) != rest.len() {
+        violations.push(PduViolation::ByteCountMismatch {
+            byte_count,
+            data_len: rest.len(),
+        });
+    }
+}
+
+#[inline]
+fn word(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([data[offset], data[offset + 1]])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -485,6 +834,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_small_pdu_grows_transparently() {
+        let read = PduBuilder::build_read_request(0x03, 0x006B, 3).unwrap();
+        assert!(read.is_small());
+
+        let mut pdu =
+            ModbusPdu::from_slice(&[0x10, 0x00, 0x01, 0x00, 0x02, 0x04, 0x00, 0x0A]).unwrap();
+        assert!(pdu.is_small());
+        pdu.push(0x01).unwrap();
+        assert!(!pdu.is_small());
+        pdu.extend(&[0x02]).unwrap();
+        assert_eq!(
+            pdu.as_slice(),
+            &[0x10, 0x00, 0x01, 0x00, 0x02, 0x04, 0x00, 0x0A, 0x01, 0x02]
+        );
+
+        let large = ModbusPdu::from_slice(&[0u8; SMALL_PDU_SIZE + 1]).unwrap();
+        assert!(!large.is_small());
+        assert_eq!(large.len(), SMALL_PDU_SIZE + 1);
+    }
+
     #[test]
     fn test_pdu_from_slice_accepts_max_size() {
         use crate::constants::MAX_PDU_SIZE;
@@ -0,0 +1,887 @@
+//! # Pipelined Modbus TCP Transport
+//!
+//! [`PipelinedTcpTransport`] keeps several requests in flight on a single TCP
+//! connection. Callers share the transport (`&self` via [`send`]) and a
+//! background reader task matches responses to callers by MBAP transaction ID.
+//!
+//! ## Out-of-order responses
+//!
+//! Devices (and especially gateways fanning out to several serial slaves) may
+//! answer pipelined requests out of order. Each response goes to the caller
+//! whose transaction ID it carries as soon as it arrives, so the caller of
+//! request 2 can never observe response 3, and a slow request 2 does not hold
+//! back an answer to request 3.
+//!
+//! Responses that overtake an older outstanding request are also recorded in
+//! a buffer keyed by transaction ID until that request completes. The buffer
+//! is bounded by [`PipelineConfig::max_out_of_order_buffer`]. When more
+//! responses than that have overtaken the oldest outstanding request, it is
+//! presumed lost and failed with [`ModbusError::Timeout`]. Responses whose
+//! transaction ID matches no outstanding request are logged and discarded.
+//!
+//! ## Duplicate transaction IDs
+//!
+//! A second response carrying the transaction ID of a recently answered
+//! request is impossible on a healthy link; it usually means the device
+//! echoed the wrong ID (a known PLC firmware bug). It is logged, counted as
+//! an error and re-matched to the oldest unanswered request it is a valid
+//! answer to (same slave, function code and response shape). That request is
+//! failed with [`ModbusError::Protocol`] (`"duplicate transaction ID X"`)
+//! rather than left to time out; its data cannot be trusted, since its
+//! answer arrived under another request's ID. A duplicate of an ID whose
+//! caller is still waiting cannot be told apart from the genuine answer.
+//! Devices known to echo wrong IDs can set
+//! [`PipelineConfig::allow_duplicate_ids`], which ignores transaction IDs and
+//! matches responses to requests in FIFO order instead.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use voltage_modbus::{ModbusFunction, ModbusRequest, PipelinedTcpTransport};
+//!
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! let transport = Arc::new(
+//!     PipelinedTcpTransport::connect("127.0.0.1:502".parse().unwrap(), Duration::from_secs(1))
+//!         .await?,
+//! );
+//!
+//! let a = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 10);
+//! let b = ModbusRequest::new_read(2, ModbusFunction::ReadHoldingRegisters, 0, 10);
+//! let (ra, rb) = tokio::join!(transport.send(&a), transport.send(&b));
+//! # let _ = (ra?, rb?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`send`]: PipelinedTcpTransport::send
+
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tokio::time::timeout;
+use tracing::{debug, warn};
+
+use crate::client::validate_response_matches_request;
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::{ModbusRequest, ModbusResponse};
+use crate::transport::{ModbusTransport, TcpTransport, TransportStats, MBAP_HEADER_SIZE};
+
+/// Default number of responses allowed to overtake the oldest outstanding request.
+pub const DEFAULT_MAX_OUT_OF_ORDER_BUFFER: usize = 16;
+
+/// Number of recently answered transaction IDs remembered to spot duplicates.
+const ANSWERED_HISTORY: usize = 16;
+
+/// Configuration for [`PipelinedTcpTransport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipelineConfig {
+    /// Maximum responses held while waiting for an older outstanding request.
+    pub max_out_of_order_buffer: usize,
+    /// Ignore response transaction IDs and match responses in FIFO order.
+    pub allow_duplicate_ids: bool,
+}
+
+impl PipelineConfig {
+    /// Create a config with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the out-of-order buffer limit.
+    pub fn with_max_out_of_order_buffer(mut self, limit: usize) -> Self {
+        self.max_out_of_order_buffer = limit;
+        self
+    }
+
+    /// Fall back to FIFO matching for devices that echo wrong transaction IDs.
+    pub fn with_allow_duplicate_ids(mut self, allow: bool) -> Self {
+        self.allow_duplicate_ids = allow;
+        self
+    }
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        Self {
+            max_out_of_order_buffer: DEFAULT_MAX_OUT_OF_ORDER_BUFFER,
+            allow_duplicate_ids: false,
+        }
+    }
+}
+
+/// A caller waiting for a response.
+struct Pending {
+    reply: oneshot::Sender<ModbusResult<ModbusResponse>>,
+    request: ModbusRequest,
+}
+
+/// Bookkeeping shared between callers and the reader task.
+#[derive(Default)]
+struct PipelineState {
+    /// Transaction IDs from the oldest outstanding request on, in send order
+    order: VecDeque<u16>,
+    /// Callers waiting for a response, keyed by transaction ID
+    pending: HashMap<u16, Pending>,
+    /// Responses delivered ahead of an older outstanding request, kept until
+    /// it completes to bound overtaking and to spot duplicate IDs
+    buffered: HashMap<u16, ModbusResponse>,
+    /// Most recently answered transaction IDs, oldest first
+    answered: VecDeque<u16>,
+    /// Set once the connection is gone; new requests fail fast
+    closed: bool,
+    /// Request timeout, reported on requests failed as overtaken
+    timeout_ms: u64,
+    config: PipelineConfig,
+    stats: TransportStats,
+}
+
+impl PipelineState {
+    /// Handle a decoded response frame from the reader task.
+    fn on_response(&mut self, tid: u16, response: ModbusResult<ModbusResponse>) {
+        let tid = if self.config.allow_duplicate_ids {
+            match self.oldest_unanswered(|_| true) {
+                Some(oldest) => oldest,
+                None => tid, // nothing outstanding; reported as unmatched below
+            }
+        } else {
+            tid
+        };
+
+        if self.pending.contains_key(&tid) {
+            match response {
+                Ok(response) => self.deliver(tid, response),
+                Err(e) => {
+                    // Undecodable frame: the caller gets the error right away.
+                    self.stats.errors += 1;
+                    self.fail(tid, e);
+                }
+            }
+        } else if self.buffered.contains_key(&tid) || self.answered.contains(&tid) {
+            match response {
+                Ok(response) => self.on_duplicate(tid, response),
+                Err(_) => self.stats.errors += 1,
+            }
+        } else {
+            warn!(
+                protocol = "tcp-pipeline",
+                transaction_id = tid,
+                "modbus.pipeline.unmatched_response"
+            );
+            self.stats.errors += 1;
+            return;
+        }
+        self.prune_head();
+
+        while self.buffered.len() > self.config.max_out_of_order_buffer {
+            let Some(head) = self.order.pop_front() else {
+                break;
+            };
+            if let Some(pending) = self.pending.remove(&head) {
+                debug!(
+                    protocol = "tcp-pipeline",
+                    transaction_id = head,
+                    buffered = self.buffered.len(),
+                    "modbus.pipeline.overtaken"
+                );
+                self.stats.timeouts += 1;
+                self.stats.errors += 1;
+                let _ = pending.reply.send(Err(ModbusError::timeout(
+                    format!("pipelined response (tid {}) overtaken", head),
+                    self.timeout_ms,
+                )));
+            }
+            self.prune_head();
+        }
+    }
+
+    /// Hand `response` to the caller of `tid`, recording it if it overtook
+    /// an older outstanding request.
+    fn deliver(&mut self, tid: u16, response: ModbusResponse) {
+        let Some(pending) = self.pending.remove(&tid) else {
+            return;
+        };
+        self.stats.responses_received += 1;
+        if self.order.front() != Some(&tid) {
+            self.buffered.insert(tid, response.clone());
+        }
+        if self.answered.len() == ANSWERED_HISTORY {
+            self.answered.pop_front();
+        }
+        self.answered.push_back(tid);
+        let _ = pending.reply.send(Ok(response));
+    }
+
+    /// A second response arrived for `tid`, which was already answered.
+    ///
+    /// The caller of `tid` already has the first frame. The new one is
+    /// re-matched to the oldest unanswered request with the same slave,
+    /// function code and response shape, if any, and that request fails with
+    /// a protocol error instead of waiting for an answer that already came.
+    fn on_duplicate(&mut self, tid: u16, response: ModbusResponse) {
+        warn!(
+            protocol = "tcp-pipeline",
+            transaction_id = tid,
+            "modbus.pipeline.duplicate_transaction_id"
+        );
+        self.stats.errors += 1;
+
+        match self
+            .oldest_unanswered(|p| validate_response_matches_request(&p.request, &response).is_ok())
+        {
+            Some(owner) => {
+                debug!(
+                    protocol = "tcp-pipeline",
+                    transaction_id = tid,
+                    matched = owner,
+                    "modbus.pipeline.duplicate_rematched"
+                );
+                self.fail(
+                    owner,
+                    ModbusError::protocol(format!("duplicate transaction ID {}", tid)),
+                );
+            }
+            None => self.stats.errors += 1,
+        }
+    }
+
+    /// Oldest outstanding request with no buffered response that satisfies `filter`.
+    fn oldest_unanswered(&self, filter: impl Fn(&Pending) -> bool) -> Option<u16> {
+        self.order.iter().copied().find(|tid| {
+            !self.buffered.contains_key(tid) && self.pending.get(tid).is_some_and(&filter)
+        })
+    }
+
+    /// Resolve `tid` with an error immediately, outside of send order.
+    fn fail(&mut self, tid: u16, error: ModbusError) {
+        self.order.retain(|&t| t != tid);
+        if let Some(pending) = self.pending.remove(&tid) {
+            let _ = pending.reply.send(Err(error));
+        }
+    }
+
+    /// Drop answered and abandoned transactions from the front of the send
+    /// order, so it starts at the oldest request still waiting.
+    fn prune_head(&mut self) {
+        while let Some(&head) = self.order.front() {
+            if self.pending.contains_key(&head) {
+                break;
+            }
+            // Answered out of order, or the caller gave up (timeout / cancelled)
+            self.order.pop_front();
+            self.buffered.remove(&head);
+        }
+    }
+
+    /// Forget a transaction whose caller stopped waiting.
+    fn abandon(&mut self, tid: u16) {
+        self.pending.remove(&tid);
+        self.prune_head();
+    }
+
+    /// Fail every outstanding request and refuse new ones.
+    fn shut_down(&mut self, reason: &str) {
+        self.closed = true;
+        self.order.clear();
+        self.buffered.clear();
+        self.answered.clear();
+        for (_, pending) in self.pending.drain() {
+            let _ = pending
+                .reply
+                .send(Err(ModbusError::connection(reason.to_string())));
+        }
+    }
+}
+
+/// Lock the shared state, recovering from a poisoned mutex.
+fn lock(state: &Mutex<PipelineState>) -> MutexGuard<'_, PipelineState> {
+    state.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Abandons a transaction when its [`PipelinedTcpTransport::send`] future is
+/// dropped before the response arrives (e.g. by `tokio::time::timeout` or
+/// `select!`), so the stale entry does not hold up the send order.
+struct PendingGuard<'a> {
+    state: &'a Mutex<PipelineState>,
+    tid: u16,
+    armed: bool,
+}
+
+impl PendingGuard<'_> {
+    /// The caller got its response; nothing is left to clean up.
+    fn disarm(mut self) {
+        self.armed = false;
+    }
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            lock(self.state).abandon(self.tid);
+        }
+    }
+}
+
+/// Modbus TCP transport with multiple requests in flight on one connection.
+pub struct PipelinedTcpTransport {
+    address: SocketAddr,
+    timeout: Duration,
+    writer: tokio::sync::Mutex<Option<OwnedWriteHalf>>,
+    state: Arc<Mutex<PipelineState>>,
+    transaction_id: AtomicU16,
+    reader: JoinHandle<()>,
+}
+
+impl PipelinedTcpTransport {
+    /// Connect with the default [`PipelineConfig`].
+    pub async fn connect(address: SocketAddr, timeout: Duration) -> ModbusResult<Self> {
+        Self::with_config(address, timeout, PipelineConfig::default()).await
+    }
+
+    /// Connect with an explicit [`PipelineConfig`].
+    pub async fn with_config(
+        address: SocketAddr,
+        timeout: Duration,
+        config: PipelineConfig,
+    ) -> ModbusResult<Self> {
+        let stream = TcpStream::connect(address).await.map_err(|e| {
+            ModbusError::connection(format!("Failed to connect to {}: {}", address, e))
+        })?;
+        stream
+            .set_nodelay(true)
+            .map_err(|e| ModbusError::connection(format!("Failed to set TCP_NODELAY: {}", e)))?;
+        let (read_half, write_half) = stream.into_split();
+
+        let state = Arc::new(Mutex::new(PipelineState {
+            timeout_ms: timeout.as_millis() as u64,
+            config,
+            ..PipelineState::default()
+        }));
+        let reader = tokio::spawn(read_loop(read_half, state.clone()));
+
+        Ok(Self {
+            address,
+            timeout,
+            writer: tokio::sync::Mutex::new(Some(write_half)),
+            state,
+            transaction_id: AtomicU16::new(0),
+            reader,
+        })
+    }
+
+    /// Remote address of the connection.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Number of requests currently awaiting a response.
+    pub fn pending_count(&self) -> usize {
+        lock(&self.state).pending.len()
+    }
+
+    /// Next non-zero transaction ID.
+    fn next_transaction_id(&self) -> u16 {
+        loop {
+            let tid = self
+                .transaction_id
+                .fetch_add(1, Ordering::Relaxed)
+                .wrapping_add(1);
+            if tid != 0 {
+                return tid;
+            }
+        }
+    }
+
+    /// Send `request` and wait for its response.
+    ///
+    /// Safe to call concurrently from many tasks; each call gets exactly the
+    /// response carrying its own transaction ID.
+    pub async fn send(&self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        request.validate()?;
+
+        let tid = self.next_transaction_id();
+        let frame = TcpTransport::encode_request_with_tid(request, tid)?;
+        let broadcast = request.slave_id == 0;
+
+        let rx = {
+            let mut state = lock(&self.state);
+            if state.closed {
+                return Err(ModbusError::connection("pipelined connection is closed"));
+            }
+            state.stats.requests_sent += 1;
+            state.stats.bytes_sent += frame.len() as u64;
+            if broadcast {
+                None
+            } else {
+                let (tx, rx) = oneshot::channel();
+                let pending = Pending {
+                    reply: tx,
+                    request: request.clone(),
+                };
+                state.pending.insert(tid, pending);
+                state.order.push_back(tid);
+                Some(rx)
+            }
+        };
+        let guard = rx.as_ref().map(|_| PendingGuard {
+            state: &self.state,
+            tid,
+            armed: true,
+        });
+
+        if let Err(e) = self.write_frame(&frame).await {
+            lock(&self.state).stats.errors += 1;
+            return Err(e);
+        }
+
+        // Broadcast (slave_id = 0): no response is expected.
+        let Some(rx) = rx else {
+            lock(&self.state).stats.responses_received += 1;
+            return Ok(ModbusResponse::new_broadcast_ack(request.function));
+        };
+
+        match timeout(self.timeout, rx).await {
+            Ok(Ok(result)) => {
+                if let Some(guard) = guard {
+                    guard.disarm();
+                }
+                result
+            }
+            Ok(Err(_)) => Err(ModbusError::connection("pipeline reader stopped")),
+            Err(_) => {
+                let mut state = lock(&self.state);
+                state.stats.timeouts += 1;
+                state.stats.errors += 1;
+                Err(ModbusError::timeout(
+                    "pipelined response",
+                    self.timeout.as_millis() as u64,
+                ))
+            }
+        }
+    }
+
+    async fn write_frame(&self, frame: &[u8]) -> ModbusResult<()> {
+        let mut writer = self.writer.lock().await;
+        let stream = writer
+            .as_mut()
+            .ok_or_else(|| ModbusError::connection("pipelined connection is closed"))?;
+        match timeout(self.timeout, stream.write_all(frame)).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(ModbusError::io(format!("pipeline send failed: {}", e))),
+            Err(_) => Err(ModbusError::timeout(
+                "pipeline send",
+                self.timeout.as_millis() as u64,
+            )),
+        }
+    }
+}
+
+/// Reader task: split the byte stream into MBAP frames and dispatch them.
+async fn read_loop(mut stream: OwnedReadHalf, state: Arc<Mutex<PipelineState>>) {
+    let reason = loop {
+        let mut header = [0u8; MBAP_HEADER_SIZE];
+        if let Err(e) = stream.read_exact(&mut header).await {
+            break format!("pipelined connection lost: {}", e);
+        }
+
+        let length = u16::from_be_bytes([header[4], header[5]]);
+        if !(2..=254).contains(&length) {
+            break format!("pipeline: invalid MBAP length {}", length);
+        }
+
+        let mut frame = vec![0u8; MBAP_HEADER_SIZE + length as usize];
+        frame[..MBAP_HEADER_SIZE].copy_from_slice(&header);
+        if let Err(e) = stream.read_exact(&mut frame[MBAP_HEADER_SIZE..]).await {
+            break format!("pipelined connection lost: {}", e);
+        }
+
+        let tid = u16::from_be_bytes([header[0], header[1]]);
+        let frame_len = frame.len() as u64;
+        let response = TcpTransport::decode_response(frame);
+
+        let mut state = lock(&state);
+        state.stats.bytes_received += frame_len;
+        state.on_response(tid, response);
+    };
+
+    debug!(protocol = "tcp-pipeline", reason = %reason, "modbus.pipeline.reader_stopped");
+    lock(&state).shut_down(&reason);
+}
+
+impl Drop for PipelinedTcpTransport {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
+impl ModbusTransport for PipelinedTcpTransport {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        self.send(request).await
+    }
+
+    fn is_connected(&self) -> bool {
+        !lock(&self.state).closed
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.reader.abort();
+        if let Some(mut writer) = self.writer.lock().await.take() {
+            let _ = writer.shutdown().await;
+        }
+        lock(&self.state).shut_down("pipelined connection closed");
+        Ok(())
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        lock(&self.state).stats.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::ModbusFunction;
+    use std::time::Instant;
+    use tokio::net::TcpListener;
+
+    /// Read one MBAP request frame; returns (tid, unit, address).
+    async fn read_request(stream: &mut TcpStream) -> (u16, u8, u16) {
+        let mut header = [0u8; MBAP_HEADER_SIZE];
+        stream.read_exact(&mut header).await.unwrap();
+        let len = u16::from_be_bytes([header[4], header[5]]) as usize;
+        let mut pdu = vec![0u8; len];
+        stream.read_exact(&mut pdu).await.unwrap();
+        let tid = u16::from_be_bytes([header[0], header[1]]);
+        (tid, pdu[0], u16::from_be_bytes([pdu[2], pdu[3]]))
+    }
+
+    /// FC03 response carrying a single register `value`.
+    fn fc03_frame(tid: u16, unit: u8, value: u16) -> Vec<u8> {
+        let mut frame = Vec::new();
+        frame.extend_from_slice(&tid.to_be_bytes());
+        frame.extend_from_slice(&[0, 0, 0, 5, unit, 0x03, 0x02]);
+        frame.extend_from_slice(&value.to_be_bytes());
+        frame
+    }
+
+    /// Server that reads `count` requests, then answers them in `reply_order`
+    /// (indices into the received list). Each reply echoes the request address
+    /// as the register value so callers can verify they got their own answer.
+    async fn reordering_server(count: usize, reply_order: Vec<usize>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut received = Vec::new();
+            for _ in 0..count {
+                received.push(read_request(&mut stream).await);
+            }
+            for idx in reply_order {
+                let (tid, unit, address) = received[idx];
+                stream
+                    .write_all(&fc03_frame(tid, unit, address))
+                    .await
+                    .unwrap();
+            }
+            // Keep the connection open until the client is done.
+            let mut buf = [0u8; 1];
+            let _ = stream.read(&mut buf).await;
+        });
+        addr
+    }
+
+    fn read(address: u16) -> ModbusRequest {
+        ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, address, 1)
+    }
+
+    async fn send_staggered(
+        transport: &PipelinedTcpTransport,
+        request: ModbusRequest,
+        delay_ms: u64,
+    ) -> ModbusResult<ModbusResponse> {
+        // Stagger so requests hit the wire in a known order.
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        transport.send(&request).await
+    }
+
+    #[tokio::test]
+    async fn out_of_order_responses_reach_the_right_caller() {
+        let addr = reordering_server(3, vec![2, 0, 1]).await;
+        let transport = PipelinedTcpTransport::connect(addr, Duration::from_secs(2))
+            .await
+            .unwrap();
+
+        let (a, b, c) = tokio::join!(
+            send_staggered(&transport, read(100), 0),
+            send_staggered(&transport, read(200), 10),
+            send_staggered(&transport, read(300), 20),
+        );
+        assert_eq!(a.unwrap().parse_registers().unwrap(), vec![100]);
+        assert_eq!(b.unwrap().parse_registers().unwrap(), vec![200]);
+        assert_eq!(c.unwrap().parse_registers().unwrap(), vec![300]);
+
+        let stats = transport.get_stats();
+        assert_eq!(stats.requests_sent, 3);
+        assert_eq!(stats.responses_received, 3);
+        assert_eq!(transport.pending_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn slow_request_does_not_hold_back_later_responses() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let slow = read_request(&mut stream).await;
+            let (tid, unit, address) = read_request(&mut stream).await;
+            stream
+                .write_all(&fc03_frame(tid, unit, address))
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(400)).await;
+            stream
+                .write_all(&fc03_frame(slow.0, slow.1, slow.2))
+                .await
+                .unwrap();
+            let mut buf = [0u8; 1];
+            let _ = stream.read(&mut buf).await;
+        });
+        let transport = PipelinedTcpTransport::connect(addr, Duration::from_secs(2))
+            .await
+            .unwrap();
+
+        let start = Instant::now();
+        let timed = |request, delay_ms| {
+            let transport = &transport;
+            async move {
+                let result = send_staggered(transport, request, delay_ms).await;
+                (result, start.elapsed())
+            }
+        };
+        let ((a, a_done), (b, b_done)) = tokio::join!(timed(read(100), 0), timed(read(200), 10));
+        assert_eq!(a.unwrap().parse_registers().unwrap(), vec![100]);
+        assert_eq!(b.unwrap().parse_registers().unwrap(), vec![200]);
+        assert!(b_done < Duration::from_millis(300), "{:?}", b_done);
+        assert!(a_done >= Duration::from_millis(400), "{:?}", a_done);
+        assert_eq!(transport.pending_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn buffer_overflow_times_out_oldest_pending() {
+        // Request #0 is never answered; #1 and #2 overtake it.
+        let addr = reordering_server(3, vec![1, 2]).await;
+        let config = PipelineConfig::new().with_max_out_of_order_buffer(1);
+        let transport = PipelinedTcpTransport::with_config(addr, Duration::from_secs(5), config)
+            .await
+            .unwrap();
+
+        let start = Instant::now();
+        let (a, b, c) = tokio::join!(
+            send_staggered(&transport, read(100), 0),
+            send_staggered(&transport, read(200), 10),
+            send_staggered(&transport, read(300), 20),
+        );
+        assert!(start.elapsed() < Duration::from_secs(2));
+
+        assert!(matches!(a, Err(ModbusError::Timeout { .. })));
+        assert_eq!(b.unwrap().parse_registers().unwrap(), vec![200]);
+        assert_eq!(c.unwrap().parse_registers().unwrap(), vec![300]);
+        assert_eq!(transport.get_stats().timeouts, 1);
+    }
+
+    #[tokio::test]
+    async fn unmatched_transaction_id_is_discarded() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let (tid, unit, address) = read_request(&mut stream).await;
+            let mut out = fc03_frame(tid.wrapping_add(0x4000), unit, 0xDEAD);
+            out.extend_from_slice(&fc03_frame(tid, unit, address));
+            stream.write_all(&out).await.unwrap();
+            let mut buf = [0u8; 1];
+            let _ = stream.read(&mut buf).await;
+        });
+
+        let transport = PipelinedTcpTransport::connect(addr, Duration::from_secs(2))
+            .await
+            .unwrap();
+        let response = transport.send(&read(42)).await.unwrap();
+        assert_eq!(response.parse_registers().unwrap(), vec![42]);
+        assert_eq!(transport.get_stats().errors, 1);
+    }
+
+    /// Server that answers `replies` as `(request index, tid override)` pairs:
+    /// the response echoes the indexed request's address, but is stamped with
+    /// the override TID when given (simulating a device echoing a wrong ID).
+    async fn mislabeling_server(count: usize, replies: Vec<(usize, Option<usize>)>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut received = Vec::new();
+            for _ in 0..count {
+                received.push(read_request(&mut stream).await);
+            }
+            for (idx, tid_from) in replies {
+                let (_, unit, address) = received[idx];
+                let tid = received[tid_from.unwrap_or(idx)].0;
+                stream
+                    .write_all(&fc03_frame(tid, unit, address))
+                    .await
+                    .unwrap();
+            }
+            let mut buf = [0u8; 1];
+            let _ = stream.read(&mut buf).await;
+        });
+        addr
+    }
+
+    #[tokio::test]
+    async fn duplicate_transaction_id_is_reported_and_rematched() {
+        // #1 answered correctly, then #2's answer arrives stamped with #1's TID
+        // while #0 (on another slave, so it cannot claim #2's frame) is still
+        // outstanding, then #0 is answered.
+        let addr = mislabeling_server(3, vec![(1, None), (2, Some(1)), (0, None)]).await;
+        let transport = PipelinedTcpTransport::connect(addr, Duration::from_secs(2))
+            .await
+            .unwrap();
+        let other_slave = ModbusRequest::new_read(2, ModbusFunction::ReadHoldingRegisters, 100, 1);
+
+        let (a, b, c) = tokio::join!(
+            send_staggered(&transport, other_slave, 0),
+            send_staggered(&transport, read(200), 10),
+            send_staggered(&transport, read(300), 20),
+        );
+        assert_eq!(a.unwrap().parse_registers().unwrap(), vec![100]);
+        // #1 already had its answer when the duplicate arrived.
+        assert_eq!(b.unwrap().parse_registers().unwrap(), vec![200]);
+        // The request the mislabelled frame actually answers is told so.
+        assert_duplicate_error(c);
+        assert_eq!(transport.get_stats().errors, 1);
+        assert_eq!(transport.pending_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn duplicate_of_in_order_answer_is_reported() {
+        // #0 is answered in order, then #1's answer arrives stamped with #0's TID.
+        let addr = mislabeling_server(2, vec![(0, None), (1, Some(0))]).await;
+        let transport = PipelinedTcpTransport::connect(addr, Duration::from_secs(2))
+            .await
+            .unwrap();
+
+        let start = Instant::now();
+        let (a, b) = tokio::join!(
+            send_staggered(&transport, read(100), 0),
+            send_staggered(&transport, read(200), 10),
+        );
+        assert_eq!(a.unwrap().parse_registers().unwrap(), vec![100]);
+        assert_duplicate_error(b);
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(transport.get_stats().errors, 1);
+        assert_eq!(transport.pending_count(), 0);
+    }
+
+    fn assert_duplicate_error(result: ModbusResult<ModbusResponse>) {
+        match result {
+            Err(ModbusError::Protocol { message }) => {
+                assert!(message.contains("duplicate transaction ID"), "{}", message)
+            }
+            other => panic!("expected a duplicate ID error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn allow_duplicate_ids_falls_back_to_fifo() {
+        // Every answer carries #0's TID; FIFO matching still routes them correctly.
+        let addr = mislabeling_server(3, vec![(0, None), (1, Some(0)), (2, Some(0))]).await;
+        let config = PipelineConfig::new().with_allow_duplicate_ids(true);
+        let transport = PipelinedTcpTransport::with_config(addr, Duration::from_secs(2), config)
+            .await
+            .unwrap();
+
+        let (a, b, c) = tokio::join!(
+            send_staggered(&transport, read(100), 0),
+            send_staggered(&transport, read(200), 10),
+            send_staggered(&transport, read(300), 20),
+        );
+        assert_eq!(a.unwrap().parse_registers().unwrap(), vec![100]);
+        assert_eq!(b.unwrap().parse_registers().unwrap(), vec![200]);
+        assert_eq!(c.unwrap().parse_registers().unwrap(), vec![300]);
+        assert_eq!(transport.get_stats().errors, 0);
+    }
+
+    #[tokio::test]
+    async fn cancelled_send_does_not_block_later_requests() {
+        // Answers every request except reads of address 0.
+        async fn selective_server() -> SocketAddr {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                loop {
+                    let (tid, unit, address) = read_request(&mut stream).await;
+                    if address != 0 {
+                        stream
+                            .write_all(&fc03_frame(tid, unit, address))
+                            .await
+                            .unwrap();
+                    }
+                }
+            });
+            addr
+        }
+
+        for config in [
+            PipelineConfig::new().with_max_out_of_order_buffer(1),
+            PipelineConfig::new().with_allow_duplicate_ids(true),
+        ] {
+            let addr = selective_server().await;
+            let transport =
+                PipelinedTcpTransport::with_config(addr, Duration::from_secs(2), config)
+                    .await
+                    .unwrap();
+
+            let cancelled =
+                tokio::time::timeout(Duration::from_millis(50), transport.send(&read(0))).await;
+            assert!(cancelled.is_err());
+            assert_eq!(transport.pending_count(), 0);
+
+            for address in [10, 20, 30] {
+                let response = transport.send(&read(address)).await.unwrap();
+                assert_eq!(response.parse_registers().unwrap(), vec![address]);
+            }
+            assert_eq!(transport.get_stats().timeouts, 0, "{:?}", config);
+        }
+    }
+
+    #[tokio::test]
+    async fn connection_loss_fails_pending_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let _ = read_request(&mut stream).await;
+            // Drop without answering.
+        });
+
+        let mut transport = PipelinedTcpTransport::connect(addr, Duration::from_secs(2))
+            .await
+            .unwrap();
+        assert!(matches!(
+            transport.send(&read(1)).await,
+            Err(ModbusError::Connection { .. })
+        ));
+        assert!(!transport.is_connected());
+        assert!(transport.request(&read(1)).await.is_err());
+    }
+}
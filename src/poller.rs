@@ -0,0 +1,359 @@
+//! # Continuous Polling Streams
+//!
+//! [`RegisterPoller`] and [`CoilPoller`] turn the usual
+//! `loop { sleep; read; process }` into a [`Stream`]: every `interval` tick
+//! they read the configured range and yield the result. Read errors are
+//! yielded as items, so one failed poll does not end the stream; call
+//! [`stop`](RegisterPoller::stop) (or drop the poller) to finish it.
+//!
+//! Ticks come from [`tokio::time::interval`] wrapped in an
+//! [`IntervalStream`]. The first reading is taken immediately; if a read takes
+//! longer than the interval, the next tick is delayed rather than fired in a
+//! burst. With [`DeviceLimits`], ticks are at least
+//! `inter_request_delay_ms` apart.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use tokio_stream::StreamExt;
+//! use voltage_modbus::poller::RegisterPoller;
+//! use voltage_modbus::{DeviceLimits, ModbusTcpClient};
+//!
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! let client = ModbusTcpClient::from_address("127.0.0.1:502", Duration::from_secs(5)).await?;
+//! let mut poller = RegisterPoller::new(client, 1, 0, 500, Duration::from_millis(500))
+//!     .with_limits(DeviceLimits::new());
+//!
+//! while let Some(reading) = poller.next().await {
+//!     match reading {
+//!         Ok(registers) => println!("{} registers", registers.len()),
+//!         Err(e) => eprintln!("poll failed: {}", e),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use tokio::time::MissedTickBehavior;
+use tokio_stream::wrappers::IntervalStream;
+
+use crate::client::ModbusClient;
+use crate::device_limits::DeviceLimits;
+use crate::error::ModbusResult;
+use crate::protocol::SlaveId;
+
+/// A read in flight; owns the client until it completes
+type ReadFuture<C, T> = Pin<Box<dyn Future<Output = (C, ModbusResult<Vec<T>>)> + Send>>;
+
+/// What a poller reads on every tick
+#[derive(Debug, Clone)]
+struct Target {
+    slave_id: SlaveId,
+    address: u16,
+    quantity: u16,
+    limits: Option<DeviceLimits>,
+}
+
+enum State<C, T> {
+    Idle(C),
+    Reading(ReadFuture<C, T>),
+    Stopped,
+}
+
+/// Tick-driven read loop shared by the register and coil pollers
+struct Poller<C, T> {
+    ticks: IntervalStream,
+    period: Duration,
+    target: Target,
+    state: State<C, T>,
+    read: fn(C, Target) -> ReadFuture<C, T>,
+}
+
+impl<C: ModbusClient + Unpin, T> Poller<C, T> {
+    fn new(
+        client: C,
+        target: Target,
+        interval: Duration,
+        read: fn(C, Target) -> ReadFuture<C, T>,
+    ) -> Self {
+        Self {
+            ticks: ticks(interval),
+            period: interval,
+            target,
+            state: State::Idle(client),
+            read,
+        }
+    }
+
+    /// Read in `limits`-sized chunks, never ticking faster than the
+    /// inter-request delay
+    fn set_limits(&mut self, limits: DeviceLimits) {
+        let min_period = Duration::from_millis(limits.inter_request_delay_ms);
+        if min_period > self.period {
+            self.period = min_period;
+            self.ticks = ticks(min_period);
+        }
+        self.target.limits = Some(limits);
+    }
+
+    fn stop(&mut self) {
+        self.state = State::Stopped;
+    }
+
+    fn is_stopped(&self) -> bool {
+        matches!(self.state, State::Stopped)
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<ModbusResult<Vec<T>>>> {
+        loop {
+            match std::mem::replace(&mut self.state, State::Stopped) {
+                State::Stopped => return Poll::Ready(None),
+                State::Idle(client) => match Pin::new(&mut self.ticks).poll_next(cx) {
+                    Poll::Ready(Some(_)) => {
+                        self.state = State::Reading((self.read)(client, self.target.clone()));
+                    }
+                    Poll::Ready(None) => return Poll::Ready(None),
+                    Poll::Pending => {
+                        self.state = State::Idle(client);
+                        return Poll::Pending;
+                    }
+                },
+                State::Reading(mut read) => match read.as_mut().poll(cx) {
+                    Poll::Ready((client, result)) => {
+                        self.state = State::Idle(client);
+                        return Poll::Ready(Some(result));
+                    }
+                    Poll::Pending => {
+                        self.state = State::Reading(read);
+                        return Poll::Pending;
+                    }
+                },
+            }
+        }
+    }
+}
+
+fn ticks(period: Duration) -> IntervalStream {
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    IntervalStream::new(interval)
+}
+
+/// Stream of holding-register readings (FC03), one per interval tick
+pub struct RegisterPoller<C> {
+    inner: Poller<C, u16>,
+}
+
+impl<C: ModbusClient + Unpin + Send + 'static> RegisterPoller<C> {
+    /// Poll `quantity` holding registers from `address` every `interval`
+    ///
+    /// Without [`with_limits`](Self::with_limits) each tick issues a single
+    /// `read_03`, so `quantity` must fit one request.
+    pub fn new(
+        client: C,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        interval: Duration,
+    ) -> Self {
+        let target = Target {
+            slave_id,
+            address,
+            quantity,
+            limits: None,
+        };
+        Self {
+            inner: Poller::new(client, target, interval, read_registers),
+        }
+    }
+
+    /// Read through `read_03_batch` with `limits`, allowing ranges larger
+    /// than one request
+    pub fn with_limits(mut self, limits: DeviceLimits) -> Self {
+        self.inner.set_limits(limits);
+        self
+    }
+
+    /// End the stream; an in-flight read is abandoned and the client dropped
+    pub fn stop(&mut self) {
+        self.inner.stop();
+    }
+
+    /// Whether [`stop`](Self::stop) has been called
+    pub fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+}
+
+impl<C: ModbusClient + Unpin> Stream for RegisterPoller<C> {
+    type Item = ModbusResult<Vec<u16>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().inner.poll_next(cx)
+    }
+}
+
+/// Stream of coil readings (FC01), one per interval tick
+pub struct CoilPoller<C> {
+    inner: Poller<C, bool>,
+}
+
+impl<C: ModbusClient + Unpin + Send + 'static> CoilPoller<C> {
+    /// Poll `quantity` coils from `address` every `interval`
+    ///
+    /// Without [`with_limits`](Self::with_limits) each tick issues a single
+    /// `read_01`, so `quantity` must fit one request.
+    pub fn new(
+        client: C,
+        slave_id: SlaveId,
+        address: u16,
+        quantity: u16,
+        interval: Duration,
+    ) -> Self {
+        let target = Target {
+            slave_id,
+            address,
+            quantity,
+            limits: None,
+        };
+        Self {
+            inner: Poller::new(client, target, interval, read_coils),
+        }
+    }
+
+    /// Read through `read_01_batch` with `limits`, allowing ranges larger
+    /// than one request
+    pub fn with_limits(mut self, limits: DeviceLimits) -> Self {
+        self.inner.set_limits(limits);
+        self
+    }
+
+    /// End the stream; an in-flight read is abandoned and the client dropped
+    pub fn stop(&mut self) {
+        self.inner.stop();
+    }
+
+    /// Whether [`stop`](Self::stop) has been called
+    pub fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+}
+
+impl<C: ModbusClient + Unpin> Stream for CoilPoller<C> {
+    type Item = ModbusResult<Vec<bool>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().inner.poll_next(cx)
+    }
+}
+
+fn read_registers<C: ModbusClient + Send + 'static>(
+    mut client: C,
+    target: Target,
+) -> ReadFuture<C, u16> {
+    Box::pin(async move {
+        let result = match &target.limits {
+            Some(limits) => {
+                client
+                    .read_03_batch(target.slave_id, target.address, target.quantity, limits)
+                    .await
+            }
+            None => {
+                client
+                    .read_03(target.slave_id, target.address, target.quantity)
+                    .await
+            }
+        };
+        (client, result)
+    })
+}
+
+fn read_coils<C: ModbusClient + Send + 'static>(
+    mut client: C,
+    target: Target,
+) -> ReadFuture<C, bool> {
+    Box::pin(async move {
+        let result = match &target.limits {
+            Some(limits) => {
+                client
+                    .read_01_batch(target.slave_id, target.address, target.quantity, limits)
+                    .await
+            }
+            None => {
+                client
+                    .read_01(target.slave_id, target.address, target.quantity)
+                    .await
+            }
+        };
+        (client, result)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GenericModbusClient;
+    use crate::error::ModbusError;
+    use crate::protocol::{ModbusFunction, ModbusResponse};
+    use crate::testing::{EchoTransport, ScriptedTransport};
+    use tokio_stream::StreamExt;
+
+    #[tokio::test(start_paused = true)]
+    async fn yields_one_reading_per_tick() {
+        let transport = ScriptedTransport::new(vec![
+            Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::ReadHoldingRegisters,
+                vec![0x02, 0x00, 0x2A],
+            )),
+            Err(ModbusError::timeout("read", 100)),
+        ]);
+        let client = GenericModbusClient::new(transport);
+        let mut poller = RegisterPoller::new(client, 1, 10, 1, Duration::from_millis(100));
+
+        let start = tokio::time::Instant::now();
+        assert_eq!(poller.next().await.unwrap().unwrap(), vec![42]);
+        assert!(matches!(
+            poller.next().await.unwrap(),
+            Err(ModbusError::Timeout { .. })
+        ));
+        assert_eq!(start.elapsed(), Duration::from_millis(100));
+
+        poller.stop();
+        assert!(poller.is_stopped());
+        assert!(poller.next().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn limits_route_through_batch_reads() {
+        let client = GenericModbusClient::new(EchoTransport::new());
+        let mut poller = RegisterPoller::new(client, 1, 0, 300, Duration::from_secs(1))
+            .with_limits(DeviceLimits::new());
+        assert_eq!(poller.next().await.unwrap().unwrap().len(), 300);
+
+        let client = GenericModbusClient::new(EchoTransport::new());
+        let mut coils = CoilPoller::new(client, 1, 0, 2500, Duration::from_secs(1))
+            .with_limits(DeviceLimits::new());
+        assert_eq!(coils.next().await.unwrap().unwrap().len(), 2500);
+        assert_eq!(coils.next().await.unwrap().unwrap().len(), 2500);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn client_poll_stream_keeps_inter_request_delay() {
+        let limits = DeviceLimits::new().with_inter_request_delay_ms(250);
+        let mut readings = GenericModbusClient::new(EchoTransport::new())
+            .poll_stream(1, 0, 3, Duration::from_millis(100))
+            .with_limits(limits);
+
+        let start = tokio::time::Instant::now();
+        for _ in 0..3 {
+            assert_eq!(readings.next().await.unwrap().unwrap(), [0, 1, 2]);
+        }
+        assert_eq!(start.elapsed(), Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,340 @@
+//! # TCP Connection Pool
+//!
+//! A [`TcpTransport`] serves one request at a time, so polling many slaves
+//! behind one gateway serialises on its socket. [`TcpConnectionPool`] keeps up
+//! to [`TcpPoolConfig::max_connections`] transports to the same server and
+//! lends them out: [`acquire`](TcpConnectionPool::acquire) returns a
+//! [`PooledConnection`], which is itself a [`ModbusTransport`] and goes back
+//! to the pool when dropped.
+//!
+//! Connections are opened lazily, on the first `acquire` that finds no idle
+//! one. A connection that is no longer connected when it is dropped is
+//! discarded instead of returned; its counters stay in the pool totals.
+//!
+//! When every connection is lent out, `acquire` waits for one to come back,
+//! or fails with [`ModbusError::ResourceExhausted`] if
+//! [`TcpPoolConfig::block_on_full`] is `false`.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use voltage_modbus::pool::{TcpConnectionPool, TcpPoolConfig};
+//! use voltage_modbus::GenericModbusClient;
+//! use voltage_modbus::ModbusClient;
+//!
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! let pool = TcpConnectionPool::new(
+//!     "192.168.1.10:502".parse().unwrap(),
+//!     Duration::from_secs(1),
+//!     TcpPoolConfig::new().with_max_connections(4),
+//! );
+//!
+//! let mut reads = Vec::new();
+//! for slave_id in 1..=8 {
+//!     let pool = pool.clone();
+//!     reads.push(tokio::spawn(async move {
+//!         let mut client = GenericModbusClient::new(pool.acquire().await?);
+//!         client.read_03(slave_id, 0, 10).await
+//!     }));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::{ModbusRequest, ModbusResponse};
+use crate::transport::{AtomicTransportStats, ModbusTransport, TcpTransport, TransportStats};
+
+/// Default number of connections a pool may open.
+pub const DEFAULT_MAX_CONNECTIONS: usize = 4;
+
+/// Configuration for [`TcpConnectionPool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TcpPoolConfig {
+    /// Maximum connections open (idle or lent out) at once.
+    pub max_connections: usize,
+    /// Wait for a free connection when all are in use, instead of failing.
+    pub block_on_full: bool,
+}
+
+impl TcpPoolConfig {
+    /// Create a config with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the connection limit.
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = max_connections;
+        self
+    }
+
+    /// Choose between waiting and failing when the pool is full.
+    pub fn with_block_on_full(mut self, block_on_full: bool) -> Self {
+        self.block_on_full = block_on_full;
+        self
+    }
+}
+
+impl Default for TcpPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            block_on_full: true,
+        }
+    }
+}
+
+/// Connections and counters shared between the pool and its loans.
+#[derive(Default)]
+struct PoolState {
+    idle: Vec<TcpTransport>,
+    /// Counters of every connection still owned by the pool.
+    live: Vec<Arc<AtomicTransportStats>>,
+    /// Totals of connections discarded after losing their link.
+    retired: TransportStats,
+}
+
+struct Shared {
+    address: SocketAddr,
+    timeout: Duration,
+    config: TcpPoolConfig,
+    permits: Arc<Semaphore>,
+    state: Mutex<PoolState>,
+}
+
+impl Shared {
+    /// Lock the pool state, recovering from a poisoned mutex.
+    fn lock(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Bounded pool of TCP connections to one Modbus server.
+///
+/// Cloning is cheap and yields a handle to the same pool.
+#[derive(Clone)]
+pub struct TcpConnectionPool {
+    shared: Arc<Shared>,
+}
+
+impl TcpConnectionPool {
+    /// Create a pool for `address`; no connection is opened until the first
+    /// [`acquire`](Self::acquire).
+    pub fn new(address: SocketAddr, timeout: Duration, config: TcpPoolConfig) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                address,
+                timeout,
+                config,
+                permits: Arc::new(Semaphore::new(config.max_connections)),
+                state: Mutex::new(PoolState::default()),
+            }),
+        }
+    }
+
+    /// Borrow a connection, reusing an idle one or opening a new one.
+    ///
+    /// # Errors
+    ///
+    /// - `ModbusError::ResourceExhausted` - all connections are in use and
+    ///   `block_on_full` is `false`
+    /// - `ModbusError::Connection` / `ModbusError::Timeout` - a new
+    ///   connection could not be opened
+    pub async fn acquire(&self) -> ModbusResult<PooledConnection> {
+        let permits = Arc::clone(&self.shared.permits);
+        let permit = if self.shared.config.block_on_full {
+            permits
+                .acquire_owned()
+                .await
+                .map_err(|_| ModbusError::internal("connection pool semaphore closed"))?
+        } else {
+            permits.try_acquire_owned().map_err(|_| {
+                ModbusError::resource_exhausted(format!(
+                    "all {} pooled connections to {} are in use",
+                    self.shared.config.max_connections, self.shared.address
+                ))
+            })?
+        };
+
+        let idle = self.shared.lock().idle.pop();
+        let transport = match idle {
+            Some(transport) => transport,
+            None => {
+                let transport = TcpTransport::new(self.shared.address, self.shared.timeout).await?;
+                self.shared.lock().live.push(transport.stats_handle());
+                transport
+            }
+        };
+
+        Ok(PooledConnection {
+            transport: Some(transport),
+            shared: Arc::clone(&self.shared),
+            _permit: permit,
+        })
+    }
+
+    /// Number of open connections waiting in the pool.
+    pub fn idle_connections(&self) -> usize {
+        self.shared.lock().idle.len()
+    }
+
+    /// Number of connections that could be lent out right now.
+    pub fn available(&self) -> usize {
+        self.shared.permits.available_permits()
+    }
+
+    /// Pool configuration.
+    pub fn config(&self) -> &TcpPoolConfig {
+        &self.shared.config
+    }
+
+    /// Counters summed over every connection the pool has opened.
+    pub fn get_stats(&self) -> TransportStats {
+        let state = self.shared.lock();
+        let mut total = state.retired.clone();
+        for stats in &state.live {
+            total.merge(&stats.snapshot());
+        }
+        total
+    }
+}
+
+/// A connection lent out by [`TcpConnectionPool::acquire`].
+///
+/// Returned to the pool on drop if it is still connected.
+pub struct PooledConnection {
+    transport: Option<TcpTransport>,
+    shared: Arc<Shared>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl PooledConnection {
+    fn transport(&mut self) -> &mut TcpTransport {
+        self.transport
+            .as_mut()
+            .expect("pooled transport is present until drop")
+    }
+}
+
+impl ModbusTransport for PooledConnection {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        self.transport().request(request).await
+    }
+
+    fn is_connected(&self) -> bool {
+        self.transport
+            .as_ref()
+            .is_some_and(|transport| transport.is_connected())
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.transport().close().await
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.transport
+            .as_ref()
+            .map(|transport| transport.get_stats())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        let Some(transport) = self.transport.take() else {
+            return;
+        };
+        let mut state = self.shared.lock();
+        if transport.is_connected() {
+            state.idle.push(transport);
+            return;
+        }
+        let handle = transport.stats_handle();
+        state.live.retain(|stats| !Arc::ptr_eq(stats, &handle));
+        state.retired.merge(&handle.snapshot());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{GenericModbusClient, ModbusClient};
+    use crate::server::{DataStore, ModbusServer, ModbusTcpServer, ModbusTcpServerConfig};
+    use std::sync::RwLock;
+
+    /// Server on an ephemeral port; its address is `server.local_addr()`.
+    async fn start_server() -> ModbusTcpServer<RwLock<DataStore>> {
+        let store = Arc::new(RwLock::new(DataStore::new()));
+        store.write().unwrap().set_registers(0, &[7, 8, 9]).unwrap();
+        let config = ModbusTcpServerConfig {
+            bind_address: "127.0.0.1:0".parse().unwrap(),
+            ..Default::default()
+        };
+        let mut server = ModbusTcpServer::with_handler(config, store);
+        server.start().await.unwrap();
+        server
+    }
+
+    #[tokio::test]
+    async fn test_pool_reuses_connections_and_sums_stats() {
+        let mut server = start_server().await;
+        let pool = TcpConnectionPool::new(
+            server.local_addr().unwrap(),
+            Duration::from_secs(1),
+            TcpPoolConfig::new().with_max_connections(2),
+        );
+
+        let (a, b) = tokio::join!(pool.acquire(), pool.acquire());
+        let (mut a, mut b) = (
+            GenericModbusClient::new(a.unwrap()),
+            GenericModbusClient::new(b.unwrap()),
+        );
+        let (ra, rb) = tokio::join!(a.read_03(1, 0, 3), b.read_03(1, 1, 1));
+        assert_eq!(ra.unwrap(), [7, 8, 9]);
+        assert_eq!(rb.unwrap(), [8]);
+        assert_eq!(pool.available(), 0);
+        drop((a, b));
+        assert_eq!(pool.idle_connections(), 2);
+
+        let mut client = GenericModbusClient::new(pool.acquire().await.unwrap());
+        client.read_03(1, 2, 1).await.unwrap();
+        drop(client);
+
+        let stats = pool.get_stats();
+        assert_eq!(stats.requests_sent, 3);
+        assert_eq!(stats.responses_received, 3);
+        assert_eq!(pool.idle_connections(), 2);
+
+        server.stop().await.unwrap();
+        assert_eq!(server.get_stats().connections_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_full_pool_fails_without_blocking() {
+        let mut server = start_server().await;
+        let config = TcpPoolConfig::new()
+            .with_max_connections(1)
+            .with_block_on_full(false);
+        let address = server.local_addr().unwrap();
+        let pool = TcpConnectionPool::new(address, Duration::from_secs(1), config);
+
+        let mut held = pool.acquire().await.unwrap();
+        assert!(matches!(
+            pool.acquire().await,
+            Err(ModbusError::ResourceExhausted { .. })
+        ));
+
+        held.close().await.unwrap();
+        drop(held);
+        assert_eq!(pool.idle_connections(), 0);
+        assert!(pool.acquire().await.unwrap().is_connected());
+
+        server.stop().await.unwrap();
+    }
+}
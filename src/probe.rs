@@ -0,0 +1,463 @@
+//! # Register Map Probing
+//!
+//! For devices without documentation, probing reads the address space and
+//! records which addresses answer and which come back with an exception.
+//!
+//! - [`RegisterMapProber`] reads one address at a time — one request per
+//!   address, but every address gets its own verdict.
+//! - [`SmartProber`] binary-searches the end of each responsive range with
+//!   block reads, so long ranges cost a handful of requests instead of one
+//!   per address.
+//!
+//! Both produce one [`ProbeResult`] per address, which
+//! [`ProbeResult::summary`] folds into a readable address map. With the
+//! `serde` feature the results serialize, so a probe can be saved and
+//! compared later.
+//!
+//! ```rust,no_run
+//! use voltage_modbus::probe::{ProbeResult, SmartProber};
+//! use voltage_modbus::ModbusTcpClient;
+//! use std::time::Duration;
+//!
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! let mut client = ModbusTcpClient::from_address("192.168.1.50:502", Duration::from_secs(1)).await?;
+//! let mut prober = SmartProber::new();
+//! let results = prober.probe(&mut client, 1, 0x03, 0, 999).await?;
+//! println!("{}", ProbeResult::summary(&results));
+//! println!("{} requests", prober.requests_sent());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Write as _;
+
+use crate::client::ModbusClient;
+use crate::constants::{MAX_READ_COILS, MAX_READ_REGISTERS};
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::SlaveId;
+
+/// Outcome of probing one address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProbeResult {
+    /// Probed address.
+    pub address: u16,
+    /// `true` if a read covering the address succeeded.
+    pub responds: bool,
+    /// Exception code the device returned for the address, if any.
+    pub exception_code: Option<u8>,
+}
+
+impl ProbeResult {
+    /// Address map of `results`: one line per run of addresses with the same
+    /// outcome, e.g. `0x0000-0x0009  ok (10)`.
+    ///
+    /// ```rust
+    /// use voltage_modbus::probe::ProbeResult;
+    ///
+    /// let results = [
+    ///     ProbeResult { address: 0, responds: true, exception_code: None },
+    ///     ProbeResult { address: 1, responds: true, exception_code: None },
+    ///     ProbeResult { address: 2, responds: false, exception_code: Some(0x02) },
+    ///     ProbeResult { address: 3, responds: false, exception_code: None },
+    /// ];
+    /// assert_eq!(
+    ///     ProbeResult::summary(&results),
+    ///     "0x0000-0x0001  ok (2)\n0x0002  exception 0x02 (1)\n0x0003  no response (1)\n"
+    /// );
+    /// ```
+    pub fn summary(results: &[ProbeResult]) -> String {
+        let mut out = String::new();
+        let mut i = 0;
+        while i < results.len() {
+            let first = results[i];
+            let mut last = first;
+            while let Some(next) = results.get(i + 1) {
+                let contiguous = u32::from(next.address) == u32::from(last.address) + 1;
+                if !contiguous || next.outcome() != first.outcome() {
+                    break;
+                }
+                last = *next;
+                i += 1;
+            }
+            let count = u32::from(last.address) - u32::from(first.address) + 1;
+            if count == 1 {
+                let _ = write!(out, "0x{:04X}", first.address);
+            } else {
+                let _ = write!(out, "0x{:04X}-0x{:04X}", first.address, last.address);
+            }
+            let _ = writeln!(out, "  {} ({})", first.outcome(), count);
+            i += 1;
+        }
+        out
+    }
+
+    fn outcome(&self) -> String {
+        match (self.responds, self.exception_code) {
+            (true, _) => "ok".to_string(),
+            (false, Some(code)) => format!("exception 0x{:02X}", code),
+            (false, None) => "no response".to_string(),
+        }
+    }
+
+    fn from_read<T>(address: u16, result: &ModbusResult<T>) -> Self {
+        Self {
+            address,
+            responds: result.is_ok(),
+            exception_code: result.as_ref().err().and_then(ModbusError::exception_code),
+        }
+    }
+}
+
+/// Linear prober: one single-address read per address.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RegisterMapProber;
+
+impl RegisterMapProber {
+    /// Probe `start_addr..=end_addr` of `slave_id` with read function `fc`
+    /// (0x01-0x04).
+    ///
+    /// Exceptions and timeouts are recorded per address; connection and
+    /// other transport failures abort the probe.
+    pub async fn probe(
+        client: &mut impl ModbusClient,
+        slave_id: SlaveId,
+        fc: u8,
+        start_addr: u16,
+        end_addr: u16,
+    ) -> ModbusResult<Vec<ProbeResult>> {
+        check_probe_args(fc, start_addr, end_addr)?;
+        let mut results = Vec::with_capacity(usize::from(end_addr - start_addr) + 1);
+        for address in start_addr..=end_addr {
+            let result = read(client, slave_id, fc, address, 1).await;
+            results.push(record(address, &result)?);
+        }
+        Ok(results)
+    }
+}
+
+/// Prober that binary-searches the end of each responsive range.
+///
+/// Gaps are scanned one address at a time, exactly like
+/// [`RegisterMapProber`]. Once an address responds, a block read from it
+/// checks whether the next `max_block` addresses all respond; if not, a
+/// binary search over the block length finds where the responsive run ends.
+/// A run of `n` addresses therefore costs about `log2(n)` requests instead
+/// of `n`, and the results are the same as a linear scan for any device
+/// that answers a block read only when every address in it is readable.
+#[derive(Debug, Clone)]
+pub struct SmartProber {
+    max_block: u16,
+    requests_sent: usize,
+}
+
+impl SmartProber {
+    /// Prober reading blocks of up to the protocol maximum per request.
+    pub fn new() -> Self {
+        Self {
+            max_block: MAX_READ_COILS as u16,
+            requests_sent: 0,
+        }
+    }
+
+    /// Cap the block size, for devices that reject long reads.
+    ///
+    /// A value of 0 is treated as 1.
+    pub fn with_max_block(mut self, max_block: u16) -> Self {
+        self.max_block = max_block.max(1);
+        self
+    }
+
+    /// Requests sent by all probes so far.
+    pub fn requests_sent(&self) -> usize {
+        self.requests_sent
+    }
+
+    /// Probe `start_addr..=end_addr`; same contract as
+    /// [`RegisterMapProber::probe`].
+    pub async fn probe(
+        &mut self,
+        client: &mut impl ModbusClient,
+        slave_id: SlaveId,
+        fc: u8,
+        start_addr: u16,
+        end_addr: u16,
+    ) -> ModbusResult<Vec<ProbeResult>> {
+        check_probe_args(fc, start_addr, end_addr)?;
+        let protocol_max = if fc <= 0x02 {
+            MAX_READ_COILS as u16
+        } else {
+            MAX_READ_REGISTERS as u16
+        };
+        let block = u32::from(self.max_block.min(protocol_max));
+        let end = u32::from(end_addr);
+
+        let mut results = Vec::with_capacity(usize::from(end_addr - start_addr) + 1);
+        let mut address = u32::from(start_addr);
+        // The previous block was readable end to end, so the run may go on
+        let mut in_run = false;
+        while address <= end {
+            let start = address as u16;
+            let len = block.min(end - address + 1) as u16;
+
+            if !in_run {
+                let single = self.read(client, slave_id, fc, start, 1).await;
+                let result = record(start, &single)?;
+                results.push(result);
+                address += 1;
+                in_run = result.responds;
+                continue;
+            }
+
+            // Longest readable prefix of the block; 0 if none
+            let mut readable = 0u16;
+            let block_read = self.read(client, slave_id, fc, start, len).await;
+            record(start, &block_read)?;
+            if block_read.is_ok() {
+                readable = len;
+            } else {
+                let mut failing = len;
+                while failing - readable > 1 {
+                    let mid = readable + (failing - readable) / 2;
+                    let probe = self.read(client, slave_id, fc, start, mid).await;
+                    record(start, &probe)?;
+                    if probe.is_ok() {
+                        readable = mid;
+                    } else {
+                        failing = mid;
+                    }
+                }
+            }
+
+            results.extend((0..readable).map(|offset| ProbeResult {
+                address: start + offset,
+                responds: true,
+                exception_code: None,
+            }));
+            address += u32::from(readable);
+            in_run = readable == len;
+        }
+        Ok(results)
+    }
+
+    async fn read(
+        &mut self,
+        client: &mut impl ModbusClient,
+        slave_id: SlaveId,
+        fc: u8,
+        address: u16,
+        quantity: u16,
+    ) -> ModbusResult<()> {
+        self.requests_sent += 1;
+        read(client, slave_id, fc, address, quantity).await
+    }
+}
+
+impl Default for SmartProber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn check_probe_args(fc: u8, start_addr: u16, end_addr: u16) -> ModbusResult<()> {
+    if !(0x01..=0x04).contains(&fc) {
+        return Err(ModbusError::invalid_function(fc));
+    }
+    if end_addr < start_addr {
+        return Err(ModbusError::invalid_address(start_addr, 0));
+    }
+    Ok(())
+}
+
+/// Turn a single-address read into a result, or abort on a dead link
+fn record(address: u16, result: &ModbusResult<()>) -> ModbusResult<ProbeResult> {
+    match result {
+        Err(e) if !e.is_device_exception() && !is_silence(e) => Err(e.clone()),
+        _ => Ok(ProbeResult::from_read(address, result)),
+    }
+}
+
+/// The device ignored the request rather than the link failing
+fn is_silence(error: &ModbusError) -> bool {
+    matches!(
+        error,
+        ModbusError::Timeout { .. } | ModbusError::DeviceNotResponding { .. }
+    )
+}
+
+async fn read(
+    client: &mut impl ModbusClient,
+    slave_id: SlaveId,
+    fc: u8,
+    address: u16,
+    quantity: u16,
+) -> ModbusResult<()> {
+    match fc {
+        0x01 => client.read_01(slave_id, address, quantity).await.map(drop),
+        0x02 => client.read_02(slave_id, address, quantity).await.map(drop),
+        0x03 => client.read_03(slave_id, address, quantity).await.map(drop),
+        0x04 => client.read_04(slave_id, address, quantity).await.map(drop),
+        _ => Err(ModbusError::invalid_function(fc)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::GenericModbusClient;
+    use crate::protocol::{ModbusFunction, ModbusRequest, ModbusResponse};
+    use crate::testing::{EchoTransport, ScriptedTransport};
+    use crate::transport::{ModbusTransport, TransportStats};
+
+    /// Device with holding registers at 100..110 and 200..260 and 400..1000;
+    /// 150 is protected (exception 0x04), 300 never answers, everything else
+    /// is an illegal address.
+    #[derive(Default)]
+    struct SparseDevice {
+        echo: EchoTransport,
+    }
+
+    impl SparseDevice {
+        fn exception_for(address: u32) -> Option<ModbusError> {
+            match address {
+                100..=109 | 200..=259 | 400..=999 => None,
+                150 => Some(ModbusError::exception(0x03, 0x04)),
+                300 => Some(ModbusError::timeout("read response header", 100)),
+                _ => Some(ModbusError::exception(0x03, 0x02)),
+            }
+        }
+    }
+
+    impl ModbusTransport for SparseDevice {
+        async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+            let start = u32::from(request.address);
+            let end = start + u32::from(request.quantity);
+            let mut errors = (start..end).filter_map(Self::exception_for);
+            match errors.next() {
+                Some(error) => Err(error),
+                None => self.echo.request(request).await,
+            }
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        async fn close(&mut self) -> ModbusResult<()> {
+            Ok(())
+        }
+
+        fn get_stats(&self) -> TransportStats {
+            TransportStats::default()
+        }
+    }
+
+    fn responsive(results: &[ProbeResult]) -> Vec<u16> {
+        results
+            .iter()
+            .filter(|r| r.responds)
+            .map(|r| r.address)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn linear_probe_finds_responsive_range() {
+        let mut client = GenericModbusClient::new(SparseDevice::default());
+        let results = RegisterMapProber::probe(&mut client, 1, 0x03, 95, 112)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 18);
+        assert_eq!(responsive(&results), (100..110).collect::<Vec<_>>());
+        assert_eq!(results[0].exception_code, Some(0x02));
+        assert_eq!(
+            ProbeResult::summary(&results),
+            "0x005F-0x0063  exception 0x02 (5)\n\
+             0x0064-0x006D  ok (10)\n\
+             0x006E-0x0070  exception 0x02 (3)\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn smart_probe_matches_linear_with_fewer_requests() {
+        let mut client = GenericModbusClient::new(SparseDevice::default());
+        let linear = RegisterMapProber::probe(&mut client, 1, 0x03, 0, 1099)
+            .await
+            .unwrap();
+
+        let mut prober = SmartProber::new();
+        let smart = prober.probe(&mut client, 1, 0x03, 0, 1099).await.unwrap();
+
+        assert_eq!(smart, linear);
+        // Gaps still cost one request per address: 1100 - 670 responsive
+        assert!(prober.requests_sent() < 500, "{}", prober.requests_sent());
+
+        let protected = smart.iter().find(|r| r.address == 150).unwrap();
+        assert_eq!(protected.exception_code, Some(0x04));
+        let silent = smart.iter().find(|r| r.address == 300).unwrap();
+        assert!(!silent.responds && silent.exception_code.is_none());
+        assert_eq!(
+            responsive(&smart),
+            (100..110)
+                .chain(200..260)
+                .chain(400..1000)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_arguments_and_dead_links() {
+        let mut client = GenericModbusClient::new(SparseDevice::default());
+        assert!(RegisterMapProber::probe(&mut client, 1, 0x06, 0, 1)
+            .await
+            .is_err());
+        assert!(SmartProber::new()
+            .probe(&mut client, 1, 0x03, 10, 9)
+            .await
+            .is_err());
+
+        let mut closed = GenericModbusClient::new(EchoTransport::new());
+        closed.transport_mut().close().await.unwrap();
+        assert!(matches!(
+            RegisterMapProber::probe(&mut closed, 1, 0x03, 0, 1).await,
+            Err(ModbusError::Connection { .. })
+        ));
+
+        // The link drops during the first block read of a run
+        let mut dropping = GenericModbusClient::new(ScriptedTransport::new(vec![
+            Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::ReadHoldingRegisters,
+                vec![2, 0, 1],
+            )),
+            Err(ModbusError::connection("connection reset")),
+        ]));
+        assert!(matches!(
+            SmartProber::new()
+                .probe(&mut dropping, 1, 0x03, 0, 99)
+                .await,
+            Err(ModbusError::Connection { .. })
+        ));
+        dropping.transport().assert_all_consumed();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn results_round_trip_through_json() {
+        let results = vec![
+            ProbeResult {
+                address: 7,
+                responds: false,
+                exception_code: Some(0x02),
+            },
+            ProbeResult {
+                address: 8,
+                responds: true,
+                exception_code: None,
+            },
+        ];
+        let json = serde_json::to_string(&results).unwrap();
+        let back: Vec<ProbeResult> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, results);
+    }
+}
@@ -14,11 +14,20 @@
 //! - **0x03**: Read Holding Registers - Read 1 to 125 contiguous holding registers
 //! - **0x04**: Read Input Registers - Read 1 to 125 contiguous input registers
 //!
+//! ### Diagnostics
+//! - **0x07**: Read Exception Status - Read 8 device-defined status bits
+//! - **0x08**: Diagnostics - Loopback echo (sub-function 0x0000)
+//! - **0x2B / 0x0E**: Read Device Identification - Vendor, product and
+//!   revision objects
+//!
 //! ### Write Functions  
 //! - **0x05**: Write Single Coil - Write a single coil ON or OFF
 //! - **0x06**: Write Single Register - Write a single 16-bit register
 //! - **0x0F**: Write Multiple Coils - Write multiple coils (1 to 1968)
 //! - **0x10**: Write Multiple Registers - Write multiple registers (1 to 123)
+//! - **0x16**: Mask Write Register - AND/OR mask a single register
+//! - **0x17**: Read/Write Multiple Registers - Write up to 121 and read up to
+//!   125 registers in one transaction
 //!
 //! ## Usage Examples
 //!
@@ -99,6 +108,7 @@
 use alloc::{format, string::ToString, vec::Vec};
 
 use core::fmt;
+use core::time::Duration;
 
 use crate::error::{ModbusError, ModbusResult};
 
@@ -124,6 +134,10 @@ pub enum ModbusFunction {
     ReadHoldingRegisters = 0x03,
     /// Read Input Registers (0x04)
     ReadInputRegisters = 0x04,
+    /// Read Exception Status (0x07)
+    ReadExceptionStatus = 0x07,
+    /// Diagnostics (0x08)
+    Diagnostics = 0x08,
     /// Write Single Coil (0x05)
     WriteSingleCoil = 0x05,
     /// Write Single Register (0x06)
@@ -132,21 +146,54 @@ pub enum ModbusFunction {
     WriteMultipleCoils = 0x0F,
     /// Write Multiple Registers (0x10)
     WriteMultipleRegisters = 0x10,
+    /// Mask Write Register (0x16)
+    MaskWriteRegister = 0x16,
+    /// Read/Write Multiple Registers (0x17)
+    ReadWriteMultipleRegisters = 0x17,
+    /// Encapsulated Interface Transport (0x2B), e.g. Read Device Identification
+    EncapsulatedInterfaceTransport = 0x2B,
 }
 
 impl ModbusFunction {
     /// Convert from u8 to ModbusFunction
+    ///
+    /// Exact match: an exception function code (bit 7 set) is rejected.
     pub fn from_u8(value: u8) -> ModbusResult<Self> {
+        Self::lookup(value).ok_or_else(|| ModbusError::invalid_function(value))
+    }
+
+    /// Function of a raw function code byte, or `None` if unsupported.
+    ///
+    /// The exception flag (0x80) is stripped first, so the function code
+    /// of an exception response maps to the function that failed. See also
+    /// the `TryFrom<u8>` impl, which fails with `InvalidFunction` instead.
+    ///
+    /// ```rust
+    /// use voltage_modbus::ModbusFunction;
+    ///
+    /// assert_eq!(ModbusFunction::from_code(0x83), Some(ModbusFunction::ReadHoldingRegisters));
+    /// assert_eq!(ModbusFunction::try_from(0x42).ok(), None);
+    /// ```
+    pub fn from_code(value: u8) -> Option<Self> {
+        Self::lookup(value & 0x7F)
+    }
+
+    fn lookup(value: u8) -> Option<Self> {
         match value {
-            0x01 => Ok(ModbusFunction::ReadCoils),
-            0x02 => Ok(ModbusFunction::ReadDiscreteInputs),
-            0x03 => Ok(ModbusFunction::ReadHoldingRegisters),
-            0x04 => Ok(ModbusFunction::ReadInputRegisters),
-            0x05 => Ok(ModbusFunction::WriteSingleCoil),
-            0x06 => Ok(ModbusFunction::WriteSingleRegister),
-            0x0F => Ok(ModbusFunction::WriteMultipleCoils),
-            0x10 => Ok(ModbusFunction::WriteMultipleRegisters),
-            _ => Err(ModbusError::invalid_function(value)),
+            0x01 => Some(ModbusFunction::ReadCoils),
+            0x02 => Some(ModbusFunction::ReadDiscreteInputs),
+            0x03 => Some(ModbusFunction::ReadHoldingRegisters),
+            0x04 => Some(ModbusFunction::ReadInputRegisters),
+            0x05 => Some(ModbusFunction::WriteSingleCoil),
+            0x06 => Some(ModbusFunction::WriteSingleRegister),
+            0x07 => Some(ModbusFunction::ReadExceptionStatus),
+            0x08 => Some(ModbusFunction::Diagnostics),
+            0x0F => Some(ModbusFunction::WriteMultipleCoils),
+            0x10 => Some(ModbusFunction::WriteMultipleRegisters),
+            0x16 => Some(ModbusFunction::MaskWriteRegister),
+            0x17 => Some(ModbusFunction::ReadWriteMultipleRegisters),
+            0x2B => Some(ModbusFunction::EncapsulatedInterfaceTransport),
+            _ => None,
         }
     }
 
@@ -156,6 +203,8 @@ impl ModbusFunction {
     }
 
     /// Check if this is a read function
+    ///
+    /// Read/Write Multiple Registers counts as both a read and a write.
     pub fn is_read_function(self) -> bool {
         matches!(
             self,
@@ -163,6 +212,10 @@ impl ModbusFunction {
                 | ModbusFunction::ReadDiscreteInputs
                 | ModbusFunction::ReadHoldingRegisters
                 | ModbusFunction::ReadInputRegisters
+                | ModbusFunction::ReadExceptionStatus
+                | ModbusFunction::Diagnostics
+                | ModbusFunction::ReadWriteMultipleRegisters
+                | ModbusFunction::EncapsulatedInterfaceTransport
         )
     }
 
@@ -174,10 +227,22 @@ impl ModbusFunction {
                 | ModbusFunction::WriteSingleRegister
                 | ModbusFunction::WriteMultipleCoils
                 | ModbusFunction::WriteMultipleRegisters
+                | ModbusFunction::MaskWriteRegister
+                | ModbusFunction::ReadWriteMultipleRegisters
         )
     }
 }
 
+/// Like [`ModbusFunction::from_code`], failing with `InvalidFunction`
+/// (carrying the byte as given) for unsupported codes.
+impl TryFrom<u8> for ModbusFunction {
+    type Error = ModbusError;
+
+    fn try_from(value: u8) -> ModbusResult<Self> {
+        Self::from_code(value).ok_or_else(|| ModbusError::invalid_function(value))
+    }
+}
+
 impl fmt::Display for ModbusFunction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let name = match self {
@@ -187,8 +252,13 @@ impl fmt::Display for ModbusFunction {
             ModbusFunction::ReadInputRegisters => "Read Input Registers",
             ModbusFunction::WriteSingleCoil => "Write Single Coil",
             ModbusFunction::WriteSingleRegister => "Write Single Register",
+            ModbusFunction::ReadExceptionStatus => "Read Exception Status",
+            ModbusFunction::Diagnostics => "Diagnostics",
             ModbusFunction::WriteMultipleCoils => "Write Multiple Coils",
             ModbusFunction::WriteMultipleRegisters => "Write Multiple Registers",
+            ModbusFunction::MaskWriteRegister => "Mask Write Register",
+            ModbusFunction::ReadWriteMultipleRegisters => "Read/Write Multiple Registers",
+            ModbusFunction::EncapsulatedInterfaceTransport => "Encapsulated Interface Transport",
         };
         write!(f, "{} (0x{:02X})", name, *self as u8)
     }
@@ -267,9 +337,24 @@ pub struct ModbusRequest {
     pub address: ModbusAddress,
     pub quantity: u16,
     pub data: Vec<u8>,
+    /// Response timeout for this request; `None` uses the transport's own.
+    /// Honoured by `TcpTransport` and `RtuTransport`, ignored by the other
+    /// transports.
+    pub timeout: Option<Duration>,
 }
 
 impl ModbusRequest {
+    /// Start building a request field by field; see [`ModbusRequestBuilder`].
+    pub fn builder() -> ModbusRequestBuilder {
+        ModbusRequestBuilder::new()
+    }
+
+    /// Override the transport's response timeout for this request
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Create a new read request
     pub fn new_read(
         slave_id: SlaveId,
@@ -283,6 +368,7 @@ impl ModbusRequest {
             address,
             quantity,
             data: Vec::new(),
+            timeout: None,
         }
     }
 
@@ -297,7 +383,9 @@ impl ModbusRequest {
         data: Vec<u8>,
     ) -> Self {
         let quantity = match function {
-            ModbusFunction::WriteSingleCoil | ModbusFunction::WriteSingleRegister => 1,
+            ModbusFunction::WriteSingleCoil
+            | ModbusFunction::WriteSingleRegister
+            | ModbusFunction::MaskWriteRegister => 1,
             ModbusFunction::WriteMultipleCoils => data.len() as u16 * 8,
             ModbusFunction::WriteMultipleRegisters => data.len() as u16 / 2,
             _ => 0,
@@ -309,6 +397,7 @@ impl ModbusRequest {
             address,
             quantity,
             data,
+            timeout: None,
         }
     }
 
@@ -325,9 +414,206 @@ impl ModbusRequest {
             address,
             quantity,
             data,
+            timeout: None,
+        }
+    }
+
+    /// Create a read-exception-status request (FC07).
+    ///
+    /// The request PDU is the bare function code; `address` and `quantity`
+    /// are not sent. `quantity` is 1 for the single status byte returned.
+    pub fn new_read_exception_status(slave_id: SlaveId) -> Self {
+        Self {
+            slave_id,
+            function: ModbusFunction::ReadExceptionStatus,
+            address: 0,
+            quantity: 1,
+            data: Vec::new(),
+            timeout: None,
         }
     }
 
+    /// Create a diagnostics request (FC08).
+    ///
+    /// The PDU carries the sub-function word followed by `data`; `address`
+    /// is not sent. Sub-function 0x0000 (Return Query Data) echoes `data`.
+    pub fn new_diagnostics(slave_id: SlaveId, sub_function: u16, data: &[u8]) -> Self {
+        let mut payload = Vec::with_capacity(2 + data.len());
+        payload.extend_from_slice(&sub_function.to_be_bytes());
+        payload.extend_from_slice(data);
+        Self {
+            slave_id,
+            function: ModbusFunction::Diagnostics,
+            address: 0,
+            quantity: 1,
+            data: payload,
+            timeout: None,
+        }
+    }
+
+    /// Create a Read Device Identification request (FC43, MEI type 0x0E).
+    ///
+    /// The PDU carries the MEI type, `read_device_id_code` (0x01 basic,
+    /// 0x02 regular, 0x03 extended, 0x04 one object) and the first
+    /// `object_id` to return; `address` is not sent.
+    pub fn new_read_device_id(slave_id: SlaveId, read_device_id_code: u8, object_id: u8) -> Self {
+        Self {
+            slave_id,
+            function: ModbusFunction::EncapsulatedInterfaceTransport,
+            address: 0,
+            quantity: 1,
+            data: [0x0E, read_device_id_code, object_id].to_vec(),
+            timeout: None,
+        }
+    }
+
+    /// Create a mask-write-register request (FC22).
+    ///
+    /// The device sets the register to
+    /// `(current & and_mask) | (or_mask & !and_mask)`.
+    pub fn new_mask_write(
+        slave_id: SlaveId,
+        address: ModbusAddress,
+        and_mask: u16,
+        or_mask: u16,
+    ) -> Self {
+        let mut data = Vec::with_capacity(4);
+        data.extend_from_slice(&and_mask.to_be_bytes());
+        data.extend_from_slice(&or_mask.to_be_bytes());
+        Self {
+            slave_id,
+            function: ModbusFunction::MaskWriteRegister,
+            address,
+            quantity: 1,
+            data,
+            timeout: None,
+        }
+    }
+
+    /// Create a read/write-multiple-registers request (FC23).
+    ///
+    /// `address` and `quantity` describe the read; the write starting
+    /// address, quantity, byte count and values are carried in `data`. The
+    /// device performs the write before the read.
+    pub fn new_read_write(
+        slave_id: SlaveId,
+        read_address: ModbusAddress,
+        read_quantity: u16,
+        write_address: ModbusAddress,
+        values: &[u16],
+    ) -> Self {
+        let mut data = Vec::with_capacity(5 + values.len() * 2);
+        data.extend_from_slice(&write_address.to_be_bytes());
+        data.extend_from_slice(&(values.len() as u16).to_be_bytes());
+        data.push((values.len() * 2) as u8);
+        for value in values {
+            data.extend_from_slice(&value.to_be_bytes());
+        }
+        Self {
+            slave_id,
+            function: ModbusFunction::ReadWriteMultipleRegisters,
+            address: read_address,
+            quantity: read_quantity,
+            data,
+            timeout: None,
+        }
+    }
+
+    /// Decode a request PDU (function code first), as received by a server.
+    ///
+    /// Fields land where the `new_*` constructors put them, so a decoded
+    /// request re-encodes to the same PDU. Only the framing is checked: a
+    /// PDU shorter or longer than its function code implies is a frame
+    /// error. Use [`validate`](Self::validate) for quantities and ranges.
+    pub fn from_pdu(slave_id: SlaveId, pdu: &[u8]) -> ModbusResult<Self> {
+        let Some((&code, body)) = pdu.split_first() else {
+            return Err(ModbusError::frame("Empty request PDU"));
+        };
+        let function = ModbusFunction::from_u8(code)?;
+        let expect_len = |len: usize| {
+            if body.len() == len {
+                Ok(())
+            } else {
+                Err(ModbusError::frame(format!(
+                    "Invalid request length for 0x{:02X}: expected {} bytes, got {}",
+                    code,
+                    1 + len,
+                    pdu.len()
+                )))
+            }
+        };
+        // Variable-length requests: check the fixed header before the byte count
+        let header_len = match function {
+            ModbusFunction::WriteMultipleCoils | ModbusFunction::WriteMultipleRegisters => 5,
+            ModbusFunction::ReadWriteMultipleRegisters => 9,
+            ModbusFunction::Diagnostics => 2,
+            ModbusFunction::EncapsulatedInterfaceTransport => 1,
+            _ => 0,
+        };
+        if body.len() < header_len {
+            return Err(ModbusError::frame(format!(
+                "Request PDU for 0x{:02X} too short: {} bytes",
+                code,
+                pdu.len()
+            )));
+        }
+        let word = |offset: usize| u16::from_be_bytes([body[offset], body[offset + 1]]);
+
+        let request = match function {
+            ModbusFunction::ReadCoils
+            | ModbusFunction::ReadDiscreteInputs
+            | ModbusFunction::ReadHoldingRegisters
+            | ModbusFunction::ReadInputRegisters => {
+                expect_len(4)?;
+                Self::new_read(slave_id, function, word(0), word(2))
+            }
+            ModbusFunction::WriteSingleCoil | ModbusFunction::WriteSingleRegister => {
+                expect_len(4)?;
+                Self::new_write(slave_id, function, word(0), body[2..].to_vec())
+            }
+            ModbusFunction::WriteMultipleCoils | ModbusFunction::WriteMultipleRegisters => {
+                expect_len(5 + usize::from(body[4]))?;
+                Self {
+                    slave_id,
+                    function,
+                    address: word(0),
+                    quantity: word(2),
+                    data: body[5..].to_vec(),
+                    timeout: None,
+                }
+            }
+            ModbusFunction::MaskWriteRegister => {
+                expect_len(6)?;
+                Self::new_mask_write(slave_id, word(0), word(2), word(4))
+            }
+            ModbusFunction::ReadWriteMultipleRegisters => {
+                expect_len(9 + usize::from(body[8]))?;
+                Self {
+                    slave_id,
+                    function,
+                    address: word(0),
+                    quantity: word(2),
+                    data: body[4..].to_vec(),
+                    timeout: None,
+                }
+            }
+            ModbusFunction::ReadExceptionStatus => {
+                expect_len(0)?;
+                Self::new_read_exception_status(slave_id)
+            }
+            ModbusFunction::Diagnostics => Self::new_diagnostics(slave_id, word(0), &body[2..]),
+            ModbusFunction::EncapsulatedInterfaceTransport => Self {
+                slave_id,
+                function,
+                address: 0,
+                quantity: 1,
+                data: body.to_vec(),
+                timeout: None,
+            },
+        };
+        Ok(request)
+    }
+
     /// Validate the request
     pub fn validate(&self) -> ModbusResult<()> {
         // Validate slave ID — 0 is the broadcast address (valid for write only), 1–247 are unicast
@@ -351,21 +637,23 @@ impl ModbusRequest {
             validate_address_range(self.address, self.quantity)?;
 
             match self.function {
-                ModbusFunction::ReadCoils | ModbusFunction::ReadDiscreteInputs => {
-                    if self.quantity > crate::MAX_READ_COILS as u16 {
-                        return Err(ModbusError::invalid_data(format!(
-                            "Too many coils requested: {}",
-                            self.quantity
-                        )));
-                    }
+                ModbusFunction::ReadCoils | ModbusFunction::ReadDiscreteInputs
+                    if self.quantity > crate::MAX_READ_COILS as u16 =>
+                {
+                    return Err(ModbusError::invalid_data(format!(
+                        "Too many coils requested: {}",
+                        self.quantity
+                    )));
                 }
-                ModbusFunction::ReadHoldingRegisters | ModbusFunction::ReadInputRegisters => {
-                    if self.quantity > crate::MAX_READ_REGISTERS as u16 {
-                        return Err(ModbusError::invalid_data(format!(
-                            "Too many registers requested: {}",
-                            self.quantity
-                        )));
-                    }
+                ModbusFunction::ReadHoldingRegisters
+                | ModbusFunction::ReadInputRegisters
+                | ModbusFunction::ReadWriteMultipleRegisters
+                    if self.quantity > crate::MAX_READ_REGISTERS as u16 =>
+                {
+                    return Err(ModbusError::invalid_data(format!(
+                        "Too many registers requested: {}",
+                        self.quantity
+                    )));
                 }
                 _ => {}
             }
@@ -428,6 +716,49 @@ impl ModbusRequest {
                     )));
                 }
             }
+            ModbusFunction::MaskWriteRegister => {
+                validate_address_range(self.address, 1)?;
+                if self.data.len() != 4 {
+                    return Err(ModbusError::invalid_data(format!(
+                        "Invalid mask write payload length: expected 4, got {}",
+                        self.data.len()
+                    )));
+                }
+            }
+            ModbusFunction::EncapsulatedInterfaceTransport if self.data.is_empty() => {
+                return Err(ModbusError::invalid_data(
+                    "Encapsulated interface payload is missing the MEI type",
+                ));
+            }
+            ModbusFunction::Diagnostics if self.data.len() < 2 => {
+                return Err(ModbusError::invalid_data(
+                    "Diagnostics payload is missing the sub-function",
+                ));
+            }
+            ModbusFunction::ReadWriteMultipleRegisters => {
+                let [hi, lo, qhi, qlo, byte_count, values @ ..] = self.data.as_slice() else {
+                    return Err(ModbusError::invalid_data(
+                        "Read/write payload is missing the write header",
+                    ));
+                };
+                let write_address = u16::from_be_bytes([*hi, *lo]);
+                let write_quantity = u16::from_be_bytes([*qhi, *qlo]);
+                validate_address_range(write_address, write_quantity)?;
+                if write_quantity > crate::MAX_READ_WRITE_REGISTERS as u16 {
+                    return Err(ModbusError::invalid_data(format!(
+                        "Too many registers to write: {}",
+                        write_quantity
+                    )));
+                }
+                let expected_bytes = usize::from(write_quantity) * 2;
+                if usize::from(*byte_count) != expected_bytes || values.len() != expected_bytes {
+                    return Err(ModbusError::invalid_data(format!(
+                        "Invalid register payload length: expected {}, got {}",
+                        expected_bytes,
+                        values.len()
+                    )));
+                }
+            }
             _ => {}
         }
 
@@ -448,6 +779,108 @@ fn validate_address_range(address: ModbusAddress, quantity: u16) -> ModbusResult
     Ok(())
 }
 
+/// Request builder - fluent API
+///
+/// Slave, function code and address are required; the quantity defaults to 1
+/// and the payload to empty. `build` does not check the fields against each
+/// other; use [`ModbusRequest::validate`] for that.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use voltage_modbus::protocol::{ModbusFunction, ModbusRequest};
+///
+/// let request = ModbusRequest::builder()
+///     .slave(1)
+///     .function(0x03)?
+///     .at(100)
+///     .count(10)
+///     .timeout(Duration::from_millis(500))
+///     .build()?;
+/// assert_eq!(request.function, ModbusFunction::ReadHoldingRegisters);
+/// assert_eq!(request.timeout, Some(Duration::from_millis(500)));
+/// # Ok::<(), voltage_modbus::ModbusError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModbusRequestBuilder {
+    slave_id: Option<SlaveId>,
+    function: Option<ModbusFunction>,
+    address: Option<ModbusAddress>,
+    quantity: u16,
+    data: Vec<u8>,
+    timeout: Option<Duration>,
+}
+
+impl Default for ModbusRequestBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModbusRequestBuilder {
+    /// Create an empty builder
+    pub fn new() -> Self {
+        Self {
+            slave_id: None,
+            function: None,
+            address: None,
+            quantity: 1,
+            data: Vec::new(),
+            timeout: None,
+        }
+    }
+
+    /// Set the slave id
+    pub fn slave(mut self, id: SlaveId) -> Self {
+        self.slave_id = Some(id);
+        self
+    }
+
+    /// Set the function code; unsupported codes are rejected
+    pub fn function(mut self, code: u8) -> ModbusResult<Self> {
+        self.function = Some(ModbusFunction::from_u8(code)?);
+        Ok(self)
+    }
+
+    /// Set the starting address
+    pub fn at(mut self, address: ModbusAddress) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Set the number of registers or coils
+    pub fn count(mut self, qty: u16) -> Self {
+        self.quantity = qty;
+        self
+    }
+
+    /// Set the payload that follows the address, as the `new_*`
+    /// constructors lay it out
+    pub fn with_data(mut self, data: Vec<u8>) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Override the transport's response timeout for this request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Build the request; fails if the slave, function or address is unset
+    pub fn build(self) -> ModbusResult<ModbusRequest> {
+        let missing =
+            |field: &str| ModbusError::invalid_data(format!("Request builder: {} not set", field));
+        Ok(ModbusRequest {
+            slave_id: self.slave_id.ok_or_else(|| missing("slave id"))?,
+            function: self.function.ok_or_else(|| missing("function code"))?,
+            address: self.address.ok_or_else(|| missing("address"))?,
+            quantity: self.quantity,
+            data: self.data,
+            timeout: self.timeout,
+        })
+    }
+}
+
 /// Modbus response structure
 ///
 /// Uses internal buffer with offset/length tracking to enable zero-copy
@@ -541,6 +974,18 @@ impl ModbusResponse {
         }
     }
 
+    /// Serialize as a response PDU: the function code followed by the
+    /// payload, or the exception function code (bit 7 set) and exception code.
+    pub fn to_pdu(&self) -> Vec<u8> {
+        if let Some(exception) = self.exception {
+            return [self.function.to_u8() | 0x80, exception.to_u8()].to_vec();
+        }
+        let mut pdu = Vec::with_capacity(1 + self.data_len);
+        pdu.push(self.function.to_u8());
+        pdu.extend_from_slice(self.data());
+        pdu
+    }
+
     /// Get payload data as a slice
     ///
     /// Returns the response payload without the function code or byte count prefix.
@@ -565,7 +1010,7 @@ impl ModbusResponse {
     /// Get exception error if present
     pub fn get_exception(&self) -> Option<ModbusError> {
         self.exception
-            .map(|exc| ModbusError::protocol(format!("Modbus exception: {}", exc)))
+            .map(|exc| ModbusError::exception(self.function.to_u8(), exc.to_u8()))
     }
 
     /// Parse response data as registers (u16 values)
@@ -622,6 +1067,90 @@ impl ModbusResponse {
 
         Ok(bits)
     }
+
+    /// Bit `bit_index` (0 = first coil or input read) of an FC01/FC02
+    /// response, read straight from the payload.
+    pub fn as_bool_at(&self, bit_index: u16) -> ModbusResult<bool> {
+        let bytes = self.counted_payload("bit")?;
+        let byte = bytes
+            .get(usize::from(bit_index / 8))
+            .ok_or_else(|| index_out_of_range("Bit", bit_index, bytes.len() * 8))?;
+        Ok(byte & (1 << (bit_index % 8)) != 0)
+    }
+
+    /// Register `register_index` (0 = first register read) as `u16`, read
+    /// straight from the payload.
+    pub fn as_u16_at(&self, register_index: u16) -> ModbusResult<u16> {
+        let [value] = self.registers_at::<1>(register_index)?;
+        Ok(value)
+    }
+
+    /// Register `register_index` as `i16`.
+    pub fn as_i16_at(&self, register_index: u16) -> ModbusResult<i16> {
+        Ok(self.as_u16_at(register_index)? as i16)
+    }
+
+    /// Registers `register_index` and `register_index + 1` as `f32` in
+    /// `byte_order`.
+    #[cfg(feature = "std")]
+    pub fn as_f32_at(
+        &self,
+        register_index: u16,
+        byte_order: crate::bytes::ByteOrder,
+    ) -> ModbusResult<f32> {
+        let registers = self.registers_at::<2>(register_index)?;
+        Ok(f32::from_be_bytes(crate::bytes::regs_to_bytes_4(
+            &registers, byte_order,
+        )))
+    }
+
+    /// Registers `register_index` to `register_index + 3` as `f64` in
+    /// `byte_order`.
+    #[cfg(feature = "std")]
+    pub fn as_f64_at(
+        &self,
+        register_index: u16,
+        byte_order: crate::bytes::ByteOrder,
+    ) -> ModbusResult<f64> {
+        let registers = self.registers_at::<4>(register_index)?;
+        Ok(f64::from_be_bytes(crate::bytes::regs_to_bytes_8(
+            &registers, byte_order,
+        )))
+    }
+
+    /// `N` consecutive registers starting at `register_index`.
+    fn registers_at<const N: usize>(&self, register_index: u16) -> ModbusResult<[u16; N]> {
+        let bytes = self.counted_payload("register")?;
+        if bytes.len() % 2 != 0 {
+            return Err(ModbusError::frame("Invalid register data length"));
+        }
+        let start = usize::from(register_index) * 2;
+        let window = bytes
+            .get(start..start + N * 2)
+            .ok_or_else(|| index_out_of_range("Register", register_index, bytes.len() / 2))?;
+        Ok(core::array::from_fn(|i| {
+            u16::from_be_bytes([window[2 * i], window[2 * i + 1]])
+        }))
+    }
+
+    /// The bytes announced by the leading byte count of a read response.
+    fn counted_payload(&self, what: &str) -> ModbusResult<&[u8]> {
+        if let Some(error) = self.get_exception() {
+            return Err(error);
+        }
+        let Some((&byte_count, rest)) = self.data().split_first() else {
+            return Err(ModbusError::frame("Empty response data"));
+        };
+        rest.get(..usize::from(byte_count))
+            .ok_or_else(|| ModbusError::frame(format!("Incomplete {} data", what)))
+    }
+}
+
+fn index_out_of_range(what: &str, index: u16, available: usize) -> ModbusError {
+    ModbusError::invalid_data(format!(
+        "{} index {} out of range ({} in response)",
+        what, index, available
+    ))
 }
 
 /// Data conversion utilities
@@ -725,6 +1254,69 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_typed_response_accessors() {
+        let registers = ModbusResponse::new_success(
+            1,
+            ModbusFunction::ReadHoldingRegisters,
+            vec![8, 0xFF, 0xFE, 0x41, 0x20, 0x00, 0x00, 0x00, 0x07],
+        );
+        assert_eq!(registers.as_u16_at(0).unwrap(), 0xFFFE);
+        assert_eq!(registers.as_i16_at(0).unwrap(), -2);
+        assert_eq!(registers.as_u16_at(3).unwrap(), 7);
+        assert!(registers.as_u16_at(4).is_err());
+        #[cfg(feature = "std")]
+        {
+            use crate::bytes::ByteOrder;
+            assert_eq!(registers.as_f32_at(1, ByteOrder::BigEndian).unwrap(), 10.0);
+            assert!(registers.as_f32_at(3, ByteOrder::BigEndian).is_err());
+            let f64_bits = registers.as_f64_at(0, ByteOrder::BigEndian).unwrap();
+            assert_eq!(f64_bits.to_bits(), 0xFFFE_4120_0000_0007);
+        }
+
+        let coils =
+            ModbusResponse::new_success(1, ModbusFunction::ReadCoils, vec![2, 0b0000_0101, 0x80]);
+        assert!(coils.as_bool_at(0).unwrap());
+        assert!(!coils.as_bool_at(1).unwrap());
+        assert!(coils.as_bool_at(15).unwrap());
+        assert!(coils.as_bool_at(16).is_err());
+
+        let short =
+            ModbusResponse::new_success(1, ModbusFunction::ReadHoldingRegisters, vec![4, 0]);
+        assert!(matches!(short.as_u16_at(0), Err(ModbusError::Frame { .. })));
+        let exception = ModbusResponse::new_exception(1, ModbusFunction::ReadCoils, 0x02);
+        assert!(exception.as_bool_at(0).unwrap_err().is_device_exception());
+    }
+
+    #[test]
+    fn test_request_builder() {
+        let request = ModbusRequest::builder()
+            .slave(7)
+            .function(0x10)
+            .unwrap()
+            .at(40)
+            .count(2)
+            .with_data(vec![0, 1, 0, 2])
+            .build()
+            .unwrap();
+        let expected = ModbusRequest::new_write(
+            7,
+            ModbusFunction::WriteMultipleRegisters,
+            40,
+            vec![0, 1, 0, 2],
+        );
+        assert_eq!(request, expected);
+        assert!(request.validate().is_ok());
+
+        assert!(ModbusRequest::builder().function(0x63).is_err());
+        let missing_address = ModbusRequest::builder().slave(1).function(0x03).unwrap();
+        assert!(matches!(
+            missing_address.build(),
+            Err(ModbusError::InvalidData { message }) if message.contains("address")
+        ));
+        assert!(ModbusRequest::builder().at(0).build().is_err());
+    }
+
     #[test]
     fn test_function_conversion() {
         assert_eq!(
@@ -734,6 +1326,22 @@ mod tests {
         assert_eq!(ModbusFunction::ReadHoldingRegisters.to_u8(), 0x03);
 
         assert!(ModbusFunction::from_u8(0xFF).is_err());
+        assert!(ModbusFunction::from_u8(0x83).is_err());
+    }
+
+    #[test]
+    fn test_function_try_from_strips_exception_flag() {
+        for code in [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x0F, 0x10] {
+            let function = ModbusFunction::try_from(code).unwrap();
+            assert_eq!(function.to_u8(), code);
+            assert_eq!(ModbusFunction::try_from(code | 0x80).unwrap(), function);
+            assert_eq!(ModbusFunction::from_code(code | 0x80), Some(function));
+        }
+        assert_eq!(
+            ModbusFunction::try_from(0xC2),
+            Err(ModbusError::InvalidFunction { code: 0xC2 })
+        );
+        assert_eq!(ModbusFunction::from_code(0x00), None);
     }
 
     #[test]
@@ -769,6 +1377,7 @@ mod tests {
             address: 10,
             quantity: 2,
             data: vec![0x12, 0x34, 0x56, 0x78],
+            timeout: None,
         };
         assert!(valid_write_multiple.validate().is_ok());
 
@@ -778,6 +1387,7 @@ mod tests {
             address: 10,
             quantity: 2,
             data: vec![0x12, 0x34],
+            timeout: None,
         };
         assert!(invalid_write_payload.validate().is_err());
 
@@ -798,6 +1408,128 @@ mod tests {
         assert!(req.validate().is_ok());
     }
 
+    #[test]
+    fn test_read_exception_status_request() {
+        let req = ModbusRequest::new_read_exception_status(1);
+        assert_eq!(req.function.to_u8(), 0x07);
+        assert!(req.data.is_empty());
+        assert!(req.validate().is_ok());
+        assert!(ModbusRequest::new_read_exception_status(0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_diagnostics_request() {
+        let req = ModbusRequest::new_diagnostics(1, 0x0000, &[0xA5, 0x37]);
+        assert_eq!(req.data, vec![0x00, 0x00, 0xA5, 0x37]);
+        assert!(req.validate().is_ok());
+
+        let missing_sub_function = ModbusRequest {
+            data: vec![0x00],
+            ..req
+        };
+        assert!(missing_sub_function.validate().is_err());
+    }
+
+    #[test]
+    fn test_read_device_id_request() {
+        let req = ModbusRequest::new_read_device_id(1, 0x01, 0x00);
+        assert_eq!(req.function.to_u8(), 0x2B);
+        assert_eq!(req.data, vec![0x0E, 0x01, 0x00]);
+        assert!(req.validate().is_ok());
+        assert!(ModbusRequest::new_read_device_id(0, 0x01, 0x00)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_request_from_pdu() {
+        let req = ModbusRequest::from_pdu(1, &[0x03, 0x00, 0x6B, 0x00, 0x03]).unwrap();
+        assert_eq!(
+            req,
+            ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0x6B, 3)
+        );
+
+        let req =
+            ModbusRequest::from_pdu(1, &[0x0F, 0x00, 0x13, 0x00, 0x0A, 0x02, 0xCD, 0x01]).unwrap();
+        assert_eq!(
+            req,
+            ModbusRequest::new_write_multiple_coils(1, 0x13, 10, vec![0xCD, 0x01])
+        );
+
+        let pdu = [
+            0x17, 0x00, 0x03, 0x00, 0x06, 0x00, 0x0E, 0x00, 0x01, 0x02, 0x00, 0xFF,
+        ];
+        let req = ModbusRequest::from_pdu(1, &pdu).unwrap();
+        assert_eq!(req, ModbusRequest::new_read_write(1, 3, 6, 0x0E, &[0x00FF]));
+        assert!(req.validate().is_ok());
+
+        // Trailing byte, truncated values, unknown function
+        assert!(ModbusRequest::from_pdu(1, &[0x06, 0x00, 0x01, 0x00, 0x03, 0x99]).is_err());
+        assert!(ModbusRequest::from_pdu(1, &[0x10, 0x00, 0x01, 0x00, 0x01, 0x02, 0x12]).is_err());
+        assert!(matches!(
+            ModbusRequest::from_pdu(1, &[0x41]),
+            Err(ModbusError::InvalidFunction { code: 0x41 })
+        ));
+    }
+
+    #[test]
+    fn test_response_to_pdu() {
+        let response =
+            ModbusResponse::new_success(1, ModbusFunction::ReadHoldingRegisters, vec![2, 0, 42]);
+        assert_eq!(response.to_pdu(), vec![0x03, 2, 0, 42]);
+        let response = ModbusResponse::new_exception(1, ModbusFunction::WriteSingleCoil, 0x02);
+        assert_eq!(response.to_pdu(), vec![0x85, 0x02]);
+    }
+
+    #[test]
+    fn test_mask_write_request() {
+        let req = ModbusRequest::new_mask_write(1, 4, 0x00F2, 0x0025);
+        assert_eq!(req.function, ModbusFunction::MaskWriteRegister);
+        assert_eq!(req.data, vec![0x00, 0xF2, 0x00, 0x25]);
+        assert!(req.validate().is_ok());
+        assert!(ModbusFunction::MaskWriteRegister.is_write_function());
+        assert_eq!(
+            ModbusFunction::from_u8(0x16).unwrap(),
+            ModbusFunction::MaskWriteRegister
+        );
+
+        let short = ModbusRequest {
+            data: vec![0x00, 0xF2],
+            ..req
+        };
+        assert!(short.validate().is_err());
+    }
+
+    #[test]
+    fn test_read_write_request() {
+        let req = ModbusRequest::new_read_write(1, 3, 6, 14, &[0x00FF, 0x00FF, 0x00FF]);
+        assert_eq!(req.address, 3);
+        assert_eq!(req.quantity, 6);
+        assert_eq!(
+            req.data,
+            vec![0x00, 0x0E, 0x00, 0x03, 0x06, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF]
+        );
+        assert!(req.validate().is_ok());
+        let function = ModbusFunction::ReadWriteMultipleRegisters;
+        assert!(function.is_read_function() && function.is_write_function());
+
+        let too_many_reads = ModbusRequest::new_read_write(1, 0, 126, 0, &[1]);
+        assert!(too_many_reads.validate().is_err());
+        let too_many_writes = ModbusRequest::new_read_write(1, 0, 1, 0, &[0; 122]);
+        assert!(too_many_writes.validate().is_err());
+        let no_writes = ModbusRequest::new_read_write(1, 0, 1, 0, &[]);
+        assert!(no_writes.validate().is_err());
+        let broadcast = ModbusRequest::new_read_write(0, 0, 1, 0, &[1]);
+        assert!(broadcast.validate().is_err());
+        let truncated = ModbusRequest {
+            data: vec![0x00, 0x0E, 0x00, 0x02, 0x04, 0x00, 0xFF],
+            ..req
+        };
+        assert!(truncated.validate().is_err());
+    }
+
     #[test]
     fn test_data_utils() {
         let registers = vec![0x1234, 0x5678];
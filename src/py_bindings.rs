@@ -0,0 +1,245 @@
+//! # Python Bindings
+//!
+//! `pyo3` wrappers that expose the TCP client and [`ModbusValue`] to Python.
+//! Enabled by the `python` feature. The extension module is the cdylib in
+//! `python/`, which `maturin` builds from the repository root (see
+//! `pyproject.toml`):
+//!
+//! ```text
+//! maturin develop
+//! ```
+//!
+//! ```python
+//! import asyncio
+//! from voltage_modbus import PyModbusTcpClient, ModbusException
+//!
+//! async def main():
+//!     client = PyModbusTcpClient("127.0.0.1:502", 1000)
+//!     regs = await client.read_registers(1, 0, 10)
+//!     await client.write_register(1, 100, 0x1234)
+//!     await client.close()
+//!
+//! asyncio.run(main())
+//! ```
+//!
+//! All client methods are coroutines driven by a shared Tokio runtime
+//! (`pyo3-async-runtimes`). The TCP connection is opened lazily on the first
+//! request and re-opened if it has been dropped.
+//!
+//! Every [`ModbusError`] is raised as `voltage_modbus.ModbusException`. The
+//! exception args are `(message, code)`, where `code` is the Modbus exception
+//! code for device exceptions and `None` for every other error kind.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use tokio::sync::Mutex;
+
+use crate::bytes::ByteOrder;
+use crate::client::{ModbusClient, ModbusTcpClient};
+use crate::codec::decode_register_value;
+use crate::error::{ModbusError, ModbusResult};
+use crate::value::ModbusValue;
+
+create_exception!(
+    voltage_modbus,
+    ModbusException,
+    PyException,
+    "Raised for any Modbus error. args = (message, code); code is the Modbus exception code or None."
+);
+
+/// Convert a [`ModbusError`] into a Python `ModbusException`.
+fn to_py_err(err: ModbusError) -> PyErr {
+    let code = match &err {
+        ModbusError::Exception { code, .. } => Some(*code),
+        _ => None,
+    };
+    ModbusException::new_err((err.to_string(), code))
+}
+
+/// Return the connected client in `slot`, connecting first if needed.
+async fn ensure_connected<'a>(
+    slot: &'a mut Option<ModbusTcpClient>,
+    address: &str,
+    timeout: Duration,
+) -> ModbusResult<&'a mut ModbusTcpClient> {
+    if !slot.as_ref().is_some_and(|c| c.is_connected()) {
+        *slot = Some(ModbusTcpClient::from_address(address, timeout).await?);
+    }
+    slot.as_mut()
+        .ok_or_else(|| ModbusError::internal("TCP client slot empty after connect"))
+}
+
+/// Python wrapper around [`ModbusTcpClient`].
+#[pyclass(module = "voltage_modbus")]
+pub struct PyModbusTcpClient {
+    address: String,
+    timeout: Duration,
+    inner: Arc<Mutex<Option<ModbusTcpClient>>>,
+}
+
+#[pymethods]
+impl PyModbusTcpClient {
+    /// Create a client for `addr` (e.g. `"192.168.1.10:502"`).
+    ///
+    /// No connection is made until the first request.
+    #[new]
+    fn new(addr: String, timeout_ms: u64) -> Self {
+        Self {
+            address: addr,
+            timeout: Duration::from_millis(timeout_ms),
+            inner: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Read holding registers (FC03). Returns `list[int]`.
+    fn read_registers<'py>(
+        &self,
+        py: Python<'py>,
+        slave_id: u8,
+        address: u16,
+        quantity: u16,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (inner, addr, timeout) = (self.inner.clone(), self.address.clone(), self.timeout);
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut slot = inner.lock().await;
+            let client = ensure_connected(&mut slot, &addr, timeout)
+                .await
+                .map_err(to_py_err)?;
+            client
+                .read_03(slave_id, address, quantity)
+                .await
+                .map_err(to_py_err)
+        })
+    }
+
+    /// Write a single holding register (FC06).
+    fn write_register<'py>(
+        &self,
+        py: Python<'py>,
+        slave_id: u8,
+        address: u16,
+        value: u16,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (inner, addr, timeout) = (self.inner.clone(), self.address.clone(), self.timeout);
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut slot = inner.lock().await;
+            let client = ensure_connected(&mut slot, &addr, timeout)
+                .await
+                .map_err(to_py_err)?;
+            client
+                .write_06(slave_id, address, value)
+                .await
+                .map_err(to_py_err)
+        })
+    }
+
+    /// Read coils (FC01). Returns `list[bool]`.
+    fn read_coils<'py>(
+        &self,
+        py: Python<'py>,
+        slave_id: u8,
+        address: u16,
+        quantity: u16,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (inner, addr, timeout) = (self.inner.clone(), self.address.clone(), self.timeout);
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut slot = inner.lock().await;
+            let client = ensure_connected(&mut slot, &addr, timeout)
+                .await
+                .map_err(to_py_err)?;
+            client
+                .read_01(slave_id, address, quantity)
+                .await
+                .map_err(to_py_err)
+        })
+    }
+
+    /// Close the connection. A later request reconnects.
+    fn close<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            match inner.lock().await.take() {
+                Some(mut client) => client.close().await.map_err(to_py_err),
+                None => Ok(()),
+            }
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PyModbusTcpClient(addr='{}')", self.address)
+    }
+}
+
+/// Python wrapper around [`ModbusValue`].
+#[pyclass(module = "voltage_modbus", frozen)]
+#[derive(Clone)]
+pub struct PyModbusValue {
+    inner: ModbusValue,
+}
+
+impl From<ModbusValue> for PyModbusValue {
+    fn from(inner: ModbusValue) -> Self {
+        Self { inner }
+    }
+}
+
+#[pymethods]
+impl PyModbusValue {
+    /// Decode a value from raw registers.
+    ///
+    /// `data_type` uses the codec names (`"uint16"`, `"float32"`, ...) and
+    /// `byte_order` the [`ByteOrder::from_str`] names (`"ABCD"`, `"CDAB"`, ...).
+    #[staticmethod]
+    #[pyo3(signature = (registers, data_type, byte_order = "ABCD", bit_position = 0))]
+    fn from_registers(
+        registers: Vec<u16>,
+        data_type: &str,
+        byte_order: &str,
+        bit_position: u8,
+    ) -> PyResult<Self> {
+        let order = ByteOrder::from_str(byte_order)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown byte order: {byte_order}")))?;
+        decode_register_value(&registers, data_type, bit_position, order, None)
+            .map(Self::from)
+            .map_err(to_py_err)
+    }
+
+    /// Value as a Python `float`.
+    fn as_float(&self) -> f64 {
+        self.inner.as_f64()
+    }
+
+    /// Value as a Python `int` (floats are rounded to the nearest integer).
+    fn as_int(&self) -> i64 {
+        self.inner.as_i64()
+    }
+
+    /// Type name (e.g. `"f32"`).
+    #[getter]
+    fn type_name(&self) -> &'static str {
+        self.inner.type_name()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PyModbusValue({:?})", self.inner)
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+/// Python module entry point (`import voltage_modbus`).
+#[pymodule]
+#[pyo3(name = "voltage_modbus")]
+fn py_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyModbusTcpClient>()?;
+    m.add_class::<PyModbusValue>()?;
+    m.add("ModbusException", m.py().get_type::<ModbusException>())?;
+    m.add("__version__", crate::VERSION)?;
+    Ok(())
+}
@@ -0,0 +1,238 @@
+//! # Circuit Breaker
+//!
+//! A slave that has gone offline costs a full response timeout on every poll,
+//! and on a shared RS-485 bus that time is taken from every other device.
+//! [`CircuitBreaker`] stops sending to such a slave after `failure_threshold`
+//! consecutive failures:
+//!
+//! - **Closed** — requests flow normally; failures are counted.
+//! - **Open** — requests are rejected without touching the transport until
+//!   `reset_timeout` has passed.
+//! - **HalfOpen** — one probe request at a time is let through. A failed
+//!   probe opens the circuit again; `success_threshold` successful probes
+//!   close it. A probe whose caller gave up must be reported with
+//!   [`record_cancelled`](CircuitBreaker::record_cancelled), or no further
+//!   probe is admitted.
+//!
+//! [`GenericModbusClient::with_circuit_breaker`](crate::client::GenericModbusClient::with_circuit_breaker)
+//! keeps one breaker per slave and fails requests to an open circuit with
+//! [`ModbusError::CircuitOpen`](crate::error::ModbusError::CircuitOpen).
+//! Only link failures (timeouts, connection and I/O errors) count against a
+//! slave; a device exception proves the device is there.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use voltage_modbus::reliability::{CircuitBreaker, CircuitState};
+//!
+//! let mut breaker = CircuitBreaker::new(2, 1, Duration::from_secs(30));
+//! breaker.record_failure();
+//! breaker.record_failure();
+//! assert_eq!(breaker.state(), CircuitState::Open);
+//! assert!(!breaker.allow_request());
+//! ```
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// State of a [`CircuitBreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent.
+    Closed,
+    /// Requests are rejected until the reset timeout passes.
+    Open,
+    /// Probe requests are sent, one at a time.
+    HalfOpen,
+}
+
+/// Per-slave circuit breaker; see the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitBreaker {
+    /// Consecutive failures that open the circuit.
+    pub failure_threshold: u32,
+    /// Successful probes that close a half-open circuit.
+    pub success_threshold: u32,
+    /// Time an open circuit waits before letting a probe through.
+    pub reset_timeout: Duration,
+    state: CircuitState,
+    failures: u32,
+    successes: u32,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker
+    pub fn new(failure_threshold: u32, success_threshold: u32, reset_timeout: Duration) -> Self {
+        Self {
+            failure_threshold,
+            success_threshold,
+            reset_timeout,
+            state: CircuitState::Closed,
+            failures: 0,
+            successes: 0,
+            opened_at: None,
+            probe_in_flight: false,
+        }
+    }
+
+    /// Current state; an open circuit becomes half-open on the next
+    /// [`allow_request`](Self::allow_request) after `reset_timeout`.
+    pub fn state(&self) -> CircuitState {
+        self.state
+    }
+
+    /// Consecutive failures counted while closed
+    pub fn consecutive_failures(&self) -> u32 {
+        self.failures
+    }
+
+    /// Whether a request may be sent now.
+    ///
+    /// Once `reset_timeout` has passed, an open circuit turns half-open and
+    /// admits one probe; further requests are rejected until that probe's
+    /// outcome is recorded.
+    pub fn allow_request(&mut self) -> bool {
+        match self.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => {
+                if self
+                    .opened_at
+                    .is_some_and(|at| at.elapsed() < self.reset_timeout)
+                {
+                    return false;
+                }
+                self.state = CircuitState::HalfOpen;
+                self.successes = 0;
+                self.probe_in_flight = true;
+                true
+            }
+            CircuitState::HalfOpen => !std::mem::replace(&mut self.probe_in_flight, true),
+        }
+    }
+
+    /// Record a request that reached the device
+    pub fn record_success(&mut self) {
+        match self.state {
+            CircuitState::Closed => self.failures = 0,
+            CircuitState::HalfOpen => {
+                self.probe_in_flight = false;
+                self.successes += 1;
+                if self.successes >= self.success_threshold {
+                    self.close();
+                }
+            }
+            CircuitState::Open => {}
+        }
+    }
+
+    /// Record a request that did not reach the device
+    pub fn record_failure(&mut self) {
+        match self.state {
+            CircuitState::Closed => {
+                self.failures += 1;
+                if self.failures >= self.failure_threshold {
+                    self.open();
+                }
+            }
+            CircuitState::HalfOpen => self.open(),
+            CircuitState::Open => {}
+        }
+    }
+
+    /// Record a request whose outcome is unknown because the caller stopped
+    /// waiting for it (e.g. its future was dropped by a timeout). A half-open
+    /// circuit admits the next probe; nothing else changes.
+    pub fn record_cancelled(&mut self) {
+        if self.state == CircuitState::HalfOpen {
+            self.probe_in_flight = false;
+        }
+    }
+
+    /// Close the circuit and clear all counters
+    pub fn reset(&mut self) {
+        self.close();
+    }
+
+    fn open(&mut self) {
+        self.state = CircuitState::Open;
+        self.opened_at = Some(Instant::now());
+        self.probe_in_flight = false;
+    }
+
+    fn close(&mut self) {
+        self.state = CircuitState::Closed;
+        self.failures = 0;
+        self.successes = 0;
+        self.opened_at = None;
+        self.probe_in_flight = false;
+    }
+}
+
+impl Default for CircuitBreaker {
+    /// Open after 5 failures, probe every 30 s, close after 1 good probe.
+    fn default() -> Self {
+        Self::new(5, 1, Duration::from_secs(30))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn opens_probes_and_closes() {
+        let mut breaker = CircuitBreaker::new(3, 2, Duration::from_secs(10));
+        breaker.record_failure();
+        breaker.record_failure();
+        breaker.record_success();
+        assert_eq!(breaker.consecutive_failures(), 0);
+        for _ in 0..3 {
+            assert!(breaker.allow_request());
+            breaker.record_failure();
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow_request());
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert!(breaker.allow_request());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(!breaker.allow_request(), "only one probe at a time");
+        breaker.record_success();
+        assert!(breaker.allow_request());
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelled_probe_admits_the_next() {
+        let mut breaker = CircuitBreaker::new(1, 1, Duration::from_secs(5));
+        breaker.record_cancelled();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.record_failure();
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert!(breaker.allow_request());
+        assert!(!breaker.allow_request());
+        breaker.record_cancelled();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.allow_request());
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_probe_reopens() {
+        let mut breaker = CircuitBreaker::new(1, 1, Duration::from_secs(5));
+        breaker.record_failure();
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert!(breaker.allow_request());
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        tokio::time::advance(Duration::from_secs(4)).await;
+        assert!(!breaker.allow_request(), "reset timeout restarts on reopen");
+        breaker.reset();
+        assert!(breaker.allow_request());
+    }
+}
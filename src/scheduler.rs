@@ -77,6 +77,9 @@ mod tests {
             register_address: 0,
             data_type: "uint16",
             byte_order: ByteOrder::BigEndian,
+            scaling: None,
+            ttl: None,
+            priority: 0,
         };
         assert_eq!(sid(&cmd), 3);
         assert_eq!(fc(&cmd), 0x10);
@@ -1,10 +1,14 @@
 //! Modbus server implementations
 //!
 //! This module provides complete server-side implementations for both TCP and RTU protocols.
+//!
+//! The TCP server hands each decoded request to a [`RequestHandler`]. The
+//! default handler is a [`ModbusRegisterBank`]; pass your own to
+//! [`ModbusTcpServer::with_handler`] to serve computed or external data.
 
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::Duration;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::{TcpListener, TcpStream};
@@ -17,7 +21,7 @@ use tracing::{debug, error, info, warn};
 
 use crate::constants::{MAX_READ_COILS, MAX_READ_REGISTERS, MAX_WRITE_COILS, MAX_WRITE_REGISTERS};
 use crate::error::{ModbusError, ModbusResult};
-
+use crate::protocol::{data_utils, ModbusFunction, ModbusRequest, ModbusResponse};
 use crate::register_bank::{ModbusRegisterBank, RegisterBankStats};
 
 /// Maximum frame size for Modbus TCP
@@ -44,6 +48,365 @@ pub trait ModbusServer: Send + Sync {
     fn get_register_bank(&self) -> Option<Arc<ModbusRegisterBank>>;
 }
 
+/// Application logic behind a server
+///
+/// The server decodes each frame into a [`ModbusRequest`] and writes back
+/// whatever response the handler returns, exception responses included. An
+/// `Err` is answered with an exception as well: the code of a
+/// [`ModbusError::Exception`], 0x01 for an unknown function, 0x02 for a bad
+/// address, 0x03 for bad data and 0x04 otherwise.
+pub trait RequestHandler: Send + Sync + 'static {
+    /// Answer one request
+    fn handle_request(
+        &self,
+        request: &ModbusRequest,
+    ) -> impl std::future::Future<Output = ModbusResult<ModbusResponse>> + Send;
+}
+
+/// Serves FC01-FC06, FC0F and FC10 from the bank's contents
+impl RequestHandler for ModbusRegisterBank {
+    async fn handle_request(&self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        let address = request.address;
+        let quantity = request.quantity;
+        let data = match request.function {
+            ModbusFunction::ReadCoils => {
+                check_quantity(quantity, MAX_READ_COILS, "Invalid read coils quantity")?;
+                bits_payload(&self.read_01(address, quantity)?)
+            }
+            ModbusFunction::ReadDiscreteInputs => {
+                check_quantity(
+                    quantity,
+                    MAX_READ_COILS,
+                    "Invalid read discrete inputs quantity",
+                )?;
+                bits_payload(&self.read_02(address, quantity)?)
+            }
+            ModbusFunction::ReadHoldingRegisters => {
+                check_quantity(
+                    quantity,
+                    MAX_READ_REGISTERS,
+                    "Invalid read holding registers quantity",
+                )?;
+                registers_payload(&self.read_03(address, quantity)?)
+            }
+            ModbusFunction::ReadInputRegisters => {
+                check_quantity(
+                    quantity,
+                    MAX_READ_REGISTERS,
+                    "Invalid read input registers quantity",
+                )?;
+                registers_payload(&self.read_04(address, quantity)?)
+            }
+            ModbusFunction::WriteSingleCoil => {
+                let value = match request.data.as_slice() {
+                    [0xFF, 0x00] => true,
+                    [0x00, 0x00] => false,
+                    _ => return Err(ModbusError::invalid_data("Invalid coil write value")),
+                };
+                self.write_05(address, value)?;
+                write_echo(address, &request.data)
+            }
+            ModbusFunction::WriteSingleRegister => {
+                let [hi, lo] = request.data.as_slice() else {
+                    return Err(ModbusError::invalid_data("Invalid register write value"));
+                };
+                self.write_06(address, u16::from_be_bytes([*hi, *lo]))?;
+                write_echo(address, &request.data)
+            }
+            ModbusFunction::WriteMultipleCoils => {
+                check_quantity(
+                    quantity,
+                    MAX_WRITE_COILS,
+                    "Invalid write multiple coils quantity",
+                )?;
+                if request.data.len() != usize::from(quantity).div_ceil(8) {
+                    return Err(ModbusError::invalid_data("Coil byte count mismatch"));
+                }
+                let coils = data_utils::unpack_bits(&request.data, usize::from(quantity));
+                self.write_0f(address, &coils)?;
+                write_echo(address, &quantity.to_be_bytes())
+            }
+            ModbusFunction::WriteMultipleRegisters => {
+                check_quantity(
+                    quantity,
+                    MAX_WRITE_REGISTERS,
+                    "Invalid write multiple registers quantity",
+                )?;
+                if request.data.len() != usize::from(quantity) * 2 {
+                    return Err(ModbusError::invalid_data("Register byte count mismatch"));
+                }
+                let registers = data_utils::bytes_to_registers(&request.data)?;
+                self.write_10(address, &registers)?;
+                write_echo(address, &quantity.to_be_bytes())
+            }
+            function => {
+                warn!("Unsupported function code: 0x{:02X}", function.to_u8());
+                return Err(ModbusError::invalid_function(function.to_u8()));
+            }
+        };
+        Ok(ModbusResponse::new_success(
+            request.slave_id,
+            request.function,
+            data,
+        ))
+    }
+}
+
+fn check_quantity(quantity: u16, max: usize, message: &'static str) -> ModbusResult<()> {
+    if quantity == 0 || usize::from(quantity) > max {
+        return Err(ModbusError::invalid_data(message));
+    }
+    Ok(())
+}
+
+/// Byte count followed by the packed bits
+fn bits_payload(bits: &[bool]) -> Vec<u8> {
+    let packed = data_utils::pack_bits(bits);
+    let mut data = Vec::with_capacity(1 + packed.len());
+    data.push(packed.len() as u8);
+    data.extend_from_slice(&packed);
+    data
+}
+
+/// Byte count followed by the big-endian registers
+fn registers_payload(registers: &[u16]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(1 + registers.len() * 2);
+    data.push((registers.len() * 2) as u8);
+    data.extend_from_slice(&data_utils::registers_to_bytes(registers));
+    data
+}
+
+/// Address followed by the echoed value or quantity
+fn write_echo(address: u16, tail: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(2 + tail.len());
+    data.extend_from_slice(&address.to_be_bytes());
+    data.extend_from_slice(tail);
+    data
+}
+
+/// Number of addresses in each Modbus data table
+const ADDRESS_SPACE: usize = 1 << 16;
+
+/// In-memory data model covering the full address space of all four tables
+///
+/// Unlike [`ModbusRegisterBank`], every address exists up front (zero /
+/// `false`), so reads never fail for unset addresses. Share it between the
+/// server and application code as `Arc<RwLock<DataStore>>`, which is what
+/// implements [`RequestHandler`]:
+///
+/// ```rust,no_run
+/// use std::sync::{Arc, RwLock};
+/// use voltage_modbus::server::{DataStore, ModbusServer, ModbusTcpServer, ModbusTcpServerConfig};
+///
+/// # async fn example() -> voltage_modbus::ModbusResult<()> {
+/// let store = Arc::new(RwLock::new(DataStore::new()));
+/// store.write().unwrap().set_register(0, 1234);
+///
+/// let mut server = ModbusTcpServer::with_handler(ModbusTcpServerConfig::default(), store.clone());
+/// server.start().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataStore {
+    coils: Vec<bool>,
+    discrete_inputs: Vec<bool>,
+    holding_registers: Vec<u16>,
+    input_registers: Vec<u16>,
+}
+
+impl Default for DataStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataStore {
+    /// Create a store with all 65536 entries of every table cleared
+    pub fn new() -> Self {
+        Self {
+            coils: vec![false; ADDRESS_SPACE],
+            discrete_inputs: vec![false; ADDRESS_SPACE],
+            holding_registers: vec![0; ADDRESS_SPACE],
+            input_registers: vec![0; ADDRESS_SPACE],
+        }
+    }
+
+    /// Coil at `address`
+    pub fn get_coil(&self, address: u16) -> bool {
+        self.coils[usize::from(address)]
+    }
+
+    /// Set the coil at `address`
+    pub fn set_coil(&mut self, address: u16, value: bool) {
+        self.coils[usize::from(address)] = value;
+    }
+
+    /// Discrete input at `address`
+    pub fn get_discrete_input(&self, address: u16) -> bool {
+        self.discrete_inputs[usize::from(address)]
+    }
+
+    /// Set the discrete input at `address`
+    pub fn set_discrete_input(&mut self, address: u16, value: bool) {
+        self.discrete_inputs[usize::from(address)] = value;
+    }
+
+    /// Holding register at `address`
+    pub fn get_register(&self, address: u16) -> u16 {
+        self.holding_registers[usize::from(address)]
+    }
+
+    /// Set the holding register at `address`
+    pub fn set_register(&mut self, address: u16, value: u16) {
+        self.holding_registers[usize::from(address)] = value;
+    }
+
+    /// Set consecutive holding registers starting at `address`
+    ///
+    /// Fails without writing anything if the values run past address 65535.
+    pub fn set_registers(&mut self, address: u16, values: &[u16]) -> ModbusResult<()> {
+        let range = table_range(address, values.len())?;
+        self.holding_registers[range].copy_from_slice(values);
+        Ok(())
+    }
+
+    /// Input register at `address`
+    pub fn get_input_register(&self, address: u16) -> u16 {
+        self.input_registers[usize::from(address)]
+    }
+
+    /// Set the input register at `address`
+    pub fn set_input_register(&mut self, address: u16, value: u16) {
+        self.input_registers[usize::from(address)] = value;
+    }
+
+    /// Answer a read (FC01-FC04) from the current contents
+    fn read(&self, request: &ModbusRequest) -> ModbusResult<Vec<u8>> {
+        let quantity = request.quantity;
+        match request.function {
+            ModbusFunction::ReadCoils | ModbusFunction::ReadDiscreteInputs => {
+                check_quantity(quantity, MAX_READ_COILS, "Invalid read bits quantity")?;
+                let range = table_range(request.address, usize::from(quantity))?;
+                let table = if request.function == ModbusFunction::ReadCoils {
+                    &self.coils
+                } else {
+                    &self.discrete_inputs
+                };
+                Ok(bits_payload(&table[range]))
+            }
+            _ => {
+                check_quantity(
+                    quantity,
+                    MAX_READ_REGISTERS,
+                    "Invalid read registers quantity",
+                )?;
+                let range = table_range(request.address, usize::from(quantity))?;
+                let table = if request.function == ModbusFunction::ReadHoldingRegisters {
+                    &self.holding_registers
+                } else {
+                    &self.input_registers
+                };
+                Ok(registers_payload(&table[range]))
+            }
+        }
+    }
+
+    /// Apply a write (FC05, FC06, FC0F, FC10) and build the echo
+    fn write(&mut self, request: &ModbusRequest) -> ModbusResult<Vec<u8>> {
+        let address = request.address;
+        let quantity = request.quantity;
+        match request.function {
+            ModbusFunction::WriteSingleCoil => {
+                let value = match request.data.as_slice() {
+                    [0xFF, 0x00] => true,
+                    [0x00, 0x00] => false,
+                    _ => return Err(ModbusError::invalid_data("Invalid coil write value")),
+                };
+                self.set_coil(address, value);
+                Ok(write_echo(address, &request.data))
+            }
+            ModbusFunction::WriteSingleRegister => {
+                let [hi, lo] = request.data.as_slice() else {
+                    return Err(ModbusError::invalid_data("Invalid register write value"));
+                };
+                self.set_register(address, u16::from_be_bytes([*hi, *lo]));
+                Ok(write_echo(address, &request.data))
+            }
+            ModbusFunction::WriteMultipleCoils => {
+                check_quantity(
+                    quantity,
+                    MAX_WRITE_COILS,
+                    "Invalid write multiple coils quantity",
+                )?;
+                if request.data.len() != usize::from(quantity).div_ceil(8) {
+                    return Err(ModbusError::invalid_data("Coil byte count mismatch"));
+                }
+                let range = table_range(address, usize::from(quantity))?;
+                let coils = data_utils::unpack_bits(&request.data, usize::from(quantity));
+                self.coils[range].copy_from_slice(&coils);
+                Ok(write_echo(address, &quantity.to_be_bytes()))
+            }
+            _ => {
+                check_quantity(
+                    quantity,
+                    MAX_WRITE_REGISTERS,
+                    "Invalid write multiple registers quantity",
+                )?;
+                if request.data.len() != usize::from(quantity) * 2 {
+                    return Err(ModbusError::invalid_data("Register byte count mismatch"));
+                }
+                let registers = data_utils::bytes_to_registers(&request.data)?;
+                self.set_registers(address, &registers)?;
+                Ok(write_echo(address, &quantity.to_be_bytes()))
+            }
+        }
+    }
+}
+
+/// Serves FC01-FC06, FC0F and FC10; reads share the lock, writes take it
+/// exclusively
+impl RequestHandler for RwLock<DataStore> {
+    async fn handle_request(&self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        let data = match request.function {
+            ModbusFunction::ReadCoils
+            | ModbusFunction::ReadDiscreteInputs
+            | ModbusFunction::ReadHoldingRegisters
+            | ModbusFunction::ReadInputRegisters => {
+                self.read().map_err(|_| poisoned())?.read(request)?
+            }
+            ModbusFunction::WriteSingleCoil
+            | ModbusFunction::WriteSingleRegister
+            | ModbusFunction::WriteMultipleCoils
+            | ModbusFunction::WriteMultipleRegisters => {
+                self.write().map_err(|_| poisoned())?.write(request)?
+            }
+            function => return Err(ModbusError::invalid_function(function.to_u8())),
+        };
+        Ok(ModbusResponse::new_success(
+            request.slave_id,
+            request.function,
+            data,
+        ))
+    }
+}
+
+fn poisoned() -> ModbusError {
+    ModbusError::internal("Data store lock poisoned")
+}
+
+/// Index range of `count` entries from `address`, if it fits the table
+fn table_range(address: u16, count: usize) -> ModbusResult<std::ops::Range<usize>> {
+    let start = usize::from(address);
+    if start + count > ADDRESS_SPACE {
+        return Err(ModbusError::invalid_address(
+            address,
+            u16::try_from(count).unwrap_or(u16::MAX),
+        ));
+    }
+    Ok(start..start + count)
+}
+
 /// Server statistics
 #[derive(Debug, Clone, Default)]
 pub struct ServerStats {
@@ -78,13 +441,18 @@ impl Default for ModbusTcpServerConfig {
 }
 
 /// Modbus TCP server implementation
-pub struct ModbusTcpServer {
+///
+/// Requests are decoded into [`ModbusRequest`]s and answered by a
+/// [`RequestHandler`]; by default a [`ModbusRegisterBank`].
+pub struct ModbusTcpServer<H: RequestHandler = ModbusRegisterBank> {
     config: ModbusTcpServerConfig,
-    register_bank: Arc<ModbusRegisterBank>,
+    handler: Arc<H>,
+    register_bank: Option<Arc<ModbusRegisterBank>>,
     stats: Arc<Mutex<ServerStats>>,
     shutdown_tx: Option<broadcast::Sender<()>>,
     is_running: Arc<AtomicBool>,
     start_time: Option<std::time::Instant>,
+    local_addr: Option<SocketAddr>,
 }
 
 impl ModbusTcpServer {
@@ -109,25 +477,52 @@ impl ModbusTcpServer {
             .clone()
             .unwrap_or_else(|| Arc::new(ModbusRegisterBank::new()));
 
-        Ok(Self {
+        let mut server = Self::with_handler(config, register_bank.clone());
+        server.register_bank = Some(register_bank);
+        Ok(server)
+    }
+
+    /// Set custom register bank
+    pub fn set_register_bank(&mut self, register_bank: Arc<ModbusRegisterBank>) {
+        self.handler = register_bank.clone();
+        self.register_bank = Some(register_bank);
+    }
+}
+
+impl<H: RequestHandler> ModbusTcpServer<H> {
+    /// Create a TCP server that answers every request through `handler`
+    ///
+    /// `config.register_bank` is not used; the handler owns the data, and
+    /// [`get_register_bank`](ModbusServer::get_register_bank) returns `None`.
+    pub fn with_handler(config: ModbusTcpServerConfig, handler: Arc<H>) -> Self {
+        Self {
             config,
-            register_bank,
+            handler,
+            register_bank: None,
             stats: Arc::new(Mutex::new(ServerStats::default())),
             shutdown_tx: None,
             is_running: Arc::new(AtomicBool::new(false)),
             start_time: None,
-        })
+            local_addr: None,
+        }
     }
 
-    /// Set custom register bank
-    pub fn set_register_bank(&mut self, register_bank: Arc<ModbusRegisterBank>) {
-        self.register_bank = register_bank;
+    /// The address the listener is bound to, once started
+    ///
+    /// Differs from `config.bind_address` when that asks for port 0.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    /// The handler answering requests
+    pub fn handler(&self) -> &Arc<H> {
+        &self.handler
     }
 
     /// Handle client connection
     async fn handle_client(
         mut stream: TcpStream,
-        register_bank: Arc<ModbusRegisterBank>,
+        handler: Arc<H>,
         stats: Arc<Mutex<ServerStats>>,
         mut shutdown_rx: broadcast::Receiver<()>,
         request_timeout: Duration,
@@ -161,7 +556,7 @@ impl ModbusTcpServer {
                             }
 
                             // Process request
-                            match Self::handle_request(&frame, &register_bank).await {
+                            match Self::handle_request(&frame, &handler).await {
                                 Ok(response_data) => {
                                     if let Err(e) = stream.write_all(&response_data).await {
                                         error!("Failed to send response to {}: {}", peer_addr, e);
@@ -243,10 +638,7 @@ impl ModbusTcpServer {
     }
 
     /// Process Modbus request
-    async fn handle_request(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
+    async fn handle_request(data: &[u8], handler: &Arc<H>) -> ModbusResult<Vec<u8>> {
         if data.len() < MBAP_HEADER_SIZE + 2 {
             return Err(ModbusError::frame("Invalid TCP frame length"));
         }
@@ -267,27 +659,12 @@ impl ModbusTcpServer {
         }
 
         let unit_id = data[6];
-        let function_code = data[7];
-        let pdu_data = &data[8..];
-
-        debug!("Processing function code: 0x{:02X}", function_code);
-
-        let pdu_response = match function_code {
-            0x01 => Self::handle_read_01(pdu_data, register_bank).await,
-            0x02 => Self::handle_read_02(pdu_data, register_bank).await,
-            0x03 => Self::handle_read_03(pdu_data, register_bank).await,
-            0x04 => Self::handle_read_04(pdu_data, register_bank).await,
-            0x05 => Self::handle_write_05(pdu_data, register_bank).await,
-            0x06 => Self::handle_write_06(pdu_data, register_bank).await,
-            0x0F => Self::handle_write_0f(pdu_data, register_bank).await,
-            0x10 => Self::handle_write_10(pdu_data, register_bank).await,
-            _ => {
-                warn!("Unsupported function code: 0x{:02X}", function_code);
-                Err(ModbusError::invalid_function(function_code))
-            }
-        }?;
+        debug!("Processing function code: 0x{:02X}", data[7]);
 
-        Self::create_success_response(transaction_id, unit_id, &pdu_response)
+        let request = ModbusRequest::from_pdu(unit_id, &data[MBAP_HEADER_SIZE + 1..])?;
+        let response = handler.handle_request(&request).await?;
+
+        Self::create_success_response(transaction_id, unit_id, &response.to_pdu())
     }
 
     fn create_success_response(
@@ -312,6 +689,7 @@ impl ModbusTcpServer {
 
     fn exception_code_for_error(error: &ModbusError) -> u8 {
         match error {
+            ModbusError::Exception { code, .. } => *code,
             ModbusError::InvalidFunction { .. } => 0x01,
             ModbusError::InvalidAddress { .. } => 0x02,
             ModbusError::InvalidData { .. } | ModbusError::Frame { .. } => 0x03,
@@ -319,287 +697,6 @@ impl ModbusTcpServer {
         }
     }
 
-    /// Handle read coils (0x01)
-    async fn handle_read_01(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        if data.len() != 4 {
-            return Err(ModbusError::frame("Invalid read coils request"));
-        }
-
-        let address = u16::from_be_bytes([data[0], data[1]]);
-        let quantity = u16::from_be_bytes([data[2], data[3]]);
-        if quantity == 0 || usize::from(quantity) > MAX_READ_COILS {
-            return Err(ModbusError::invalid_data("Invalid read coils quantity"));
-        }
-
-        let coils = register_bank.read_01(address, quantity)?;
-
-        // Pack coils into bytes
-        let byte_count = (quantity as usize).div_ceil(8);
-        // Pre-allocate: function_code(1) + byte_count(1) + data(byte_count)
-        let mut response = Vec::with_capacity(2 + byte_count);
-        response.push(0x01);
-        response.push(
-            u8::try_from(byte_count)
-                .map_err(|_| ModbusError::invalid_data("Read coils response too large"))?,
-        );
-
-        for chunk in coils.chunks(8) {
-            let mut byte = 0u8;
-            for (i, &coil) in chunk.iter().enumerate() {
-                if coil {
-                    byte |= 1 << i;
-                }
-            }
-            response.push(byte);
-        }
-
-        Ok(response)
-    }
-
-    /// Handle read discrete inputs (0x02)
-    async fn handle_read_02(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        if data.len() != 4 {
-            return Err(ModbusError::frame("Invalid read discrete inputs request"));
-        }
-
-        let address = u16::from_be_bytes([data[0], data[1]]);
-        let quantity = u16::from_be_bytes([data[2], data[3]]);
-        if quantity == 0 || usize::from(quantity) > MAX_READ_COILS {
-            return Err(ModbusError::invalid_data(
-                "Invalid read discrete inputs quantity",
-            ));
-        }
-
-        let inputs = register_bank.read_02(address, quantity)?;
-
-        // Pack inputs into bytes
-        let byte_count = (quantity as usize).div_ceil(8);
-        // Pre-allocate: function_code(1) + byte_count(1) + data(byte_count)
-        let mut response = Vec::with_capacity(2 + byte_count);
-        response.push(0x02);
-        response.push(
-            u8::try_from(byte_count).map_err(|_| {
-                ModbusError::invalid_data("Read discrete inputs response too large")
-            })?,
-        );
-
-        for chunk in inputs.chunks(8) {
-            let mut byte = 0u8;
-            for (i, &input) in chunk.iter().enumerate() {
-                if input {
-                    byte |= 1 << i;
-                }
-            }
-            response.push(byte);
-        }
-
-        Ok(response)
-    }
-
-    /// Handle read holding registers (0x03)
-    async fn handle_read_03(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        if data.len() != 4 {
-            return Err(ModbusError::frame("invalid frame"));
-        }
-
-        let address = u16::from_be_bytes([data[0], data[1]]);
-        let quantity = u16::from_be_bytes([data[2], data[3]]);
-        if quantity == 0 || usize::from(quantity) > MAX_READ_REGISTERS {
-            return Err(ModbusError::invalid_data(
-                "Invalid read holding registers quantity",
-            ));
-        }
-
-        let registers = register_bank.read_03(address, quantity)?;
-
-        // Pre-allocate: function_code(1) + byte_count(1) + data(quantity*2)
-        let data_len = (quantity as usize) * 2;
-        let mut response = Vec::with_capacity(2 + data_len);
-        response.push(0x03);
-        response.push(
-            u8::try_from(data_len).map_err(|_| {
-                ModbusError::invalid_data("Read holding registers response too large")
-            })?,
-        );
-        for &register in &registers {
-            response.extend_from_slice(&register.to_be_bytes());
-        }
-
-        Ok(response)
-    }
-
-    /// Handle read input registers (0x04)
-    async fn handle_read_04(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        if data.len() != 4 {
-            return Err(ModbusError::frame("invalid frame"));
-        }
-
-        let address = u16::from_be_bytes([data[0], data[1]]);
-        let quantity = u16::from_be_bytes([data[2], data[3]]);
-        if quantity == 0 || usize::from(quantity) > MAX_READ_REGISTERS {
-            return Err(ModbusError::invalid_data(
-                "Invalid read input registers quantity",
-            ));
-        }
-
-        let registers = register_bank.read_04(address, quantity)?;
-
-        // Pre-allocate: function_code(1) + byte_count(1) + data(quantity*2)
-        let data_len = (quantity as usize) * 2;
-        let mut response = Vec::with_capacity(2 + data_len);
-        response.push(0x04);
-        response.push(
-            u8::try_from(data_len).map_err(|_| {
-                ModbusError::invalid_data("Read input registers response too large")
-            })?,
-        );
-        for &register in &registers {
-            response.extend_from_slice(&register.to_be_bytes());
-        }
-
-        Ok(response)
-    }
-
-    /// Handle write single coil (0x05)
-    async fn handle_write_05(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        if data.len() != 4 {
-            return Err(ModbusError::frame("invalid frame"));
-        }
-
-        let address = u16::from_be_bytes([data[0], data[1]]);
-        let value_bytes = u16::from_be_bytes([data[2], data[3]]);
-        if value_bytes != 0xFF00 && value_bytes != 0x0000 {
-            return Err(ModbusError::invalid_data("Invalid coil write value"));
-        }
-        let coil_value = value_bytes == 0xFF00;
-
-        register_bank.write_05(address, coil_value)?;
-
-        let mut response = vec![0x05];
-        response.extend_from_slice(&address.to_be_bytes());
-        response.extend_from_slice(&value_bytes.to_be_bytes());
-        Ok(response)
-    }
-
-    /// Handle write single register (0x06)
-    async fn handle_write_06(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        if data.len() != 4 {
-            return Err(ModbusError::frame("invalid frame"));
-        }
-
-        let address = u16::from_be_bytes([data[0], data[1]]);
-        let value = u16::from_be_bytes([data[2], data[3]]);
-
-        register_bank.write_06(address, value)?;
-
-        let mut response = vec![0x06];
-        response.extend_from_slice(&address.to_be_bytes());
-        response.extend_from_slice(&value.to_be_bytes());
-        Ok(response)
-    }
-
-    /// Handle write multiple coils (0x0F)
-    async fn handle_write_0f(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        if data.len() < 5 {
-            return Err(ModbusError::frame("invalid frame"));
-        }
-
-        let address = u16::from_be_bytes([data[0], data[1]]);
-        let quantity = u16::from_be_bytes([data[2], data[3]]);
-        let byte_count = data[4] as usize;
-        if quantity == 0 || usize::from(quantity) > MAX_WRITE_COILS {
-            return Err(ModbusError::invalid_data(
-                "Invalid write multiple coils quantity",
-            ));
-        }
-        if byte_count != usize::from(quantity).div_ceil(8) {
-            return Err(ModbusError::frame("invalid frame"));
-        }
-
-        if data.len() != 5 + byte_count {
-            return Err(ModbusError::frame("invalid frame"));
-        }
-
-        // Pre-allocate coils vector
-        let mut coils = Vec::with_capacity(quantity as usize);
-        for i in 0..quantity {
-            let byte_index = 5 + (i / 8) as usize;
-            let bit_index = i % 8;
-            let bit_value = (data[byte_index] & (1 << bit_index)) != 0;
-            coils.push(bit_value);
-        }
-
-        register_bank.write_0f(address, &coils)?;
-
-        // Pre-allocate response: function_code(1) + address(2) + quantity(2) = 5
-        let mut response = Vec::with_capacity(5);
-        response.push(0x0F);
-        response.extend_from_slice(&address.to_be_bytes());
-        response.extend_from_slice(&quantity.to_be_bytes());
-        Ok(response)
-    }
-
-    /// Handle write multiple registers (0x10)
-    async fn handle_write_10(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        if data.len() < 5 {
-            return Err(ModbusError::frame("invalid frame"));
-        }
-
-        let address = u16::from_be_bytes([data[0], data[1]]);
-        let quantity = u16::from_be_bytes([data[2], data[3]]);
-        let byte_count = data[4] as usize;
-        if quantity == 0 || usize::from(quantity) > MAX_WRITE_REGISTERS {
-            return Err(ModbusError::invalid_data(
-                "Invalid write multiple registers quantity",
-            ));
-        }
-
-        if data.len() != 5 + byte_count || byte_count != (quantity as usize * 2) {
-            return Err(ModbusError::frame("invalid frame"));
-        }
-
-        // Pre-allocate registers vector
-        let mut registers = Vec::with_capacity(quantity as usize);
-        for i in 0..quantity {
-            let byte_offset = 5 + (i as usize * 2);
-            let value = u16::from_be_bytes([data[byte_offset], data[byte_offset + 1]]);
-            registers.push(value);
-        }
-
-        register_bank.write_10(address, &registers)?;
-
-        // Pre-allocate response: function_code(1) + address(2) + quantity(2) = 5
-        let mut response = Vec::with_capacity(5);
-        response.push(0x10);
-        response.extend_from_slice(&address.to_be_bytes());
-        response.extend_from_slice(&quantity.to_be_bytes());
-        Ok(response)
-    }
-
     /// Create error response
     fn create_error_response(request: &[u8], exception_code: u8) -> ModbusResult<Vec<u8>> {
         if request.len() < MBAP_HEADER_SIZE + 2 {
@@ -628,7 +725,7 @@ impl ModbusTcpServer {
     }
 }
 
-impl ModbusServer for ModbusTcpServer {
+impl<H: RequestHandler> ModbusServer for ModbusTcpServer<H> {
     async fn start(&mut self) -> ModbusResult<()> {
         if self.is_running.load(Ordering::Relaxed) {
             return Err(ModbusError::protocol("Server is already running"));
@@ -647,6 +744,7 @@ impl ModbusServer for ModbusTcpServer {
                     self.config.bind_address, e
                 ))
             })?;
+        self.local_addr = listener.local_addr().ok();
 
         let (shutdown_tx, _) = broadcast::channel(1);
         self.shutdown_tx = Some(shutdown_tx.clone());
@@ -658,7 +756,7 @@ impl ModbusServer for ModbusTcpServer {
         info!("   - Max connections: {}", self.config.max_connections);
         info!("   - Request timeout: {:?}", self.config.request_timeout);
 
-        let register_bank = self.register_bank.clone();
+        let handler = self.handler.clone();
         let stats = self.stats.clone();
         let request_timeout = self.config.request_timeout;
         let connection_limit = Arc::new(Semaphore::new(self.config.max_connections));
@@ -683,13 +781,13 @@ impl ModbusServer for ModbusTcpServer {
                                     }
                                 };
 
-                                let register_bank = register_bank.clone();
+                                let handler = handler.clone();
                                 let stats = stats.clone();
                                 let shutdown_rx = shutdown_tx.subscribe();
 
                                 tokio::spawn(async move {
                                     let _permit = permit;
-                                    Self::handle_client(stream, register_bank, stats, shutdown_rx, request_timeout).await;
+                                    Self::handle_client(stream, handler, stats, shutdown_rx, request_timeout).await;
                                 });
                             }
                             Err(e) => {
@@ -736,12 +834,12 @@ impl ModbusServer for ModbusTcpServer {
             stats.uptime_seconds = start_time.elapsed().as_secs();
         }
 
-        stats.register_bank_stats = Some(self.register_bank.get_stats());
+        stats.register_bank_stats = self.register_bank.as_ref().map(|bank| bank.get_stats());
         stats
     }
 
     fn get_register_bank(&self) -> Option<Arc<ModbusRegisterBank>> {
-        Some(self.register_bank.clone())
+        self.register_bank.clone()
     }
 }
 
@@ -835,33 +933,20 @@ impl ModbusRtuServer {
         }
 
         let slave_id = data[0];
-        let function_code = data[1];
-        let pdu_data = &data[2..data.len() - 2]; // Remove CRC
+        let pdu = &data[1..data.len() - 2]; // Remove CRC
 
         // Verify slave ID matches (or is broadcast)
         if slave_id != 0 && slave_id != 1 {
             return Err(ModbusError::device_not_responding(slave_id));
         }
 
-        let response_pdu = match function_code {
-            0x01 => Self::handle_read_01(pdu_data, &self.register_bank).await?,
-            0x02 => Self::handle_read_02(pdu_data, &self.register_bank).await?,
-            0x03 => Self::handle_read_03(pdu_data, &self.register_bank).await?,
-            0x04 => Self::handle_read_04(pdu_data, &self.register_bank).await?,
-            0x05 => Self::handle_write_05(pdu_data, &self.register_bank).await?,
-            0x06 => Self::handle_write_06(pdu_data, &self.register_bank).await?,
-            0x0F => Self::handle_write_0f(pdu_data, &self.register_bank).await?,
-            0x10 => Self::handle_write_10(pdu_data, &self.register_bank).await?,
-            _ => {
-                return Err(ModbusError::invalid_function(function_code));
-            }
-        };
-
-        // Build RTU response: slave_id + function_code + response_data
-        let mut response = vec![slave_id, function_code];
-        response.extend_from_slice(&response_pdu[1..]); // Skip function code from PDU
+        let request = ModbusRequest::from_pdu(slave_id, pdu)?;
+        let response = self.register_bank.handle_request(&request).await?;
 
-        Ok(response)
+        // Build RTU response: slave_id + PDU
+        let mut frame = vec![slave_id];
+        frame.extend_from_slice(&response.to_pdu());
+        Ok(frame)
     }
 
     /// Create RTU error response
@@ -1005,63 +1090,6 @@ impl ModbusRtuServer {
             }
         }
     }
-
-    // Reuse the same handler methods from TCP server
-    async fn handle_read_01(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        ModbusTcpServer::handle_read_01(data, register_bank).await
-    }
-
-    async fn handle_read_02(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        ModbusTcpServer::handle_read_02(data, register_bank).await
-    }
-
-    async fn handle_read_03(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        ModbusTcpServer::handle_read_03(data, register_bank).await
-    }
-
-    async fn handle_read_04(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        ModbusTcpServer::handle_read_04(data, register_bank).await
-    }
-
-    async fn handle_write_05(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        ModbusTcpServer::handle_write_05(data, register_bank).await
-    }
-
-    async fn handle_write_06(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        ModbusTcpServer::handle_write_06(data, register_bank).await
-    }
-
-    async fn handle_write_0f(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        ModbusTcpServer::handle_write_0f(data, register_bank).await
-    }
-
-    async fn handle_write_10(
-        data: &[u8],
-        register_bank: &Arc<ModbusRegisterBank>,
-    ) -> ModbusResult<Vec<u8>> {
-        ModbusTcpServer::handle_write_10(data, register_bank).await
-    }
 }
 
 /// Modbus RTU server implementation
@@ -1160,8 +1188,6 @@ impl ModbusServer for ModbusRtuServer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[cfg(feature = "rtu")]
-    use std::time::Duration;
 
     #[test]
     fn test_tcp_server_creation() {
@@ -1251,6 +1277,132 @@ mod tests {
         assert!(matches!(err, ModbusError::Frame { .. }));
     }
 
+    /// Answers FC03 with the register address as value; everything else
+    /// is refused with exception 0x01 through an exception response.
+    struct AddressEcho;
+
+    impl RequestHandler for AddressEcho {
+        async fn handle_request(&self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+            if request.function != ModbusFunction::ReadHoldingRegisters {
+                return Ok(ModbusResponse::new_exception(
+                    request.slave_id,
+                    request.function,
+                    0x01,
+                ));
+            }
+            let registers: Vec<u16> = (request.address..)
+                .take(usize::from(request.quantity))
+                .collect();
+            let mut data = vec![(registers.len() * 2) as u8];
+            data.extend_from_slice(&data_utils::registers_to_bytes(&registers));
+            Ok(ModbusResponse::new_success(
+                request.slave_id,
+                request.function,
+                data,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tcp_handle_request_uses_custom_handler() {
+        let handler = Arc::new(AddressEcho);
+        let request = [
+            0x00, 0x07, 0x00, 0x00, 0x00, 0x06, 0x11, 0x05, 0x00, 0x01, 0xFF, 0x00,
+        ];
+        let response = ModbusTcpServer::handle_request(&request, &handler)
+            .await
+            .unwrap();
+        assert_eq!(
+            response,
+            [0x00, 0x07, 0x00, 0x00, 0x00, 0x03, 0x11, 0x85, 0x01]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tcp_server_serves_concurrent_clients() {
+        use crate::client::{ModbusClient, ModbusTcpClient};
+
+        let config = ModbusTcpServerConfig {
+            bind_address: "127.0.0.1:0".parse().unwrap(),
+            ..Default::default()
+        };
+        let mut server = ModbusTcpServer::with_handler(config, Arc::new(AddressEcho));
+        server.start().await.unwrap();
+        let address = server.local_addr().unwrap();
+        assert!(server.get_register_bank().is_none());
+
+        let mut first = ModbusTcpClient::new(address, Duration::from_secs(1))
+            .await
+            .unwrap();
+        let mut second = ModbusTcpClient::new(address, Duration::from_secs(1))
+            .await
+            .unwrap();
+        let (a, b) = tokio::join!(first.read_03(1, 100, 3), second.read_03(2, 7, 1));
+        assert_eq!(a.unwrap(), [100, 101, 102]);
+        assert_eq!(b.unwrap(), [7]);
+        assert!(matches!(
+            first.write_06(1, 0, 1).await,
+            Err(ModbusError::Exception { code: 0x01, .. })
+        ));
+
+        server.stop().await.unwrap();
+        assert_eq!(server.get_stats().connections_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_data_store_shared_with_server() {
+        use crate::client::{ModbusClient, ModbusTcpClient};
+
+        let store = Arc::new(RwLock::new(DataStore::new()));
+        {
+            let mut seed = store.write().unwrap();
+            seed.set_coil(3, true);
+            seed.set_input_register(65535, 0xBEEF);
+            seed.set_registers(10, &[1, 2, 3]).unwrap();
+        }
+
+        let config = ModbusTcpServerConfig {
+            bind_address: "127.0.0.1:0".parse().unwrap(),
+            ..Default::default()
+        };
+        let mut server = ModbusTcpServer::with_handler(config, store.clone());
+        server.start().await.unwrap();
+        let address = server.local_addr().unwrap();
+        let mut client = ModbusTcpClient::new(address, Duration::from_secs(1))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            client.read_01(1, 0, 4).await.unwrap(),
+            [false, false, false, true]
+        );
+        assert_eq!(client.read_04(1, 65535, 1).await.unwrap(), [0xBEEF]);
+        let past_end = ModbusRequest::new_read(1, ModbusFunction::ReadInputRegisters, 65535, 2);
+        assert!(matches!(
+            store.handle_request(&past_end).await,
+            Err(ModbusError::InvalidAddress { .. })
+        ));
+
+        client.write_10(1, 11, &[20, 30]).await.unwrap();
+        client.write_0f(1, 0, &[true; 9]).await.unwrap();
+        {
+            let store = store.read().unwrap();
+            assert_eq!(store.get_register(10), 1);
+            assert_eq!(store.get_register(12), 30);
+            assert!(store.get_coil(8) && !store.get_coil(9));
+        }
+
+        // Application updates are visible to the next poll
+        store.write().unwrap().set_register(10, 99);
+        assert_eq!(client.read_03(1, 10, 3).await.unwrap(), [99, 20, 30]);
+        assert!(matches!(
+            client.read_exception_status(1).await,
+            Err(ModbusError::Exception { code: 0x01, .. })
+        ));
+
+        server.stop().await.unwrap();
+    }
+
     #[cfg(feature = "rtu")]
     #[test]
     fn test_rtu_server_creation() {
@@ -0,0 +1,480 @@
+//! # Register Snapshots
+//!
+//! A [`RegisterSnapshot`] is a set of typed register values read from one
+//! slave together with when and how they were captured. Derived quantities
+//! (power = voltage × current) are only trustworthy when their inputs come
+//! from the same instant, so the snapshot records whether every value was
+//! read by a single Modbus request ([`RegisterSnapshot::is_consistent`]).
+//!
+//! Snapshots are taken with
+//! [`GenericModbusClient::read_snapshot`](crate::GenericModbusClient::read_snapshot),
+//! which reads the smallest span of holding registers covering all
+//! [`RegisterDescriptor`]s in as few requests as [`DeviceLimits`] allow.
+//!
+//! ```rust
+//! use voltage_modbus::snapshot::RegisterDescriptor;
+//! use voltage_modbus::testing::EchoTransport;
+//! use voltage_modbus::{DeviceLimits, GenericModbusClient};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> voltage_modbus::ModbusResult<()> {
+//! let voltage = RegisterDescriptor::new(0, "float32");
+//! let current = RegisterDescriptor::new(2, "float32");
+//!
+//! let mut client = GenericModbusClient::new(EchoTransport::new());
+//! let snapshot = client
+//!     .read_snapshot(1, &[&voltage, &current], &DeviceLimits::new())
+//!     .await?;
+//! assert!(snapshot.is_consistent);
+//! assert_eq!(snapshot.values.len(), 2);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Change detection
+//!
+//! [`RegisterSnapshot::diff`] lists the raw registers that changed between two
+//! snapshots. Plain `read_03`/`read_03_batch` results become snapshots through
+//! [`RegisterSnapshot::from_registers`], and a [`SnapshotStore`] keeps the
+//! last few snapshots per slave so each poll can be compared with the one
+//! before it:
+//!
+//! ```rust
+//! use voltage_modbus::snapshot::{RegisterDiff, RegisterSnapshot, SnapshotStore};
+//!
+//! let mut store = SnapshotStore::new(8);
+//! store.record(RegisterSnapshot::from_registers(1, 100, &[10, 20, 30]));
+//! store.record(RegisterSnapshot::from_registers(1, 100, &[10, 21, 30]));
+//! assert_eq!(
+//!     store.last_diff(1),
+//!     vec![RegisterDiff { address: 101, old: 20, new: 21 }]
+//! );
+//! ```
+//!
+//! [`DeviceLimits`]: crate::DeviceLimits
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::bytes::ByteOrder;
+use crate::codec::{decode_register_value, registers_for_type};
+use crate::error::ModbusResult;
+use crate::map::Access;
+use crate::protocol::SlaveId;
+use crate::utils::EngineeringScaler;
+use crate::value::ModbusValue;
+
+/// Location and data type of one value in the holding-register table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisterDescriptor {
+    /// Point name (tag) used when exporting the value.
+    pub name: Option<String>,
+    /// First register address.
+    pub address: u16,
+    /// Codec data type name (`"uint16"`, `"float32"`, ...).
+    pub data_type: String,
+    /// Byte order for multi-register types.
+    pub byte_order: ByteOrder,
+    /// Bit to extract for `"bool"` values (0-15, LSB = 0).
+    pub bit_position: u8,
+    /// Conversion from the decoded value to engineering units.
+    pub scaler: Option<EngineeringScaler>,
+    /// Whether [`RegisterMap`](crate::map::RegisterMap) may read or write it.
+    pub access: Access,
+}
+
+impl RegisterDescriptor {
+    /// Describe a big-endian value of `data_type` starting at `address`.
+    pub fn new(address: u16, data_type: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            address,
+            data_type: data_type.into(),
+            byte_order: ByteOrder::default(),
+            bit_position: 0,
+            scaler: None,
+            access: Access::ReadWrite,
+        }
+    }
+
+    /// Set the point name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the byte order.
+    pub fn with_byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    /// Set the bit extracted for `"bool"` values.
+    pub fn with_bit_position(mut self, bit_position: u8) -> Self {
+        self.bit_position = bit_position;
+        self
+    }
+
+    /// Set the engineering unit conversion.
+    pub fn with_scaler(mut self, scaler: EngineeringScaler) -> Self {
+        self.scaler = Some(scaler);
+        self
+    }
+
+    /// Set the access direction.
+    pub fn with_access(mut self, access: Access) -> Self {
+        self.access = access;
+        self
+    }
+
+    /// Number of registers the value occupies (bools occupy one register).
+    pub fn register_count(&self) -> u16 {
+        registers_for_type(&self.data_type).max(1) as u16
+    }
+
+    /// One past the last register address (as `u32` so it cannot overflow).
+    pub fn end_address(&self) -> u32 {
+        u32::from(self.address) + u32::from(self.register_count())
+    }
+
+    /// Decode this value from `registers`, a block read starting at `block_start`.
+    pub(crate) fn decode(&self, block_start: u16, registers: &[u16]) -> ModbusResult<ModbusValue> {
+        let offset = usize::from(self.address - block_start);
+        let end = offset + usize::from(self.register_count());
+        decode_register_value(
+            &registers[offset..end],
+            &self.data_type,
+            self.bit_position,
+            self.byte_order,
+            None,
+        )
+    }
+}
+
+/// Typed values read from one slave, with capture timing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisterSnapshot {
+    /// `(address, value)` pairs in descriptor order.
+    pub values: Vec<(u16, ModbusValue)>,
+    /// Raw `(address, register)` words behind `values`, in address order.
+    pub registers: Vec<(u16, u16)>,
+    /// When the first request of the snapshot was sent.
+    pub captured_at: Instant,
+    /// Slave the values were read from.
+    pub slave_id: SlaveId,
+    /// `true` if every value came from a single Modbus request.
+    pub is_consistent: bool,
+    /// Total time spent reading, including inter-request delays.
+    pub read_duration: Duration,
+}
+
+/// One register that differs between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisterDiff {
+    /// Register address.
+    pub address: u16,
+    /// Value in the earlier snapshot.
+    pub old: u16,
+    /// Value in the later snapshot.
+    pub new: u16,
+}
+
+impl RegisterSnapshot {
+    /// Snapshot of raw registers read from `start_address` on, e.g. the
+    /// result of `read_03` or `read_03_batch`, captured now.
+    ///
+    /// The snapshot has no typed `values`; addresses past 0xFFFF are dropped.
+    pub fn from_registers(slave_id: SlaveId, start_address: u16, values: &[u16]) -> Self {
+        Self {
+            values: Vec::new(),
+            registers: (start_address..=u16::MAX)
+                .zip(values.iter().copied())
+                .collect(),
+            captured_at: Instant::now(),
+            slave_id,
+            is_consistent: true,
+            read_duration: Duration::ZERO,
+        }
+    }
+
+    /// Registers whose value in `other` differs from this snapshot, in
+    /// address order.
+    ///
+    /// Only addresses present in both snapshots are compared.
+    pub fn diff(&self, other: &RegisterSnapshot) -> Vec<RegisterDiff> {
+        let old: HashMap<u16, u16> = self.registers.iter().copied().collect();
+        let mut diffs: Vec<RegisterDiff> = other
+            .registers
+            .iter()
+            .filter_map(|&(address, new)| {
+                let old = *old.get(&address)?;
+                (old != new).then_some(RegisterDiff { address, old, new })
+            })
+            .collect();
+        diffs.sort_unstable_by_key(|diff| diff.address);
+        diffs
+    }
+
+    /// Value read from `address`, if it is part of the snapshot.
+    pub fn get(&self, address: u16) -> Option<&ModbusValue> {
+        self.values
+            .iter()
+            .find(|(addr, _)| *addr == address)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The most recent snapshots of each slave, oldest dropped first.
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    capacity: usize,
+    history: HashMap<SlaveId, VecDeque<RegisterSnapshot>>,
+}
+
+impl SnapshotStore {
+    /// Keep up to `capacity` snapshots per slave (at least 2, so there is
+    /// always a pair to diff).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(2),
+            history: HashMap::new(),
+        }
+    }
+
+    /// Add `snapshot` to its slave's history.
+    pub fn record(&mut self, snapshot: RegisterSnapshot) {
+        let history = self.history.entry(snapshot.slave_id).or_default();
+        if history.len() == self.capacity {
+            history.pop_front();
+        }
+        history.push_back(snapshot);
+    }
+
+    /// Changes between the two most recent snapshots of `slave_id`; empty
+    /// until two have been recorded.
+    pub fn last_diff(&self, slave_id: SlaveId) -> Vec<RegisterDiff> {
+        match self.history.get(&slave_id) {
+            Some(history) if history.len() >= 2 => {
+                history[history.len() - 2].diff(&history[history.len() - 1])
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Most recent snapshot of `slave_id`.
+    pub fn latest(&self, slave_id: SlaveId) -> Option<&RegisterSnapshot> {
+        self.history.get(&slave_id)?.back()
+    }
+
+    /// Recorded snapshots of `slave_id`, oldest first.
+    pub fn history(&self, slave_id: SlaveId) -> impl Iterator<Item = &RegisterSnapshot> {
+        self.history.get(&slave_id).into_iter().flatten()
+    }
+}
+
+/// Group descriptor indices into register blocks no larger than `max_registers`.
+///
+/// Returns `(start, quantity, indices)` per block, in address order. A
+/// descriptor that alone exceeds `max_registers` gets a block of its own,
+/// which the caller rejects.
+pub(crate) fn plan_blocks(
+    descriptors: &[&RegisterDescriptor],
+    max_registers: u16,
+) -> Vec<(u16, u16, Vec<usize>)> {
+    let mut order: Vec<usize> = (0..descriptors.len()).collect();
+    order.sort_by_key(|&i| descriptors[i].address);
+
+    let mut blocks: Vec<(u16, u16, Vec<usize>)> = Vec::new();
+    for i in order {
+        let desc = descriptors[i];
+        if let Some((start, quantity, members)) = blocks.last_mut() {
+            let end = (u32::from(*start) + u32::from(*quantity)).max(desc.end_address());
+            if end - u32::from(*start) <= u32::from(max_registers) {
+                *quantity = (end - u32::from(*start)) as u16;
+                members.push(i);
+                continue;
+            }
+        }
+        blocks.push((desc.address, desc.register_count(), vec![i]));
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{GenericModbusClient, ModbusClient};
+    use crate::device_limits::DeviceLimits;
+    use crate::protocol::{ModbusFunction, ModbusResponse};
+    use crate::testing::{EchoTransport, ScriptedTransport};
+    use crate::transport::ModbusTransport;
+
+    fn floats(count: u16) -> Vec<RegisterDescriptor> {
+        (0..count)
+            .map(|i| RegisterDescriptor::new(i * 2, "float32"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn single_request_snapshot_is_consistent() {
+        let descs = floats(5); // registers 0..10
+        let refs: Vec<&RegisterDescriptor> = descs.iter().collect();
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+
+        let snapshot = client
+            .read_snapshot(1, &refs, &DeviceLimits::new())
+            .await
+            .unwrap();
+        assert!(snapshot.is_consistent);
+        assert_eq!(snapshot.slave_id, 1);
+        assert_eq!(snapshot.values.len(), 5);
+        assert_eq!(snapshot.registers.len(), 10);
+        assert_eq!(snapshot.registers[3], (3, 3));
+        assert_eq!(client.transport().get_stats().requests_sent, 1);
+        // EchoTransport returns 0, 1, 2, ... so register pair (2, 3) at address 2.
+        assert_eq!(
+            snapshot.get(2),
+            Some(&ModbusValue::F32(f32::from_bits(0x0002_0003)))
+        );
+    }
+
+    #[tokio::test]
+    async fn split_snapshot_is_not_consistent() {
+        let descs = floats(100); // registers 0..200
+        let refs: Vec<&RegisterDescriptor> = descs.iter().collect();
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+
+        let snapshot = client
+            .read_snapshot(1, &refs, &DeviceLimits::new())
+            .await
+            .unwrap();
+        assert!(!snapshot.is_consistent);
+        assert_eq!(snapshot.values.len(), 100);
+        assert_eq!(snapshot.values[99].0, 198);
+        assert_eq!(client.transport().get_stats().requests_sent, 2);
+    }
+
+    #[tokio::test]
+    async fn read_engineering_applies_scaler() {
+        use crate::protocol::{ModbusFunction, ModbusResponse};
+        use crate::testing::ScriptedTransport;
+
+        let reply = |raw: u16| {
+            let [hi, lo] = raw.to_be_bytes();
+            Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::ReadHoldingRegisters,
+                vec![0x02, hi, lo],
+            ))
+        };
+        let mut client =
+            GenericModbusClient::new(ScriptedTransport::new(vec![reply(500), reply(500)]));
+        let scaled = RegisterDescriptor::new(7, "uint16")
+            .with_scaler(EngineeringScaler::new(0.0, 1000.0, 0.0, 10.0, "bar"));
+        let unscaled = RegisterDescriptor::new(7, "uint16");
+
+        assert_eq!(
+            client.read_engineering(1, &scaled).await.unwrap(),
+            (5.0, "bar")
+        );
+        assert_eq!(
+            client.read_engineering(1, &unscaled).await.unwrap(),
+            (500.0, "")
+        );
+        assert_eq!(client.transport().requests()[0].address, 7);
+    }
+
+    #[tokio::test]
+    async fn value_wider_than_limit_is_rejected() {
+        let desc = RegisterDescriptor::new(0, "float64");
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+        let limits = DeviceLimits::new().with_max_read_registers(2);
+
+        assert!(client.read_snapshot(1, &[&desc], &limits).await.is_err());
+    }
+
+    #[test]
+    fn plan_blocks_splits_at_limit() {
+        let descs: Vec<RegisterDescriptor> = (0..100u16)
+            .map(|i| RegisterDescriptor::new(i * 2, "float32"))
+            .collect();
+        let refs: Vec<&RegisterDescriptor> = descs.iter().collect();
+
+        let blocks = plan_blocks(&refs, 125);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!((blocks[0].0, blocks[0].1), (0, 124));
+        assert_eq!((blocks[1].0, blocks[1].1), (124, 76));
+        assert_eq!(blocks[0].2.len() + blocks[1].2.len(), 100);
+    }
+
+    #[test]
+    fn plan_blocks_merges_overlapping_and_unordered() {
+        let a = RegisterDescriptor::new(10, "uint16");
+        let b = RegisterDescriptor::new(0, "float64");
+        let c = RegisterDescriptor::new(2, "bool").with_bit_position(3);
+
+        let blocks = plan_blocks(&[&a, &b, &c], 125);
+        assert_eq!(blocks, vec![(0, 11, vec![1, 2, 0])]);
+    }
+
+    #[tokio::test]
+    async fn store_diffs_consecutive_batch_reads() {
+        let poll = |values: [u8; 4]| {
+            let mut data = vec![8];
+            for v in values {
+                data.extend_from_slice(&[0, v]);
+            }
+            Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::ReadHoldingRegisters,
+                data,
+            ))
+        };
+        let mut client = GenericModbusClient::new(ScriptedTransport::new(vec![
+            poll([1, 2, 3, 4]),
+            poll([1, 2, 3, 4]),
+            poll([1, 9, 3, 0]),
+        ]));
+        let limits = DeviceLimits::new();
+        let mut store = SnapshotStore::new(2);
+
+        for _ in 0..2 {
+            let values = client.read_03_batch(1, 10, 4, &limits).await.unwrap();
+            store.record(RegisterSnapshot::from_registers(1, 10, &values));
+        }
+        assert!(store.last_diff(1).is_empty());
+
+        let values = client.read_03_batch(1, 10, 4, &limits).await.unwrap();
+        store.record(RegisterSnapshot::from_registers(1, 10, &values));
+        assert_eq!(store.history(1).count(), 2, "oldest snapshot dropped");
+        assert_eq!(
+            store.last_diff(1),
+            vec![
+                RegisterDiff {
+                    address: 11,
+                    old: 2,
+                    new: 9
+                },
+                RegisterDiff {
+                    address: 13,
+                    old: 4,
+                    new: 0
+                },
+            ]
+        );
+        assert!(store.last_diff(2).is_empty());
+
+        let latest = store.latest(1).unwrap();
+        assert!(latest.diff(latest).is_empty());
+        let shifted = RegisterSnapshot::from_registers(1, 12, &[0, 0, 0, 0]);
+        assert_eq!(
+            latest.diff(&shifted),
+            vec![RegisterDiff {
+                address: 12,
+                old: 3,
+                new: 0
+            }],
+            "only overlapping addresses are compared"
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! # Request Metrics
+//!
+//! With the `metrics` feature, [`GenericModbusClient::with_metrics`](crate::GenericModbusClient::with_metrics)
+//! reports every request through the [`metrics`] facade, so any installed
+//! recorder (for example `metrics-exporter-prometheus`) can export them:
+//!
+//! | Metric | Kind | Labels |
+//! |--------|------|--------|
+//! | `modbus_requests_total` | counter | `function_code`, `slave_id` |
+//! | `modbus_errors_total` | counter | `function_code`, `slave_id`, `error_type` |
+//! | `modbus_request_duration_seconds` | histogram | `function_code`, `slave_id` |
+//! | `modbus_connection_up` | gauge | `address` |
+//!
+//! `error_type` is [`ModbusError::kind`]. The `modbus` prefix comes from
+//! [`MetricsConfig::label_prefix`], and the connection gauge is only reported
+//! once [`MetricsConfig::address`] is set; [`ModbusTcpClient::with_metrics`](crate::ModbusTcpClient::with_metrics)
+//! fills it in from the server address.
+//!
+//! Without the feature, none of this is compiled in.
+//!
+//! ```rust
+//! use voltage_modbus::telemetry::MetricsConfig;
+//! use voltage_modbus::testing::EchoTransport;
+//! use voltage_modbus::GenericModbusClient;
+//!
+//! let client = GenericModbusClient::new(EchoTransport::new())
+//!     .with_metrics(MetricsConfig::new("plant_a").with_address("10.0.0.5:502"));
+//! ```
+
+use std::time::Duration;
+
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::SlaveId;
+
+/// Naming of the metrics reported by a client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricsConfig {
+    /// Prefix of every metric name, e.g. `modbus` in `modbus_requests_total`.
+    pub label_prefix: String,
+    /// Value of the `address` label on the connection gauge; `None` skips the gauge.
+    pub address: Option<String>,
+}
+
+impl MetricsConfig {
+    /// Metrics named `{label_prefix}_requests_total` and so on
+    pub fn new(label_prefix: impl Into<String>) -> Self {
+        Self {
+            label_prefix: label_prefix.into(),
+            address: None,
+        }
+    }
+
+    /// Report `modbus_connection_up` for `address`
+    pub fn with_address(mut self, address: impl Into<String>) -> Self {
+        self.address = Some(address.into());
+        self
+    }
+}
+
+impl Default for MetricsConfig {
+    /// The `modbus` prefix, no connection gauge.
+    fn default() -> Self {
+        Self::new("modbus")
+    }
+}
+
+/// Metric names resolved once from a [`MetricsConfig`].
+#[derive(Debug, Clone)]
+pub(crate) struct ClientMetrics {
+    requests: String,
+    errors: String,
+    duration: String,
+    connection_up: String,
+    address: Option<String>,
+}
+
+impl ClientMetrics {
+    pub(crate) fn new(config: MetricsConfig) -> Self {
+        let prefix = config.label_prefix;
+        Self {
+            requests: format!("{prefix}_requests_total"),
+            errors: format!("{prefix}_errors_total"),
+            duration: format!("{prefix}_request_duration_seconds"),
+            connection_up: format!("{prefix}_connection_up"),
+            address: config.address,
+        }
+    }
+
+    /// Count one request and its latency, and its error if it failed
+    pub(crate) fn record_request<R>(
+        &self,
+        slave_id: SlaveId,
+        function_code: u8,
+        elapsed: Duration,
+        result: &ModbusResult<R>,
+    ) {
+        let function_code = function_code.to_string();
+        let slave_id = slave_id.to_string();
+        metrics::counter!(
+            self.requests.clone(),
+            "function_code" => function_code.clone(),
+            "slave_id" => slave_id.clone(),
+        )
+        .increment(1);
+        metrics::histogram!(
+            self.duration.clone(),
+            "function_code" => function_code.clone(),
+            "slave_id" => slave_id.clone(),
+        )
+        .record(elapsed.as_secs_f64());
+        if let Err(e) = result {
+            metrics::counter!(
+                self.errors.clone(),
+                "function_code" => function_code,
+                "slave_id" => slave_id,
+                "error_type" => ModbusError::kind(e),
+            )
+            .increment(1);
+        }
+    }
+
+    /// Set the connection gauge to 1 or 0
+    pub(crate) fn record_connection(&self, up: bool) {
+        if let Some(address) = &self.address {
+            metrics::gauge!(self.connection_up.clone(), "address" => address.clone()).set(if up {
+                1.0
+            } else {
+                0.0
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use metrics::{
+        Counter, CounterFn, Gauge, GaugeFn, Histogram, HistogramFn, Key, KeyName, Metadata,
+        Recorder, SharedString, Unit,
+    };
+
+    use super::*;
+    use crate::client::{GenericModbusClient, ModbusClient};
+    use crate::protocol::{ModbusFunction, ModbusResponse};
+    use crate::testing::ScriptedTransport;
+
+    /// `(name, labels, value)` of every update, in order
+    type Updates = Arc<Mutex<Vec<(String, Vec<(String, String)>, f64)>>>;
+
+    #[derive(Default)]
+    struct CapturingRecorder {
+        updates: Updates,
+    }
+
+    struct Handle {
+        key: Key,
+        updates: Updates,
+    }
+
+    impl Handle {
+        fn push(&self, value: f64) {
+            let labels = self
+                .key
+                .labels()
+                .map(|l| (l.key().to_string(), l.value().to_string()))
+                .collect();
+            self.updates
+                .lock()
+                .unwrap()
+                .push((self.key.name().to_string(), labels, value));
+        }
+    }
+
+    impl CounterFn for Handle {
+        fn increment(&self, value: u64) {
+            self.push(value as f64);
+        }
+        fn absolute(&self, value: u64) {
+            self.push(value as f64);
+        }
+    }
+
+    impl GaugeFn for Handle {
+        fn increment(&self, value: f64) {
+            self.push(value);
+        }
+        fn decrement(&self, value: f64) {
+            self.push(-value);
+        }
+        fn set(&self, value: f64) {
+            self.push(value);
+        }
+    }
+
+    impl HistogramFn for Handle {
+        fn record(&self, value: f64) {
+            self.push(value);
+        }
+    }
+
+    impl CapturingRecorder {
+        fn handle(&self, key: &Key) -> Arc<Handle> {
+            Arc::new(Handle {
+                key: key.clone(),
+                updates: self.updates.clone(),
+            })
+        }
+    }
+
+    impl Recorder for CapturingRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.handle(key))
+        }
+        fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::from_arc(self.handle(key))
+        }
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::from_arc(self.handle(key))
+        }
+    }
+
+    fn labels(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn records_requests_errors_and_connection() {
+        let recorder = CapturingRecorder::default();
+        let transport = ScriptedTransport::new(vec![
+            Ok(ModbusResponse::new_success(
+                7,
+                ModbusFunction::ReadHoldingRegisters,
+                vec![2, 0, 1],
+            )),
+            Err(ModbusError::exception(0x03, 0x02)),
+        ]);
+        let mut client = GenericModbusClient::new(transport)
+            .with_metrics(MetricsConfig::new("plc").with_address("10.0.0.5:502"));
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        metrics::with_local_recorder(&recorder, || {
+            runtime.block_on(async {
+                client.read_03(7, 0, 1).await.unwrap();
+                client.read_03(7, 0, 1).await.unwrap_err();
+                client.close().await.unwrap();
+            })
+        });
+
+        let updates = recorder.updates.lock().unwrap();
+        let request = labels(&[("function_code", "3"), ("slave_id", "7")]);
+        let count = |name: &str, labels: &[(String, String)]| {
+            updates
+                .iter()
+                .filter(|(n, l, _)| n == name && l == labels)
+                .count()
+        };
+        assert_eq!(count("plc_requests_total", &request), 2);
+        assert_eq!(count("plc_request_duration_seconds", &request), 2);
+        let error = labels(&[
+            ("function_code", "3"),
+            ("slave_id", "7"),
+            ("error_type", "Exception"),
+        ]);
+        assert_eq!(count("plc_errors_total", &error), 1);
+
+        let address = labels(&[("address", "10.0.0.5:502")]);
+        let gauge: Vec<f64> = updates
+            .iter()
+            .filter(|(n, l, _)| n == "plc_connection_up" && *l == address)
+            .map(|(_, _, v)| *v)
+            .collect();
+        assert_eq!(
+            gauge.last(),
+            Some(&0.0),
+            "closing reports the connection down"
+        );
+        assert_eq!(gauge.len(), 3);
+    }
+}
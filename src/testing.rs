@@ -0,0 +1,879 @@
+//! # Test Transports
+//!
+//! In-process [`ModbusTransport`] implementations for exercising application
+//! logic (retry policies, coalescing, caching) without a network.
+//!
+//! - [`ScriptedTransport`] — returns a pre-recorded list of results in order
+//!   and records every request it receives.
+//! - [`EchoTransport`] — answers every request with a well-formed response:
+//!   reads return consecutive values `0, 1, 2, ...`, writes are echoed back.
+//!
+//! Fault-injection wrappers that decorate any other transport (including each
+//! other) for testing timeouts, retries and watchdogs under bad conditions:
+//!
+//! - [`LatencyTransport`] — sleeps `latency + rand(0..jitter)` per request.
+//! - [`DropTransport`] — fails a fraction of requests with a connection error.
+//! - [`CorruptTransport`] — flips one random bit in a fraction of responses.
+//!
+//! For round trips over a real socket, [`MockModbusServer`] serves a
+//! [`DataStore`] on a random local port, records what it receives and can
+//! fail requests touching chosen addresses.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use voltage_modbus::testing::ScriptedTransport;
+//! use voltage_modbus::{GenericModbusClient, ModbusClient, ModbusFunction, ModbusResponse};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> voltage_modbus::ModbusResult<()> {
+//! let transport = ScriptedTransport::new(vec![Ok(ModbusResponse::new_success(
+//!     1,
+//!     ModbusFunction::ReadHoldingRegisters,
+//!     vec![0x02, 0x12, 0x34],
+//! ))]);
+//! let mut client = GenericModbusClient::new(transport);
+//!
+//! assert_eq!(client.read_03(1, 0, 1).await?, vec![0x1234]);
+//! client.transport().assert_all_consumed();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Wrappers compose by nesting:
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use voltage_modbus::testing::{CorruptTransport, DropTransport, EchoTransport, LatencyTransport};
+//!
+//! let flaky = LatencyTransport::new(
+//!     DropTransport::new(CorruptTransport::new(EchoTransport::new(), 0.01), 0.05),
+//!     Duration::from_millis(20),
+//!     Duration::from_millis(5),
+//! );
+//! # let _ = flaky;
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::{ModbusFunction, ModbusRequest, ModbusResponse};
+use crate::server::{
+    DataStore, ModbusServer, ModbusTcpServer, ModbusTcpServerConfig, RequestHandler,
+};
+use crate::transport::{unit_random, ModbusTransport, TransportStats};
+
+/// Transport that replays a fixed script of results, one per request.
+///
+/// Each call to [`request`](ModbusTransport::request) pops the next entry.
+/// Once the script runs out, requests fail with an internal error. Call
+/// [`assert_all_consumed`](Self::assert_all_consumed) at the end of a test to
+/// make sure every scripted scenario was actually exercised.
+#[derive(Debug, Default)]
+pub struct ScriptedTransport {
+    script: VecDeque<ModbusResult<ModbusResponse>>,
+    requests: Vec<ModbusRequest>,
+    connected: bool,
+    stats: TransportStats,
+}
+
+impl ScriptedTransport {
+    /// Create a transport that replays `script` in order.
+    pub fn new(script: Vec<ModbusResult<ModbusResponse>>) -> Self {
+        Self {
+            script: script.into(),
+            requests: Vec::new(),
+            connected: true,
+            stats: TransportStats::default(),
+        }
+    }
+
+    /// Append another result to the end of the script.
+    pub fn push(&mut self, result: ModbusResult<ModbusResponse>) {
+        self.script.push_back(result);
+    }
+
+    /// Number of scripted results not yet consumed.
+    pub fn remaining(&self) -> usize {
+        self.script.len()
+    }
+
+    /// Requests received so far, in order.
+    pub fn requests(&self) -> &[ModbusRequest] {
+        &self.requests
+    }
+
+    /// Panic if any scripted results were never consumed.
+    #[track_caller]
+    pub fn assert_all_consumed(&self) {
+        assert!(
+            self.script.is_empty(),
+            "ScriptedTransport: {} scripted response(s) left unconsumed after {} request(s)",
+            self.script.len(),
+            self.requests.len()
+        );
+    }
+}
+
+impl ModbusTransport for ScriptedTransport {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        if !self.connected {
+            return Err(ModbusError::connection("ScriptedTransport is closed"));
+        }
+
+        self.requests.push(request.clone());
+        self.stats.requests_sent += 1;
+
+        let result = self.script.pop_front().unwrap_or_else(|| {
+            Err(ModbusError::internal(format!(
+                "ScriptedTransport: script exhausted at request #{}",
+                self.requests.len()
+            )))
+        });
+
+        match &result {
+            Ok(response) => {
+                self.stats.responses_received += 1;
+                self.stats.bytes_received += response.data_len() as u64;
+            }
+            Err(e) => {
+                self.stats.errors += 1;
+                if matches!(e, ModbusError::Timeout { .. }) {
+                    self.stats.timeouts += 1;
+                }
+            }
+        }
+        result
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.connected = false;
+        Ok(())
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.stats.clone()
+    }
+}
+
+/// Transport that synthesizes a valid response for every request.
+///
+/// - FC03/FC04 return registers `0, 1, 2, ...` (one per requested register).
+/// - FC01/FC02 return alternating bits `false, true, false, ...`.
+/// - Write requests are echoed back exactly as a real slave would.
+/// - Broadcast writes (slave 0) get the same synthetic ack as real transports.
+#[derive(Debug)]
+pub struct EchoTransport {
+    connected: bool,
+    stats: TransportStats,
+}
+
+impl EchoTransport {
+    /// Create a connected echo transport.
+    pub fn new() -> Self {
+        Self {
+            connected: true,
+            stats: TransportStats::default(),
+        }
+    }
+
+    /// Build the response a well-behaved slave would send for `request`.
+    fn respond(request: &ModbusRequest) -> ModbusResponse {
+        if request.slave_id == 0 {
+            return ModbusResponse::new_broadcast_ack(request.function);
+        }
+
+        let quantity = request.quantity;
+        let data = match request.function {
+            ModbusFunction::ReadHoldingRegisters
+            | ModbusFunction::ReadInputRegisters
+            | ModbusFunction::ReadWriteMultipleRegisters => {
+                let mut data = Vec::with_capacity(1 + usize::from(quantity) * 2);
+                data.push((quantity * 2) as u8);
+                for value in 0..quantity {
+                    data.extend_from_slice(&value.to_be_bytes());
+                }
+                data
+            }
+            ModbusFunction::ReadCoils | ModbusFunction::ReadDiscreteInputs => {
+                // 0b1010_1010: bit i is set when i is odd
+                let byte_count = quantity.div_ceil(8) as usize;
+                let mut data = vec![0xAA; 1 + byte_count];
+                data[0] = byte_count as u8;
+                data
+            }
+            ModbusFunction::WriteSingleCoil => {
+                let value: u16 = if request.data.first().is_some_and(|&b| b != 0) {
+                    0xFF00
+                } else {
+                    0x0000
+                };
+                echo(request.address, value)
+            }
+            ModbusFunction::WriteSingleRegister => {
+                let mut data = request.address.to_be_bytes().to_vec();
+                data.extend_from_slice(&request.data);
+                data
+            }
+            ModbusFunction::WriteMultipleCoils | ModbusFunction::WriteMultipleRegisters => {
+                echo(request.address, quantity)
+            }
+            ModbusFunction::ReadExceptionStatus => vec![0x00],
+            ModbusFunction::Diagnostics => request.data.clone(),
+            // No identification objects; MEI type, code, conformity, no more, next, count
+            ModbusFunction::EncapsulatedInterfaceTransport => {
+                vec![0x0E, request.data[1], 0x01, 0x00, 0x00, 0x00]
+            }
+            ModbusFunction::MaskWriteRegister => {
+                let mut data = request.address.to_be_bytes().to_vec();
+                data.extend_from_slice(&request.data);
+                data
+            }
+        };
+
+        ModbusResponse::new_success(request.slave_id, request.function, data)
+    }
+}
+
+impl Default for EchoTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Address + value/quantity echo payload used by write responses.
+fn echo(address: u16, value: u16) -> Vec<u8> {
+    let mut data = Vec::with_capacity(4);
+    data.extend_from_slice(&address.to_be_bytes());
+    data.extend_from_slice(&value.to_be_bytes());
+    data
+}
+
+impl ModbusTransport for EchoTransport {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        if !self.connected {
+            return Err(ModbusError::connection("EchoTransport is closed"));
+        }
+        self.stats.requests_sent += 1;
+
+        if let Err(e) = request.validate() {
+            self.stats.errors += 1;
+            return Err(e);
+        }
+
+        let response = Self::respond(request);
+        self.stats.responses_received += 1;
+        self.stats.bytes_received += response.data_len() as u64;
+        Ok(response)
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.connected = false;
+        Ok(())
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.stats.clone()
+    }
+}
+
+/// Wrapper that delays every request by `latency + rand(0..jitter)`.
+#[derive(Debug)]
+pub struct LatencyTransport<T: ModbusTransport> {
+    inner: T,
+    latency: Duration,
+    jitter: Duration,
+}
+
+impl<T: ModbusTransport> LatencyTransport<T> {
+    /// Wrap `inner`, adding `latency` plus up to `jitter` of random delay.
+    pub fn new(inner: T, latency: Duration, jitter: Duration) -> Self {
+        Self {
+            inner,
+            latency,
+            jitter,
+        }
+    }
+
+    /// Delay to apply to the next request.
+    fn next_delay(&self) -> Duration {
+        self.latency + self.jitter.mul_f64(unit_random())
+    }
+
+    /// Access the wrapped transport.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped transport.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap and return the inner transport.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: ModbusTransport> ModbusTransport for LatencyTransport<T> {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        tokio::time::sleep(self.next_delay()).await;
+        self.inner.request(request).await
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.inner.close().await
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.inner.get_stats()
+    }
+}
+
+/// Wrapper that simulates packet loss.
+///
+/// With probability `drop_rate` a request fails with
+/// `ModbusError::Connection("simulated packet loss")` without reaching the
+/// inner transport.
+#[derive(Debug)]
+pub struct DropTransport<T: ModbusTransport> {
+    inner: T,
+    drop_rate: f64,
+    dropped: u64,
+}
+
+impl<T: ModbusTransport> DropTransport<T> {
+    /// Wrap `inner`, dropping requests with probability `drop_rate` (clamped to 0.0-1.0).
+    pub fn new(inner: T, drop_rate: f64) -> Self {
+        Self {
+            inner,
+            drop_rate: drop_rate.clamp(0.0, 1.0),
+            dropped: 0,
+        }
+    }
+
+    /// Number of requests dropped so far.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Access the wrapped transport.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped transport.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap and return the inner transport.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: ModbusTransport> ModbusTransport for DropTransport<T> {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        if unit_random() < self.drop_rate {
+            self.dropped += 1;
+            return Err(ModbusError::connection("simulated packet loss"));
+        }
+        self.inner.request(request).await
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.inner.close().await
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        let mut stats = self.inner.get_stats();
+        stats.requests_sent += self.dropped;
+        stats.errors += self.dropped;
+        stats
+    }
+}
+
+/// Wrapper that flips one random bit in the response PDU data.
+///
+/// With probability `corrupt_rate` a successful response has a single bit of
+/// its payload inverted. Exception and empty responses pass through untouched.
+#[derive(Debug)]
+pub struct CorruptTransport<T: ModbusTransport> {
+    inner: T,
+    corrupt_rate: f64,
+    corrupted: u64,
+}
+
+impl<T: ModbusTransport> CorruptTransport<T> {
+    /// Wrap `inner`, corrupting responses with probability `corrupt_rate` (clamped to 0.0-1.0).
+    pub fn new(inner: T, corrupt_rate: f64) -> Self {
+        Self {
+            inner,
+            corrupt_rate: corrupt_rate.clamp(0.0, 1.0),
+            corrupted: 0,
+        }
+    }
+
+    /// Number of responses corrupted so far.
+    pub fn corrupted(&self) -> u64 {
+        self.corrupted
+    }
+
+    /// Access the wrapped transport.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped transport.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap and return the inner transport.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: ModbusTransport> ModbusTransport for CorruptTransport<T> {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        let response = self.inner.request(request).await?;
+        if response.is_exception()
+            || response.data().is_empty()
+            || unit_random() >= self.corrupt_rate
+        {
+            return Ok(response);
+        }
+
+        let mut data = response.data().to_vec();
+        let bit = ((unit_random() * (data.len() * 8) as f64) as usize).min(data.len() * 8 - 1);
+        data[bit / 8] ^= 1 << (bit % 8);
+        self.corrupted += 1;
+        Ok(ModbusResponse::new_success(
+            response.slave_id,
+            response.function,
+            data,
+        ))
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.inner.close().await
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.inner.get_stats()
+    }
+}
+
+/// Modbus TCP server on a random local port, for round-trip tests.
+///
+/// Requests are answered from a [`DataStore`] seeded with
+/// [`set_coil`](Self::set_coil) and [`set_register`](Self::set_register).
+/// Every decoded request is recorded, and a request whose address range
+/// covers an address passed to [`inject_error`](Self::inject_error) fails with
+/// that error. Since the error has to travel over the wire, the client sees
+/// the exception response the server sends for it: the code of a
+/// [`ModbusError::Exception`], 0x02 for an invalid address and so on (see
+/// [`RequestHandler`]).
+///
+/// ```rust
+/// use std::time::Duration;
+/// use voltage_modbus::testing::MockModbusServer;
+/// use voltage_modbus::{ModbusClient, ModbusTcpClient};
+///
+/// # #[tokio::main]
+/// # async fn main() -> voltage_modbus::ModbusResult<()> {
+/// let mut server = MockModbusServer::new();
+/// server.set_register(10, 0x1234);
+/// let port = server.bind().await?;
+///
+/// let address = format!("127.0.0.1:{port}");
+/// let mut client = ModbusTcpClient::from_address(&address, Duration::from_secs(1)).await?;
+/// assert_eq!(client.read_03(1, 10, 1).await?, vec![0x1234]);
+/// assert_eq!(server.received_requests().len(), 1);
+/// # server.stop().await
+/// # }
+/// ```
+#[derive(Default)]
+pub struct MockModbusServer {
+    handler: Arc<MockHandler>,
+    server: Option<ModbusTcpServer<MockHandler>>,
+}
+
+impl MockModbusServer {
+    /// Create a server with an empty data store; call [`bind`](Self::bind) to start it.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start listening on `127.0.0.1` and return the chosen port.
+    pub async fn bind(&mut self) -> ModbusResult<u16> {
+        let config = ModbusTcpServerConfig {
+            bind_address: ([127, 0, 0, 1], 0).into(),
+            ..Default::default()
+        };
+        let mut server = ModbusTcpServer::with_handler(config, self.handler.clone());
+        server.start().await?;
+        let port = server
+            .local_addr()
+            .map(|addr| addr.port())
+            .ok_or_else(|| ModbusError::internal("Mock server has no local address"))?;
+        self.server = Some(server);
+        Ok(port)
+    }
+
+    /// Stop listening and drop open connections.
+    pub async fn stop(&mut self) -> ModbusResult<()> {
+        match self.server.take() {
+            Some(mut server) => server.stop().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Set coil `address`.
+    pub fn set_coil(&self, address: u16, value: bool) {
+        self.handler.store().set_coil(address, value);
+    }
+
+    /// Set holding register `address`.
+    pub fn set_register(&self, address: u16, value: u16) {
+        self.handler.store().set_register(address, value);
+    }
+
+    /// Current value of coil `address`, e.g. after a client wrote it.
+    pub fn get_coil(&self, address: u16) -> bool {
+        self.handler.store().get_coil(address)
+    }
+
+    /// Current value of holding register `address`, e.g. after a client wrote it.
+    pub fn get_register(&self, address: u16) -> u16 {
+        self.handler.store().get_register(address)
+    }
+
+    /// Fail every request whose address range covers `address` with `error`.
+    pub fn inject_error(&self, address: u16, error: ModbusError) {
+        lock(&self.handler.errors).insert(address, error);
+    }
+
+    /// Stop failing requests injected with [`inject_error`](Self::inject_error).
+    pub fn clear_errors(&self) {
+        lock(&self.handler.errors).clear();
+    }
+
+    /// Copy of every request received so far, in order.
+    ///
+    /// Requests arrive on the server's own tasks, so this is a snapshot
+    /// rather than a borrow.
+    pub fn received_requests(&self) -> Vec<ModbusRequest> {
+        lock(&self.handler.requests).clone()
+    }
+}
+
+impl std::fmt::Debug for MockModbusServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockModbusServer")
+            .field(
+                "local_addr",
+                &self.server.as_ref().and_then(|s| s.local_addr()),
+            )
+            .field("received", &lock(&self.handler.requests).len())
+            .finish()
+    }
+}
+
+/// Request handler behind [`MockModbusServer`]
+#[derive(Debug, Default)]
+struct MockHandler {
+    store: RwLock<DataStore>,
+    requests: Mutex<Vec<ModbusRequest>>,
+    errors: Mutex<HashMap<u16, ModbusError>>,
+}
+
+impl MockHandler {
+    fn store(&self) -> std::sync::RwLockWriteGuard<'_, DataStore> {
+        self.store.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// The injected error for the first address `request` covers, if any
+    fn injected_error(&self, request: &ModbusRequest) -> Option<ModbusError> {
+        let start = u32::from(request.address);
+        let end = start + u32::from(request.quantity.max(1));
+        lock(&self.errors)
+            .iter()
+            .filter(|(address, _)| (start..end).contains(&u32::from(**address)))
+            .min_by_key(|(address, _)| **address)
+            .map(|(_, error)| error.clone())
+    }
+}
+
+impl RequestHandler for MockHandler {
+    async fn handle_request(&self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        lock(&self.requests).push(request.clone());
+        if let Some(error) = self.injected_error(request) {
+            return Err(error);
+        }
+        self.store.handle_request(request).await
+    }
+}
+
+/// Lock `mutex`, ignoring poisoning by a panicked test thread
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{GenericModbusClient, ModbusClient};
+
+    fn register_response(slave_id: u8, values: &[u16]) -> ModbusResponse {
+        let mut data = vec![(values.len() * 2) as u8];
+        for v in values {
+            data.extend_from_slice(&v.to_be_bytes());
+        }
+        ModbusResponse::new_success(slave_id, ModbusFunction::ReadHoldingRegisters, data)
+    }
+
+    #[tokio::test]
+    async fn scripted_transport_replays_in_order() {
+        let transport = ScriptedTransport::new(vec![
+            Ok(register_response(1, &[10, 20])),
+            Err(ModbusError::timeout("read", 100)),
+            Ok(register_response(1, &[30])),
+        ]);
+        let mut client = GenericModbusClient::new(transport);
+
+        assert_eq!(client.read_03(1, 0, 2).await.unwrap(), vec![10, 20]);
+        assert!(matches!(
+            client.read_03(1, 0, 1).await,
+            Err(ModbusError::Timeout { .. })
+        ));
+        assert_eq!(client.read_03(1, 5, 1).await.unwrap(), vec![30]);
+
+        let transport = client.transport();
+        transport.assert_all_consumed();
+        assert_eq!(transport.requests().len(), 3);
+        assert_eq!(transport.requests()[2].address, 5);
+
+        let stats = transport.get_stats();
+        assert_eq!(stats.requests_sent, 3);
+        assert_eq!(stats.responses_received, 2);
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.timeouts, 1);
+    }
+
+    #[tokio::test]
+    async fn scripted_transport_errors_when_exhausted() {
+        let mut transport = ScriptedTransport::new(Vec::new());
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+        assert!(matches!(
+            transport.request(&request).await,
+            Err(ModbusError::Internal { .. })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "1 scripted response(s) left unconsumed")]
+    fn scripted_transport_assert_all_consumed_panics_on_leftovers() {
+        let transport = ScriptedTransport::new(vec![Ok(register_response(1, &[1]))]);
+        transport.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn scripted_transport_rejects_requests_after_close() {
+        let mut transport = ScriptedTransport::new(vec![Ok(register_response(1, &[1]))]);
+        transport.close().await.unwrap();
+        assert!(!transport.is_connected());
+
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+        assert!(matches!(
+            transport.request(&request).await,
+            Err(ModbusError::Connection { .. })
+        ));
+        assert_eq!(transport.remaining(), 1);
+    }
+
+    #[tokio::test]
+    async fn echo_transport_reads_return_consecutive_values() {
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+
+        assert_eq!(client.read_03(1, 100, 4).await.unwrap(), vec![0, 1, 2, 3]);
+        assert_eq!(client.read_04(2, 0, 2).await.unwrap(), vec![0, 1]);
+        assert_eq!(
+            client.read_01(1, 0, 4).await.unwrap(),
+            vec![false, true, false, true]
+        );
+    }
+
+    #[tokio::test]
+    async fn echo_transport_echoes_writes() {
+        let mut client = GenericModbusClient::new(EchoTransport::new());
+
+        client.write_05(1, 10, true).await.unwrap();
+        client.write_05(1, 11, false).await.unwrap();
+        client.write_06(1, 20, 0xBEEF).await.unwrap();
+        client.write_10(1, 30, &[1, 2, 3]).await.unwrap();
+        client.write_0f(1, 40, &[true, false, true]).await.unwrap();
+        client.write_06(0, 20, 1).await.unwrap(); // broadcast
+
+        assert_eq!(client.get_stats().requests_sent, 6);
+    }
+
+    #[tokio::test]
+    async fn echo_transport_validates_requests() {
+        let mut transport = EchoTransport::new();
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 200);
+        assert!(transport.request(&request).await.is_err());
+        assert_eq!(transport.get_stats().errors, 1);
+    }
+
+    #[tokio::test]
+    async fn latency_transport_delays_requests() {
+        let latency = Duration::from_millis(20);
+        let jitter = Duration::from_millis(10);
+        let mut transport = LatencyTransport::new(EchoTransport::new(), latency, jitter);
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+
+        for _ in 0..3 {
+            let start = std::time::Instant::now();
+            transport.request(&request).await.unwrap();
+            assert!(start.elapsed() >= latency);
+        }
+        for _ in 0..100 {
+            let delay = transport.next_delay();
+            assert!(delay >= latency && delay <= latency + jitter);
+        }
+        assert_eq!(transport.get_stats().requests_sent, 3);
+    }
+
+    #[tokio::test]
+    async fn latency_transport_triggers_caller_timeout() {
+        let mut transport = LatencyTransport::new(
+            EchoTransport::new(),
+            Duration::from_millis(200),
+            Duration::ZERO,
+        );
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+        let result =
+            tokio::time::timeout(Duration::from_millis(20), transport.request(&request)).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn drop_transport_rates() {
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+
+        let mut never = DropTransport::new(EchoTransport::new(), 0.0);
+        let mut always = DropTransport::new(EchoTransport::new(), 1.0);
+        for _ in 0..20 {
+            assert!(never.request(&request).await.is_ok());
+            match always.request(&request).await {
+                Err(ModbusError::Connection { message, .. }) => {
+                    assert_eq!(message, "simulated packet loss")
+                }
+                other => panic!("expected simulated loss, got {:?}", other),
+            }
+        }
+        assert_eq!(never.dropped(), 0);
+        assert_eq!(always.dropped(), 20);
+        assert_eq!(always.inner().get_stats().requests_sent, 0);
+        assert_eq!(always.get_stats().errors, 20);
+
+        let mut half = DropTransport::new(EchoTransport::new(), 0.5);
+        for _ in 0..1000 {
+            let _ = half.request(&request).await;
+        }
+        assert!((300..=700).contains(&half.dropped()));
+    }
+
+    #[tokio::test]
+    async fn corrupt_transport_flips_exactly_one_bit() {
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 4);
+        let clean = EchoTransport::respond(&request);
+
+        let mut transport = CorruptTransport::new(EchoTransport::new(), 1.0);
+        for _ in 0..50 {
+            let response = transport.request(&request).await.unwrap();
+            let flipped: u32 = response
+                .data()
+                .iter()
+                .zip(clean.data())
+                .map(|(a, b)| (a ^ b).count_ones())
+                .sum();
+            assert_eq!(flipped, 1);
+        }
+        assert_eq!(transport.corrupted(), 50);
+
+        let mut passthrough = CorruptTransport::new(EchoTransport::new(), 0.0);
+        let response = passthrough.request(&request).await.unwrap();
+        assert_eq!(response.data(), clean.data());
+    }
+
+    #[tokio::test]
+    async fn wrappers_compose() {
+        let mut client = GenericModbusClient::new(LatencyTransport::new(
+            DropTransport::new(CorruptTransport::new(EchoTransport::new(), 0.0), 0.0),
+            Duration::from_millis(1),
+            Duration::ZERO,
+        ));
+        assert_eq!(client.read_03(1, 0, 3).await.unwrap(), vec![0, 1, 2]);
+
+        let corrupt = client.transport().inner().inner();
+        assert_eq!(corrupt.corrupted(), 0);
+        assert_eq!(corrupt.inner().get_stats().requests_sent, 1);
+    }
+
+    #[tokio::test]
+    async fn mock_server_round_trip() {
+        let mut server = MockModbusServer::new();
+        server.set_register(10, 0x1234);
+        server.set_coil(3, true);
+        server.inject_error(21, ModbusError::exception(0x03, 0x04));
+        let port = server.bind().await.unwrap();
+
+        let address = format!("127.0.0.1:{port}");
+        let mut client =
+            crate::client::ModbusTcpClient::from_address(&address, Duration::from_secs(1))
+                .await
+                .unwrap();
+        assert_eq!(client.read_03(1, 10, 2).await.unwrap(), vec![0x1234, 0]);
+        assert_eq!(client.read_01(1, 2, 2).await.unwrap(), vec![false, true]);
+        client.write_06(1, 11, 7).await.unwrap();
+        assert_eq!(server.get_register(11), 7);
+
+        let err = client.read_03(1, 20, 4).await.unwrap_err();
+        assert_eq!(err.exception_code(), Some(0x04));
+        server.clear_errors();
+        client.read_03(1, 20, 4).await.unwrap();
+
+        let received = server.received_requests();
+        assert_eq!(received.len(), 5);
+        assert_eq!(received[2].function, ModbusFunction::WriteSingleRegister);
+        assert_eq!(received[3].address, 20);
+        server.stop().await.unwrap();
+    }
+}
@@ -0,0 +1,459 @@
+//! # TLS for Modbus TCP
+//!
+//! [`TlsTcpTransport`] runs standard Modbus TCP (MBAP framing, PDUs, response
+//! validation — all shared with [`TcpTransport`]) inside a TLS session, as
+//! described by the Modbus/TCP Security specification. Enabled by the `tls`
+//! feature; the TLS stack is `tokio-rustls` with the `ring` crypto provider.
+//!
+//! Only TLS 1.3 and TLS 1.2 are negotiated, restricted to forward-secret
+//! AEAD cipher suites (ECDHE with AES-GCM or ChaCha20-Poly1305).
+//!
+//! For mutual TLS, set both `client_cert_pem` and `client_key_pem` (see
+//! [`TlsClientConfig::with_client_cert`]); the client then presents that
+//! certificate when the server requests one. [`CertificateStore`] loads and
+//! checks PEM certificate files up front.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use voltage_modbus::tls::TlsClientConfig;
+//! use voltage_modbus::{ModbusClient, ModbusTcpClient};
+//!
+//! # async fn example() -> voltage_modbus::ModbusResult<()> {
+//! let ca = std::fs::read("ca.pem").unwrap();
+//! let tls = TlsClientConfig::new(&ca, "plc-01.plant.local");
+//!
+//! let addr = "10.0.0.10:802".parse().unwrap();
+//! let mut client = ModbusTcpClient::with_tls(addr, Duration::from_secs(5), &tls).await?;
+//! let registers = client.read_03(1, 0, 10).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::crypto::{ring, CryptoProvider};
+use tokio_rustls::rustls::{self, ClientConfig, RootCertStore, SupportedCipherSuite};
+use tokio_rustls::TlsConnector;
+
+use crate::error::{ModbusError, ModbusResult};
+use crate::protocol::{ModbusRequest, ModbusResponse};
+use crate::transport::{ModbusTransport, TcpTransport, TransportStats};
+
+/// Forward-secret AEAD suites offered to the server, strongest first.
+const SAFE_CIPHER_SUITES: &[SupportedCipherSuite] = &[
+    ring::cipher_suite::TLS13_AES_256_GCM_SHA384,
+    ring::cipher_suite::TLS13_AES_128_GCM_SHA256,
+    ring::cipher_suite::TLS13_CHACHA20_POLY1305_SHA256,
+    ring::cipher_suite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    ring::cipher_suite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    ring::cipher_suite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    ring::cipher_suite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    ring::cipher_suite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    ring::cipher_suite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+];
+
+/// PEM material and server identity for a TLS connection.
+#[derive(Debug, Clone, Copy)]
+pub struct TlsClientConfig<'a> {
+    /// CA certificate(s) trusted to sign the server certificate.
+    pub ca_cert_pem: &'a [u8],
+    /// Client certificate chain for mutual TLS.
+    pub client_cert_pem: Option<&'a [u8]>,
+    /// Private key for `client_cert_pem`.
+    pub client_key_pem: Option<&'a [u8]>,
+    /// Name the server certificate must be valid for (DNS name or IP).
+    pub server_name: &'a str,
+}
+
+impl<'a> TlsClientConfig<'a> {
+    /// Server-authenticated TLS trusting `ca_cert_pem`.
+    pub fn new(ca_cert_pem: &'a [u8], server_name: &'a str) -> Self {
+        Self {
+            ca_cert_pem,
+            client_cert_pem: None,
+            client_key_pem: None,
+            server_name,
+        }
+    }
+
+    /// Present `cert_pem` (chain, leaf first) signed by `key_pem` for mutual TLS.
+    pub fn with_client_cert(mut self, cert_pem: &'a [u8], key_pem: &'a [u8]) -> Self {
+        self.client_cert_pem = Some(cert_pem);
+        self.client_key_pem = Some(key_pem);
+        self
+    }
+}
+
+/// Certificates parsed from PEM, validated at load time.
+#[derive(Debug, Clone)]
+pub struct CertificateStore {
+    pem: Vec<u8>,
+    certificates: Vec<CertificateDer<'static>>,
+}
+
+impl CertificateStore {
+    /// Load every certificate in the PEM file at `path`.
+    pub fn from_pem_file(path: impl AsRef<Path>) -> ModbusResult<Self> {
+        let path = path.as_ref();
+        let pem = std::fs::read(path).map_err(|e| {
+            ModbusError::configuration(format!(
+                "Cannot read certificate file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::from_pem(pem)
+            .map_err(|e| ModbusError::configuration(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Parse every certificate in `pem`; fails if there is none.
+    pub fn from_pem(pem: impl Into<Vec<u8>>) -> ModbusResult<Self> {
+        let pem = pem.into();
+        let certificates = parse_certificates(&pem, "certificate")?;
+        Ok(Self { pem, certificates })
+    }
+
+    /// The PEM text, for [`TlsClientConfig`] fields.
+    pub fn pem(&self) -> &[u8] {
+        &self.pem
+    }
+
+    /// The parsed DER certificates, in file order.
+    pub fn certificates(&self) -> &[CertificateDer<'static>] {
+        &self.certificates
+    }
+
+    /// Number of certificates loaded.
+    pub fn len(&self) -> usize {
+        self.certificates.len()
+    }
+
+    /// Whether the store is empty (never true for a successfully loaded store).
+    pub fn is_empty(&self) -> bool {
+        self.certificates.is_empty()
+    }
+}
+
+/// Parse all certificates in `pem`, naming them `what` in errors.
+fn parse_certificates(pem: &[u8], what: &str) -> ModbusResult<Vec<CertificateDer<'static>>> {
+    let certificates = CertificateDer::pem_slice_iter(pem)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ModbusError::configuration(format!("Invalid {} PEM: {}", what, e)))?;
+    if certificates.is_empty() {
+        return Err(ModbusError::configuration(format!(
+            "No {} found in PEM data",
+            what
+        )));
+    }
+    Ok(certificates)
+}
+
+/// Connector and server identity, kept by the transport for reconnects.
+#[derive(Clone)]
+pub(crate) struct TlsSettings {
+    connector: TlsConnector,
+    server_name: ServerName<'static>,
+}
+
+impl TlsSettings {
+    /// Validate `config` and build the rustls client configuration.
+    pub(crate) fn new(config: &TlsClientConfig<'_>) -> ModbusResult<Self> {
+        let server_name = ServerName::try_from(config.server_name.to_string()).map_err(|e| {
+            ModbusError::configuration(format!(
+                "Invalid TLS server name '{}': {}",
+                config.server_name, e
+            ))
+        })?;
+
+        let mut roots = RootCertStore::empty();
+        for cert in parse_certificates(config.ca_cert_pem, "CA certificate")? {
+            roots.add(cert).map_err(|e| {
+                ModbusError::configuration(format!("Unusable CA certificate: {}", e))
+            })?;
+        }
+
+        let builder = ClientConfig::builder_with_provider(Arc::new(safe_provider()))
+            .with_protocol_versions(&[&rustls::version::TLS13, &rustls::version::TLS12])
+            .map_err(|e| ModbusError::configuration(format!("TLS configuration: {}", e)))?
+            .with_root_certificates(roots);
+
+        let client_config = match (config.client_cert_pem, config.client_key_pem) {
+            (None, None) => builder.with_no_client_auth(),
+            (Some(cert_pem), Some(key_pem)) => {
+                let chain = parse_certificates(cert_pem, "client certificate")?;
+                let key = PrivateKeyDer::from_pem_slice(key_pem).map_err(|e| {
+                    ModbusError::configuration(format!("Invalid client private key PEM: {}", e))
+                })?;
+                builder.with_client_auth_cert(chain, key).map_err(|e| {
+                    ModbusError::configuration(format!("Client certificate rejected: {}", e))
+                })?
+            }
+            _ => {
+                return Err(ModbusError::configuration(
+                    "Mutual TLS needs both client_cert_pem and client_key_pem",
+                ))
+            }
+        };
+
+        Ok(Self {
+            connector: TlsConnector::from(Arc::new(client_config)),
+            server_name,
+        })
+    }
+
+    /// Run the TLS handshake over an open TCP stream.
+    pub(crate) async fn handshake(
+        &self,
+        stream: TcpStream,
+        limit: Duration,
+    ) -> ModbusResult<TlsStream<TcpStream>> {
+        match timeout(
+            limit,
+            self.connector.connect(self.server_name.clone(), stream),
+        )
+        .await
+        {
+            Ok(Ok(stream)) => Ok(stream),
+            Ok(Err(e)) => Err(ModbusError::connection(format!(
+                "TLS handshake failed: {}",
+                e
+            ))),
+            Err(_) => Err(ModbusError::timeout(
+                "TLS handshake",
+                limit.as_millis() as u64,
+            )),
+        }
+    }
+}
+
+/// `ring` provider restricted to [`SAFE_CIPHER_SUITES`].
+fn safe_provider() -> CryptoProvider {
+    CryptoProvider {
+        cipher_suites: SAFE_CIPHER_SUITES.to_vec(),
+        ..ring::default_provider()
+    }
+}
+
+/// Modbus TCP transport over TLS.
+///
+/// Framing, transaction IDs, statistics and reconnects (which repeat the
+/// handshake) behave exactly as in [`TcpTransport`].
+pub struct TlsTcpTransport {
+    inner: TcpTransport,
+}
+
+impl TlsTcpTransport {
+    /// Connect to `address` and complete the TLS handshake.
+    pub async fn connect(
+        address: SocketAddr,
+        timeout: Duration,
+        config: &TlsClientConfig<'_>,
+    ) -> ModbusResult<Self> {
+        let settings = TlsSettings::new(config)?;
+        Ok(Self {
+            inner: TcpTransport::connect_tls(address, timeout, settings).await?,
+        })
+    }
+
+    /// The underlying TCP transport.
+    pub fn inner(&self) -> &TcpTransport {
+        &self.inner
+    }
+
+    /// Mutable access to the underlying TCP transport (packet logging, callbacks).
+    pub fn inner_mut(&mut self) -> &mut TcpTransport {
+        &mut self.inner
+    }
+
+    /// Unwrap into the underlying TCP transport, which keeps using TLS.
+    pub fn into_inner(self) -> TcpTransport {
+        self.inner
+    }
+}
+
+impl ModbusTransport for TlsTcpTransport {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        self.inner.request(request).await
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        self.inner.close().await
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.inner.get_stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{ModbusClient, ModbusTcpClient};
+    use rcgen::{BasicConstraints, CertificateParams, IsCa, KeyPair};
+    use rustls_pki_types::PrivateKeyDer;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio_rustls::rustls::ServerConfig;
+    use tokio_rustls::TlsAcceptor;
+
+    /// A CA plus a `localhost` server certificate signed by it.
+    struct TestPki {
+        ca_pem: String,
+        server_config: Arc<ServerConfig>,
+    }
+
+    fn test_pki() -> TestPki {
+        let ca_key = KeyPair::generate().unwrap();
+        let mut ca_params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        let ca_cert = ca_params.self_signed(&ca_key).unwrap();
+
+        let server_key = KeyPair::generate().unwrap();
+        let server_cert = CertificateParams::new(vec!["localhost".to_string()])
+            .unwrap()
+            .signed_by(&server_key, &ca_cert, &ca_key)
+            .unwrap();
+
+        let server_config = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_no_client_auth()
+            .with_single_cert(
+                vec![server_cert.der().clone()],
+                PrivateKeyDer::Pkcs8(server_key.serialize_der().into()),
+            )
+            .unwrap();
+
+        TestPki {
+            ca_pem: ca_cert.pem(),
+            server_config: Arc::new(server_config),
+        }
+    }
+
+    /// TLS Modbus server with one holding register table, serving FC03 and FC06.
+    async fn tls_server(config: Arc<ServerConfig>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let Ok(mut stream) = TlsAcceptor::from(config).accept(tcp).await else {
+                return;
+            };
+            let mut registers = [0u16; 16];
+            loop {
+                let mut header = [0u8; 7];
+                if stream.read_exact(&mut header).await.is_err() {
+                    return;
+                }
+                let len = u16::from_be_bytes([header[4], header[5]]) as usize;
+                let mut pdu = vec![0u8; len - 1];
+                stream.read_exact(&mut pdu).await.unwrap();
+                let address = u16::from_be_bytes([pdu[1], pdu[2]]) as usize;
+                let reply = match pdu[0] {
+                    0x03 => {
+                        let qty = u16::from_be_bytes([pdu[3], pdu[4]]) as usize;
+                        let mut reply = vec![0x03, (qty * 2) as u8];
+                        for r in &registers[address..address + qty] {
+                            reply.extend_from_slice(&r.to_be_bytes());
+                        }
+                        reply
+                    }
+                    0x06 => {
+                        registers[address] = u16::from_be_bytes([pdu[3], pdu[4]]);
+                        pdu.clone()
+                    }
+                    fc => vec![fc | 0x80, 0x01],
+                };
+                let mut frame = header[..4].to_vec();
+                frame.extend_from_slice(&((reply.len() + 1) as u16).to_be_bytes());
+                frame.push(header[6]);
+                frame.extend_from_slice(&reply);
+                stream.write_all(&frame).await.unwrap();
+            }
+        });
+        addr
+    }
+
+    #[tokio::test]
+    async fn read_write_cycle_over_tls() {
+        let pki = test_pki();
+        let addr = tls_server(pki.server_config.clone()).await;
+        let tls = TlsClientConfig::new(pki.ca_pem.as_bytes(), "localhost");
+
+        let mut client = ModbusTcpClient::with_tls(addr, Duration::from_secs(2), &tls)
+            .await
+            .unwrap();
+        client.write_06(1, 3, 0xBEEF).await.unwrap();
+        assert_eq!(client.read_03(1, 2, 3).await.unwrap(), vec![0, 0xBEEF, 0]);
+        assert_eq!(client.get_stats().responses_received, 2);
+        client.close().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn untrusted_server_certificate_is_rejected() {
+        let server = test_pki();
+        let other_ca = test_pki();
+        let addr = tls_server(server.server_config.clone()).await;
+        let tls = TlsClientConfig::new(other_ca.ca_pem.as_bytes(), "localhost");
+
+        let result = TlsTcpTransport::connect(addr, Duration::from_secs(2), &tls).await;
+        assert!(matches!(result, Err(ModbusError::Connection { .. })));
+    }
+
+    #[test]
+    fn invalid_configuration_is_reported() {
+        let missing = TlsSettings::new(&TlsClientConfig::new(b"not a certificate", "localhost"));
+        assert!(matches!(missing, Err(ModbusError::Configuration { .. })));
+
+        let pki = test_pki();
+        let bad_name = TlsSettings::new(&TlsClientConfig::new(pki.ca_pem.as_bytes(), "bad name!"));
+        assert!(matches!(bad_name, Err(ModbusError::Configuration { .. })));
+
+        let ca = pki.ca_pem.as_bytes();
+        let cert_only = TlsClientConfig {
+            client_cert_pem: Some(ca),
+            ..TlsClientConfig::new(ca, "localhost")
+        };
+        assert!(matches!(
+            TlsSettings::new(&cert_only),
+            Err(ModbusError::Configuration { message, .. }) if message.contains("both")
+        ));
+
+        let bad_key = TlsClientConfig::new(ca, "localhost").with_client_cert(ca, b"garbage");
+        assert!(matches!(
+            TlsSettings::new(&bad_key),
+            Err(ModbusError::Configuration { message, .. }) if message.contains("private key")
+        ));
+    }
+
+    #[test]
+    fn certificate_store_loads_pem_files() {
+        let pki = test_pki();
+        let dir = std::env::temp_dir();
+        let good = dir.join(format!("voltage_modbus_ca_{}.pem", std::process::id()));
+        std::fs::write(&good, &pki.ca_pem).unwrap();
+
+        let store = CertificateStore::from_pem_file(&good).unwrap();
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.pem(), pki.ca_pem.as_bytes());
+        std::fs::remove_file(&good).unwrap();
+
+        let missing = CertificateStore::from_pem_file(dir.join("voltage_modbus_missing.pem"));
+        assert!(
+            matches!(missing, Err(ModbusError::Configuration { message, .. }) if message.contains("Cannot read"))
+        );
+        assert!(CertificateStore::from_pem(b"no pem here".to_vec()).is_err());
+    }
+}
@@ -120,18 +120,23 @@ use crc::{Crc, CRC_16_MODBUS};
 ///
 /// This module provides the transport layer abstractions and implementations
 /// for both Modbus TCP and RTU protocols.
+use std::io::IoSlice;
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::time::timeout;
 use tracing::{debug, info};
 
-#[cfg(feature = "rtu")]
+#[cfg(feature = "ascii")]
 use tokio_serial;
 
 use crate::error::{ModbusError, ModbusResult};
+use crate::pdu::ModbusPdu;
+#[cfg(feature = "rtu")]
+use crate::protocol::SlaveId;
 use crate::protocol::{ModbusFunction, ModbusRequest, ModbusResponse};
 
 // ============================================================================
@@ -188,7 +193,7 @@ pub type PacketCallback = Arc<dyn Fn(PacketDirection, &[u8]) + Send + Sync>;
 const MAX_TCP_FRAME_SIZE: usize = 260;
 
 /// Modbus TCP Application Protocol header size
-const MBAP_HEADER_SIZE: usize = 6;
+pub(crate) const MBAP_HEADER_SIZE: usize = 6;
 
 /// Maximum frame size for Modbus RTU
 const MAX_RTU_FRAME_SIZE: usize = 256;
@@ -196,6 +201,18 @@ const MAX_RTU_FRAME_SIZE: usize = 256;
 /// CRC calculator shared by RTU and RTU-over-TCP transports
 const CRC_MODBUS: Crc<u16> = Crc::<u16>::new(&CRC_16_MODBUS);
 
+/// CRC-16/MODBUS of `data`, as appended to RTU frames (low byte first).
+///
+/// ```rust
+/// use voltage_modbus::transport::crc16_modbus;
+///
+/// let crc = crc16_modbus(&[0x01, 0x03, 0x00, 0x00, 0x00, 0x0A]);
+/// assert_eq!(crc.to_le_bytes(), [0xC5, 0xCD]);
+/// ```
+pub fn crc16_modbus(data: &[u8]) -> u16 {
+    CRC_MODBUS.checksum(data)
+}
+
 /// Format raw bytes as hex string for packet logging
 ///
 /// Uses direct string writing for efficiency (avoids intermediate allocations).
@@ -409,8 +426,12 @@ pub trait ModbusTransport: Send + Sync {
     fn get_stats(&self) -> TransportStats;
 }
 
+/// Number of buckets in the [`TransportStats`] latency histogram
+pub const LATENCY_BUCKETS: usize = 16;
+
 /// Transport layer statistics
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransportStats {
     pub requests_sent: u64,
     pub responses_received: u64,
@@ -418,15 +439,647 @@ pub struct TransportStats {
     pub timeouts: u64,
     pub bytes_sent: u64,
     pub bytes_received: u64,
+    /// Retries that waited longer after a timeout (see [`EscalatingTimeout`])
+    pub timeout_escalations: u64,
+    /// Round-trip latency histogram; bucket `i` counts samples in
+    /// `BUCKET_BOUNDARIES[i]..BUCKET_BOUNDARIES[i + 1]` microseconds
+    pub latency_buckets: [u64; LATENCY_BUCKETS],
+    /// Requests that failed as seen by the client, device exceptions
+    /// included; counted by [`GenericModbusClient`](crate::client::GenericModbusClient),
+    /// transports leave it at 0
+    pub error_count: u64,
+    /// When the most recent of those failures happened
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub last_error_at: Option<Instant>,
+    /// Requests resent under a client [`RetryPolicy`](crate::client::RetryPolicy)
+    pub retry_count: u64,
+    /// Latest error of the kind (variant) that failed requests most often;
+    /// kept by [`GenericModbusClient`](crate::client::GenericModbusClient),
+    /// transports leave it `None`
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub most_frequent_error: Option<ModbusError>,
+}
+
+impl TransportStats {
+    /// Latency bucket edges in microseconds (100 µs to 10 s, 1-2-5 steps)
+    ///
+    /// Samples of 10 s or more are counted in the last bucket.
+    pub const BUCKET_BOUNDARIES: [u64; LATENCY_BUCKETS + 1] = [
+        0, 100, 200, 500, 1_000, 2_000, 5_000, 10_000, 20_000, 50_000, 100_000, 200_000, 500_000,
+        1_000_000, 2_000_000, 5_000_000, 10_000_000,
+    ];
+
+    /// Histogram bucket a latency of `micros` falls into
+    pub fn bucket_index(micros: u64) -> usize {
+        Self::BUCKET_BOUNDARIES[1..]
+            .iter()
+            .position(|&upper| micros < upper)
+            .unwrap_or(LATENCY_BUCKETS - 1)
+    }
+
+    /// Count one round trip of `latency` in the histogram
+    pub fn record_latency(&mut self, latency: Duration) {
+        let micros = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        self.latency_buckets[Self::bucket_index(micros)] += 1;
+    }
+
+    /// The `p`-th latency percentile (`p` in 0.0–100.0) in microseconds
+    ///
+    /// Interpolates linearly inside the bucket holding the `p`-th sample, so
+    /// the result is only as precise as the bucket widths. Returns `None`
+    /// without samples or for `p` outside 0.0–100.0.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let total: u64 = self.latency_buckets.iter().sum();
+        if total == 0 {
+            return None;
+        }
+
+        let rank = p / 100.0 * total as f64;
+        let mut below = 0u64;
+        for (i, &count) in self.latency_buckets.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            if rank <= (below + count) as f64 {
+                let lower = Self::BUCKET_BOUNDARIES[i] as f64;
+                let upper = Self::BUCKET_BOUNDARIES[i + 1] as f64;
+                let fraction = ((rank - below as f64) / count as f64).clamp(0.0, 1.0);
+                return Some(lower + fraction * (upper - lower));
+            }
+            below += count;
+        }
+        None
+    }
+
+    /// Median latency in microseconds
+    pub fn p50(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// 95th percentile latency in microseconds
+    pub fn p95(&self) -> Option<f64> {
+        self.percentile(95.0)
+    }
+
+    /// 99th percentile latency in microseconds
+    pub fn p99(&self) -> Option<f64> {
+        self.percentile(99.0)
+    }
+
+    /// Add every counter and latency bucket of `other` into `self`
+    ///
+    /// `most_frequent_error` cannot be combined without the underlying
+    /// tallies; `other`'s is taken only if `self` has none.
+    pub fn merge(&mut self, other: &TransportStats) {
+        self.requests_sent += other.requests_sent;
+        self.responses_received += other.responses_received;
+        self.errors += other.errors;
+        self.timeouts += other.timeouts;
+        self.bytes_sent += other.bytes_sent;
+        self.bytes_received += other.bytes_received;
+        self.timeout_escalations += other.timeout_escalations;
+        for (bucket, count) in self.latency_buckets.iter_mut().zip(other.latency_buckets) {
+            *bucket += count;
+        }
+        self.error_count += other.error_count;
+        self.last_error_at = self.last_error_at.max(other.last_error_at);
+        self.retry_count += other.retry_count;
+        if self.most_frequent_error.is_none() {
+            self.most_frequent_error = other.most_frequent_error.clone();
+        }
+    }
+
+    /// What was counted between `baseline` and `self`, an earlier and a later
+    /// snapshot of the same counters
+    pub fn since(&self, baseline: &TransportStats) -> TransportStats {
+        TransportStats {
+            requests_sent: self.requests_sent.saturating_sub(baseline.requests_sent),
+            responses_received: self
+                .responses_received
+                .saturating_sub(baseline.responses_received),
+            errors: self.errors.saturating_sub(baseline.errors),
+            timeouts: self.timeouts.saturating_sub(baseline.timeouts),
+            bytes_sent: self.bytes_sent.saturating_sub(baseline.bytes_sent),
+            bytes_received: self.bytes_received.saturating_sub(baseline.bytes_received),
+            timeout_escalations: self
+                .timeout_escalations
+                .saturating_sub(baseline.timeout_escalations),
+            latency_buckets: std::array::from_fn(|i| {
+                self.latency_buckets[i].saturating_sub(baseline.latency_buckets[i])
+            }),
+            error_count: self.error_count.saturating_sub(baseline.error_count),
+            last_error_at: self
+                .last_error_at
+                .filter(|_| self.last_error_at != baseline.last_error_at),
+            retry_count: self.retry_count.saturating_sub(baseline.retry_count),
+            most_frequent_error: self.most_frequent_error.clone(),
+        }
+    }
+}
+
+/// Lock-free transport counters
+///
+/// Updated by the transport on every request and readable from any thread
+/// without locking. Loads and stores are `Relaxed`: each counter is exact, but
+/// a [`snapshot`](Self::snapshot) taken mid-request may see one counter
+/// updated before another.
+#[derive(Debug, Default)]
+pub struct AtomicTransportStats {
+    requests_sent: AtomicU64,
+    responses_received: AtomicU64,
+    errors: AtomicU64,
+    timeouts: AtomicU64,
+    timeout_escalations: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    latency_buckets: [AtomicU64; LATENCY_BUCKETS],
+}
+
+impl AtomicTransportStats {
+    /// Create zeroed counters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count one request sent
+    #[inline]
+    pub fn increment_requests_sent(&self) {
+        self.requests_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count one response received
+    #[inline]
+    pub fn increment_responses_received(&self) {
+        self.responses_received.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count one error
+    #[inline]
+    pub fn increment_errors(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count one timeout
+    #[inline]
+    pub fn increment_timeouts(&self) {
+        self.timeouts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count one escalated retry
+    #[inline]
+    pub fn increment_timeout_escalations(&self) {
+        self.timeout_escalations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Add `n` bytes sent
+    #[inline]
+    pub fn add_bytes_sent(&self, n: u64) {
+        self.bytes_sent.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// Add `n` bytes received
+    #[inline]
+    pub fn add_bytes_received(&self, n: u64) {
+        self.bytes_received.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// Count one round trip of `latency` in the histogram
+    #[inline]
+    pub fn record_latency(&self, latency: Duration) {
+        let micros = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        self.latency_buckets[TransportStats::bucket_index(micros)].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Read all counters into a plain [`TransportStats`]
+    pub fn snapshot(&self) -> TransportStats {
+        TransportStats {
+            requests_sent: self.requests_sent.load(Ordering::Relaxed),
+            responses_received: self.responses_received.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            timeout_escalations: self.timeout_escalations.load(Ordering::Relaxed),
+            latency_buckets: std::array::from_fn(|i| {
+                self.latency_buckets[i].load(Ordering::Relaxed)
+            }),
+            error_count: 0,
+            last_error_at: None,
+            retry_count: 0,
+            most_frequent_error: None,
+        }
+    }
+}
+
+/// Back-off schedule between TCP reconnect attempts.
+///
+/// After `n` consecutive failed reconnects the transport waits
+/// `min(initial_ms * multiplier^(n-1), max_ms) * (1 + jitter * rand)` before
+/// trying again, where `jitter = jitter_pct / 100` and `rand` is uniform in
+/// `[0, 1)`. This keeps a rebooting device from being flooded with SYNs.
+///
+/// # Example
+///
+/// ```rust
+/// use voltage_modbus::ReconnectBackoff;
+/// use std::time::Duration;
+///
+/// let backoff = ReconnectBackoff {
+///     initial_ms: 100,
+///     max_ms: 1_000,
+///     multiplier: 2.0,
+///     jitter_pct: 0.0,
+/// };
+/// assert_eq!(backoff.base_delay(0), Duration::from_millis(100));
+/// assert_eq!(backoff.base_delay(3), Duration::from_millis(800));
+/// assert_eq!(backoff.base_delay(4), Duration::from_millis(1_000)); // capped
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconnectBackoff {
+    /// Delay after the first failed attempt (milliseconds).
+    pub initial_ms: u64,
+    /// Upper bound on the un-jittered delay (milliseconds).
+    pub max_ms: u64,
+    /// Growth factor applied per failed attempt.
+    pub multiplier: f64,
+    /// Maximum random extra delay, as a percentage of the base delay (0-100).
+    pub jitter_pct: f64,
+}
+
+impl ReconnectBackoff {
+    /// Un-jittered delay after `attempt` previous failures (0-based).
+    pub fn base_delay(&self, attempt: u32) -> Duration {
+        let exp = attempt.min(i32::MAX as u32) as i32;
+        let ms = (self.initial_ms as f64 * self.multiplier.powi(exp)).min(self.max_ms as f64);
+        Duration::from_millis(ms.max(0.0) as u64)
+    }
+
+    /// Delay for `attempt` with a caller-supplied random sample in `[0, 1)`.
+    ///
+    /// Returns a value in `[base, base * (1 + jitter_pct / 100)]`.
+    pub fn delay_with_sample(&self, attempt: u32, sample: f64) -> Duration {
+        let jitter = self.jitter_pct.clamp(0.0, 100.0) / 100.0;
+        self.base_delay(attempt)
+            .mul_f64(1.0 + jitter * sample.clamp(0.0, 1.0))
+    }
+
+    /// Delay for `attempt` with random jitter applied.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.delay_with_sample(attempt, unit_random())
+    }
+}
+
+impl Default for ReconnectBackoff {
+    /// 100 ms doubling up to 30 s, with 10% jitter.
+    fn default() -> Self {
+        Self {
+            initial_ms: 100,
+            max_ms: 30_000,
+            multiplier: 2.0,
+            jitter_pct: 10.0,
+        }
+    }
+}
+
+/// When and how often [`TcpTransport`] reconnects to retry a request after
+/// the connection drops.
+///
+/// Retry `n` (0-based) waits `min(base_delay * 2^n, max_delay)` before
+/// reconnecting and resending. `max_retries: None` keeps retrying until
+/// the server is back.
+///
+/// # Example
+///
+/// ```rust
+/// use voltage_modbus::ReconnectPolicy;
+/// use std::time::Duration;
+///
+/// let policy = ReconnectPolicy {
+///     max_retries: Some(5),
+///     base_delay: Duration::from_millis(100),
+///     max_delay: Duration::from_secs(1),
+/// };
+/// assert_eq!(policy.delay(0), Duration::from_millis(100));
+/// assert_eq!(policy.delay(2), Duration::from_millis(400));
+/// assert_eq!(policy.delay(4), Duration::from_secs(1)); // capped
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Retries after the first failure (`None` = unlimited).
+    pub max_retries: Option<u32>,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound on the delay between retries.
+    pub max_delay: Duration,
+}
+
+impl ReconnectPolicy {
+    /// Delay before retry `retry` (0-based)
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.checked_pow(retry).unwrap_or(u32::MAX);
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+
+    /// Whether retry `retry` (0-based) is allowed
+    pub fn allows(&self, retry: u32) -> bool {
+        self.max_retries.is_none_or(|max| retry < max)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    /// Three retries, 100 ms doubling up to 5 s.
+    fn default() -> Self {
+        Self {
+            max_retries: Some(3),
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+/// How [`TcpTransport`] resends a request whose response timed out.
+///
+/// A timeout does not mean the device never saw the request: the response
+/// may have been lost on the way back, and may still arrive late. Reads are
+/// safe to repeat, so [`FreshTransactionId`](Self::FreshTransactionId) and
+/// [`SameTransactionId`](Self::SameTransactionId) only resend reads. Writes
+/// might be applied twice and are resent only with [`Force`](Self::Force).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResendStrategy {
+    /// Resend reads under a new MBAP transaction ID.
+    ///
+    /// A late response to the original request no longer matches and is
+    /// discarded as stale.
+    FreshTransactionId,
+    /// Resend reads under the original transaction ID.
+    ///
+    /// Whichever response arrives first is accepted, including a late answer
+    /// to the original request.
+    SameTransactionId,
+    /// Resend reads and writes under a new transaction ID.
+    Force,
+}
+
+impl ResendStrategy {
+    /// Whether `function` may be resent under this strategy
+    pub fn applies_to(self, function: ModbusFunction) -> bool {
+        self == Self::Force || !function.is_write_function()
+    }
+}
+
+/// Response timeout that grows on each retry.
+///
+/// Devices that answer quickly most of the time but occasionally stall
+/// (flash writes, self-diagnostics) can use a short `initial` timeout instead
+/// of a conservatively long one. Attempt `n` (0-based) waits
+/// `initial * escalation_factor^n`; after the first attempt up to
+/// `max_retries` retries are made.
+///
+/// # Example
+///
+/// ```rust
+/// use voltage_modbus::EscalatingTimeout;
+/// use std::time::Duration;
+///
+/// let escalating = EscalatingTimeout {
+///     initial: Duration::from_millis(200),
+///     escalation_factor: 2.5,
+///     max_retries: 2,
+/// };
+/// assert_eq!(escalating.timeout_for(0), Duration::from_millis(200));
+/// assert_eq!(escalating.timeout_for(2), Duration::from_millis(1_250));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EscalatingTimeout {
+    /// Response timeout of the first attempt.
+    pub initial: Duration,
+    /// Factor applied to the timeout on every retry.
+    pub escalation_factor: f64,
+    /// Retries after the first attempt times out.
+    pub max_retries: u32,
+}
+
+impl EscalatingTimeout {
+    /// Response timeout of `attempt` (0 = first attempt)
+    pub fn timeout_for(&self, attempt: u32) -> Duration {
+        let exp = attempt.min(i32::MAX as u32) as i32;
+        self.initial
+            .mul_f64(self.escalation_factor.max(1.0).powi(exp))
+    }
+}
+
+/// Uniform sample in `[0, 1)` without pulling in a RNG crate.
+///
+/// `RandomState` is seeded per instance from OS randomness, which is plenty
+/// for spreading reconnect attempts.
+pub(crate) fn unit_random() -> f64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let bits = RandomState::new().build_hasher().finish() >> 11; // keep 53 bits
+    bits as f64 / (1u64 << 53) as f64
+}
+
+/// Write `header` followed by `pdu`, preferring a single vectored write
+///
+/// Short writes are retried with whatever is left of the two buffers.
+async fn write_frame_vectored<W>(stream: &mut W, header: &[u8], pdu: &[u8]) -> std::io::Result<()>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    let total = header.len() + pdu.len();
+    let mut written = 0;
+    while written < total {
+        let n = if written < header.len() {
+            let bufs = [IoSlice::new(&header[written..]), IoSlice::new(pdu)];
+            stream.write_vectored(&bufs).await?
+        } else {
+            stream.write(&pdu[written - header.len()..]).await?
+        };
+        if n == 0 {
+            return Err(std::io::ErrorKind::WriteZero.into());
+        }
+        written += n;
+    }
+    Ok(())
+}
+
+/// Byte stream under a [`TcpTransport`]: plain TCP or TLS over TCP.
+pub(crate) enum TcpLink {
+    Plain(TcpStream),
+    #[cfg(feature = "tls")]
+    Tls(Box<tokio_rustls::client::TlsStream<TcpStream>>),
+}
+
+impl tokio::io::AsyncRead for TcpLink {
+    fn poll_read(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            TcpLink::Plain(s) => std::pin::Pin::new(s).poll_read(cx, buf),
+            #[cfg(feature = "tls")]
+            TcpLink::Tls(s) => std::pin::Pin::new(s.as_mut()).poll_read(cx, buf),
+        }
+    }
+}
+
+impl tokio::io::AsyncWrite for TcpLink {
+    fn poll_write(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        match self.get_mut() {
+            TcpLink::Plain(s) => std::pin::Pin::new(s).poll_write(cx, buf),
+            #[cfg(feature = "tls")]
+            TcpLink::Tls(s) => std::pin::Pin::new(s.as_mut()).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        match self.get_mut() {
+            TcpLink::Plain(s) => std::pin::Pin::new(s).poll_write_vectored(cx, bufs),
+            #[cfg(feature = "tls")]
+            TcpLink::Tls(s) => std::pin::Pin::new(s.as_mut()).poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            TcpLink::Plain(s) => s.is_write_vectored(),
+            #[cfg(feature = "tls")]
+            TcpLink::Tls(s) => s.is_write_vectored(),
+        }
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            TcpLink::Plain(s) => std::pin::Pin::new(s).poll_flush(cx),
+            #[cfg(feature = "tls")]
+            TcpLink::Tls(s) => std::pin::Pin::new(s.as_mut()).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            TcpLink::Plain(s) => std::pin::Pin::new(s).poll_shutdown(cx),
+            #[cfg(feature = "tls")]
+            TcpLink::Tls(s) => std::pin::Pin::new(s.as_mut()).poll_shutdown(cx),
+        }
+    }
+}
+
+pub use socket2::TcpKeepalive;
+
+/// Connection settings for [`TcpTransport::with_config`].
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use voltage_modbus::transport::{TcpKeepalive, TcpTransport, TcpTransportConfig};
+///
+/// # async fn example() -> voltage_modbus::ModbusResult<()> {
+/// let config = TcpTransportConfig::new(Duration::from_secs(1)).with_tcp_keepalive(
+///     TcpKeepalive::new()
+///         .with_time(Duration::from_secs(60))
+///         .with_interval(Duration::from_secs(10)),
+/// );
+/// let transport = TcpTransport::with_config("127.0.0.1:502".parse().unwrap(), config).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TcpTransportConfig {
+    /// Connect and response timeout.
+    pub timeout: Duration,
+    /// Disable Nagle's algorithm (`TCP_NODELAY`), on by default.
+    ///
+    /// Modbus frames are small and every request waits for its response, so
+    /// with Nagle enabled a request can sit in the send buffer until the
+    /// previous segment is acknowledged, adding up to a delayed-ACK period
+    /// (often 40 ms or more) to each round trip.
+    pub tcp_nodelay: bool,
+    /// OS-level TCP keep-alive probing (`SO_KEEPALIVE`), off by default.
+    ///
+    /// Modbus sessions are long-lived and often idle between polls. A
+    /// firewall or NAT that silently drops the idle connection leaves it
+    /// half-open, and the loss is only noticed when the next request times
+    /// out. Keep-alive probes detect the dead peer in the background, so the
+    /// next request reconnects instead of waiting out a timeout.
+    pub tcp_keepalive: Option<TcpKeepalive>,
+}
+
+impl TcpTransportConfig {
+    /// `TCP_NODELAY` on and keep-alive off, with `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            tcp_nodelay: true,
+            tcp_keepalive: None,
+        }
+    }
+
+    /// Enable or disable `TCP_NODELAY`.
+    pub fn with_tcp_nodelay(mut self, tcp_nodelay: bool) -> Self {
+        self.tcp_nodelay = tcp_nodelay;
+        self
+    }
+
+    /// Enable TCP keep-alive with `keepalive` probe settings.
+    pub fn with_tcp_keepalive(mut self, keepalive: TcpKeepalive) -> Self {
+        self.tcp_keepalive = Some(keepalive);
+        self
+    }
+
+    /// Apply the socket options to a freshly connected `stream`.
+    fn apply(&self, stream: &TcpStream) -> ModbusResult<()> {
+        stream.set_nodelay(self.tcp_nodelay).map_err(|e| {
+            ModbusError::connection_with_source(format!("Failed to set TCP_NODELAY: {}", e), e)
+        })?;
+        if let Some(keepalive) = &self.tcp_keepalive {
+            socket2::SockRef::from(stream)
+                .set_tcp_keepalive(keepalive)
+                .map_err(|e| {
+                    ModbusError::connection_with_source(
+                        format!("Failed to set TCP keep-alive: {}", e),
+                        e,
+                    )
+                })?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for TcpTransportConfig {
+    /// 5 s timeout, `TCP_NODELAY` on, keep-alive off.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5))
+    }
 }
 
 /// Modbus TCP transport implementation
 pub struct TcpTransport {
-    stream: Option<TcpStream>,
+    stream: Option<TcpLink>,
     pub address: SocketAddr,
     timeout: Duration,
     transaction_id: u16,
-    stats: TransportStats,
+    stats: Arc<AtomicTransportStats>,
     /// Persistent read buffer — reused across requests to avoid per-response heap allocation
     read_buf: Box<[u8; 512]>,
     /// Enable packet logging for debugging (built-in tracing)
@@ -436,53 +1089,151 @@ pub struct TcpTransport {
     /// When set, this callback is invoked with the actual bytes sent/received,
     /// enabling accurate logging without packet reconstruction.
     packet_callback: Option<PacketCallback>,
+    /// Optional back-off between reconnect attempts (`None` = reconnect immediately)
+    reconnect_backoff: Option<ReconnectBackoff>,
+    /// Consecutive failed reconnect attempts since the last successful connect
+    reconnect_attempt: u32,
+    /// Earliest instant the next reconnect attempt may start
+    next_reconnect_at: Option<Instant>,
+    /// Reconnect and retry a request whose connection dropped
+    reconnect_policy: Option<ReconnectPolicy>,
+    /// Resends allowed after a response timeout, and how to send them
+    auto_resend: Option<(u32, ResendStrategy)>,
+    /// Longer response timeouts for retries after a timeout
+    escalating_timeout: Option<EscalatingTimeout>,
+    /// TLS settings; every (re)connect runs the handshake when set
+    #[cfg(feature = "tls")]
+    tls: Option<crate::tls::TlsSettings>,
+    /// `TCP_NODELAY` and keep-alive, applied on every (re)connect
+    socket_options: TcpTransportConfig,
 }
 
 impl TcpTransport {
     /// Create a new TCP transport
     pub async fn new(address: SocketAddr, timeout: Duration) -> ModbusResult<Self> {
+        Self::with_config(address, TcpTransportConfig::new(timeout)).await
+    }
+
+    /// Create a new TCP transport with `TCP_NODELAY` and keep-alive set per
+    /// `config`; the options are applied again on every reconnect.
+    pub async fn with_config(
+        address: SocketAddr,
+        config: TcpTransportConfig,
+    ) -> ModbusResult<Self> {
         let stream = TcpStream::connect(address).await.map_err(|e| {
-            ModbusError::connection(format!("Failed to connect to {}: {}", address, e))
+            ModbusError::connection_with_source(
+                format!("Failed to connect to {}: {}", address, e),
+                e,
+            )
         })?;
-        stream
-            .set_nodelay(true)
-            .map_err(|e| ModbusError::connection(format!("Failed to set TCP_NODELAY: {}", e)))?;
+        config.apply(&stream)?;
 
         Ok(Self {
-            stream: Some(stream),
+            stream: Some(TcpLink::Plain(stream)),
             address,
-            timeout,
+            timeout: config.timeout,
             transaction_id: 1,
-            stats: TransportStats::default(),
+            stats: Arc::default(),
             read_buf: Box::new([0u8; 512]),
             packet_logging: false,
             packet_callback: None,
+            reconnect_backoff: None,
+            reconnect_attempt: 0,
+            next_reconnect_at: None,
+            reconnect_policy: None,
+            auto_resend: None,
+            escalating_timeout: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            socket_options: config,
         })
     }
 
+    /// Create a new TCP transport that backs off between reconnect attempts
+    ///
+    /// The initial connection is made immediately; the back-off only applies
+    /// when the connection is lost and later requests have to reconnect.
+    pub async fn with_reconnect_backoff(
+        address: SocketAddr,
+        timeout: Duration,
+        backoff: ReconnectBackoff,
+    ) -> ModbusResult<Self> {
+        let mut transport = Self::new(address, timeout).await?;
+        transport.reconnect_backoff = Some(backoff);
+        Ok(transport)
+    }
+
+    /// Create a new TCP transport that reconnects and retries when the
+    /// connection drops
+    ///
+    /// When a request fails with a connection error (reset, EOF, failed
+    /// reconnect) the transport waits per `policy`, reconnects and resends
+    /// the same request, surfacing the error only once the retries run out.
+    /// Timeouts and device exceptions are returned as usual. A write that
+    /// was in flight when the link dropped may be applied twice.
+    ///
+    /// The initial connection is made immediately and is not retried.
+    pub async fn new_with_reconnect(
+        address: SocketAddr,
+        timeout: Duration,
+        policy: ReconnectPolicy,
+    ) -> ModbusResult<Self> {
+        let mut transport = Self::new(address, timeout).await?;
+        transport.reconnect_policy = Some(policy);
+        Ok(transport)
+    }
+
+    /// Create a new TCP transport whose response timeout escalates on retry
+    ///
+    /// `escalating.initial` is the timeout of the first attempt and of the
+    /// connect; each retry after a response timeout waits longer, see
+    /// [`EscalatingTimeout`]. Retries reuse the connection and a fresh
+    /// transaction ID, so a late answer to an earlier attempt is discarded.
+    /// Like [`with_auto_resend`](Self::with_auto_resend), writes are only
+    /// retried under [`ResendStrategy::Force`].
+    pub async fn with_escalating_timeout(
+        address: SocketAddr,
+        escalating: EscalatingTimeout,
+    ) -> ModbusResult<Self> {
+        let mut transport = Self::new(address, escalating.initial).await?;
+        transport.escalating_timeout = Some(escalating);
+        Ok(transport)
+    }
+
     /// Create a new TCP transport with packet logging enabled
     pub async fn with_packet_logging(
         address: SocketAddr,
         timeout: Duration,
         enable_logging: bool,
     ) -> ModbusResult<Self> {
-        let stream = TcpStream::connect(address).await.map_err(|e| {
-            ModbusError::connection(format!("Failed to connect to {}: {}", address, e))
-        })?;
-        stream
-            .set_nodelay(true)
-            .map_err(|e| ModbusError::connection(format!("Failed to set TCP_NODELAY: {}", e)))?;
+        let mut transport = Self::new(address, timeout).await?;
+        transport.packet_logging = enable_logging;
+        Ok(transport)
+    }
 
-        Ok(Self {
-            stream: Some(stream),
-            address,
-            timeout,
-            transaction_id: 1,
-            stats: TransportStats::default(),
-            read_buf: Box::new([0u8; 512]),
-            packet_logging: enable_logging,
-            packet_callback: None,
-        })
+    /// Resend a request up to `attempts` times when its response times out
+    ///
+    /// Only timeouts waiting for the response trigger a resend; the
+    /// connection is kept open so a late response can still be read (and,
+    /// with a fresh transaction ID, discarded). Writes are never resent
+    /// unless `strategy` is [`ResendStrategy::Force`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use voltage_modbus::transport::{ResendStrategy, TcpTransport};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> voltage_modbus::ModbusResult<()> {
+    /// let transport = TcpTransport::new("127.0.0.1:502".parse().unwrap(), Duration::from_secs(1))
+    ///     .await?
+    ///     .with_auto_resend(2, ResendStrategy::FreshTransactionId);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_auto_resend(mut self, attempts: u32, strategy: ResendStrategy) -> Self {
+        self.auto_resend = Some((attempts, strategy));
+        self
     }
 
     /// Enable or disable packet logging
@@ -528,19 +1279,107 @@ impl TcpTransport {
         self.packet_callback = None;
     }
 
+    /// Shared handle to the live counters, for monitoring from another task
+    ///
+    /// The handle keeps updating as the transport sends requests; call
+    /// [`AtomicTransportStats::snapshot`] to read it.
+    pub fn stats_handle(&self) -> Arc<AtomicTransportStats> {
+        Arc::clone(&self.stats)
+    }
+
+    /// Number of consecutive failed reconnect attempts (0 while healthy)
+    pub fn reconnect_attempt(&self) -> u32 {
+        self.reconnect_attempt
+    }
+
+    /// Time left before the next reconnect attempt is allowed
+    ///
+    /// Returns `Duration::ZERO` when no back-off is pending.
+    pub fn next_reconnect_in(&self) -> Duration {
+        self.next_reconnect_at.map_or(Duration::ZERO, |at| {
+            at.saturating_duration_since(Instant::now())
+        })
+    }
+
     /// Reconnect to the server
+    ///
+    /// With a [`ReconnectBackoff`] configured, waits out any pending back-off
+    /// first and schedules the next one if this attempt fails.
     async fn reconnect(&mut self) -> ModbusResult<()> {
         self.stream = None;
 
-        let stream = TcpStream::connect(self.address).await.map_err(|e| {
-            ModbusError::connection(format!("Failed to reconnect to {}: {}", self.address, e))
-        })?;
-        stream.set_nodelay(true).map_err(|e| {
-            ModbusError::connection(format!("Failed to set TCP_NODELAY on reconnect: {}", e))
-        })?;
+        if let Some(at) = self.next_reconnect_at {
+            tokio::time::sleep_until(at.into()).await;
+        }
 
-        self.stream = Some(stream);
-        Ok(())
+        match self.connect_stream().await {
+            Ok(stream) => {
+                self.stream = Some(stream);
+                self.reconnect_attempt = 0;
+                self.next_reconnect_at = None;
+                Ok(())
+            }
+            Err(e) => {
+                if let Some(backoff) = self.reconnect_backoff {
+                    let delay = backoff.delay(self.reconnect_attempt);
+                    self.next_reconnect_at = Some(Instant::now() + delay);
+                    debug!(
+                        protocol = "tcp",
+                        attempt = self.reconnect_attempt + 1,
+                        delay_ms = delay.as_millis() as u64,
+                        "modbus.reconnect.backoff"
+                    );
+                }
+                self.reconnect_attempt = self.reconnect_attempt.saturating_add(1);
+                Err(e)
+            }
+        }
+    }
+
+    /// Open a fresh stream to `self.address`
+    async fn connect_stream(&self) -> ModbusResult<TcpLink> {
+        let stream = TcpStream::connect(self.address).await.map_err(|e| {
+            ModbusError::connection_with_source(
+                format!("Failed to reconnect to {}: {}", self.address, e),
+                e,
+            )
+        })?;
+        self.socket_options.apply(&stream)?;
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            let stream = tls.handshake(stream, self.timeout).await?;
+            return Ok(TcpLink::Tls(Box::new(stream)));
+        }
+        Ok(TcpLink::Plain(stream))
+    }
+
+    /// Connect to `address` over TLS
+    #[cfg(feature = "tls")]
+    pub(crate) async fn connect_tls(
+        address: SocketAddr,
+        timeout: Duration,
+        tls: crate::tls::TlsSettings,
+    ) -> ModbusResult<Self> {
+        let mut transport = Self {
+            stream: None,
+            address,
+            timeout,
+            transaction_id: 1,
+            stats: Arc::default(),
+            read_buf: Box::new([0u8; 512]),
+            packet_logging: false,
+            packet_callback: None,
+            reconnect_backoff: None,
+            reconnect_attempt: 0,
+            next_reconnect_at: None,
+            reconnect_policy: None,
+            auto_resend: None,
+            escalating_timeout: None,
+            tls: Some(tls),
+            socket_options: TcpTransportConfig::new(timeout),
+        };
+        transport.stream = Some(transport.connect_stream().await?);
+        Ok(transport)
     }
 
     /// Get next transaction ID
@@ -552,70 +1391,45 @@ impl TcpTransport {
         self.transaction_id
     }
 
-    /// Encode request to TCP frame
+    /// Encode request to TCP frame parts
     ///
-    /// Returns a stack-allocated buffer and the number of valid bytes.
-    /// Modbus TCP frames are at most 260 bytes (MBAP 6 + PDU max 254),
-    /// so [u8; 260] on the stack is always sufficient.
+    /// Returns the MBAP header (including the unit ID) as a stack array and
+    /// the PDU separately, so both can be handed to one vectored write without
+    /// copying the PDU into a frame buffer.
     fn encode_request(
         &mut self,
         request: &ModbusRequest,
-    ) -> ModbusResult<([u8; MAX_TCP_FRAME_SIZE], usize)> {
+    ) -> ModbusResult<([u8; MBAP_HEADER_SIZE + 1], ModbusPdu)> {
         let transaction_id = self.next_transaction_id();
-        let protocol_id = 0u16; // Always 0 for Modbus
-
-        // Calculate PDU length (unit_id + function_code + data)
-        let pdu_length = 1
-            + 1
-            + match request.function {
-                ModbusFunction::ReadCoils
-                | ModbusFunction::ReadDiscreteInputs
-                | ModbusFunction::ReadHoldingRegisters
-                | ModbusFunction::ReadInputRegisters => 4, // address (2) + quantity (2)
-
-                ModbusFunction::WriteSingleCoil | ModbusFunction::WriteSingleRegister => 4, // address (2) + value (2)
+        Self::encode_request_as(request, transaction_id)
+    }
 
-                ModbusFunction::WriteMultipleCoils | ModbusFunction::WriteMultipleRegisters => {
-                    5 + request.data.len()
-                } // address (2) + quantity (2) + byte_count (1) + data
-            };
+    /// Encode request to TCP frame parts under a given transaction ID
+    fn encode_request_as(
+        request: &ModbusRequest,
+        transaction_id: u16,
+    ) -> ModbusResult<([u8; MBAP_HEADER_SIZE + 1], ModbusPdu)> {
+        let protocol_id = 0u16; // Always 0 for Modbus
 
-        let mut frame = [0u8; MAX_TCP_FRAME_SIZE];
-        let mut pos = 0usize;
-
-        // MBAP Header: Transaction ID (2) + Protocol ID (2) + Length (2)
-        let tid_bytes = transaction_id.to_be_bytes();
-        frame[pos] = tid_bytes[0];
-        frame[pos + 1] = tid_bytes[1];
-        pos += 2;
-        let pid_bytes = protocol_id.to_be_bytes();
-        frame[pos] = pid_bytes[0];
-        frame[pos + 1] = pid_bytes[1];
-        pos += 2;
-        let len_bytes = (pdu_length as u16).to_be_bytes();
-        frame[pos] = len_bytes[0];
-        frame[pos + 1] = len_bytes[1];
-        pos += 2;
-
-        // PDU: Unit ID + Function Code + Data
-        frame[pos] = request.slave_id;
-        pos += 1;
-        frame[pos] = request.function.to_u8();
-        pos += 1;
-        let addr_bytes = request.address.to_be_bytes();
-        frame[pos] = addr_bytes[0];
-        frame[pos + 1] = addr_bytes[1];
-        pos += 2;
+        // PDU: Function Code + Data
+        let mut pdu = ModbusPdu::new();
+        pdu.push(request.function.to_u8())?;
+        // Read Exception Status, Diagnostics and MEI requests carry no register address
+        if !matches!(
+            request.function,
+            ModbusFunction::ReadExceptionStatus
+                | ModbusFunction::Diagnostics
+                | ModbusFunction::EncapsulatedInterfaceTransport
+        ) {
+            pdu.push_u16(request.address)?;
+        }
 
         match request.function {
             ModbusFunction::ReadCoils
             | ModbusFunction::ReadDiscreteInputs
             | ModbusFunction::ReadHoldingRegisters
             | ModbusFunction::ReadInputRegisters => {
-                let qty_bytes = request.quantity.to_be_bytes();
-                frame[pos] = qty_bytes[0];
-                frame[pos + 1] = qty_bytes[1];
-                pos += 2;
+                pdu.push_u16(request.quantity)?;
             }
 
             ModbusFunction::WriteSingleCoil => {
@@ -624,49 +1438,64 @@ impl TcpTransport {
                 } else {
                     0x0000
                 };
-                let val_bytes = value.to_be_bytes();
-                frame[pos] = val_bytes[0];
-                frame[pos + 1] = val_bytes[1];
-                pos += 2;
+                pdu.push_u16(value)?;
             }
 
             ModbusFunction::WriteSingleRegister => {
                 if request.data.len() >= 2 {
-                    frame[pos] = request.data[0];
-                    frame[pos + 1] = request.data[1];
+                    pdu.extend(&request.data[0..2])?;
                 } else {
-                    frame[pos] = 0;
-                    frame[pos + 1] = 0;
+                    pdu.push_u16(0)?;
                 }
-                pos += 2;
             }
 
             ModbusFunction::WriteMultipleCoils | ModbusFunction::WriteMultipleRegisters => {
-                let qty_bytes = request.quantity.to_be_bytes();
-                frame[pos] = qty_bytes[0];
-                frame[pos + 1] = qty_bytes[1];
-                pos += 2;
+                pdu.push_u16(request.quantity)?;
                 debug_assert!(
                     request.data.len() <= 246,
                     "data payload too large for Modbus frame"
                 );
-                frame[pos] = u8::try_from(request.data.len()).map_err(|_| {
+                pdu.push(u8::try_from(request.data.len()).map_err(|_| {
                     ModbusError::invalid_data("data payload too large for Modbus frame")
-                })?;
-                pos += 1;
-                let data_len = request.data.len();
-                frame[pos..pos + data_len].copy_from_slice(&request.data);
-                pos += data_len;
+                })?)?;
+                pdu.extend(&request.data)?;
+            }
+
+            ModbusFunction::MaskWriteRegister => {
+                // AND mask (2 bytes) + OR mask (2 bytes)
+                pdu.extend(&request.data)?;
+            }
+
+            ModbusFunction::ReadWriteMultipleRegisters => {
+                // Read quantity, then write address, quantity, byte count and values
+                pdu.push_u16(request.quantity)?;
+                pdu.extend(&request.data)?;
+            }
+
+            ModbusFunction::ReadExceptionStatus => {}
+
+            ModbusFunction::Diagnostics | ModbusFunction::EncapsulatedInterfaceTransport => {
+                // Sub-function (2 bytes) or MEI type (1 byte), then data
+                pdu.extend(&request.data)?;
             }
         }
 
-        Ok((frame, pos))
+        // MBAP Header: Transaction ID (2) + Protocol ID (2) + Length (2) + Unit ID (1)
+        // Length counts the unit ID plus the PDU.
+        let length = (1 + pdu.len()) as u16;
+        let mut header = [0u8; MBAP_HEADER_SIZE + 1];
+        header[0..2].copy_from_slice(&transaction_id.to_be_bytes());
+        header[2..4].copy_from_slice(&protocol_id.to_be_bytes());
+        header[4..6].copy_from_slice(&length.to_be_bytes());
+        header[6] = request.slave_id;
+
+        Ok((header, pdu))
     }
 
     /// Decode response from TCP frame (zero-copy)
     ///
     /// Takes ownership of the frame buffer to avoid copying payload data.
-    fn decode_response(&self, frame: Vec<u8>) -> ModbusResult<ModbusResponse> {
+    pub(crate) fn decode_response(frame: Vec<u8>) -> ModbusResult<ModbusResponse> {
         if frame.len() < MBAP_HEADER_SIZE + 2 {
             return Err(ModbusError::frame("Frame too short"));
         }
@@ -714,23 +1543,35 @@ impl TcpTransport {
     ///
     /// Returns `(frame_bytes, transaction_id)`.  The transaction ID is assigned by the
     /// caller so that we can track which response belongs to which request in pipelining.
-    fn encode_request_with_tid(&self, request: &ModbusRequest, tid: u16) -> ModbusResult<Vec<u8>> {
+    pub(crate) fn encode_request_with_tid(
+        request: &ModbusRequest,
+        tid: u16,
+    ) -> ModbusResult<Vec<u8>> {
         let protocol_id = 0u16;
 
-        let pdu_length = 1
-            + 1
-            + match request.function {
-                ModbusFunction::ReadCoils
-                | ModbusFunction::ReadDiscreteInputs
-                | ModbusFunction::ReadHoldingRegisters
-                | ModbusFunction::ReadInputRegisters => 4,
+        let pdu_length =
+            1 + 1
+                + match request.function {
+                    ModbusFunction::ReadCoils
+                    | ModbusFunction::ReadDiscreteInputs
+                    | ModbusFunction::ReadHoldingRegisters
+                    | ModbusFunction::ReadInputRegisters => 4,
 
-                ModbusFunction::WriteSingleCoil | ModbusFunction::WriteSingleRegister => 4,
+                    ModbusFunction::WriteSingleCoil | ModbusFunction::WriteSingleRegister => 4,
 
-                ModbusFunction::WriteMultipleCoils | ModbusFunction::WriteMultipleRegisters => {
-                    5 + request.data.len()
-                }
-            };
+                    ModbusFunction::WriteMultipleCoils | ModbusFunction::WriteMultipleRegisters => {
+                        5 + request.data.len()
+                    }
+
+                    ModbusFunction::MaskWriteRegister => 2 + request.data.len(),
+
+                    ModbusFunction::ReadWriteMultipleRegisters => 4 + request.data.len(),
+
+                    ModbusFunction::ReadExceptionStatus => 0,
+
+                    ModbusFunction::Diagnostics
+                    | ModbusFunction::EncapsulatedInterfaceTransport => request.data.len(),
+                };
 
         let mut frame = Vec::with_capacity(MBAP_HEADER_SIZE + pdu_length);
 
@@ -740,7 +1581,14 @@ impl TcpTransport {
 
         frame.push(request.slave_id);
         frame.push(request.function.to_u8());
-        frame.extend_from_slice(&request.address.to_be_bytes());
+        if !matches!(
+            request.function,
+            ModbusFunction::ReadExceptionStatus
+                | ModbusFunction::Diagnostics
+                | ModbusFunction::EncapsulatedInterfaceTransport
+        ) {
+            frame.extend_from_slice(&request.address.to_be_bytes());
+        }
 
         match request.function {
             ModbusFunction::ReadCoils
@@ -771,6 +1619,17 @@ impl TcpTransport {
                 })?);
                 frame.extend_from_slice(&request.data);
             }
+            ModbusFunction::MaskWriteRegister => {
+                frame.extend_from_slice(&request.data);
+            }
+            ModbusFunction::ReadWriteMultipleRegisters => {
+                frame.extend_from_slice(&request.quantity.to_be_bytes());
+                frame.extend_from_slice(&request.data);
+            }
+            ModbusFunction::ReadExceptionStatus => {}
+            ModbusFunction::Diagnostics | ModbusFunction::EncapsulatedInterfaceTransport => {
+                frame.extend_from_slice(&request.data);
+            }
         }
 
         Ok(frame)
@@ -802,7 +1661,7 @@ impl TcpTransport {
         for request in requests {
             request.validate()?;
             let tid = self.next_transaction_id();
-            let frame = self.encode_request_with_tid(request, tid)?;
+            let frame = Self::encode_request_with_tid(request, tid)?;
 
             if self.packet_logging {
                 log_packet("send", &frame, "TCP", Some(request.slave_id));
@@ -811,8 +1670,8 @@ impl TcpTransport {
                 callback(PacketDirection::Send, &frame);
             }
 
-            self.stats.bytes_sent += frame.len() as u64;
-            self.stats.requests_sent += 1;
+            self.stats.add_bytes_sent(frame.len() as u64);
+            self.stats.increment_requests_sent();
 
             tids.push(tid);
             combined.extend_from_slice(&frame);
@@ -825,8 +1684,8 @@ impl TcpTransport {
             .ok_or_else(|| ModbusError::connection("stream not connected during pipeline send"))?;
         let send_result = timeout(self.timeout, stream.write_all(&combined)).await;
         if !matches!(send_result, Ok(Ok(_))) {
-            self.stats.timeouts += 1;
-            self.stats.errors += 1;
+            self.stats.increment_timeouts();
+            self.stats.increment_errors();
             self.stream = None;
             return Err(ModbusError::timeout(
                 "pipeline send",
@@ -868,8 +1727,8 @@ impl TcpTransport {
             if remaining.is_zero() {
                 // Return stream so connection is marked broken via None
                 drop(stream);
-                self.stats.timeouts += 1;
-                self.stats.errors += 1;
+                self.stats.increment_timeouts();
+                self.stats.increment_errors();
                 return Err(ModbusError::timeout(
                     "pipeline receive",
                     pipeline_timeout.as_millis() as u64,
@@ -881,8 +1740,8 @@ impl TcpTransport {
             let read_result = timeout(remaining, stream.read_exact(&mut header_buf)).await;
             if !matches!(read_result, Ok(Ok(_))) {
                 drop(stream);
-                self.stats.timeouts += 1;
-                self.stats.errors += 1;
+                self.stats.increment_timeouts();
+                self.stats.increment_errors();
                 return Err(ModbusError::timeout(
                     "pipeline receive header",
                     pipeline_timeout.as_millis() as u64,
@@ -893,7 +1752,7 @@ impl TcpTransport {
             let length = u16::from_be_bytes([header_buf[4], header_buf[5]]);
             if !(2..=254).contains(&length) {
                 drop(stream);
-                self.stats.errors += 1;
+                self.stats.increment_errors();
                 return Err(ModbusError::frame(format!(
                     "Pipeline: invalid MBAP length: {} (must be 2-254)",
                     length
@@ -904,7 +1763,7 @@ impl TcpTransport {
             let protocol_id = u16::from_be_bytes([header_buf[2], header_buf[3]]);
             if protocol_id != 0 {
                 drop(stream);
-                self.stats.errors += 1;
+                self.stats.increment_errors();
                 return Err(ModbusError::frame(format!(
                     "Pipeline: invalid protocol ID: {:04X}",
                     protocol_id
@@ -927,8 +1786,8 @@ impl TcpTransport {
 
                 if !matches!(read_result, Ok(Ok(_))) {
                     drop(stream);
-                    self.stats.timeouts += 1;
-                    self.stats.errors += 1;
+                    self.stats.increment_timeouts();
+                    self.stats.increment_errors();
                     return Err(ModbusError::timeout(
                         "pipeline receive data",
                         pipeline_timeout.as_millis() as u64,
@@ -946,7 +1805,7 @@ impl TcpTransport {
         let mut map: HashMap<u16, ModbusResult<ModbusResponse>> = HashMap::with_capacity(count);
 
         for response_buf in raw_frames {
-            self.stats.bytes_received += response_buf.len() as u64;
+            self.stats.add_bytes_received(response_buf.len() as u64);
 
             if let Some(ref callback) = self.packet_callback {
                 callback(PacketDirection::Receive, &response_buf);
@@ -956,20 +1815,20 @@ impl TcpTransport {
             }
 
             let tid = u16::from_be_bytes([response_buf[0], response_buf[1]]);
-            let decode_result = self.decode_response(response_buf);
+            let decode_result = Self::decode_response(response_buf);
 
             let entry = match decode_result {
                 Ok(response) => {
                     if let Some(err) = response.get_exception() {
-                        self.stats.errors += 1;
+                        self.stats.increment_errors();
                         Err(err)
                     } else {
-                        self.stats.responses_received += 1;
+                        self.stats.increment_responses_received();
                         Ok(response)
                     }
                 }
                 Err(e) => {
-                    self.stats.errors += 1;
+                    self.stats.increment_errors();
                     Err(e)
                 }
             };
@@ -992,28 +1851,137 @@ impl ModbusTransport for TcpTransport {
         // Validate request
         request.validate()?;
 
+        let mut result = self.request_with_resends(request).await;
+        let Some(policy) = self.reconnect_policy else {
+            return result;
+        };
+        let mut retry = 0;
+        while matches!(result, Err(ModbusError::Connection { .. })) && policy.allows(retry) {
+            let delay = policy.delay(retry);
+            retry += 1;
+            debug!(
+                protocol = "tcp",
+                slave_id = request.slave_id,
+                retry = retry,
+                delay_ms = delay.as_millis() as u64,
+                "modbus.request.reconnect"
+            );
+            tokio::time::sleep(delay).await;
+            // The failed attempt dropped the stream; this reconnects first
+            result = self.request_with_resends(request).await;
+        }
+        result
+    }
+
+    fn is_connected(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    async fn close(&mut self) -> ModbusResult<()> {
+        if let Some(mut stream) = self.stream.take() {
+            let _ = stream.shutdown().await;
+        }
+        Ok(())
+    }
+
+    fn get_stats(&self) -> TransportStats {
+        self.stats.snapshot()
+    }
+}
+
+impl TcpTransport {
+    /// Send `request`, resending after response timeouts as configured
+    async fn request_with_resends(
+        &mut self,
+        request: &ModbusRequest,
+    ) -> ModbusResult<ModbusResponse> {
+        // Escalating retries follow the same write guard as auto-resend.
+        let strategy = self
+            .auto_resend
+            .map_or(ResendStrategy::FreshTransactionId, |(_, strategy)| strategy);
+        let retries = if strategy.applies_to(request.function) {
+            let resends = self.auto_resend.map_or(0, |(attempts, _)| attempts);
+            resends.max(self.escalating_timeout.map_or(0, |e| e.max_retries))
+        } else {
+            0
+        };
+        let escalations = self.escalating_timeout.map_or(0, |e| e.max_retries);
+
+        let mut reuse_tid = None;
+        let mut attempt = 0;
+        loop {
+            let wait = request.timeout.unwrap_or_else(|| {
+                self.escalating_timeout
+                    .map_or(self.timeout, |e| e.timeout_for(attempt))
+            });
+            let result = self
+                .request_once(request, reuse_tid, attempt < retries, wait)
+                .await;
+            match result {
+                Err(ModbusError::Timeout { .. }) if attempt < retries => {
+                    attempt += 1;
+                    if attempt <= escalations {
+                        self.stats.increment_timeout_escalations();
+                    }
+                    if let Some((_, ResendStrategy::SameTransactionId)) = self.auto_resend {
+                        reuse_tid = Some(self.transaction_id);
+                    }
+                    debug!(
+                        protocol = "tcp",
+                        slave_id = request.slave_id,
+                        attempt = attempt,
+                        "modbus.request.resend"
+                    );
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Send `request` once and wait for its response
+    ///
+    /// `reuse_tid` resends under an earlier transaction ID instead of the
+    /// next one. `wait` bounds each send and read. With `keep_on_timeout`, a
+    /// timeout waiting for the response header leaves the connection open
+    /// for a resend.
+    async fn request_once(
+        &mut self,
+        request: &ModbusRequest,
+        reuse_tid: Option<u16>,
+        keep_on_timeout: bool,
+        wait: Duration,
+    ) -> ModbusResult<ModbusResponse> {
         // Ensure connection
         if self.stream.is_none() {
             self.reconnect().await?;
         }
 
-        // Encode request into stack-allocated frame (zero heap allocation)
-        let (frame_buf, frame_len) = self.encode_request(request)?;
-        let frame = &frame_buf[..frame_len];
+        // Encode request into stack-allocated header + PDU (zero heap allocation)
+        let (header, pdu) = match reuse_tid {
+            Some(tid) => Self::encode_request_as(request, tid)?,
+            None => self.encode_request(request)?,
+        };
         // Save the transaction ID for later verification
-        // (encode_request updates self.transaction_id via next_transaction_id())
-        let expected_transaction_id = self.transaction_id;
-        self.stats.requests_sent += 1;
-        self.stats.bytes_sent += frame_len as u64;
-
-        // Callback with REAL packet data (before sending)
-        if let Some(ref callback) = self.packet_callback {
-            callback(PacketDirection::Send, frame);
-        }
+        let expected_transaction_id = u16::from_be_bytes([header[0], header[1]]);
+        self.stats.increment_requests_sent();
+        self.stats.add_bytes_sent((header.len() + pdu.len()) as u64);
+
+        // Callback / logging need the contiguous frame; only assemble it then
+        if self.packet_callback.is_some() || self.packet_logging {
+            let mut frame = [0u8; MAX_TCP_FRAME_SIZE];
+            let frame_len = header.len() + pdu.len();
+            frame[..header.len()].copy_from_slice(&header);
+            frame[header.len()..frame_len].copy_from_slice(pdu.as_slice());
+
+            // Callback with REAL packet data (before sending)
+            if let Some(ref callback) = self.packet_callback {
+                callback(PacketDirection::Send, &frame[..frame_len]);
+            }
 
-        // Log outgoing packet (built-in tracing)
-        if self.packet_logging {
-            log_packet("send", frame, "TCP", Some(request.slave_id));
+            // Log outgoing packet (built-in tracing)
+            if self.packet_logging {
+                log_packet("send", &frame[..frame_len], "TCP", Some(request.slave_id));
+            }
         }
 
         let stream = self
@@ -1021,21 +1989,29 @@ impl ModbusTransport for TcpTransport {
             .as_mut()
             .ok_or_else(|| ModbusError::connection("stream not connected"))?;
 
-        let send_result = timeout(self.timeout, stream.write_all(frame)).await;
+        let started = Instant::now();
+        let send_result =
+            timeout(wait, write_frame_vectored(stream, &header, pdu.as_slice())).await;
         if !matches!(send_result, Ok(Ok(_))) {
-            self.stats.timeouts += 1;
-            self.stats.errors += 1;
+            self.stats.increment_errors();
             self.stream = None; // Mark connection as broken
+            if let Ok(Err(e)) = send_result {
+                return Err(ModbusError::connection_with_source(
+                    format!("Connection lost sending request: {}", e),
+                    e,
+                ));
+            }
+            self.stats.increment_timeouts();
             return Err(ModbusError::timeout(
                 "send request",
-                self.timeout.as_millis() as u64,
+                wait.as_millis() as u64,
             ));
         }
 
         // Broadcast (slave_id = 0): per Modbus spec no response is expected.
         // Return a synthetic ack immediately without waiting.
         if request.slave_id == 0 {
-            self.stats.responses_received += 1;
+            self.stats.increment_responses_received();
             return Ok(ModbusResponse::new_broadcast_ack(request.function));
         }
 
@@ -1049,7 +2025,7 @@ impl ModbusTransport for TcpTransport {
         let mut stale_count = 0usize;
         let response_buf = loop {
             if stale_count >= MAX_STALE_RESPONSES {
-                self.stats.errors += 1;
+                self.stats.increment_errors();
                 self.stream = None;
                 return Err(ModbusError::protocol(
                     "too many mismatched responses; possible bus conflict",
@@ -1057,25 +2033,35 @@ impl ModbusTransport for TcpTransport {
             }
             // Read response header first (MBAP header + function code) into persistent buf
             let read_result = timeout(
-                self.timeout,
+                wait,
                 stream.read_exact(&mut self.read_buf[..MBAP_HEADER_SIZE + 1]),
             )
             .await;
 
             if !matches!(read_result, Ok(Ok(_))) {
-                self.stats.timeouts += 1;
-                self.stats.errors += 1;
-                self.stream = None;
+                self.stats.increment_errors();
+                if let Ok(Err(e)) = read_result {
+                    self.stream = None;
+                    return Err(ModbusError::connection_with_source(
+                        format!("Connection lost reading response: {}", e),
+                        e,
+                    ));
+                }
+                self.stats.increment_timeouts();
+                // An elapsed timeout leaves the stream usable for a resend
+                if !keep_on_timeout {
+                    self.stream = None;
+                }
                 return Err(ModbusError::timeout(
                     "read response header",
-                    self.timeout.as_millis() as u64,
+                    wait.as_millis() as u64,
                 ));
             }
 
             // L1: Validate Length field (must be in valid range [2, 254])
             let length = u16::from_be_bytes([self.read_buf[4], self.read_buf[5]]);
             if !(2..=254).contains(&length) {
-                self.stats.errors += 1;
+                self.stats.increment_errors();
                 self.stream = None;
                 return Err(ModbusError::frame(format!(
                     "Invalid MBAP length: {} (must be 2-254)",
@@ -1086,7 +2072,7 @@ impl ModbusTransport for TcpTransport {
             // L2: Validate Protocol ID (must be 0 for Modbus TCP)
             let protocol_id = u16::from_be_bytes([self.read_buf[2], self.read_buf[3]]);
             if protocol_id != 0 {
-                self.stats.errors += 1;
+                self.stats.increment_errors();
                 self.stream = None;
                 return Err(ModbusError::frame(format!(
                     "Invalid protocol ID: {:04X} (expected 0000)",
@@ -1100,23 +2086,23 @@ impl ModbusTransport for TcpTransport {
 
             if remaining_bytes > 0 {
                 let read_result = timeout(
-                    self.timeout,
+                    wait,
                     stream.read_exact(&mut self.read_buf[MBAP_HEADER_SIZE + 1..total_len]),
                 )
                 .await;
 
                 if !matches!(read_result, Ok(Ok(_))) {
-                    self.stats.timeouts += 1;
-                    self.stats.errors += 1;
+                    self.stats.increment_timeouts();
+                    self.stats.increment_errors();
                     self.stream = None;
                     return Err(ModbusError::timeout(
                         "read response data",
-                        self.timeout.as_millis() as u64,
+                        wait.as_millis() as u64,
                     ));
                 }
             }
 
-            self.stats.bytes_received += total_len as u64;
+            self.stats.add_bytes_received(total_len as u64);
 
             // Callback with REAL packet data (after receiving)
             if let Some(ref callback) = self.packet_callback {
@@ -1165,34 +2151,20 @@ impl ModbusTransport for TcpTransport {
             break self.read_buf[..total_len].to_vec();
         };
 
-        self.stats.responses_received += 1;
+        self.stats.increment_responses_received();
+        self.stats.record_latency(started.elapsed());
 
         // Decode response (takes ownership of buffer for zero-copy)
-        let response = self.decode_response(response_buf)?;
+        let response = Self::decode_response(response_buf)?;
 
         // Check for exception
         if let Some(error) = response.get_exception() {
-            self.stats.errors += 1;
+            self.stats.increment_errors();
             return Err(error);
         }
 
         Ok(response)
     }
-
-    fn is_connected(&self) -> bool {
-        self.stream.is_some()
-    }
-
-    async fn close(&mut self) -> ModbusResult<()> {
-        if let Some(mut stream) = self.stream.take() {
-            let _ = stream.shutdown().await;
-        }
-        Ok(())
-    }
-
-    fn get_stats(&self) -> TransportStats {
-        self.stats
-    }
 }
 
 /// Modbus RTU transport implementation
@@ -1214,6 +2186,10 @@ pub struct RtuTransport {
     timeout: Duration,
     /// Frame gap time in milliseconds (minimum time between frames)
     frame_gap: Duration,
+    /// Extra silence added to `frame_gap` before each request
+    inter_frame_delay: Duration,
+    /// Pause between sending a request and reading its response
+    turnaround_delay: Duration,
     /// Transport statistics
     stats: TransportStats,
     /// Enable packet logging for debugging (built-in tracing)
@@ -1262,6 +2238,8 @@ impl RtuTransport {
             parity,
             timeout,
             frame_gap,
+            inter_frame_delay: Duration::ZERO,
+            turnaround_delay: Duration::ZERO,
             stats: TransportStats::default(),
             packet_logging: false,
             packet_callback: None,
@@ -1295,6 +2273,8 @@ impl RtuTransport {
             parity,
             timeout,
             frame_gap,
+            inter_frame_delay: Duration::ZERO,
+            turnaround_delay: Duration::ZERO,
             stats: TransportStats::default(),
             packet_logging: enable_logging,
             packet_callback: None,
@@ -1326,6 +2306,35 @@ impl RtuTransport {
         self.packet_callback = None;
     }
 
+    /// Add `delay` of silence on top of the computed 3.5-character gap
+    /// before each request.
+    ///
+    /// At high baud rates the computed gap is shorter than the OS timer
+    /// resolution, so some devices miss the frame boundary; a few
+    /// milliseconds here restores it. Defaults to zero.
+    pub fn set_inter_frame_delay(&mut self, delay: Duration) {
+        self.inter_frame_delay = delay;
+    }
+
+    /// Extra silence added before each request
+    pub fn inter_frame_delay(&self) -> Duration {
+        self.inter_frame_delay
+    }
+
+    /// Wait `delay` after sending a request before reading the response.
+    ///
+    /// Gives RS-485 converters that switch direction automatically time to
+    /// release the bus. The response timeout starts after this delay.
+    /// Defaults to zero.
+    pub fn set_turnaround_delay(&mut self, delay: Duration) {
+        self.turnaround_delay = delay;
+    }
+
+    /// Pause between sending a request and reading its response
+    pub fn turnaround_delay(&self) -> Duration {
+        self.turnaround_delay
+    }
+
     /// Connect to the serial port
     fn connect(&mut self) -> ModbusResult<()> {
         let builder = tokio_serial::new(&self.port_name, self.baud_rate)
@@ -1335,10 +2344,10 @@ impl RtuTransport {
             .timeout(self.timeout);
 
         let port = tokio_serial::SerialStream::open(&builder).map_err(|e| {
-            ModbusError::connection(format!(
-                "Failed to open serial port {}: {}",
-                self.port_name, e
-            ))
+            ModbusError::connection_with_source(
+                format!("Failed to open serial port {}: {}", self.port_name, e),
+                e,
+            )
         })?;
 
         self.port = Some(port);
@@ -1348,7 +2357,32 @@ impl RtuTransport {
 
     /// Calculate CRC for RTU frame
     fn calculate_crc(data: &[u8]) -> u16 {
-        CRC_MODBUS.checksum(data)
+        crc16_modbus(data)
+    }
+
+    /// Send a pre-built RTU frame and return the raw reply.
+    ///
+    /// **Expert escape hatch.** `frame` goes on the wire exactly as given:
+    /// no validation, no slave address or CRC is added (see
+    /// [`crc16_modbus`]), and the reply is returned undecoded with its CRC
+    /// unchecked. Frame gap, turnaround delay, timeout, statistics and
+    /// packet logging apply as for [`request`](ModbusTransport::request).
+    /// A frame addressed to slave 0 (broadcast) gets no reply; an empty
+    /// `Vec` is returned without reading.
+    pub async fn send_raw_frame(&mut self, frame: &[u8]) -> ModbusResult<Vec<u8>> {
+        let slave_id = *frame
+            .first()
+            .ok_or_else(|| ModbusError::invalid_data("Raw RTU frame is empty"))?;
+        if self.port.is_none() {
+            self.connect()?;
+        }
+
+        self.wait_frame_gap().await;
+        self.send_frame(frame, slave_id).await?;
+        if slave_id == 0 {
+            return Ok(Vec::new());
+        }
+        self.receive_frame(slave_id, self.timeout).await
     }
 
     /// Encode request to RTU frame
@@ -1412,6 +2446,29 @@ impl RtuTransport {
                 })?);
                 frame.extend_from_slice(&request.data);
             }
+
+            ModbusFunction::MaskWriteRegister => {
+                // Address (2 bytes) + AND mask (2 bytes) + OR mask (2 bytes)
+                frame.extend_from_slice(&request.address.to_be_bytes());
+                frame.extend_from_slice(&request.data);
+            }
+
+            ModbusFunction::ReadWriteMultipleRegisters => {
+                // Read address (2 bytes) + Read quantity (2 bytes) + Write address (2 bytes)
+                // + Write quantity (2 bytes) + Byte count (1 byte) + Data
+                frame.extend_from_slice(&request.address.to_be_bytes());
+                frame.extend_from_slice(&request.quantity.to_be_bytes());
+                frame.extend_from_slice(&request.data);
+            }
+
+            ModbusFunction::ReadExceptionStatus => {
+                // Function code only
+            }
+
+            ModbusFunction::Diagnostics | ModbusFunction::EncapsulatedInterfaceTransport => {
+                // Sub-function (2 bytes) or MEI type (1 byte), then data
+                frame.extend_from_slice(&request.data);
+            }
         }
 
         // Calculate and append CRC
@@ -1486,6 +2543,8 @@ impl RtuTransport {
             parity: tokio_serial::Parity::None,
             timeout: std::time::Duration::from_millis(100),
             frame_gap: std::time::Duration::from_millis(4),
+            inter_frame_delay: Duration::ZERO,
+            turnaround_delay: Duration::ZERO,
             stats: TransportStats::default(),
             packet_logging: false,
             packet_callback: None,
@@ -1503,88 +2562,32 @@ impl RtuTransport {
 
     /// Wait for frame gap before sending next frame
     async fn wait_frame_gap(&self) {
-        tokio::time::sleep(self.frame_gap).await;
+        tokio::time::sleep(self.frame_gap + self.inter_frame_delay).await;
     }
 
-    /// Read RTU frame from serial port
-    async fn read_frame(&mut self) -> ModbusResult<Vec<u8>> {
-        let port = self
-            .port
-            .as_mut()
-            .ok_or_else(|| ModbusError::connection("Serial port not connected"))?;
-
-        let mut frame = Vec::new();
-        let mut buffer = [0u8; 1];
-
-        // Read until frame gap timeout
-        loop {
-            match timeout(self.frame_gap, port.read_exact(&mut buffer)).await {
-                Ok(Ok(_)) => {
-                    frame.push(buffer[0]);
-
-                    // Prevent frames from getting too large
-                    if frame.len() > MAX_RTU_FRAME_SIZE {
-                        return Err(ModbusError::frame("RTU frame too large"));
-                    }
-                }
-                Ok(Err(e)) => {
-                    return Err(ModbusError::io(format!("Serial read error: {}", e)));
-                }
-                Err(_) => {
-                    // Timeout - end of frame
-                    if !frame.is_empty() {
-                        break;
-                    }
-                    // If no data yet, continue waiting
-                }
+    /// Write `frame` to the port as-is, counting and logging it
+    ///
+    /// Bytes still waiting in the input buffer, such as a reply that arrived
+    /// after an earlier request timed out, are discarded first so they are
+    /// not taken for the answer to `frame`.
+    async fn send_frame(&mut self, frame: &[u8], slave_id: SlaveId) -> ModbusResult<()> {
+        if let Some(port) = &self.port {
+            if let Err(e) = tokio_serial::SerialPort::clear(port, tokio_serial::ClearBuffer::Input)
+            {
+                debug!(protocol = "rtu", error = %e, "failed to discard stale input");
             }
         }
-
-        if frame.is_empty() {
-            return Err(ModbusError::timeout(
-                "No response received",
-                self.timeout.as_millis() as u64,
-            ));
-        }
-
-        Ok(frame)
-    }
-}
-
-#[cfg(feature = "rtu")]
-impl ModbusTransport for RtuTransport {
-    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
-        tracing::trace!(
-            protocol = "rtu",
-            slave_id = request.slave_id,
-            function_code = request.function.to_u8(),
-            "modbus.request.start"
-        );
-
-        // Validate request
-        request.validate()?;
-
-        // Ensure connection
-        if self.port.is_none() {
-            self.connect()?;
-        }
-
-        // Wait for frame gap before sending
-        self.wait_frame_gap().await;
-
-        // Encode request
-        let frame = self.encode_request(request)?;
         self.stats.requests_sent += 1;
         self.stats.bytes_sent += frame.len() as u64;
 
         // Callback with REAL packet data (before sending, includes CRC)
         if let Some(ref callback) = self.packet_callback {
-            callback(PacketDirection::Send, &frame);
+            callback(PacketDirection::Send, frame);
         }
 
         // Log outgoing packet (built-in tracing)
         if self.packet_logging {
-            log_packet("send", &frame, "RTU", Some(request.slave_id));
+            log_packet("send", frame, "RTU", Some(slave_id));
         }
 
         // Send request
@@ -1593,7 +2596,7 @@ impl ModbusTransport for RtuTransport {
             .as_mut()
             .ok_or_else(|| ModbusError::connection("Serial port not connected"))?;
 
-        let send_result = timeout(self.timeout, port.write_all(&frame)).await;
+        let send_result = timeout(self.timeout, port.write_all(frame)).await;
         match send_result {
             Ok(Ok(_)) => {
                 // Flush to ensure data is sent
@@ -1613,15 +2616,18 @@ impl ModbusTransport for RtuTransport {
             }
         }
 
-        // Broadcast (slave_id = 0): per Modbus spec no response is expected.
-        // Return a synthetic ack immediately without waiting.
-        if request.slave_id == 0 {
-            self.stats.responses_received += 1;
-            return Ok(ModbusResponse::new_broadcast_ack(request.function));
+        Ok(())
+    }
+
+    /// Read the reply to a sent frame, after the turnaround delay, waiting
+    /// at most `wait` for it
+    async fn receive_frame(&mut self, slave_id: SlaveId, wait: Duration) -> ModbusResult<Vec<u8>> {
+        if !self.turnaround_delay.is_zero() {
+            tokio::time::sleep(self.turnaround_delay).await;
         }
 
         // Read response
-        let response_frame = match timeout(self.timeout, self.read_frame()).await {
+        let response_frame = match timeout(wait, self.read_frame()).await {
             Ok(Ok(frame)) => frame,
             Ok(Err(e)) => {
                 self.stats.errors += 1;
@@ -1632,7 +2638,7 @@ impl ModbusTransport for RtuTransport {
                 self.stats.errors += 1;
                 return Err(ModbusError::timeout(
                     "read response",
-                    self.timeout.as_millis() as u64,
+                    wait.as_millis() as u64,
                 ));
             }
         };
@@ -1647,9 +2653,92 @@ impl ModbusTransport for RtuTransport {
 
         // Log incoming packet (built-in tracing)
         if self.packet_logging {
-            log_packet("receive", &response_frame, "RTU", Some(request.slave_id));
+            log_packet("receive", &response_frame, "RTU", Some(slave_id));
+        }
+
+        Ok(response_frame)
+    }
+
+    /// Read RTU frame from serial port
+    async fn read_frame(&mut self) -> ModbusResult<Vec<u8>> {
+        let port = self
+            .port
+            .as_mut()
+            .ok_or_else(|| ModbusError::connection("Serial port not connected"))?;
+
+        let mut frame = Vec::new();
+        let mut buffer = [0u8; 1];
+
+        // Read until frame gap timeout
+        loop {
+            match timeout(self.frame_gap, port.read_exact(&mut buffer)).await {
+                Ok(Ok(_)) => {
+                    frame.push(buffer[0]);
+
+                    // Prevent frames from getting too large
+                    if frame.len() > MAX_RTU_FRAME_SIZE {
+                        return Err(ModbusError::frame("RTU frame too large"));
+                    }
+                }
+                Ok(Err(e)) => {
+                    return Err(ModbusError::io(format!("Serial read error: {}", e)));
+                }
+                Err(_) => {
+                    // Timeout - end of frame
+                    if !frame.is_empty() {
+                        break;
+                    }
+                    // If no data yet, continue waiting
+                }
+            }
+        }
+
+        if frame.is_empty() {
+            return Err(ModbusError::timeout(
+                "No response received",
+                self.timeout.as_millis() as u64,
+            ));
+        }
+
+        Ok(frame)
+    }
+}
+
+#[cfg(feature = "rtu")]
+impl ModbusTransport for RtuTransport {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        tracing::trace!(
+            protocol = "rtu",
+            slave_id = request.slave_id,
+            function_code = request.function.to_u8(),
+            "modbus.request.start"
+        );
+
+        // Validate request
+        request.validate()?;
+
+        // Ensure connection
+        if self.port.is_none() {
+            self.connect()?;
+        }
+
+        // Wait for frame gap before sending
+        self.wait_frame_gap().await;
+
+        // Encode request
+        let frame = self.encode_request(request)?;
+        self.send_frame(&frame, request.slave_id).await?;
+
+        // Broadcast (slave_id = 0): per Modbus spec no response is expected.
+        // Return a synthetic ack immediately without waiting.
+        if request.slave_id == 0 {
+            self.stats.responses_received += 1;
+            return Ok(ModbusResponse::new_broadcast_ack(request.function));
         }
 
+        let wait = request.timeout.unwrap_or(self.timeout);
+        let response_frame = self.receive_frame(request.slave_id, wait).await?;
+
         // Decode response (takes ownership of buffer for zero-copy)
         let response = self.decode_response(response_frame)?;
 
@@ -1683,7 +2772,7 @@ impl ModbusTransport for RtuTransport {
     }
 
     fn get_stats(&self) -> TransportStats {
-        self.stats
+        self.stats.clone()
     }
 }
 
@@ -1712,7 +2801,7 @@ impl ModbusTransport for RtuTransport {
 /// - **Legacy Systems**: Integration with older SCADA systems
 /// - **Educational**: Learning Modbus protocol structure
 /// - **Manual Testing**: Can be typed manually in serial terminals
-#[cfg(feature = "rtu")]
+#[cfg(feature = "ascii")]
 pub struct AsciiTransport {
     /// Serial port connection
     port: Option<tokio_serial::SerialStream>,
@@ -1734,7 +2823,7 @@ pub struct AsciiTransport {
     stats: TransportStats,
 }
 
-#[cfg(feature = "rtu")]
+#[cfg(feature = "ascii")]
 impl AsciiTransport {
     /// Create a new ASCII transport with default settings
     ///
@@ -1835,10 +2924,10 @@ impl AsciiTransport {
             .timeout(self.timeout);
 
         let port = tokio_serial::SerialStream::open(&builder).map_err(|e| {
-            ModbusError::connection(format!(
-                "Failed to open serial port {}: {}",
-                self.port_name, e
-            ))
+            ModbusError::connection_with_source(
+                format!("Failed to open serial port {}: {}", self.port_name, e),
+                e,
+            )
         })?;
 
         self.port = Some(port);
@@ -1973,6 +3062,23 @@ impl AsciiTransport {
                 })?);
                 raw_data.extend_from_slice(&request.data);
             }
+
+            ModbusFunction::MaskWriteRegister => {
+                raw_data.extend_from_slice(&request.address.to_be_bytes());
+                raw_data.extend_from_slice(&request.data);
+            }
+
+            ModbusFunction::ReadWriteMultipleRegisters => {
+                raw_data.extend_from_slice(&request.address.to_be_bytes());
+                raw_data.extend_from_slice(&request.quantity.to_be_bytes());
+                raw_data.extend_from_slice(&request.data);
+            }
+
+            ModbusFunction::ReadExceptionStatus => {}
+
+            ModbusFunction::Diagnostics | ModbusFunction::EncapsulatedInterfaceTransport => {
+                raw_data.extend_from_slice(&request.data);
+            }
         }
 
         // Calculate LRC
@@ -2145,7 +3251,7 @@ impl AsciiTransport {
     }
 }
 
-#[cfg(feature = "rtu")]
+#[cfg(feature = "ascii")]
 impl ModbusTransport for AsciiTransport {
     async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
         // Validate request
@@ -2243,7 +3349,7 @@ impl ModbusTransport for AsciiTransport {
     }
 
     fn get_stats(&self) -> TransportStats {
-        self.stats
+        self.stats.clone()
     }
 }
 
@@ -2274,11 +3380,14 @@ impl RtuOverTcpTransport {
     /// Connect to a gateway.
     pub async fn new(address: SocketAddr, timeout: Duration) -> ModbusResult<Self> {
         let stream = TcpStream::connect(address).await.map_err(|e| {
-            ModbusError::connection(format!("Failed to connect to {}: {}", address, e))
+            ModbusError::connection_with_source(
+                format!("Failed to connect to {}: {}", address, e),
+                e,
+            )
+        })?;
+        stream.set_nodelay(true).map_err(|e| {
+            ModbusError::connection_with_source(format!("Failed to set TCP_NODELAY: {}", e), e)
         })?;
-        stream
-            .set_nodelay(true)
-            .map_err(|e| ModbusError::connection(format!("Failed to set TCP_NODELAY: {}", e)))?;
         Ok(Self {
             address,
             stream: Some(stream),
@@ -2289,9 +3398,9 @@ impl RtuOverTcpTransport {
 
     /// Parse address string and connect.
     pub async fn from_address(address: &str, timeout: Duration) -> ModbusResult<Self> {
-        let addr: SocketAddr = address
-            .parse()
-            .map_err(|e| ModbusError::connection(format!("Invalid address {}: {}", address, e)))?;
+        let addr: SocketAddr = address.parse().map_err(|e| {
+            ModbusError::connection_with_source(format!("Invalid address {}: {}", address, e), e)
+        })?;
         Self::new(addr, timeout).await
     }
 
@@ -2334,6 +3443,19 @@ impl RtuOverTcpTransport {
                 })?);
                 frame.extend_from_slice(&request.data);
             }
+            ModbusFunction::MaskWriteRegister => {
+                frame.extend_from_slice(&request.address.to_be_bytes());
+                frame.extend_from_slice(&request.data);
+            }
+            ModbusFunction::ReadWriteMultipleRegisters => {
+                frame.extend_from_slice(&request.address.to_be_bytes());
+                frame.extend_from_slice(&request.quantity.to_be_bytes());
+                frame.extend_from_slice(&request.data);
+            }
+            ModbusFunction::ReadExceptionStatus => {}
+            ModbusFunction::Diagnostics | ModbusFunction::EncapsulatedInterfaceTransport => {
+                frame.extend_from_slice(&request.data);
+            }
         }
         let crc = CRC_MODBUS.checksum(&frame);
         frame.extend_from_slice(&crc.to_le_bytes());
@@ -2385,7 +3507,7 @@ impl RtuOverTcpTransport {
             3
         } else {
             match func {
-                0x01..=0x04 => {
+                0x01..=0x04 | 0x17 => {
                     // [byte_count, data..., crc(2)]: read byte_count first
                     let mut bc = [0u8; 1];
                     stream.read_exact(&mut bc).await?;
@@ -2397,7 +3519,11 @@ impl RtuOverTcpTransport {
                     out.extend_from_slice(&data);
                     return Ok(out);
                 }
+                0x07 => 3,                      // status(1) + crc(2)
                 0x05 | 0x06 | 0x0F | 0x10 => 6, // echo: addr(2) + val(2) + crc(2)
+                0x08 => 6,                      // echo: sub-function(2) + data(2) + crc(2)
+                0x2B => return Self::read_device_id_frame(stream, header).await,
+                0x16 => 8, // echo: addr(2) + and(2) + or(2) + crc(2)
                 _ => {
                     return Err(ModbusError::frame(format!(
                         "Unsupported function code 0x{:02X}",
@@ -2415,9 +3541,41 @@ impl RtuOverTcpTransport {
         Ok(frame)
     }
 
+    /// Read the rest of a Read Device Identification response, whose length
+    /// is only known object by object.
+    async fn read_device_id_frame(
+        stream: &mut TcpStream,
+        header: [u8; 2],
+    ) -> ModbusResult<Vec<u8>> {
+        // [mei, code, conformity, more_follows, next_object, object_count]
+        let mut fixed = [0u8; 6];
+        stream.read_exact(&mut fixed).await?;
+        let mut frame = Vec::with_capacity(MAX_RTU_FRAME_SIZE);
+        frame.extend_from_slice(&header);
+        frame.extend_from_slice(&fixed);
+        for _ in 0..fixed[5] {
+            let mut object = [0u8; 2]; // [id, length]
+            stream.read_exact(&mut object).await?;
+            frame.extend_from_slice(&object);
+            let mut value = vec![0u8; usize::from(object[1])];
+            stream.read_exact(&mut value).await?;
+            frame.extend_from_slice(&value);
+            if frame.len() > MAX_RTU_FRAME_SIZE {
+                return Err(ModbusError::frame("RTU frame too large"));
+            }
+        }
+        let mut crc = [0u8; 2];
+        stream.read_exact(&mut crc).await?;
+        frame.extend_from_slice(&crc);
+        Ok(frame)
+    }
+
     async fn reconnect(&mut self) -> ModbusResult<()> {
         let stream = TcpStream::connect(self.address).await.map_err(|e| {
-            ModbusError::connection(format!("Reconnect to {} failed: {}", self.address, e))
+            ModbusError::connection_with_source(
+                format!("Reconnect to {} failed: {}", self.address, e),
+                e,
+            )
         })?;
         stream.set_nodelay(true).ok();
         self.stream = Some(stream);
@@ -2459,7 +3617,12 @@ impl ModbusTransport for RtuOverTcpTransport {
                 self.stats.timeouts += 1;
                 return Err(ModbusError::timeout("write", io_timeout.as_millis() as u64));
             }
-            Ok(Err(e)) => return Err(ModbusError::connection(format!("write failed: {}", e))),
+            Ok(Err(e)) => {
+                return Err(ModbusError::connection_with_source(
+                    format!("write failed: {}", e),
+                    e,
+                ))
+            }
             Ok(Ok(())) => {}
         }
 
@@ -2518,7 +3681,322 @@ impl ModbusTransport for RtuOverTcpTransport {
     }
 
     fn get_stats(&self) -> TransportStats {
-        self.stats
+        self.stats.clone()
+    }
+}
+
+// ============================================================================
+// Round-robin transport
+// ============================================================================
+//
+// Client-side load balancing over identical replicas of one server. Reads
+// rotate through the replicas; writes go to the first replica or to all of
+// them. A replica that fails with a transport error is skipped for a while
+// instead of costing a timeout on every rotation.
+
+/// Where [`RoundRobinTransport`] sends write requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WritePolicy {
+    /// Write to the first replica only.
+    #[default]
+    Primary,
+    /// Write to every replica that is not being skipped.
+    FanOut,
+}
+
+/// A replica and the time until which it is skipped.
+#[derive(Debug)]
+struct Replica<T> {
+    transport: T,
+    skipped_until: Option<tokio::time::Instant>,
+}
+
+impl<T> Replica<T> {
+    fn is_available(&self) -> bool {
+        self.skipped_until
+            .is_none_or(|until| tokio::time::Instant::now() >= until)
+    }
+}
+
+/// Round-robin load balancing over identical Modbus servers.
+///
+/// Each read goes to the next replica in turn; if it fails with a transport
+/// error (see [`ModbusError::is_transport_error`]) the replica is skipped for
+/// `skip_duration` and the read moves on to the next one. Writes follow the
+/// [`WritePolicy`]: with `FanOut` a write succeeds only if every replica
+/// written to accepts it, and replicas that fail it are skipped like above.
+/// When every replica is being skipped, all of them are tried anyway.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use voltage_modbus::transport::{RoundRobinTransport, TcpTransport, WritePolicy};
+///
+/// # async fn example() -> voltage_modbus::ModbusResult<()> {
+/// let timeout = Duration::from_secs(1);
+/// let replicas = vec![
+///     TcpTransport::new("10.0.0.1:502".parse().unwrap(), timeout).await?,
+///     TcpTransport::new("10.0.0.2:502".parse().unwrap(), timeout).await?,
+/// ];
+/// let transport = RoundRobinTransport::new(replicas)?
+///     .with_write_policy(WritePolicy::FanOut)
+///     .with_skip_duration(Duration::from_secs(10));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RoundRobinTransport<T: ModbusTransport = TcpTransport> {
+    replicas: Vec<Replica<T>>,
+    next: usize,
+    write_policy: WritePolicy,
+    skip_duration: Duration,
+}
+
+impl<T: ModbusTransport> RoundRobinTransport<T> {
+    /// Default time a failed replica is skipped.
+    pub const DEFAULT_SKIP_DURATION: Duration = Duration::from_secs(30);
+
+    /// Balance over `transports`; fails if there are none.
+    pub fn new(transports: Vec<T>) -> ModbusResult<Self> {
+        if transports.is_empty() {
+            return Err(ModbusError::configuration(
+                "RoundRobinTransport needs at least one transport",
+            ));
+        }
+        Ok(Self {
+            replicas: transports
+                .into_iter()
+                .map(|transport| Replica {
+                    transport,
+                    skipped_until: None,
+                })
+                .collect(),
+            next: 0,
+            write_policy: WritePolicy::default(),
+            skip_duration: Self::DEFAULT_SKIP_DURATION,
+        })
+    }
+
+    /// Set where write requests are sent.
+    pub fn with_write_policy(mut self, write_policy: WritePolicy) -> Self {
+        self.write_policy = write_policy;
+        self
+    }
+
+    /// Set how long a failed replica is skipped.
+    pub fn with_skip_duration(mut self, skip_duration: Duration) -> Self {
+        self.skip_duration = skip_duration;
+        self
+    }
+
+    /// Where write requests are sent.
+    pub fn write_policy(&self) -> WritePolicy {
+        self.write_policy
+    }
+
+    /// Number of replicas.
+    pub fn len(&self) -> usize {
+        self.replicas.len()
+    }
+
+    /// Always `false`; a transport has at least one replica.
+    pub fn is_empty(&self) -> bool {
+        self.replicas.is_empty()
+    }
+
+    /// The replica at `index`.
+    pub fn replica(&self, index: usize) -> Option<&T> {
+        self.replicas.get(index).map(|replica| &replica.transport)
+    }
+
+    /// Whether the replica at `index` is currently being skipped.
+    pub fn is_skipped(&self, index: usize) -> bool {
+        self.replicas
+            .get(index)
+            .is_some_and(|replica| !replica.is_available())
+    }
+
+    /// Indices of the replicas to try, starting at `start`; every replica
+    /// when all of them are skipped.
+    fn candidates(&self, start: usize) -> Vec<usize> {
+        let len = self.replicas.len();
+        let order = (0..len).map(|i| (start + i) % len);
+        let available: Vec<usize> = order
+            .clone()
+            .filter(|&i| self.replicas[i].is_available())
+            .collect();
+        if available.is_empty() {
+            order.collect()
+        } else {
+            available
+        }
+    }
+
+    /// Send `request` to replica `index`, skipping it on transport errors.
+    async fn send_to(
+        &mut self,
+        index: usize,
+        request: &ModbusRequest,
+    ) -> ModbusResult<ModbusResponse> {
+        let skip_duration = self.skip_duration;
+        let replica = &mut self.replicas[index];
+        let result = replica.transport.request(request).await;
+        match &result {
+            Err(e) if e.is_transport_error() => {
+                debug!("Replica {} skipped for {:?}: {}", index, skip_duration, e);
+                replica.skipped_until = Some(tokio::time::Instant::now() + skip_duration);
+            }
+            _ => replica.skipped_until = None,
+        }
+        result
+    }
+
+    async fn read(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        let candidates = self.candidates(self.next);
+        self.next = (self.next + 1) % self.replicas.len();
+
+        let mut last_error = None;
+        for index in candidates {
+            match self.send_to(index, request).await {
+                Err(e) if e.is_transport_error() => last_error = Some(e),
+                result => return result,
+            }
+        }
+        Err(last_error.unwrap_or_else(|| ModbusError::connection("no replica available")))
+    }
+
+    async fn fan_out(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        let mut response = None;
+        let mut error = None;
+        for index in self.candidates(0) {
+            match self.send_to(index, request).await {
+                Ok(r) => {
+                    response.get_or_insert(r);
+                }
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+        match error {
+            Some(e) => Err(e),
+            None => response.ok_or_else(|| ModbusError::connection("no replica available")),
+        }
+    }
+}
+
+impl<T: ModbusTransport> ModbusTransport for RoundRobinTransport<T> {
+    async fn request(&mut self, request: &ModbusRequest) -> ModbusResult<ModbusResponse> {
+        if !request.function.is_write_function() {
+            return self.read(request).await;
+        }
+        match self.write_policy {
+            WritePolicy::Primary => self.send_to(0, request).await,
+            WritePolicy::FanOut => self.fan_out(request).await,
+        }
+    }
+
+    /// Connected if any replica is.
+    fn is_connected(&self) -> bool {
+        self.replicas
+            .iter()
+            .any(|replica| replica.transport.is_connected())
+    }
+
+    /// Close every replica, returning the first error.
+    async fn close(&mut self) -> ModbusResult<()> {
+        let mut result = Ok(());
+        for replica in &mut self.replicas {
+            let closed = replica.transport.close().await;
+            if result.is_ok() {
+                result = closed;
+            }
+        }
+        result
+    }
+
+    /// Sum of the statistics of all replicas.
+    fn get_stats(&self) -> TransportStats {
+        let mut stats = TransportStats::default();
+        for replica in &self.replicas {
+            stats.merge(&replica.transport.get_stats());
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod round_robin_tests {
+    use super::*;
+    use crate::testing::ScriptedTransport;
+
+    fn ok(function: ModbusFunction) -> ModbusResult<ModbusResponse> {
+        Ok(ModbusResponse::new_success(1, function, vec![2, 0, 1]))
+    }
+
+    fn read() -> ModbusRequest {
+        ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1)
+    }
+
+    fn write() -> ModbusRequest {
+        ModbusRequest::new_write(1, ModbusFunction::WriteSingleRegister, 0, vec![0, 1])
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reads_rotate_and_skip_failed_replicas() {
+        let fc = ModbusFunction::ReadHoldingRegisters;
+        let replicas = vec![
+            ScriptedTransport::new(vec![ok(fc), ok(fc), ok(fc)]),
+            ScriptedTransport::new(vec![Err(ModbusError::connection("refused")), ok(fc)]),
+            ScriptedTransport::new(vec![ok(fc), ok(fc), ok(fc), ok(fc)]),
+        ];
+        let mut transport = RoundRobinTransport::new(replicas)
+            .unwrap()
+            .with_skip_duration(Duration::from_secs(10));
+
+        transport.request(&read()).await.unwrap(); // replica 0
+        transport.request(&read()).await.unwrap(); // replica 1 fails, then 2
+        assert!(transport.is_skipped(1));
+        transport.request(&read()).await.unwrap(); // replica 2
+        transport.request(&read()).await.unwrap(); // replica 0
+        transport.request(&read()).await.unwrap(); // replica 1 skipped, 2
+        assert_eq!(transport.replica(1).unwrap().requests().len(), 1);
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        transport.request(&read()).await.unwrap(); // replica 2
+        transport.request(&read()).await.unwrap(); // replica 0
+        transport.request(&read()).await.unwrap(); // replica 1 again
+        assert!(!transport.is_skipped(1));
+        for i in 0..3 {
+            transport.replica(i).unwrap().assert_all_consumed();
+        }
+        assert_eq!(transport.get_stats().requests_sent, 9);
+    }
+
+    #[tokio::test]
+    async fn write_policy_selects_replicas() {
+        let fc = ModbusFunction::WriteSingleRegister;
+        let replicas = vec![
+            ScriptedTransport::new(vec![ok(fc)]),
+            ScriptedTransport::new(vec![]),
+        ];
+        let mut primary = RoundRobinTransport::new(replicas).unwrap();
+        primary.request(&write()).await.unwrap();
+        primary.request(&write()).await.unwrap_err();
+        assert_eq!(primary.replica(1).unwrap().requests().len(), 0);
+
+        let replicas = vec![
+            ScriptedTransport::new(vec![ok(fc), ok(fc)]),
+            ScriptedTransport::new(vec![Err(ModbusError::timeout("read", 100))]),
+        ];
+        let mut fan_out = RoundRobinTransport::new(replicas)
+            .unwrap()
+            .with_write_policy(WritePolicy::FanOut);
+        assert!(fan_out.request(&write()).await.is_err());
+        assert!(fan_out.is_skipped(1));
+        fan_out.request(&write()).await.unwrap();
+        fan_out.replica(0).unwrap().assert_all_consumed();
+
+        assert!(RoundRobinTransport::<ScriptedTransport>::new(Vec::new()).is_err());
     }
 }
 
@@ -2608,6 +4086,41 @@ mod rtu_over_tcp_tests {
 
         server.await.unwrap();
     }
+
+    #[tokio::test]
+    async fn request_reads_device_id_frame_object_by_object() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 7];
+            socket.read_exact(&mut request).await.unwrap();
+            assert_eq!(request[..5], [0x01, 0x2B, 0x0E, 0x01, 0x00]);
+
+            let mut response = vec![0x01, 0x2B, 0x0E, 0x01, 0x01, 0x00, 0x00, 0x02, 0x00, 0x03];
+            response.extend_from_slice(b"ACM");
+            response.extend_from_slice(&[0x01, 0x00]);
+            let crc = CRC_MODBUS.checksum(&response);
+            response.extend_from_slice(&crc.to_le_bytes());
+            socket.write_all(&response).await.unwrap();
+        });
+
+        let mut transport = RtuOverTcpTransport::new(address, Duration::from_secs(1))
+            .await
+            .unwrap();
+        let request = ModbusRequest::new_read_device_id(1, 0x01, 0x00);
+        let response = transport.request(&request).await.unwrap();
+
+        assert_eq!(
+            response.function,
+            ModbusFunction::EncapsulatedInterfaceTransport
+        );
+        assert_eq!(response.data()[6..11], [0x00, 0x03, b'A', b'C', b'M']);
+        assert_eq!(transport.get_stats().bytes_received, 17);
+
+        server.await.unwrap();
+    }
 }
 
 #[cfg(test)]
@@ -2625,6 +4138,33 @@ mod tests {
         println!("TCP transport creation result: {:?}", result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_tcp_config_sets_socket_options() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let config = TcpTransportConfig::new(Duration::from_secs(1))
+            .with_tcp_nodelay(false)
+            .with_tcp_keepalive(TcpKeepalive::new().with_time(Duration::from_secs(30)));
+        let transport = TcpTransport::with_config(addr, config).await.unwrap();
+        let Some(TcpLink::Plain(stream)) = &transport.stream else {
+            panic!("expected a plain TCP stream");
+        };
+        let socket = socket2::SockRef::from(stream);
+        assert!(!socket.nodelay().unwrap());
+        assert!(socket.keepalive().unwrap());
+
+        let transport = TcpTransport::new(addr, Duration::from_secs(1))
+            .await
+            .unwrap();
+        let Some(TcpLink::Plain(stream)) = &transport.stream else {
+            panic!("expected a plain TCP stream");
+        };
+        let socket = socket2::SockRef::from(stream);
+        assert!(socket.nodelay().unwrap());
+        assert!(!socket.keepalive().unwrap());
+    }
+
     #[test]
     fn test_transaction_id_mismatch_error() {
         // Test that TransactionIdMismatch error is created correctly
@@ -2660,10 +4200,19 @@ mod tests {
             address: "127.0.0.1:502".parse().unwrap(),
             timeout: Duration::from_secs(5),
             transaction_id: 0,
-            stats: TransportStats::default(),
+            stats: Arc::default(),
             read_buf: Box::new([0u8; 512]),
             packet_logging: false,
             packet_callback: None,
+            reconnect_backoff: None,
+            reconnect_attempt: 0,
+            next_reconnect_at: None,
+            reconnect_policy: None,
+            auto_resend: None,
+            escalating_timeout: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            socket_options: TcpTransportConfig::default(),
         };
 
         // Test transaction ID starts at 1 (after first call)
@@ -2689,10 +4238,19 @@ mod tests {
             address: "127.0.0.1:502".parse().unwrap(),
             timeout: Duration::from_secs(5),
             transaction_id: 0,
-            stats: TransportStats::default(),
+            stats: Arc::default(),
             read_buf: Box::new([0u8; 512]),
             packet_logging: false,
             packet_callback: None,
+            reconnect_backoff: None,
+            reconnect_attempt: 0,
+            next_reconnect_at: None,
+            reconnect_policy: None,
+            auto_resend: None,
+            escalating_timeout: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            socket_options: TcpTransportConfig::default(),
         };
 
         let request = ModbusRequest::new_read(
@@ -2702,19 +4260,583 @@ mod tests {
             10,                                   // quantity
         );
 
-        let (frame, frame_len) = transport.encode_request(&request).unwrap();
+        let (frame, pdu) = transport.encode_request(&request).unwrap();
 
         // Transaction ID should be in first 2 bytes (big-endian)
         let tid_in_frame = u16::from_be_bytes([frame[0], frame[1]]);
         assert_eq!(tid_in_frame, transport.transaction_id);
         assert_eq!(transport.transaction_id, 1);
-        assert!(frame_len > 0);
+        assert!(!pdu.is_empty());
 
         // Second request should have incremented transaction ID
         let (frame2, _) = transport.encode_request(&request).unwrap();
         let tid_in_frame2 = u16::from_be_bytes([frame2[0], frame2[1]]);
         assert_eq!(tid_in_frame2, 2);
     }
+
+    #[test]
+    fn test_tcp_mask_write_frame() {
+        let request = ModbusRequest::new_mask_write(1, 4, 0x00F2, 0x0025);
+        let expected = [
+            0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0x01, 0x16, 0x00, 0x04, 0x00, 0xF2, 0x00, 0x25,
+        ];
+
+        let (header, pdu) = TcpTransport::encode_request_as(&request, 7).unwrap();
+        assert_eq!([&header[..], pdu.as_slice()].concat(), expected);
+        assert_eq!(
+            TcpTransport::encode_request_with_tid(&request, 7).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_tcp_read_write_frame() {
+        let request = ModbusRequest::new_read_write(1, 3, 6, 14, &[0x00FF, 0x00FF, 0x00FF]);
+        let expected = [
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x11, 0x01, 0x17, 0x00, 0x03, 0x00, 0x06, 0x00, 0x0E,
+            0x00, 0x03, 0x06, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF,
+        ];
+
+        let (header, pdu) = TcpTransport::encode_request_as(&request, 1).unwrap();
+        assert_eq!([&header[..], pdu.as_slice()].concat(), expected);
+        assert_eq!(
+            TcpTransport::encode_request_with_tid(&request, 1).unwrap(),
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tcp_request_frame_reaches_server_intact() {
+        use tokio::io::AsyncWriteExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut frame = [0u8; 17];
+            socket.read_exact(&mut frame).await.unwrap();
+            // Echo the FC16 header back as the response
+            let mut response = frame[..12].to_vec();
+            response[4..6].copy_from_slice(&6u16.to_be_bytes());
+            socket.write_all(&response).await.unwrap();
+            frame
+        });
+
+        let mut transport = TcpTransport::new(address, Duration::from_secs(1))
+            .await
+            .unwrap();
+        let request = ModbusRequest::new_write(
+            7,
+            ModbusFunction::WriteMultipleRegisters,
+            0x0102,
+            vec![0x12, 0x34, 0x56, 0x78],
+        );
+        let monitor = transport.stats_handle();
+        transport.request(&request).await.unwrap();
+
+        let tid = transport.transaction_id.to_be_bytes();
+        assert_eq!(
+            server.await.unwrap(),
+            [
+                tid[0], tid[1], // transaction ID
+                0x00, 0x00, // protocol ID
+                0x00, 0x0B, // length: unit ID + 10-byte PDU
+                0x07, // unit ID
+                0x10, 0x01, 0x02, 0x00, 0x02, 0x04, 0x12, 0x34, 0x56, 0x78,
+            ]
+        );
+        assert_eq!(transport.get_stats().bytes_sent, 17);
+        let seen = monitor.snapshot();
+        assert_eq!(seen.requests_sent, 1);
+        assert_eq!(seen.responses_received, 1);
+        assert_eq!(seen.bytes_received, 12);
+        assert_eq!(seen.latency_buckets.iter().sum::<u64>(), 1);
+    }
+
+    #[test]
+    fn test_latency_percentiles_fall_in_expected_buckets() {
+        let mut stats = TransportStats::default();
+        assert_eq!(stats.p50(), None);
+
+        // 50 samples in 1-2 ms, 45 in 5-10 ms, 4 in 20-50 ms, 1 in 0.5-1 s
+        for _ in 0..50 {
+            stats.record_latency(Duration::from_micros(1_500));
+        }
+        for _ in 0..45 {
+            stats.record_latency(Duration::from_millis(7));
+        }
+        for _ in 0..4 {
+            stats.record_latency(Duration::from_millis(30));
+        }
+        stats.record_latency(Duration::from_millis(600));
+        assert_eq!(stats.latency_buckets[4], 50);
+        assert_eq!(stats.latency_buckets[12], 1);
+
+        assert_eq!(stats.p50(), Some(2_000.0));
+        let p95 = stats.p95().unwrap();
+        assert!((5_000.0..=10_000.0).contains(&p95), "p95 = {}", p95);
+        assert_eq!(p95, 10_000.0);
+        // The 99th sample is the last one in the 20-50 ms bucket
+        assert_eq!(stats.p99(), Some(50_000.0));
+        let p97 = stats.percentile(97.0).unwrap();
+        assert!((20_000.0..50_000.0).contains(&p97), "p97 = {}", p97);
+        assert_eq!(stats.percentile(100.0), Some(1_000_000.0));
+        assert_eq!(stats.percentile(0.0), Some(1_000.0));
+        assert_eq!(stats.percentile(100.5), None);
+
+        // Interpolation inside a bucket
+        let mut even = TransportStats::default();
+        for _ in 0..10 {
+            even.record_latency(Duration::from_micros(150));
+        }
+        assert_eq!(even.p50(), Some(150.0));
+
+        // Out-of-range samples land in the last bucket
+        even.record_latency(Duration::from_secs(60));
+        assert_eq!(even.latency_buckets[LATENCY_BUCKETS - 1], 1);
+    }
+
+    #[test]
+    fn test_atomic_stats_count_across_threads() {
+        let stats = Arc::new(AtomicTransportStats::new());
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let stats = Arc::clone(&stats);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        stats.increment_requests_sent();
+                        stats.add_bytes_sent(12);
+                    }
+                    stats.increment_errors();
+                    stats.increment_timeouts();
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.requests_sent, 4000);
+        assert_eq!(snapshot.bytes_sent, 48_000);
+        assert_eq!(snapshot.errors, 4);
+        assert_eq!(snapshot.timeouts, 4);
+        assert_eq!(snapshot.responses_received, 0);
+    }
+
+    #[tokio::test]
+    async fn test_vectored_write_retries_short_writes() {
+        /// Accepts at most 3 bytes per write call
+        struct Trickle(Vec<u8>);
+
+        impl tokio::io::AsyncWrite for Trickle {
+            fn poll_write(
+                mut self: std::pin::Pin<&mut Self>,
+                _cx: &mut std::task::Context<'_>,
+                buf: &[u8],
+            ) -> std::task::Poll<std::io::Result<usize>> {
+                let n = buf.len().min(3);
+                self.0.extend_from_slice(&buf[..n]);
+                std::task::Poll::Ready(Ok(n))
+            }
+
+            fn poll_flush(
+                self: std::pin::Pin<&mut Self>,
+                _cx: &mut std::task::Context<'_>,
+            ) -> std::task::Poll<std::io::Result<()>> {
+                std::task::Poll::Ready(Ok(()))
+            }
+
+            fn poll_shutdown(
+                self: std::pin::Pin<&mut Self>,
+                _cx: &mut std::task::Context<'_>,
+            ) -> std::task::Poll<std::io::Result<()>> {
+                std::task::Poll::Ready(Ok(()))
+            }
+        }
+
+        let header = [0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x01];
+        let pdu = [0x03, 0x00, 0x64, 0x00, 0x0A];
+        let mut sink = Trickle(Vec::new());
+        write_frame_vectored(&mut sink, &header, &pdu)
+            .await
+            .unwrap();
+        assert_eq!(sink.0, [&header[..], &pdu[..]].concat());
+    }
+
+    #[test]
+    fn test_reconnect_backoff_follows_exponential_curve() {
+        let backoff = ReconnectBackoff {
+            initial_ms: 100,
+            max_ms: 1_000,
+            multiplier: 2.0,
+            jitter_pct: 0.0,
+        };
+
+        let delays: Vec<u64> = (0..6)
+            .map(|attempt| backoff.delay(attempt).as_millis() as u64)
+            .collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1_000, 1_000]);
+
+        // Huge attempt counts saturate at max_ms instead of overflowing
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_millis(1_000));
+    }
+
+    #[test]
+    fn test_reconnect_backoff_jitter_within_range() {
+        let backoff = ReconnectBackoff {
+            initial_ms: 200,
+            max_ms: 10_000,
+            multiplier: 1.5,
+            jitter_pct: 25.0,
+        };
+
+        assert_eq!(
+            backoff.delay_with_sample(0, 0.0),
+            Duration::from_millis(200)
+        );
+        assert_eq!(
+            backoff.delay_with_sample(0, 1.0),
+            Duration::from_millis(250)
+        );
+
+        for attempt in 0..8 {
+            let base = backoff.base_delay(attempt);
+            let upper = base.mul_f64(1.25);
+            for _ in 0..200 {
+                let delay = backoff.delay(attempt);
+                assert!(
+                    delay >= base && delay <= upper,
+                    "attempt {}: {:?} outside [{:?}, {:?}]",
+                    attempt,
+                    delay,
+                    base,
+                    upper
+                );
+            }
+        }
+    }
+
+    /// Read one FC03 request frame and return its transaction ID
+    async fn read_fc03_request(socket: &mut tokio::net::TcpStream) -> u16 {
+        let mut frame = [0u8; 12];
+        socket.read_exact(&mut frame).await.unwrap();
+        u16::from_be_bytes([frame[0], frame[1]])
+    }
+
+    /// FC03 response frame carrying one register
+    fn fc03_response(tid: u16, value: u16) -> Vec<u8> {
+        let mut frame = tid.to_be_bytes().to_vec();
+        frame.extend_from_slice(&[0x00, 0x00, 0x00, 0x05, 0x01, 0x03, 0x02]);
+        frame.extend_from_slice(&value.to_be_bytes());
+        frame
+    }
+
+    #[tokio::test]
+    async fn test_auto_resend_discards_late_response_to_first_request() {
+        use tokio::io::AsyncWriteExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            // The first response is "lost": it only goes out after the resend
+            let first = read_fc03_request(&mut socket).await;
+            let second = read_fc03_request(&mut socket).await;
+            socket.write_all(&fc03_response(first, 1)).await.unwrap();
+            socket.write_all(&fc03_response(second, 2)).await.unwrap();
+            (first, second)
+        });
+
+        let mut transport = TcpTransport::new(address, Duration::from_millis(100))
+            .await
+            .unwrap()
+            .with_auto_resend(1, ResendStrategy::FreshTransactionId);
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+        let response = transport.request(&request).await.unwrap();
+
+        let (first, second) = server.await.unwrap();
+        assert_ne!(first, second);
+        assert_eq!(response.data(), [0x02, 0x00, 0x02]);
+        let stats = transport.get_stats();
+        assert_eq!(stats.requests_sent, 2);
+        assert_eq!(stats.timeouts, 1);
+        assert_eq!(stats.responses_received, 1);
+    }
+
+    #[tokio::test]
+    async fn test_auto_resend_same_transaction_id_and_write_opt_in() {
+        use tokio::io::AsyncWriteExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let first = read_fc03_request(&mut socket).await;
+            let second = read_fc03_request(&mut socket).await;
+            socket.write_all(&fc03_response(second, 7)).await.unwrap();
+            // A write is sent once and never answered
+            let mut frame = [0u8; 12];
+            socket.read_exact(&mut frame).await.unwrap();
+            let resent = timeout(Duration::from_millis(300), socket.read(&mut frame)).await;
+            // The client gives up and closes the connection instead
+            (first, second, matches!(resent, Ok(Ok(n)) if n > 0))
+        });
+
+        let mut transport = TcpTransport::new(address, Duration::from_millis(100))
+            .await
+            .unwrap()
+            .with_auto_resend(3, ResendStrategy::SameTransactionId);
+        let read = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+        let response = transport.request(&read).await.unwrap();
+        assert_eq!(response.data(), [0x02, 0x00, 0x07]);
+
+        let write = ModbusRequest::new_write(1, ModbusFunction::WriteSingleRegister, 0, vec![0, 1]);
+        let result = transport.request(&write).await;
+        assert!(matches!(result, Err(ModbusError::Timeout { .. })));
+
+        let (first, second, write_resent) = server.await.unwrap();
+        assert_eq!(first, second);
+        assert!(!write_resent);
+
+        assert!(ResendStrategy::Force.applies_to(ModbusFunction::WriteMultipleCoils));
+        assert!(!ResendStrategy::FreshTransactionId.applies_to(ModbusFunction::WriteSingleCoil));
+        assert!(ResendStrategy::SameTransactionId.applies_to(ModbusFunction::ReadCoils));
+    }
+
+    #[tokio::test]
+    async fn test_escalating_timeout_waits_longer_on_retry() {
+        use tokio::io::AsyncWriteExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            // Busy: answers later than the initial timeout, every time
+            read_fc03_request(&mut socket).await;
+            let second = read_fc03_request(&mut socket).await;
+            tokio::time::sleep(Duration::from_millis(150)).await;
+            socket.write_all(&fc03_response(second, 9)).await.unwrap();
+        });
+
+        let escalating = EscalatingTimeout {
+            initial: Duration::from_millis(100),
+            escalation_factor: 3.0,
+            max_retries: 2,
+        };
+        let mut transport = TcpTransport::with_escalating_timeout(address, escalating)
+            .await
+            .unwrap();
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+        let response = transport.request(&request).await.unwrap();
+        server.await.unwrap();
+
+        assert_eq!(response.data(), [0x02, 0x00, 0x09]);
+        let stats = transport.get_stats();
+        assert_eq!(stats.requests_sent, 2);
+        assert_eq!(stats.timeouts, 1);
+        assert_eq!(stats.timeout_escalations, 1);
+    }
+
+    #[tokio::test]
+    async fn test_escalating_timeout_gives_up_after_max_retries() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut received = 0;
+            let mut frame = [0u8; 12];
+            while let Ok(()) = socket.read_exact(&mut frame).await.map(drop) {
+                received += 1;
+            }
+            received
+        });
+
+        let escalating = EscalatingTimeout {
+            initial: Duration::from_millis(20),
+            escalation_factor: 2.0,
+            max_retries: 2,
+        };
+        assert_eq!(escalating.timeout_for(2), Duration::from_millis(80));
+        let mut transport = TcpTransport::with_escalating_timeout(address, escalating)
+            .await
+            .unwrap();
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+        let result = transport.request(&request).await;
+        assert!(matches!(
+            result,
+            Err(ModbusError::Timeout { timeout_ms: 80, .. })
+        ));
+        transport.close().await.unwrap();
+
+        assert_eq!(server.await.unwrap(), 3);
+        let stats = transport.get_stats();
+        assert_eq!(stats.timeouts, 3);
+        assert_eq!(stats.timeout_escalations, 2);
+    }
+
+    #[tokio::test]
+    async fn test_escalating_timeout_does_not_resend_writes() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut received = 0;
+            let mut frame = [0u8; 12];
+            while let Ok(()) = socket.read_exact(&mut frame).await.map(drop) {
+                received += 1;
+            }
+            received
+        });
+
+        let escalating = EscalatingTimeout {
+            initial: Duration::from_millis(20),
+            escalation_factor: 2.0,
+            max_retries: 2,
+        };
+        let mut transport = TcpTransport::with_escalating_timeout(address, escalating)
+            .await
+            .unwrap();
+        let write = ModbusRequest::new_write(1, ModbusFunction::WriteSingleRegister, 0, vec![0, 1]);
+        let result = transport.request(&write).await;
+        assert!(matches!(
+            result,
+            Err(ModbusError::Timeout { timeout_ms: 20, .. })
+        ));
+        transport.close().await.unwrap();
+
+        assert_eq!(server.await.unwrap(), 1);
+        assert_eq!(transport.get_stats().timeout_escalations, 0);
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_overrides_transport_timeout() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut frame = [0u8; 12];
+            while socket.read_exact(&mut frame).await.is_ok() {}
+        });
+
+        let mut transport = TcpTransport::new(address, Duration::from_secs(5))
+            .await
+            .unwrap();
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1)
+            .with_timeout(Duration::from_millis(30));
+        let start = Instant::now();
+        let result = transport.request(&request).await;
+        assert!(matches!(
+            result,
+            Err(ModbusError::Timeout { timeout_ms: 30, .. })
+        ));
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        transport.close().await.unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_backoff_tracks_attempts_and_resets() {
+        // Grab a free port, then close it so connects are refused
+        let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let closed_addr = closed.local_addr().unwrap();
+        drop(closed);
+
+        let mut transport = TcpTransport {
+            stream: None,
+            address: closed_addr,
+            timeout: Duration::from_secs(1),
+            transaction_id: 0,
+            stats: Arc::default(),
+            read_buf: Box::new([0u8; 512]),
+            packet_logging: false,
+            packet_callback: None,
+            reconnect_backoff: Some(ReconnectBackoff {
+                initial_ms: 20,
+                max_ms: 1_000,
+                multiplier: 2.0,
+                jitter_pct: 0.0,
+            }),
+            reconnect_attempt: 0,
+            next_reconnect_at: None,
+            reconnect_policy: None,
+            auto_resend: None,
+            escalating_timeout: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+            socket_options: TcpTransportConfig::default(),
+        };
+        assert_eq!(transport.next_reconnect_in(), Duration::ZERO);
+
+        assert!(transport.reconnect().await.is_err());
+        assert_eq!(transport.reconnect_attempt(), 1);
+        let wait = transport.next_reconnect_in();
+        assert!(wait > Duration::ZERO && wait <= Duration::from_millis(20));
+
+        // Second attempt must wait out the first back-off before connecting
+        let start = Instant::now();
+        assert!(transport.reconnect().await.is_err());
+        assert!(start.elapsed() >= Duration::from_millis(15));
+        assert_eq!(transport.reconnect_attempt(), 2);
+        let wait = transport.next_reconnect_in();
+        assert!(wait > Duration::from_millis(20) && wait <= Duration::from_millis(40));
+
+        // A successful reconnect clears the counter and pending back-off
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        transport.address = listener.local_addr().unwrap();
+        let start = Instant::now();
+        transport.reconnect().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        assert!(transport.is_connected());
+        assert_eq!(transport.reconnect_attempt(), 0);
+        assert_eq!(transport.next_reconnect_in(), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_policy_retries_dropped_connection() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            // First connection drops the request on the floor
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 12];
+            socket.read_exact(&mut request).await.unwrap();
+            drop(socket);
+
+            let (mut socket, _) = listener.accept().await.unwrap();
+            socket.read_exact(&mut request).await.unwrap();
+            let mut response = request[..4].to_vec();
+            response.extend_from_slice(&[0x00, 0x05, 0x01, 0x03, 0x02, 0x12, 0x34]);
+            socket.write_all(&response).await.unwrap();
+        });
+
+        let policy = ReconnectPolicy {
+            max_retries: Some(2),
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(100),
+        };
+        let mut transport =
+            TcpTransport::new_with_reconnect(address, Duration::from_secs(1), policy)
+                .await
+                .unwrap();
+        let request = ModbusRequest::new_read(1, ModbusFunction::ReadHoldingRegisters, 0, 1);
+        let response = transport.request(&request).await.unwrap();
+        assert_eq!(response.parse_registers().unwrap(), [0x1234]);
+        server.await.unwrap();
+
+        // Server gone: both retries fail and the connection error surfaces
+        let start = Instant::now();
+        let err = transport.request(&request).await.unwrap_err();
+        assert!(matches!(err, ModbusError::Connection { .. }));
+        assert!(start.elapsed() >= Duration::from_millis(30));
+    }
 }
 
 #[cfg(all(test, feature = "rtu"))]
@@ -2730,6 +4852,51 @@ mod rtu_tests {
         assert!(crc > 0);
     }
 
+    #[test]
+    fn test_rtu_read_exception_status_frame() {
+        let transport = RtuTransport::new_for_fuzz();
+        let request = ModbusRequest::new_read_exception_status(0x11);
+        assert_eq!(
+            transport.encode_request(&request).unwrap(),
+            [0x11, 0x07, 0x4C, 0x22]
+        );
+
+        let response = transport
+            .decode_response(vec![0x11, 0x07, 0x6D, 0xE2, 0x18])
+            .unwrap();
+        assert_eq!(response.function, ModbusFunction::ReadExceptionStatus);
+        assert_eq!(response.data(), [0x6D]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rtu_inter_frame_delay_extends_gap() {
+        let mut transport = RtuTransport::new_for_fuzz();
+        assert_eq!(transport.inter_frame_delay(), Duration::ZERO);
+        assert_eq!(transport.turnaround_delay(), Duration::ZERO);
+
+        transport.set_inter_frame_delay(Duration::from_millis(6));
+        transport.set_turnaround_delay(Duration::from_millis(2));
+        assert_eq!(transport.turnaround_delay(), Duration::from_millis(2));
+
+        let started = tokio::time::Instant::now();
+        transport.wait_frame_gap().await;
+        assert_eq!(started.elapsed(), Duration::from_millis(10));
+    }
+
+    #[tokio::test]
+    async fn test_send_raw_frame_rejects_empty_frame() {
+        let mut transport = RtuTransport::new_for_fuzz();
+        let err = transport.send_raw_frame(&[]).await.unwrap_err();
+        assert!(matches!(err, ModbusError::InvalidData { .. }));
+        assert_eq!(transport.get_stats().requests_sent, 0);
+    }
+}
+
+#[cfg(all(test, feature = "ascii"))]
+mod ascii_tests {
+    use super::*;
+    use crate::protocol::ModbusFunction;
+
     #[test]
     fn test_ascii_lrc_calculation() {
         let data = [0x01, 0x03, 0x00, 0x00, 0x00, 0x02];
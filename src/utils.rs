@@ -1,13 +1,80 @@
 use crate::error::{ModbusError, ModbusResult};
+use crate::value::ModbusValue;
 /// Utility functions and helpers for Modbus operations
 ///
 /// This module contains various utility functions for data conversion,
 /// logging, and performance monitoring.
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
+const BLOCK_CRC: crc::Crc<u16> = crc::Crc::<u16>::new(&crc::CRC_16_MODBUS);
+
+/// Default upper bounds of the [`PerformanceMetrics`] latency histogram; a
+/// last, open-ended bucket counts everything slower.
+pub const DEFAULT_LATENCY_BOUNDS: [Duration; 6] = [
+    Duration::from_millis(1),
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(500),
+];
+
+fn default_latency_histogram() -> Vec<(Duration, u64)> {
+    latency_histogram(&DEFAULT_LATENCY_BOUNDS)
+}
+
+/// Empty histogram with buckets ending at `bounds`, then at `Duration::MAX`.
+fn latency_histogram(bounds: &[Duration]) -> Vec<(Duration, u64)> {
+    let mut bounds = bounds.to_vec();
+    bounds.sort();
+    bounds.dedup();
+    bounds.retain(|&bound| bound != Duration::MAX);
+    bounds.push(Duration::MAX);
+    bounds.into_iter().map(|bound| (bound, 0)).collect()
+}
+
+/// Request counters of one function code in [`PerformanceMetrics`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FunctionCodeStats {
+    pub request_count: u64,
+    pub success_count: u64,
+    pub error_count: u64,
+    pub total_latency_ms: f64,
+}
+
+impl FunctionCodeStats {
+    /// Fraction of requests that succeeded, in `[0.0, 1.0]`; 0.0 without requests
+    pub fn success_rate(&self) -> f64 {
+        if self.request_count == 0 {
+            return 0.0;
+        }
+        self.success_count as f64 / self.request_count as f64
+    }
+
+    /// Mean latency in milliseconds; 0.0 without requests
+    pub fn avg_latency_ms(&self) -> f64 {
+        if self.request_count == 0 {
+            return 0.0;
+        }
+        self.total_latency_ms / self.request_count as f64
+    }
+}
+
 /// Performance metrics for Modbus operations
-#[derive(Debug, Clone, Default)]
+///
+/// `Display` renders the metrics as a two-column table for logs and consoles.
+/// With the `serde` feature the metrics serialize field by field, e.g. for a
+/// REST endpoint (see [`to_json`](Self::to_json)).
+///
+/// Every recorded duration, successful or not, is also counted in a latency
+/// histogram ([`DEFAULT_LATENCY_BOUNDS`] unless set with
+/// [`with_latency_bounds`](Self::with_latency_bounds)).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerformanceMetrics {
     pub total_requests: u64,
     pub successful_requests: u64,
@@ -16,6 +83,27 @@ pub struct PerformanceMetrics {
     pub min_duration: Option<Duration>,
     pub max_duration: Option<Duration>,
     pub avg_duration: Duration,
+    #[cfg_attr(feature = "serde", serde(default = "default_latency_histogram"))]
+    latency_histogram: Vec<(Duration, u64)>,
+    /// Counters per function code, filled by [`record_function`](Self::record_function)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub function_codes: HashMap<u8, FunctionCodeStats>,
+}
+
+impl Default for PerformanceMetrics {
+    fn default() -> Self {
+        Self {
+            total_requests: 0,
+            successful_requests: 0,
+            failed_requests: 0,
+            total_duration: Duration::ZERO,
+            min_duration: None,
+            max_duration: None,
+            avg_duration: Duration::ZERO,
+            latency_histogram: default_latency_histogram(),
+            function_codes: HashMap::new(),
+        }
+    }
 }
 
 impl PerformanceMetrics {
@@ -24,6 +112,59 @@ impl PerformanceMetrics {
         Self::default()
     }
 
+    /// Use histogram buckets ending at `bounds` (in any order), plus an
+    /// open-ended bucket for slower operations. Clears recorded samples.
+    pub fn with_latency_bounds(mut self, bounds: &[Duration]) -> Self {
+        self.latency_histogram = latency_histogram(bounds);
+        self
+    }
+
+    /// `(upper bound, count)` per latency bucket, fastest first; the last
+    /// bucket's bound is `Duration::MAX`.
+    pub fn histogram_buckets(&self) -> &[(Duration, u64)] {
+        &self.latency_histogram
+    }
+
+    /// The `p`-th latency percentile (`p` in 0.0–100.0, clamped)
+    ///
+    /// Interpolates linearly inside the bucket holding the `p`-th sample; the
+    /// open-ended bucket is taken to end at `max_duration`. Returns
+    /// `Duration::ZERO` without samples.
+    pub fn latency_percentile(&self, p: f64) -> Duration {
+        let total: u64 = self.latency_histogram.iter().map(|&(_, count)| count).sum();
+        if total == 0 {
+            return Duration::ZERO;
+        }
+
+        let rank = p.clamp(0.0, 100.0) / 100.0 * total as f64;
+        let mut lower = Duration::ZERO;
+        let mut below = 0u64;
+        for &(bound, count) in &self.latency_histogram {
+            if count > 0 && rank <= (below + count) as f64 {
+                let upper = if bound == Duration::MAX {
+                    self.max_duration.unwrap_or(lower).max(lower)
+                } else {
+                    bound
+                };
+                let fraction = ((rank - below as f64) / count as f64).clamp(0.0, 1.0);
+                return lower + (upper - lower).mul_f64(fraction);
+            }
+            below += count;
+            lower = bound;
+        }
+        lower
+    }
+
+    fn record_latency(&mut self, duration: Duration) {
+        if let Some((_, count)) = self
+            .latency_histogram
+            .iter_mut()
+            .find(|(bound, _)| duration < *bound || *bound == Duration::MAX)
+        {
+            *count += 1;
+        }
+    }
+
     /// Record a successful operation
     pub fn record_success(&mut self, duration: Duration) {
         self.total_requests += 1;
@@ -32,6 +173,7 @@ impl PerformanceMetrics {
 
         self.min_duration = Some(self.min_duration.map_or(duration, |min| min.min(duration)));
         self.max_duration = Some(self.max_duration.map_or(duration, |max| max.max(duration)));
+        self.record_latency(duration);
 
         if self.total_requests > 0 {
             self.avg_duration = self.total_duration / self.total_requests as u32;
@@ -43,12 +185,39 @@ impl PerformanceMetrics {
         self.total_requests += 1;
         self.failed_requests += 1;
         self.total_duration += duration;
+        self.record_latency(duration);
 
         if self.total_requests > 0 {
             self.avg_duration = self.total_duration / self.total_requests as u32;
         }
     }
 
+    /// Record a request of `function_code`, both in the overall counters and
+    /// in [`function_codes`](Self::function_codes)
+    pub fn record_function(&mut self, function_code: u8, duration: Duration, success: bool) {
+        if success {
+            self.record_success(duration);
+        } else {
+            self.record_failure(duration);
+        }
+        let stats = self.function_codes.entry(function_code).or_default();
+        stats.request_count += 1;
+        if success {
+            stats.success_count += 1;
+        } else {
+            stats.error_count += 1;
+        }
+        stats.total_latency_ms += duration.as_secs_f64() * 1000.0;
+    }
+
+    /// Fraction of `function_code` requests that succeeded, in `[0.0, 1.0]`;
+    /// 0.0 if none were recorded
+    pub fn success_rate_for(&self, function_code: u8) -> f64 {
+        self.function_codes
+            .get(&function_code)
+            .map_or(0.0, FunctionCodeStats::success_rate)
+    }
+
     /// Get success rate as percentage
     pub fn success_rate(&self) -> f64 {
         if self.total_requests == 0 {
@@ -65,16 +234,133 @@ impl PerformanceMetrics {
         self.total_requests as f64 / self.total_duration.as_secs_f64()
     }
 
-    /// Reset all metrics
+    /// Reset all metrics, keeping the histogram bucket bounds
     pub fn reset(&mut self) {
-        *self = Self::default();
+        let mut histogram = std::mem::take(&mut self.latency_histogram);
+        histogram.iter_mut().for_each(|(_, count)| *count = 0);
+        *self = Self {
+            latency_histogram: histogram,
+            ..Self::default()
+        };
+    }
+
+    /// Counter deltas since `baseline`, for per-interval rate reporting
+    ///
+    /// Counters that went backwards (metrics reset in between) saturate at 0.
+    pub fn diff(&self, baseline: &PerformanceMetrics) -> PerformanceMetricsDiff {
+        PerformanceMetricsDiff {
+            total_requests: self.total_requests.saturating_sub(baseline.total_requests),
+            successful_requests: self
+                .successful_requests
+                .saturating_sub(baseline.successful_requests),
+            failed_requests: self
+                .failed_requests
+                .saturating_sub(baseline.failed_requests),
+            total_duration: self.total_duration.saturating_sub(baseline.total_duration),
+        }
+    }
+
+    /// Serialize the metrics as a JSON object
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("PerformanceMetrics always serializes")
+    }
+}
+
+impl std::fmt::Display for PerformanceMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ms = |d: Duration| format!("{:.2}", d.as_secs_f64() * 1000.0);
+        let rows = [
+            ("total_requests", self.total_requests.to_string()),
+            ("successful", self.successful_requests.to_string()),
+            ("failed", self.failed_requests.to_string()),
+            ("success_rate_pct", format!("{:.2}", self.success_rate())),
+            (
+                "requests_per_sec",
+                format!("{:.2}", self.requests_per_second()),
+            ),
+            ("avg_latency_ms", ms(self.avg_duration)),
+            (
+                "min_latency_ms",
+                self.min_duration.map_or("-".to_string(), ms),
+            ),
+            (
+                "max_latency_ms",
+                self.max_duration.map_or("-".to_string(), ms),
+            ),
+        ];
+
+        let name_width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0) + 1;
+        let value_width = rows.iter().map(|(_, v)| v.len()).max().unwrap_or(0).max(8);
+        let border = format!(
+            "+{}+{}+",
+            "-".repeat(name_width + 2),
+            "-".repeat(value_width + 2)
+        );
+
+        writeln!(f, "{}", border)?;
+        for (name, value) in &rows {
+            writeln!(
+                f,
+                "| {:<name_width$} | {:>value_width$} |",
+                name,
+                value,
+                name_width = name_width,
+                value_width = value_width
+            )?;
+        }
+        write!(f, "{}", border)
+    }
+}
+
+/// Change in [`PerformanceMetrics`] between two samples
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerformanceMetricsDiff {
+    pub total_requests: u64,
+    pub successful_requests: u64,
+    pub failed_requests: u64,
+    pub total_duration: Duration,
+}
+
+impl PerformanceMetricsDiff {
+    /// Success rate over the interval as a percentage
+    pub fn success_rate(&self) -> f64 {
+        if self.total_requests == 0 {
+            return 0.0;
+        }
+        (self.successful_requests as f64 / self.total_requests as f64) * 100.0
+    }
+
+    /// Requests per second over a sampling interval of `elapsed`
+    pub fn requests_per_second(&self, elapsed: Duration) -> f64 {
+        if elapsed.is_zero() {
+            return 0.0;
+        }
+        self.total_requests as f64 / elapsed.as_secs_f64()
+    }
+
+    /// Average request duration over the interval
+    pub fn avg_duration(&self) -> Duration {
+        if self.total_requests == 0 {
+            return Duration::ZERO;
+        }
+        self.total_duration.div_f64(self.total_requests as f64)
     }
 }
 
 /// Timer for measuring operation duration
+///
+/// With [`with_metrics`](Self::with_metrics) the duration is recorded into
+/// shared [`PerformanceMetrics`] when the timer is dropped: as a success after
+/// [`stop`](Self::stop), as given to [`stop_and_log`](Self::stop_and_log), and
+/// as a failure if the timer is dropped without being stopped (e.g. an early
+/// return through `?`).
 pub struct OperationTimer {
     start: Instant,
     operation_name: String,
+    metrics: Option<Arc<Mutex<PerformanceMetrics>>>,
+    outcome: Option<(Duration, bool)>,
 }
 
 impl OperationTimer {
@@ -84,22 +370,32 @@ impl OperationTimer {
         Self {
             start: Instant::now(),
             operation_name: operation_name.to_string(),
+            metrics: None,
+            outcome: None,
         }
     }
 
+    /// Record the operation into `metrics` when the timer is dropped
+    pub fn with_metrics(mut self, metrics: Arc<Mutex<PerformanceMetrics>>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Stop the timer and return duration
-    pub fn stop(self) -> Duration {
+    pub fn stop(mut self) -> Duration {
         let duration = self.start.elapsed();
         debug!(
             "Operation '{}' completed in {:?}",
             self.operation_name, duration
         );
+        self.outcome = Some((duration, true));
         duration
     }
 
     /// Stop timer and log result
-    pub fn stop_and_log(self, success: bool) -> Duration {
+    pub fn stop_and_log(mut self, success: bool) -> Duration {
         let duration = self.start.elapsed();
+        self.outcome = Some((duration, success));
         if success {
             info!(
                 "✅ Operation '{}' succeeded in {:?}",
@@ -115,6 +411,288 @@ impl OperationTimer {
     }
 }
 
+impl Drop for OperationTimer {
+    fn drop(&mut self) {
+        let Some(metrics) = self.metrics.take() else {
+            return;
+        };
+        let (duration, success) = self
+            .outcome
+            .unwrap_or_else(|| (self.start.elapsed(), false));
+        let mut metrics = metrics.lock().unwrap_or_else(|e| e.into_inner());
+        if success {
+            metrics.record_success(duration);
+        } else {
+            metrics.record_failure(duration);
+        }
+    }
+}
+
+/// Linear raw-to-engineering-unit conversion.
+///
+/// Implements the usual device-manual formula
+/// `eng = (raw - raw_min) / (raw_max - raw_min) * (eng_max - eng_min) + eng_min`.
+///
+/// ```rust
+/// use voltage_modbus::utils::EngineeringScaler;
+///
+/// // 4-20 mA transmitter digitised to 0..=65535, ranged 0-100 bar
+/// let scaler = EngineeringScaler::new(0.0, 65535.0, 0.0, 100.0, "bar");
+/// assert_eq!(scaler.to_engineering(65535.0), 100.0);
+/// assert_eq!(scaler.to_raw(50.0).round(), 32768.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EngineeringScaler {
+    /// Raw value mapped to `eng_min`
+    pub raw_min: f64,
+    /// Raw value mapped to `eng_max`
+    pub raw_max: f64,
+    /// Engineering value at `raw_min`
+    pub eng_min: f64,
+    /// Engineering value at `raw_max`
+    pub eng_max: f64,
+    /// Engineering unit label (e.g. `"bar"`)
+    pub unit: &'static str,
+}
+
+impl EngineeringScaler {
+    /// Create a scaler mapping `raw_min..raw_max` onto `eng_min..eng_max`.
+    pub fn new(raw_min: f64, raw_max: f64, eng_min: f64, eng_max: f64, unit: &'static str) -> Self {
+        Self {
+            raw_min,
+            raw_max,
+            eng_min,
+            eng_max,
+            unit,
+        }
+    }
+
+    /// Convert a raw value to engineering units.
+    ///
+    /// A degenerate raw range (`raw_min == raw_max`) maps everything to `eng_min`.
+    pub fn to_engineering(&self, raw: f64) -> f64 {
+        let span = self.raw_max - self.raw_min;
+        if span == 0.0 {
+            return self.eng_min;
+        }
+        (raw - self.raw_min) / span * (self.eng_max - self.eng_min) + self.eng_min
+    }
+
+    /// Convert an engineering value back to raw, clamped to the raw range.
+    pub fn to_raw(&self, eng: f64) -> f64 {
+        let span = self.eng_max - self.eng_min;
+        let raw = if span == 0.0 {
+            self.raw_min
+        } else {
+            (eng - self.eng_min) / span * (self.raw_max - self.raw_min) + self.raw_min
+        };
+        raw.clamp(
+            self.raw_min.min(self.raw_max),
+            self.raw_min.max(self.raw_max),
+        )
+    }
+}
+
+/// Piecewise-linear lookup table for non-linear sensors (thermocouples, RTDs).
+///
+/// Values between two breakpoints are interpolated linearly; values outside
+/// the table are extrapolated with the slope of the first or last segment.
+///
+/// ```rust
+/// use voltage_modbus::utils::LinearizationTable;
+///
+/// let table = LinearizationTable::new(vec![(0.0, 0.0), (10.0, 50.0), (20.0, 80.0)])?;
+/// assert_eq!(table.interpolate(5.0), 25.0);
+/// assert_eq!(table.interpolate(15.0), 65.0);
+/// assert_eq!(table.to_raw(65.0), Some(15.0));
+/// # Ok::<(), voltage_modbus::ModbusError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearizationTable {
+    breakpoints: Vec<(f64, f64)>,
+}
+
+impl LinearizationTable {
+    /// Build a table from `(raw, eng)` breakpoints.
+    ///
+    /// Requires at least two breakpoints with finite values and strictly
+    /// increasing raw values.
+    pub fn new(breakpoints: Vec<(f64, f64)>) -> ModbusResult<Self> {
+        if breakpoints.len() < 2 {
+            return Err(ModbusError::invalid_data(
+                "Linearization table needs at least two breakpoints",
+            ));
+        }
+        if breakpoints
+            .iter()
+            .any(|(raw, eng)| !raw.is_finite() || !eng.is_finite())
+        {
+            return Err(ModbusError::invalid_data(
+                "Linearization breakpoints must be finite",
+            ));
+        }
+        if breakpoints.windows(2).any(|w| w[0].0 >= w[1].0) {
+            return Err(ModbusError::invalid_data(
+                "Linearization breakpoints must be sorted by strictly increasing raw value",
+            ));
+        }
+        Ok(Self { breakpoints })
+    }
+
+    /// The `(raw, eng)` breakpoints.
+    pub fn breakpoints(&self) -> &[(f64, f64)] {
+        &self.breakpoints
+    }
+
+    /// Engineering value for `raw`.
+    pub fn interpolate(&self, raw: f64) -> f64 {
+        let points = &self.breakpoints;
+        // Segment whose end is the first breakpoint at or above `raw`,
+        // clamped to the first/last segment for extrapolation.
+        let end = points
+            .iter()
+            .position(|&(r, _)| r >= raw)
+            .unwrap_or(points.len() - 1)
+            .max(1);
+        let ((r0, e0), (r1, e1)) = (points[end - 1], points[end]);
+        e0 + (raw - r0) * (e1 - e0) / (r1 - r0)
+    }
+
+    /// Raw value for `eng`, or `None` if the table is not strictly monotonic
+    /// in its engineering values (the inverse would be ambiguous).
+    pub fn to_raw(&self, eng: f64) -> Option<f64> {
+        let points = &self.breakpoints;
+        let increasing = points.windows(2).all(|w| w[0].1 < w[1].1);
+        let decreasing = points.windows(2).all(|w| w[0].1 > w[1].1);
+        if !increasing && !decreasing {
+            return None;
+        }
+        let end = points
+            .iter()
+            .position(|&(_, e)| if increasing { e >= eng } else { e <= eng })
+            .unwrap_or(points.len() - 1)
+            .max(1);
+        let ((r0, e0), (r1, e1)) = (points[end - 1], points[end]);
+        Some(r0 + (eng - e0) * (r1 - r0) / (e1 - e0))
+    }
+}
+
+/// On/off filter with hysteresis for driving coils from an analog value.
+///
+/// The output switches on when the value reaches `on_threshold` and only
+/// switches off again once it falls to `off_threshold`, so noise around a
+/// single threshold cannot make a relay chatter.
+///
+/// ```rust
+/// use voltage_modbus::utils::HysteresisFilter;
+/// use voltage_modbus::ModbusValue;
+///
+/// let mut pump = HysteresisFilter::new(80.0, 60.0)?;
+/// assert_eq!(pump.update(85.0), Some(true));
+/// assert_eq!(pump.update_value(&ModbusValue::F32(70.0)), None);
+/// assert_eq!(pump.update(55.0), Some(false));
+/// # Ok::<(), voltage_modbus::ModbusError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HysteresisFilter {
+    on_threshold: f64,
+    off_threshold: f64,
+    current_state: bool,
+}
+
+impl HysteresisFilter {
+    /// Create a filter in the off state; requires `off_threshold < on_threshold`.
+    pub fn new(on_threshold: f64, off_threshold: f64) -> ModbusResult<Self> {
+        if off_threshold.partial_cmp(&on_threshold) != Some(std::cmp::Ordering::Less) {
+            return Err(ModbusError::invalid_data(format!(
+                "Hysteresis off threshold ({}) must be below on threshold ({})",
+                off_threshold, on_threshold
+            )));
+        }
+        Ok(Self {
+            on_threshold,
+            off_threshold,
+            current_state: false,
+        })
+    }
+
+    /// Start in `state` instead of off (e.g. to match the coil's current state).
+    pub fn with_initial_state(mut self, state: bool) -> Self {
+        self.current_state = state;
+        self
+    }
+
+    /// Threshold at or above which the output turns on.
+    pub fn on_threshold(&self) -> f64 {
+        self.on_threshold
+    }
+
+    /// Threshold at or below which the output turns off.
+    pub fn off_threshold(&self) -> f64 {
+        self.off_threshold
+    }
+
+    /// Current output state.
+    pub fn state(&self) -> bool {
+        self.current_state
+    }
+
+    /// Feed a new value; returns the new state if it changed.
+    pub fn update(&mut self, value: f64) -> Option<bool> {
+        let next = if self.current_state {
+            value > self.off_threshold
+        } else {
+            value >= self.on_threshold
+        };
+        if next == self.current_state {
+            return None;
+        }
+        self.current_state = next;
+        Some(next)
+    }
+
+    /// Feed a register value (via [`ModbusValue::as_f64`]).
+    pub fn update_value(&mut self, value: &ModbusValue) -> Option<bool> {
+        self.update(value.as_f64())
+    }
+}
+
+/// CRC16/Modbus over a block of registers, for devices that store a block
+/// checksum in the register following the data.
+///
+/// The registers are hashed as big-endian bytes (the order they travel on the
+/// wire) and the CRC is compared as a plain register value.
+///
+/// ```rust
+/// use voltage_modbus::utils::RegisterBlockCrc;
+///
+/// let crc = RegisterBlockCrc::compute(&[0x0103, 0x0000, 0x000A]);
+/// assert_eq!(crc, 0xCDC5);
+/// assert!(RegisterBlockCrc::verify(&[0x0103, 0x0000, 0x000A, crc]));
+/// ```
+pub struct RegisterBlockCrc;
+
+impl RegisterBlockCrc {
+    /// CRC16/Modbus of `registers` taken as big-endian bytes.
+    pub fn compute(registers: &[u16]) -> u16 {
+        let mut digest = BLOCK_CRC.digest();
+        for register in registers {
+            digest.update(&register.to_be_bytes());
+        }
+        digest.finalize()
+    }
+
+    /// Check that the last register holds the CRC of the ones before it.
+    ///
+    /// An empty block never verifies.
+    pub fn verify(registers: &[u16]) -> bool {
+        match registers.split_last() {
+            Some((&stored, data)) => Self::compute(data) == stored,
+            None => false,
+        }
+    }
+}
+
 /// Data validation utilities
 pub mod validation {
     use super::*;
@@ -321,6 +899,135 @@ mod tests {
         assert!((metrics.success_rate() - 66.67).abs() < 0.1);
     }
 
+    #[test]
+    fn test_performance_metrics_display_table() {
+        let mut metrics = PerformanceMetrics::new();
+        metrics.record_success(Duration::from_micros(2_500));
+        metrics.record_failure(Duration::from_micros(7_500));
+
+        let expected = "\
++-------------------+----------+
+| total_requests    |        2 |
+| successful        |        1 |
+| failed            |        1 |
+| success_rate_pct  |    50.00 |
+| requests_per_sec  |   200.00 |
+| avg_latency_ms    |     5.00 |
+| min_latency_ms    |     2.50 |
+| max_latency_ms    |     2.50 |
++-------------------+----------+";
+        assert_eq!(metrics.to_string(), expected);
+    }
+
+    #[test]
+    fn test_latency_histogram_percentiles() {
+        let ms = Duration::from_millis;
+        let mut metrics = PerformanceMetrics::new().with_latency_bounds(&[ms(10), ms(2)]);
+        assert_eq!(metrics.latency_percentile(50.0), Duration::ZERO);
+        for d in [1, 1, 4, 6, 8] {
+            metrics.record_success(ms(d));
+        }
+        metrics.record_failure(ms(20));
+        metrics.record_success(ms(30));
+
+        let counts: Vec<u64> = metrics.histogram_buckets().iter().map(|b| b.1).collect();
+        assert_eq!(counts, [2, 3, 2]);
+        assert_eq!(metrics.histogram_buckets()[2].0, Duration::MAX);
+
+        // Rank 3.5 of 7 is halfway into the 2-10 ms bucket.
+        assert_eq!(metrics.latency_percentile(50.0), ms(6));
+        assert_eq!(metrics.latency_percentile(0.0), Duration::ZERO);
+        assert_eq!(metrics.latency_percentile(100.0), ms(30));
+        assert_eq!(metrics.latency_percentile(250.0), ms(30));
+
+        metrics.reset();
+        assert_eq!(metrics.histogram_buckets().len(), 3);
+        assert_eq!(metrics.latency_percentile(50.0), Duration::ZERO);
+        assert_eq!(
+            PerformanceMetrics::new().histogram_buckets().len(),
+            DEFAULT_LATENCY_BOUNDS.len() + 1
+        );
+    }
+
+    #[test]
+    fn test_operation_timer_records_on_drop() {
+        let metrics = Arc::new(Mutex::new(PerformanceMetrics::new()));
+        OperationTimer::start("read")
+            .with_metrics(metrics.clone())
+            .stop();
+        OperationTimer::start("write")
+            .with_metrics(metrics.clone())
+            .stop_and_log(false);
+        drop(OperationTimer::start("abandoned").with_metrics(metrics.clone()));
+        OperationTimer::start("untracked").stop();
+
+        let metrics = metrics.lock().unwrap();
+        assert_eq!(metrics.total_requests, 3);
+        assert_eq!(metrics.failed_requests, 2);
+        assert_eq!(metrics.histogram_buckets()[0].1, 3);
+    }
+
+    #[test]
+    fn test_performance_metrics_diff() {
+        let mut metrics = PerformanceMetrics::new();
+        metrics.record_success(Duration::from_millis(10));
+        let baseline = metrics.clone();
+        metrics.record_success(Duration::from_millis(20));
+        metrics.record_failure(Duration::from_millis(40));
+
+        let diff = metrics.diff(&baseline);
+        assert_eq!(diff.total_requests, 2);
+        assert_eq!(diff.successful_requests, 1);
+        assert_eq!(diff.failed_requests, 1);
+        assert_eq!(diff.avg_duration(), Duration::from_millis(30));
+        assert_eq!(diff.success_rate(), 50.0);
+        assert_eq!(diff.requests_per_second(Duration::from_secs(4)), 0.5);
+        assert_eq!(baseline.diff(&metrics), PerformanceMetricsDiff::default());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_performance_metrics_json_roundtrip() {
+        let mut metrics = PerformanceMetrics::new();
+        metrics.record_success(Duration::from_millis(3));
+        metrics.record_failure(Duration::from_millis(5));
+
+        let json = metrics.to_json();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["total_requests"], 2);
+        assert_eq!(value["failed_requests"], 1);
+        assert!(value["max_duration"].is_object());
+
+        let back: PerformanceMetrics = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, metrics);
+
+        metrics.record_function(0x10, Duration::from_millis(2), false);
+        let value: serde_json::Value = serde_json::from_str(&metrics.to_json()).unwrap();
+        assert_eq!(value["function_codes"]["16"]["error_count"], 1);
+    }
+
+    #[test]
+    fn test_per_function_code_stats() {
+        let mut metrics = PerformanceMetrics::new();
+        for _ in 0..3 {
+            metrics.record_function(0x03, Duration::from_millis(4), true);
+        }
+        metrics.record_function(0x10, Duration::from_millis(2), true);
+        metrics.record_function(0x10, Duration::from_millis(6), false);
+
+        assert_eq!(metrics.total_requests, 5);
+        assert_eq!(metrics.failed_requests, 1);
+        assert_eq!(metrics.success_rate_for(0x03), 1.0);
+        assert_eq!(metrics.success_rate_for(0x10), 0.5);
+        assert_eq!(metrics.success_rate_for(0x06), 0.0);
+        let fc16 = metrics.function_codes[&0x10];
+        assert_eq!((fc16.request_count, fc16.error_count), (2, 1));
+        assert!((fc16.avg_latency_ms() - 4.0).abs() < 1e-9);
+
+        metrics.reset();
+        assert!(metrics.function_codes.is_empty());
+    }
+
     #[test]
     fn test_validation() {
         assert!(validation::validate_slave_id(1).is_ok());
@@ -344,4 +1051,153 @@ mod tests {
         let duration = Duration::from_millis(1500);
         assert_eq!(format::format_duration(duration), "1.50s");
     }
+
+    #[test]
+    fn test_engineering_scaler_mapping() {
+        let scaler = EngineeringScaler::new(4000.0, 20000.0, 0.0, 100.0, "bar");
+        assert_eq!(scaler.to_engineering(4000.0), 0.0);
+        assert_eq!(scaler.to_engineering(20000.0), 100.0);
+        assert_eq!(scaler.to_engineering(12000.0), 50.0);
+
+        for raw in [4000.0, 7123.0, 12000.0, 19999.0] {
+            assert!((scaler.to_raw(scaler.to_engineering(raw)) - raw).abs() < 1e-9);
+        }
+        // Out-of-range engineering values clamp to the raw range
+        assert_eq!(scaler.to_raw(150.0), 20000.0);
+        assert_eq!(scaler.to_raw(-5.0), 4000.0);
+    }
+
+    fn five_point_table() -> LinearizationTable {
+        // Type-K-like curve: raw mV*100 -> degrees C
+        LinearizationTable::new(vec![
+            (0.0, 0.0),
+            (100.0, 25.0),
+            (200.0, 49.0),
+            (400.0, 98.0),
+            (800.0, 200.0),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_linearization_at_and_between_breakpoints() {
+        let table = five_point_table();
+        for &(raw, eng) in table.breakpoints() {
+            assert_eq!(table.interpolate(raw), eng);
+        }
+        assert_eq!(table.interpolate(50.0), 12.5);
+        assert_eq!(table.interpolate(150.0), 37.0);
+        assert_eq!(table.interpolate(300.0), 73.5);
+        assert_eq!(table.interpolate(600.0), 149.0);
+
+        assert_eq!(table.to_raw(37.0), Some(150.0));
+        assert_eq!(table.to_raw(149.0), Some(600.0));
+    }
+
+    #[test]
+    fn test_linearization_extrapolates_with_end_slopes() {
+        let table = five_point_table();
+        // First segment slope 0.25, last segment slope 0.255
+        assert_eq!(table.interpolate(-100.0), -25.0);
+        assert_eq!(table.interpolate(1000.0), 251.0);
+        assert_eq!(table.to_raw(-25.0), Some(-100.0));
+        assert_eq!(table.to_raw(251.0), Some(1000.0));
+    }
+
+    #[test]
+    fn test_linearization_validation_and_non_monotonic_inverse() {
+        assert!(LinearizationTable::new(vec![(0.0, 0.0)]).is_err());
+        assert!(LinearizationTable::new(vec![(0.0, 0.0), (0.0, 1.0)]).is_err());
+        assert!(LinearizationTable::new(vec![(10.0, 0.0), (0.0, 1.0)]).is_err());
+        assert!(LinearizationTable::new(vec![(0.0, f64::NAN), (1.0, 1.0)]).is_err());
+
+        let peaked = LinearizationTable::new(vec![(0.0, 0.0), (1.0, 10.0), (2.0, 0.0)]).unwrap();
+        assert_eq!(peaked.interpolate(1.5), 5.0);
+        assert_eq!(peaked.to_raw(5.0), None);
+
+        let falling = LinearizationTable::new(vec![(0.0, 100.0), (10.0, 0.0)]).unwrap();
+        assert_eq!(falling.to_raw(25.0), Some(7.5));
+    }
+
+    #[test]
+    fn test_hysteresis_one_change_per_crossing() {
+        let mut filter = HysteresisFilter::new(80.0, 60.0).unwrap();
+        let sweep = [
+            50.0, 65.0, 79.9, 80.0, 85.0, 70.0, 61.0, 79.0, 60.0, 55.0, 70.0,
+        ];
+        let changes: Vec<(f64, bool)> = sweep
+            .iter()
+            .filter_map(|&v| filter.update(v).map(|state| (v, state)))
+            .collect();
+        assert_eq!(changes, vec![(80.0, true), (60.0, false)]);
+        assert!(!filter.state());
+    }
+
+    #[test]
+    fn test_hysteresis_validation_and_register_values() {
+        assert!(HysteresisFilter::new(60.0, 60.0).is_err());
+        assert!(HysteresisFilter::new(60.0, 80.0).is_err());
+        assert!(HysteresisFilter::new(f64::NAN, 0.0).is_err());
+
+        let mut filter = HysteresisFilter::new(100.0, 50.0)
+            .unwrap()
+            .with_initial_state(true);
+        assert_eq!(filter.update_value(&ModbusValue::U16(75)), None);
+        assert_eq!(filter.update_value(&ModbusValue::I32(-1)), Some(false));
+        assert_eq!(filter.update_value(&ModbusValue::F32(100.0)), Some(true));
+    }
+
+    #[test]
+    fn test_register_block_crc() {
+        // Same bytes as the RTU frame "01 03 00 00 00 0A", whose CRC is C5 CD on the wire
+        let block = [0x0103, 0x0000, 0x000A];
+        assert_eq!(RegisterBlockCrc::compute(&block), 0xCDC5);
+        assert!(RegisterBlockCrc::verify(&[0x0103, 0x0000, 0x000A, 0xCDC5]));
+
+        assert!(!RegisterBlockCrc::verify(&[0x0103, 0x0000, 0x000B, 0xCDC5]));
+        assert!(!RegisterBlockCrc::verify(&[0x0103, 0x0000, 0x000A, 0xC5CD]));
+        assert!(!RegisterBlockCrc::verify(&[]));
+        // A lone register is the CRC of an empty block (0xFFFF initial value)
+        assert!(RegisterBlockCrc::verify(&[0xFFFF]));
+    }
+
+    #[tokio::test]
+    async fn test_read_with_block_crc() {
+        use crate::client::GenericModbusClient;
+        use crate::protocol::{ModbusFunction, ModbusResponse};
+        use crate::testing::ScriptedTransport;
+
+        let reply = |registers: &[u16]| {
+            let mut data = vec![(registers.len() * 2) as u8];
+            for r in registers {
+                data.extend_from_slice(&r.to_be_bytes());
+            }
+            Ok(ModbusResponse::new_success(
+                1,
+                ModbusFunction::ReadHoldingRegisters,
+                data,
+            ))
+        };
+        let mut client = GenericModbusClient::new(ScriptedTransport::new(vec![
+            reply(&[0x0103, 0x0000, 0x000A, 0xCDC5]),
+            reply(&[0x0103, 0x0000, 0x000A, 0x0000]),
+        ]));
+
+        let data = client.read_with_block_crc(1, 10, 3).await.unwrap();
+        assert_eq!(data, vec![0x0103, 0x0000, 0x000A]);
+        assert_eq!(client.transport().requests()[0].quantity, 4);
+
+        match client.read_with_block_crc(1, 10, 3).await {
+            Err(ModbusError::InvalidData { message }) => assert_eq!(message, "CRC mismatch"),
+            other => panic!("expected CRC mismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_engineering_scaler_inverted_range() {
+        let scaler = EngineeringScaler::new(0.0, 1000.0, 100.0, 0.0, "%");
+        assert_eq!(scaler.to_engineering(250.0), 75.0);
+        assert_eq!(scaler.to_raw(75.0), 250.0);
+        assert_eq!(scaler.to_raw(-10.0), 1000.0);
+    }
 }
@@ -3,7 +3,15 @@
 //! Self-contained data types for industrial Modbus applications.
 //! Designed for register encoding/decoding with minimal allocations.
 
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut};
+
+use crate::error::{ModbusError, ModbusResult};
+
+/// Largest value a [`ModbusValue::Bcd32`] can hold (8 decimal digits).
+pub const BCD32_MAX: u32 = 99_999_999;
 
 /// Industrial data type enumeration for Modbus register values.
 ///
@@ -17,8 +25,11 @@ use core::fmt;
 /// |------|-----------|-------------|
 /// | Bool | 1 (coil) | Single bit value |
 /// | U16/I16 | 1 | Single 16-bit register |
+/// | Bits | 1 | 16 packed flags, LSB first |
 /// | U32/I32/F32 | 2 | Two consecutive registers |
 /// | U64/I64/F64 | 4 | Four consecutive registers |
+/// | Bcd32 | 2 | Up to 8 decimal digits, one per nibble |
+/// | Str | 4 or 8 | Up to 8 or 16 ASCII characters, two per register |
 ///
 /// # Example
 ///
@@ -29,7 +40,22 @@ use core::fmt;
 /// assert_eq!(temp.register_count(), 2);
 /// assert!((temp.as_f64() - 25.5).abs() < 0.001);
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+///
+/// # Equality and Hashing
+///
+/// `ModbusValue` is `Eq + Hash` so it can key a `HashMap`. Values are equal
+/// only if they are the same variant with the same value. Unlike IEEE 754,
+/// NaN equals NaN of the same variant (all NaN payloads hash alike), and
+/// `0.0` equals `-0.0`.
+///
+/// With the `serde` feature a value serializes as its type name and payload,
+/// e.g. `{"type":"u16","value":42}`.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", content = "value", rename_all = "lowercase")
+)]
 pub enum ModbusValue {
     /// Boolean value (typically from coils)
     Bool(bool),
@@ -49,13 +75,35 @@ pub enum ModbusValue {
     I64(i64),
     /// 64-bit floating point (4 registers)
     F64(f64),
+    /// Binary coded decimal, holding the decoded decimal value (2 registers)
+    Bcd32(u32),
+    /// ASCII text packed two characters per register (4 or 8 registers)
+    Str(String),
+    /// 16 flags packed into one register, index 0 = LSB (1 register).
+    ///
+    /// Indexing a `ModbusValue` reaches these flags:
+    ///
+    /// ```rust
+    /// use voltage_modbus::ModbusValue;
+    ///
+    /// let mut flags = ModbusValue::bits_from_u16(0b0101);
+    /// assert!(flags[0] && !flags[1] && flags[2]);
+    /// flags[15] = true;
+    /// assert_eq!(flags.as_i64(), 0x8005);
+    /// ```
+    Bits([bool; 16]),
 }
 
 impl ModbusValue {
+    /// Unpack a register into [`ModbusValue::Bits`], LSB first.
+    pub fn bits_from_u16(register: u16) -> Self {
+        ModbusValue::Bits(core::array::from_fn(|i| register >> i & 1 != 0))
+    }
+
     /// Convert the value to f64 for uniform numeric handling.
     ///
     /// This is useful for calculations, comparisons, and storing
-    /// values in a normalized format.
+    /// values in a normalized format. Strings are not numbers and give NaN.
     #[inline]
     pub fn as_f64(&self) -> f64 {
         match self {
@@ -74,12 +122,15 @@ impl ModbusValue {
             ModbusValue::U64(v) => *v as f64,
             ModbusValue::I64(v) => *v as f64,
             ModbusValue::F64(v) => *v,
+            ModbusValue::Bcd32(v) => f64::from(*v),
+            ModbusValue::Str(_) => f64::NAN,
+            ModbusValue::Bits(bits) => f64::from(pack_bits(bits)),
         }
     }
 
     /// Convert the value to i64 for integer operations.
     ///
-    /// Float values are rounded to the nearest integer.
+    /// Float values are rounded to the nearest integer; strings give 0.
     #[inline]
     pub fn as_i64(&self) -> i64 {
         match self {
@@ -92,6 +143,9 @@ impl ModbusValue {
             ModbusValue::U64(v) => *v as i64,
             ModbusValue::I64(v) => *v,
             ModbusValue::F64(v) => v.round() as i64,
+            ModbusValue::Bcd32(v) => i64::from(*v),
+            ModbusValue::Str(_) => 0,
+            ModbusValue::Bits(bits) => i64::from(pack_bits(bits)),
         }
     }
 
@@ -100,20 +154,26 @@ impl ModbusValue {
     /// # Returns
     ///
     /// - `0` for Bool (coils use separate addressing)
-    /// - `1` for U16/I16
-    /// - `2` for U32/I32/F32
+    /// - `1` for U16/I16/Bits
+    /// - `2` for U32/I32/F32/Bcd32
     /// - `4` for U64/I64/F64
+    /// - `4` for Str of up to 8 bytes, `8` for longer ones
     #[inline]
     pub fn register_count(&self) -> usize {
         match self {
             ModbusValue::Bool(_) => 0, // Coils don't use registers
-            ModbusValue::U16(_) | ModbusValue::I16(_) => 1,
-            ModbusValue::U32(_) | ModbusValue::I32(_) | ModbusValue::F32(_) => 2,
+            ModbusValue::U16(_) | ModbusValue::I16(_) | ModbusValue::Bits(_) => 1,
+            ModbusValue::U32(_)
+            | ModbusValue::I32(_)
+            | ModbusValue::F32(_)
+            | ModbusValue::Bcd32(_) => 2,
             ModbusValue::U64(_) | ModbusValue::I64(_) | ModbusValue::F64(_) => 4,
+            ModbusValue::Str(s) if s.len() <= 8 => 4,
+            ModbusValue::Str(_) => 8,
         }
     }
 
-    /// Check if the value is zero or false.
+    /// Check if the value is zero, false, an empty string or all-clear bits.
     #[inline]
     pub fn is_zero(&self) -> bool {
         match self {
@@ -126,6 +186,9 @@ impl ModbusValue {
             ModbusValue::U64(v) => *v == 0,
             ModbusValue::I64(v) => *v == 0,
             ModbusValue::F64(v) => *v == 0.0,
+            ModbusValue::Bcd32(v) => *v == 0,
+            ModbusValue::Str(s) => s.is_empty(),
+            ModbusValue::Bits(bits) => !bits.contains(&true),
         }
     }
 
@@ -141,6 +204,205 @@ impl ModbusValue {
             ModbusValue::U64(_) => "u64",
             ModbusValue::I64(_) => "i64",
             ModbusValue::F64(_) => "f64",
+            ModbusValue::Bcd32(_) => "bcd32",
+            ModbusValue::Str(_) => "str",
+            ModbusValue::Bits(_) => "bits",
+        }
+    }
+
+    /// `false` for NaN and infinite floats, `true` for everything else.
+    pub fn is_finite(&self) -> bool {
+        match self {
+            ModbusValue::F32(v) => v.is_finite(),
+            ModbusValue::F64(v) => v.is_finite(),
+            _ => true,
+        }
+    }
+
+    /// Absolute value; unsigned, bool and string values are returned unchanged.
+    ///
+    /// Signed integers saturate (`I16(i16::MIN).abs()` is `I16(i16::MAX)`).
+    pub fn abs(&self) -> ModbusValue {
+        match *self {
+            ModbusValue::I16(v) => ModbusValue::I16(v.saturating_abs()),
+            ModbusValue::I32(v) => ModbusValue::I32(v.saturating_abs()),
+            ModbusValue::I64(v) => ModbusValue::I64(v.saturating_abs()),
+            ModbusValue::F32(v) => ModbusValue::F32(v.abs()),
+            ModbusValue::F64(v) => ModbusValue::F64(v.abs()),
+            _ => self.clone(),
+        }
+    }
+
+    /// The smaller of two values (compared as by `partial_cmp`), converted to
+    /// the wider of the two types when that is exact. A float never becomes
+    /// an integer: against a wider integer the result is `F64`. A NaN
+    /// operand is ignored.
+    pub fn min_value(&self, other: &ModbusValue) -> ModbusValue {
+        self.pick(other, Ordering::Less)
+    }
+
+    /// The larger of two values (compared as by `partial_cmp`), converted as
+    /// in [`min_value`](Self::min_value). A NaN operand is ignored.
+    pub fn max_value(&self, other: &ModbusValue) -> ModbusValue {
+        self.pick(other, Ordering::Greater)
+    }
+
+    /// Limit to `lo..=hi`, keeping this value's type.
+    ///
+    /// A value below `lo` (above `hi`) becomes `lo` (`hi`) converted to this
+    /// type; NaN is returned unchanged. Float bounds of an integer value
+    /// round toward the inside of the range, so the result never leaves it.
+    pub fn clamp_value(&self, lo: &ModbusValue, hi: &ModbusValue) -> ModbusValue {
+        let bound = |bound: &ModbusValue, round: fn(f64) -> f64| {
+            if bound.is_float() && !self.is_float() {
+                ModbusValue::F64(round(bound.as_f64())).convert_like(self)
+            } else {
+                bound.convert_like(self)
+            }
+        };
+        if self.partial_cmp(lo) == Some(Ordering::Less) {
+            bound(lo, f64::ceil)
+        } else if self.partial_cmp(hi) == Some(Ordering::Greater) {
+            bound(hi, f64::floor)
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Owned-argument form of [`clamp_value`](Self::clamp_value), for
+    /// saturating against thresholds held by value.
+    pub fn clamp(&self, min: ModbusValue, max: ModbusValue) -> ModbusValue {
+        self.clamp_value(&min, &max)
+    }
+
+    /// Numeric equality across variants, for comparing a reading against a
+    /// threshold of another type.
+    ///
+    /// Unlike `==`, which distinguishes variants (`U16(1) != I32(1)`) so
+    /// that it agrees with [`Hash`], this compares integers exactly and
+    /// anything involving a float by [`as_f64`](Self::as_f64). NaN equals
+    /// nothing, itself included. Strings equal only identical strings.
+    ///
+    /// ```rust
+    /// use voltage_modbus::ModbusValue;
+    ///
+    /// assert!(ModbusValue::U16(100).numeric_eq(&ModbusValue::F32(100.0)));
+    /// assert!(ModbusValue::U64(u64::MAX).numeric_eq(&ModbusValue::U64(u64::MAX)));
+    /// assert!(!ModbusValue::F64(f64::NAN).numeric_eq(&ModbusValue::F64(f64::NAN)));
+    /// ```
+    pub fn numeric_eq(&self, other: &ModbusValue) -> bool {
+        match (self, other) {
+            (ModbusValue::Str(a), ModbusValue::Str(b)) => a == b,
+            (ModbusValue::Str(_), _) | (_, ModbusValue::Str(_)) => false,
+            (a, b) if a.is_float() || b.is_float() => a.as_f64() == b.as_f64(),
+            (a, b) => a.as_i128() == b.as_i128(),
+        }
+    }
+
+    fn is_float(&self) -> bool {
+        matches!(self, ModbusValue::F32(_) | ModbusValue::F64(_))
+    }
+
+    /// Winner of `self` vs `other` under `wanted`, in the wider type if the
+    /// conversion is exact
+    fn pick(&self, other: &ModbusValue, wanted: Ordering) -> ModbusValue {
+        let winner = match self.partial_cmp(other) {
+            Some(ordering) if ordering == wanted => self,
+            Some(_) => other,
+            None if self.as_f64().is_nan() && !other.as_f64().is_nan() => return other.clone(),
+            None => return self.clone(),
+        };
+        let wider = if self.width_rank() >= other.width_rank() {
+            self
+        } else {
+            other
+        };
+        if (self.is_float() || other.is_float()) && !wider.is_float() {
+            return ModbusValue::F64(winner.as_f64());
+        }
+        let converted = winner.convert_like(wider);
+        if converted.numeric_eq(winner) {
+            converted
+        } else {
+            winner.clone()
+        }
+    }
+
+    /// Rank for choosing the wider type: register count, then
+    /// unsigned < signed < float. BCD ranks with the 32-bit integers.
+    fn width_rank(&self) -> u8 {
+        match self {
+            ModbusValue::Bool(_) => 0,
+            ModbusValue::U16(_) | ModbusValue::Bits(_) => 1,
+            ModbusValue::I16(_) => 2,
+            ModbusValue::U32(_) | ModbusValue::Bcd32(_) => 3,
+            ModbusValue::I32(_) => 4,
+            ModbusValue::F32(_) => 5,
+            ModbusValue::U64(_) => 6,
+            ModbusValue::I64(_) => 7,
+            ModbusValue::F64(_) => 8,
+            ModbusValue::Str(_) => 9,
+        }
+    }
+
+    /// This value converted to the variant of `like`, saturating integers.
+    pub(crate) fn convert_like(&self, like: &ModbusValue) -> ModbusValue {
+        let int = match *self {
+            ModbusValue::U64(v) => i128::from(v),
+            ModbusValue::F32(_) | ModbusValue::F64(_) => self.as_f64().round() as i128,
+            _ => i128::from(self.as_i64()),
+        };
+        fn sat<T: TryFrom<i128> + Copy>(v: i128, min: T, max: T) -> T {
+            T::try_from(v).unwrap_or(if v < 0 { min } else { max })
+        }
+        match like {
+            ModbusValue::Bool(_) => ModbusValue::Bool(!self.is_zero()),
+            ModbusValue::U16(_) => ModbusValue::U16(sat(int, u16::MIN, u16::MAX)),
+            ModbusValue::I16(_) => ModbusValue::I16(sat(int, i16::MIN, i16::MAX)),
+            ModbusValue::U32(_) => ModbusValue::U32(sat(int, u32::MIN, u32::MAX)),
+            ModbusValue::I32(_) => ModbusValue::I32(sat(int, i32::MIN, i32::MAX)),
+            ModbusValue::F32(_) => ModbusValue::F32(self.as_f64() as f32),
+            ModbusValue::U64(_) => ModbusValue::U64(sat(int, u64::MIN, u64::MAX)),
+            ModbusValue::I64(_) => ModbusValue::I64(sat(int, i64::MIN, i64::MAX)),
+            ModbusValue::F64(_) => ModbusValue::F64(self.as_f64()),
+            ModbusValue::Bcd32(_) => ModbusValue::Bcd32(sat(int, 0, BCD32_MAX)),
+            ModbusValue::Str(_) => ModbusValue::Str(self.to_string()),
+            ModbusValue::Bits(_) => ModbusValue::bits_from_u16(sat(int, u16::MIN, u16::MAX)),
+        }
+    }
+
+    /// Total order over all values, for sorting and ordered collections.
+    ///
+    /// Values compare numerically like `partial_cmp`, except that NaN is
+    /// equal to NaN and sorts after every other number. Strings sort after
+    /// all numbers, in byte order among themselves.
+    pub fn total_cmp(&self, other: &ModbusValue) -> Ordering {
+        match (self, other) {
+            (ModbusValue::Str(a), ModbusValue::Str(b)) => return a.cmp(b),
+            (ModbusValue::Str(_), _) => return Ordering::Greater,
+            (_, ModbusValue::Str(_)) => return Ordering::Less,
+            _ => {}
+        }
+        let is_nan = |v: &ModbusValue| v.is_float() && v.as_f64().is_nan();
+        match (is_nan(self), is_nan(other)) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.partial_cmp(other).unwrap_or(Ordering::Equal),
+        }
+    }
+
+    /// JSON number, boolean or string for event payloads (non-finite floats
+    /// become `null`).
+    #[cfg(any(feature = "kafka", feature = "mqtt"))]
+    pub(crate) fn to_json(&self) -> serde_json::Value {
+        match *self {
+            ModbusValue::Bool(v) => serde_json::Value::from(v),
+            ModbusValue::F32(v) => serde_json::Value::from(v),
+            ModbusValue::F64(v) => serde_json::Value::from(v),
+            ModbusValue::U64(v) => serde_json::Value::from(v),
+            ModbusValue::Str(ref s) => serde_json::Value::from(s.as_str()),
+            _ => serde_json::Value::from(self.as_i64()),
         }
     }
 }
@@ -157,6 +419,9 @@ impl fmt::Display for ModbusValue {
             ModbusValue::U64(v) => write!(f, "{}", v),
             ModbusValue::I64(v) => write!(f, "{}", v),
             ModbusValue::F64(v) => write!(f, "{}", v),
+            ModbusValue::Bcd32(v) => write!(f, "{}", v),
+            ModbusValue::Str(v) => f.write_str(v),
+            ModbusValue::Bits(bits) => write!(f, "{:016b}", pack_bits(bits)),
         }
     }
 }
@@ -167,6 +432,440 @@ impl Default for ModbusValue {
     }
 }
 
+impl PartialEq for ModbusValue {
+    fn eq(&self, other: &Self) -> bool {
+        use ModbusValue::*;
+        match (self, other) {
+            (Bool(a), Bool(b)) => a == b,
+            (U16(a), U16(b)) => a == b,
+            (I16(a), I16(b)) => a == b,
+            (U32(a), U32(b)) => a == b,
+            (I32(a), I32(b)) => a == b,
+            (F32(a), F32(b)) => a == b || (a.is_nan() && b.is_nan()),
+            (U64(a), U64(b)) => a == b,
+            (I64(a), I64(b)) => a == b,
+            (F64(a), F64(b)) => a == b || (a.is_nan() && b.is_nan()),
+            (Bcd32(a), Bcd32(b)) => a == b,
+            (Str(a), Str(b)) => a == b,
+            (Bits(a), Bits(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ModbusValue {}
+
+impl Hash for ModbusValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        match *self {
+            ModbusValue::Str(ref v) => v.hash(state),
+            ModbusValue::Bool(v) => v.hash(state),
+            ModbusValue::U16(v) => v.hash(state),
+            ModbusValue::I16(v) => v.hash(state),
+            ModbusValue::U32(v) => v.hash(state),
+            ModbusValue::I32(v) => v.hash(state),
+            ModbusValue::F32(v) => canonical_f32_bits(v).hash(state),
+            ModbusValue::U64(v) => v.hash(state),
+            ModbusValue::I64(v) => v.hash(state),
+            ModbusValue::F64(v) => canonical_f64_bits(v).hash(state),
+            ModbusValue::Bcd32(v) => v.hash(state),
+            ModbusValue::Bits(v) => v.hash(state),
+        }
+    }
+}
+
+/// Register value of packed flags, index 0 = LSB.
+pub(crate) fn pack_bits(bits: &[bool; 16]) -> u16 {
+    bits.iter()
+        .enumerate()
+        .fold(0, |acc, (i, &bit)| acc | (u16::from(bit) << i))
+}
+
+/// Flag `index` of a [`ModbusValue::Bits`].
+///
+/// # Panics
+///
+/// If the value is not `Bits` or `index` is 16 or more.
+impl Index<usize> for ModbusValue {
+    type Output = bool;
+
+    fn index(&self, index: usize) -> &bool {
+        match self {
+            ModbusValue::Bits(bits) => &bits[index],
+            other => panic!("cannot index a {} value, only bits", other.type_name()),
+        }
+    }
+}
+
+/// Mutable flag `index` of a [`ModbusValue::Bits`].
+///
+/// # Panics
+///
+/// If the value is not `Bits` or `index` is 16 or more.
+impl IndexMut<usize> for ModbusValue {
+    fn index_mut(&mut self, index: usize) -> &mut bool {
+        match self {
+            ModbusValue::Bits(bits) => &mut bits[index],
+            other => panic!("cannot index a {} value, only bits", other.type_name()),
+        }
+    }
+}
+
+/// Bit pattern with every NaN mapped to `f32::NAN` and `-0.0` to `0.0`.
+fn canonical_f32_bits(v: f32) -> u32 {
+    if v.is_nan() {
+        f32::NAN.to_bits()
+    } else {
+        (v + 0.0).to_bits()
+    }
+}
+
+/// Bit pattern with every NaN mapped to `f64::NAN` and `-0.0` to `0.0`.
+fn canonical_f64_bits(v: f64) -> u64 {
+    if v.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        (v + 0.0).to_bits()
+    }
+}
+
+/// Numeric comparison across variants, consistent with
+/// [`ModbusValue::numeric_eq`].
+///
+/// Integers (including `U64` and `I64` beyond 2^53) compare exactly; a
+/// comparison involving a float goes through [`ModbusValue::as_f64`].
+///
+/// This deviates from `==` in two ways. NaN is unordered (`None`) against
+/// everything, as in IEEE 754, although `==` treats NaN of the same variant
+/// as equal so that it agrees with [`Hash`]: `F32(NAN) == F32(NAN)` holds
+/// but their `partial_cmp` is `None`. And values of different variants with
+/// the same numeric value compare as `Some(Ordering::Equal)` although `==`
+/// distinguishes variants. Use [`ModbusValue::total_cmp`] where a total
+/// order is needed. Two strings compare in byte order; a string and a number
+/// are unordered.
+impl PartialOrd for ModbusValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (ModbusValue::Str(a), ModbusValue::Str(b)) => Some(a.cmp(b)),
+            (ModbusValue::Str(_), _) | (_, ModbusValue::Str(_)) => None,
+            (a, b) if a.is_float() || b.is_float() => a.as_f64().partial_cmp(&b.as_f64()),
+            (a, b) => Some(a.as_i128().cmp(&b.as_i128())),
+        }
+    }
+}
+
+// ============================================================================
+// Arithmetic
+// ============================================================================
+
+/// Numeric type an arithmetic result is produced in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArithType {
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+}
+
+impl ArithType {
+    /// Operand type after widening `Bool` to `U16` and `Bcd32` to `U32`;
+    /// `None` for strings.
+    fn of(value: &ModbusValue) -> Option<Self> {
+        Some(match value {
+            ModbusValue::Bool(_) | ModbusValue::U16(_) | ModbusValue::Bits(_) => Self::U16,
+            ModbusValue::I16(_) => Self::I16,
+            ModbusValue::U32(_) | ModbusValue::Bcd32(_) => Self::U32,
+            ModbusValue::I32(_) => Self::I32,
+            ModbusValue::U64(_) => Self::U64,
+            ModbusValue::I64(_) => Self::I64,
+            ModbusValue::F32(_) => Self::F32,
+            ModbusValue::F64(_) => Self::F64,
+            ModbusValue::Str(_) => return None,
+        })
+    }
+
+    /// `(signed, bits)` of an integer type.
+    fn int_shape(self) -> (bool, u32) {
+        match self {
+            Self::U16 => (false, 16),
+            Self::I16 => (true, 16),
+            Self::U32 => (false, 32),
+            Self::I32 => (true, 32),
+            Self::U64 => (false, 64),
+            _ => (true, 64),
+        }
+    }
+
+    fn from_shape(signed: bool, bits: u32) -> Self {
+        match (signed, bits) {
+            (false, 16) => Self::U16,
+            (true, 16) => Self::I16,
+            (false, 32) => Self::U32,
+            (true, 32) => Self::I32,
+            (false, _) => Self::U64,
+            (true, _) => Self::I64,
+        }
+    }
+
+    /// Result type of combining `a` and `b`: `F64` wins over `F32`, which
+    /// wins over any integer. Integers of the same signedness widen to the
+    /// wider one; mixed signedness gives a signed type wider than the
+    /// unsigned operand (capped at `I64`).
+    fn common(a: Self, b: Self) -> Self {
+        if a == Self::F64 || b == Self::F64 {
+            return Self::F64;
+        }
+        if a == Self::F32 || b == Self::F32 {
+            return Self::F32;
+        }
+        let ((a_signed, a_bits), (b_signed, b_bits)) = (a.int_shape(), b.int_shape());
+        if a_signed == b_signed {
+            return Self::from_shape(a_signed, a_bits.max(b_bits));
+        }
+        let (unsigned_bits, signed_bits) = if a_signed {
+            (b_bits, a_bits)
+        } else {
+            (a_bits, b_bits)
+        };
+        Self::from_shape(true, (unsigned_bits * 2).min(64).max(signed_bits))
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::U16 => "u16",
+            Self::I16 => "i16",
+            Self::U32 => "u32",
+            Self::I32 => "i32",
+            Self::U64 => "u64",
+            Self::I64 => "i64",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        }
+    }
+
+    /// `v` as this integer type, or `None` if it is out of range.
+    fn fit(self, v: i128) -> Option<ModbusValue> {
+        Some(match self {
+            Self::U16 => ModbusValue::U16(u16::try_from(v).ok()?),
+            Self::I16 => ModbusValue::I16(i16::try_from(v).ok()?),
+            Self::U32 => ModbusValue::U32(u32::try_from(v).ok()?),
+            Self::I32 => ModbusValue::I32(i32::try_from(v).ok()?),
+            Self::U64 => ModbusValue::U64(u64::try_from(v).ok()?),
+            Self::I64 => ModbusValue::I64(i64::try_from(v).ok()?),
+            Self::F32 => ModbusValue::F32(v as f32),
+            Self::F64 => ModbusValue::F64(v as f64),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl ArithOp {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "subtract",
+            Self::Mul => "multiply",
+            Self::Div => "divide",
+        }
+    }
+
+    fn apply_f64(self, a: f64, b: f64) -> f64 {
+        match self {
+            Self::Add => a + b,
+            Self::Sub => a - b,
+            Self::Mul => a * b,
+            Self::Div => a / b,
+        }
+    }
+
+    fn apply_i128(self, a: i128, b: i128) -> Option<i128> {
+        match self {
+            Self::Add => a.checked_add(b),
+            Self::Sub => a.checked_sub(b),
+            Self::Mul => a.checked_mul(b),
+            Self::Div => a.checked_div(b),
+        }
+    }
+}
+
+impl ModbusValue {
+    /// `self + rhs` in the widened result type.
+    ///
+    /// Operands widen before the operation: `Bool` counts as `U16` and
+    /// `Bcd32` as `U32`; any `F64` operand gives `F64`, otherwise any `F32`
+    /// gives `F32`. Integers of the same signedness give the wider type,
+    /// and mixed signedness gives a signed type wider than the unsigned
+    /// operand, so `U16 + I16` is `I32` and `U32 + I16` is `I64`.
+    ///
+    /// ```rust
+    /// use voltage_modbus::ModbusValue;
+    ///
+    /// let sum = ModbusValue::U16(40_000).try_add(&ModbusValue::I16(-1)).unwrap();
+    /// assert_eq!(sum, ModbusValue::I32(39_999));
+    /// assert!(ModbusValue::Bool(true).try_add(&ModbusValue::Bool(true)).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ModbusError::TypeMismatch`] for string operands or two `Bool`s;
+    /// [`ModbusError::InvalidData`] if an integer result overflows its type.
+    pub fn try_add(&self, rhs: &ModbusValue) -> ModbusResult<ModbusValue> {
+        self.arith(rhs, ArithOp::Add)
+    }
+
+    /// `self - rhs`; see [`try_add`](Self::try_add) for the result type.
+    ///
+    /// # Errors
+    ///
+    /// As [`try_add`](Self::try_add); unsigned results below zero overflow.
+    pub fn try_sub(&self, rhs: &ModbusValue) -> ModbusResult<ModbusValue> {
+        self.arith(rhs, ArithOp::Sub)
+    }
+
+    /// `self * rhs`; see [`try_add`](Self::try_add) for the result type.
+    ///
+    /// # Errors
+    ///
+    /// As [`try_add`](Self::try_add).
+    pub fn try_mul(&self, rhs: &ModbusValue) -> ModbusResult<ModbusValue> {
+        self.arith(rhs, ArithOp::Mul)
+    }
+
+    /// `self / rhs`; see [`try_add`](Self::try_add) for the result type.
+    /// Integer division truncates toward zero.
+    ///
+    /// # Errors
+    ///
+    /// As [`try_add`](Self::try_add), and [`ModbusError::InvalidData`] for
+    /// integer division by zero. Float division by zero gives infinity or NaN.
+    pub fn try_div(&self, rhs: &ModbusValue) -> ModbusResult<ModbusValue> {
+        self.arith(rhs, ArithOp::Div)
+    }
+
+    fn arith(&self, rhs: &ModbusValue, op: ArithOp) -> ModbusResult<ModbusValue> {
+        let mismatch = || ModbusError::type_mismatch(op.name(), self.type_name(), rhs.type_name());
+        if matches!((self, rhs), (ModbusValue::Bool(_), ModbusValue::Bool(_))) {
+            return Err(mismatch());
+        }
+        let (a, b) = match (ArithType::of(self), ArithType::of(rhs)) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Err(mismatch()),
+        };
+        let ty = ArithType::common(a, b);
+        match ty {
+            ArithType::F64 => Ok(ModbusValue::F64(op.apply_f64(self.as_f64(), rhs.as_f64()))),
+            ArithType::F32 => Ok(ModbusValue::F32(
+                op.apply_f64(self.as_f64(), rhs.as_f64()) as f32
+            )),
+            _ => {
+                let (x, y) = (self.as_i128(), rhs.as_i128());
+                if matches!(op, ArithOp::Div) && y == 0 {
+                    return Err(ModbusError::invalid_data("integer division by zero"));
+                }
+                op.apply_i128(x, y).and_then(|v| ty.fit(v)).ok_or_else(|| {
+                    ModbusError::invalid_data(format!(
+                        "cannot {} {} and {}: result overflows {}",
+                        op.name(),
+                        self,
+                        rhs,
+                        ty.name()
+                    ))
+                })
+            }
+        }
+    }
+
+    /// Exact integer value of an integer variant.
+    fn as_i128(&self) -> i128 {
+        match self {
+            ModbusValue::U64(v) => i128::from(*v),
+            other => i128::from(other.as_i64()),
+        }
+    }
+}
+
+macro_rules! impl_arith_op {
+    ($trait:ident, $method:ident, $try_method:ident, $symbol:literal) => {
+        #[doc = concat!("`a ", $symbol, " b` via [`ModbusValue::", stringify!($try_method), "`].")]
+        ///
+        /// # Panics
+        ///
+        /// Panics where the `try_` method returns an error: mismatched
+        /// operand types, integer overflow or integer division by zero.
+        impl core::ops::$trait for ModbusValue {
+            type Output = ModbusValue;
+
+            fn $method(self, rhs: ModbusValue) -> ModbusValue {
+                self.$try_method(&rhs).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+    };
+}
+
+impl_arith_op!(Add, add, try_add, "+");
+impl_arith_op!(Sub, sub, try_sub, "-");
+impl_arith_op!(Mul, mul, try_mul, "*");
+impl_arith_op!(Div, div, try_div, "/");
+
+// ============================================================================
+// Scaling
+// ============================================================================
+
+/// A raw register value with a linear conversion to engineering units:
+/// `engineering = raw * gain + offset`.
+///
+/// ```rust
+/// use voltage_modbus::{ModbusValue, ScaledValue};
+///
+/// // 0.1 K per count, reported in °C
+/// let temp = ScaledValue::new(ModbusValue::U16(2982), 0.1, -273.15);
+/// assert!((temp.to_engineering() - 25.05).abs() < 1e-9);
+/// assert_eq!(temp.from_engineering(30.05), ModbusValue::U16(3032));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaledValue {
+    /// Value as read from or written to the device
+    pub raw: ModbusValue,
+    /// Engineering units per raw count
+    pub gain: f64,
+    /// Engineering value at raw zero
+    pub offset: f64,
+}
+
+impl ScaledValue {
+    /// Wrap `raw` with a gain and offset.
+    pub fn new(raw: ModbusValue, gain: f64, offset: f64) -> Self {
+        Self { raw, gain, offset }
+    }
+
+    /// The raw value in engineering units (NaN for strings).
+    pub fn to_engineering(&self) -> f64 {
+        self.raw.as_f64() * self.gain + self.offset
+    }
+
+    /// Raw value for the engineering value `eng`, in the same variant as
+    /// [`raw`](Self::raw).
+    ///
+    /// Integer variants are rounded and saturate at their range, as in
+    /// [`ModbusValue::clamp_value`]. A zero gain yields an infinite raw
+    /// value, which saturates likewise.
+    pub fn from_engineering(&self, eng: f64) -> ModbusValue {
+        ModbusValue::F64((eng - self.offset) / self.gain).convert_like(&self.raw)
+    }
+}
+
 // ============================================================================
 // From implementations for ergonomic construction
 // ============================================================================
@@ -225,6 +924,233 @@ impl From<f64> for ModbusValue {
     }
 }
 
+impl From<String> for ModbusValue {
+    fn from(v: String) -> Self {
+        ModbusValue::Str(v)
+    }
+}
+
+impl From<&str> for ModbusValue {
+    fn from(v: &str) -> Self {
+        ModbusValue::Str(v.to_string())
+    }
+}
+
+// ============================================================================
+// Compact binary format
+// ============================================================================
+
+/// Size of one value in the compact binary format.
+pub const COMPACT_VALUE_LEN: usize = 9;
+
+impl ModbusValue {
+    /// Encode as 9 bytes: a type discriminant followed by the value in
+    /// big-endian order, zero-padded to 8 bytes.
+    ///
+    /// | Byte 0 | Type |
+    /// |--------|------|
+    /// | 0 | Bool |
+    /// | 1 | U16 |
+    /// | 2 | I16 |
+    /// | 3 | U32 |
+    /// | 4 | I32 |
+    /// | 5 | F32 |
+    /// | 6 | U64 |
+    /// | 7 | I64 |
+    /// | 8 | F64 |
+    /// | 9 | Str |
+    /// | 10 | Bcd32 |
+    /// | 11 | Bits (packed as a `u16`) |
+    ///
+    /// Floats are stored by bit pattern, so NaN payloads survive a round
+    /// trip through [`from_bytes`](Self::from_bytes). Strings keep at most
+    /// their first 8 bytes (cut at a character boundary), zero-padded, so
+    /// only `str8` values round-trip intact; [`try_to_bytes`](Self::try_to_bytes)
+    /// rejects the others instead.
+    ///
+    /// ```rust
+    /// use voltage_modbus::ModbusValue;
+    ///
+    /// let bytes = ModbusValue::U32(0x0102_0304).to_bytes();
+    /// assert_eq!(bytes, [3, 1, 2, 3, 4, 0, 0, 0, 0]);
+    /// assert_eq!(ModbusValue::from_bytes(&bytes).unwrap(), ModbusValue::U32(0x0102_0304));
+    /// ```
+    pub fn to_bytes(&self) -> [u8; COMPACT_VALUE_LEN] {
+        let (discriminant, data): (u8, &[u8]) = match self {
+            ModbusValue::Bool(v) => (0, &[*v as u8]),
+            ModbusValue::U16(v) => (1, &v.to_be_bytes()),
+            ModbusValue::I16(v) => (2, &v.to_be_bytes()),
+            ModbusValue::U32(v) => (3, &v.to_be_bytes()),
+            ModbusValue::I32(v) => (4, &v.to_be_bytes()),
+            ModbusValue::F32(v) => (5, &v.to_bits().to_be_bytes()),
+            ModbusValue::U64(v) => (6, &v.to_be_bytes()),
+            ModbusValue::I64(v) => (7, &v.to_be_bytes()),
+            ModbusValue::F64(v) => (8, &v.to_bits().to_be_bytes()),
+            ModbusValue::Str(v) => {
+                let mut end = v.len().min(8);
+                while !v.is_char_boundary(end) {
+                    end -= 1;
+                }
+                (9, &v.as_bytes()[..end])
+            }
+            ModbusValue::Bcd32(v) => (10, &v.to_be_bytes()),
+            ModbusValue::Bits(v) => (11, &pack_bits(v).to_be_bytes()),
+        };
+        let mut bytes = [0u8; COMPACT_VALUE_LEN];
+        bytes[0] = discriminant;
+        bytes[1..=data.len()].copy_from_slice(data);
+        bytes
+    }
+
+    /// [`to_bytes`](Self::to_bytes), failing for a value that would not
+    /// round-trip.
+    ///
+    /// Returns `InvalidData` for a string longer than 8 bytes or ending in a
+    /// NUL byte, which the zero padding would cut off.
+    pub fn try_to_bytes(&self) -> ModbusResult<[u8; COMPACT_VALUE_LEN]> {
+        if let ModbusValue::Str(v) = self {
+            if v.len() > COMPACT_VALUE_LEN - 1 || v.ends_with('\0') {
+                return Err(ModbusError::invalid_data(format!(
+                    "string of {} bytes does not fit a compact value",
+                    v.len()
+                )));
+            }
+        }
+        Ok(self.to_bytes())
+    }
+
+    /// Decode a value written by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Returns `InvalidData` for an unknown discriminant, a bool byte
+    /// other than 0 or 1, or a string that is not UTF-8. Padding bytes are
+    /// ignored.
+    pub fn from_bytes(bytes: &[u8; COMPACT_VALUE_LEN]) -> ModbusResult<ModbusValue> {
+        let data = &bytes[1..];
+        let be2 = [data[0], data[1]];
+        let be4 = [data[0], data[1], data[2], data[3]];
+        let be8 = [
+            data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7],
+        ];
+        Ok(match bytes[0] {
+            0 => match data[0] {
+                0 => ModbusValue::Bool(false),
+                1 => ModbusValue::Bool(true),
+                other => {
+                    return Err(ModbusError::invalid_data(format!(
+                        "invalid bool byte 0x{:02X} in compact value",
+                        other
+                    )))
+                }
+            },
+            1 => ModbusValue::U16(u16::from_be_bytes(be2)),
+            2 => ModbusValue::I16(i16::from_be_bytes(be2)),
+            3 => ModbusValue::U32(u32::from_be_bytes(be4)),
+            4 => ModbusValue::I32(i32::from_be_bytes(be4)),
+            5 => ModbusValue::F32(f32::from_bits(u32::from_be_bytes(be4))),
+            6 => ModbusValue::U64(u64::from_be_bytes(be8)),
+            7 => ModbusValue::I64(i64::from_be_bytes(be8)),
+            8 => ModbusValue::F64(f64::from_bits(u64::from_be_bytes(be8))),
+            9 => {
+                let len = data.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+                let text = core::str::from_utf8(&data[..len]).map_err(|_| {
+                    ModbusError::invalid_data("compact string value is not valid UTF-8")
+                })?;
+                ModbusValue::Str(text.to_string())
+            }
+            10 => ModbusValue::Bcd32(u32::from_be_bytes(be4)),
+            11 => ModbusValue::bits_from_u16(u16::from_be_bytes(be2)),
+            other => {
+                return Err(ModbusError::invalid_data(format!(
+                    "unknown value type discriminant {}",
+                    other
+                )))
+            }
+        })
+    }
+}
+
+/// A batch of values with a compact binary encoding: a big-endian `u16`
+/// count followed by [`ModbusValue::to_bytes`] for each value.
+///
+/// ```rust
+/// use voltage_modbus::{ModbusValue, ModbusValueVec};
+///
+/// let batch = ModbusValueVec(vec![ModbusValue::Bool(true), ModbusValue::F32(1.5)]);
+/// let bytes = batch.to_compact_bytes()?;
+/// assert_eq!(bytes.len(), 2 + 2 * 9);
+/// assert_eq!(ModbusValueVec::from_compact_bytes(&bytes)?, batch.0);
+/// # Ok::<(), voltage_modbus::ModbusError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModbusValueVec(pub Vec<ModbusValue>);
+
+impl ModbusValueVec {
+    /// Encode the count prefix and every value.
+    ///
+    /// Returns `InvalidData` if the batch holds more than `u16::MAX` values
+    /// or a value that does not round-trip, see
+    /// [`ModbusValue::try_to_bytes`].
+    pub fn to_compact_bytes(&self) -> ModbusResult<Vec<u8>> {
+        let count = u16::try_from(self.0.len()).map_err(|_| {
+            ModbusError::invalid_data(format!(
+                "compact batch of {} values exceeds {}",
+                self.0.len(),
+                u16::MAX
+            ))
+        })?;
+        let mut bytes = Vec::with_capacity(2 + self.0.len() * COMPACT_VALUE_LEN);
+        bytes.extend_from_slice(&count.to_be_bytes());
+        for value in &self.0 {
+            bytes.extend_from_slice(&value.try_to_bytes()?);
+        }
+        Ok(bytes)
+    }
+
+    /// Decode a batch written by [`to_compact_bytes`](Self::to_compact_bytes).
+    ///
+    /// The length must match the count prefix exactly.
+    pub fn from_compact_bytes(bytes: &[u8]) -> ModbusResult<Vec<ModbusValue>> {
+        let (prefix, body) = match bytes {
+            [hi, lo, body @ ..] => (u16::from_be_bytes([*hi, *lo]), body),
+            _ => {
+                return Err(ModbusError::invalid_data(
+                    "compact batch is missing its count",
+                ))
+            }
+        };
+        let count = usize::from(prefix);
+        if body.len() != count * COMPACT_VALUE_LEN {
+            return Err(ModbusError::invalid_data(format!(
+                "compact batch of {} values needs {} bytes, got {}",
+                count,
+                count * COMPACT_VALUE_LEN,
+                body.len()
+            )));
+        }
+        body.chunks_exact(COMPACT_VALUE_LEN)
+            .map(|chunk| {
+                let mut value = [0u8; COMPACT_VALUE_LEN];
+                value.copy_from_slice(chunk);
+                ModbusValue::from_bytes(&value)
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<ModbusValue>> for ModbusValueVec {
+    fn from(values: Vec<ModbusValue>) -> Self {
+        Self(values)
+    }
+}
+
+impl core::ops::Deref for ModbusValueVec {
+    type Target = Vec<ModbusValue>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -233,6 +1159,239 @@ impl From<f64> for ModbusValue {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_partial_ord() {
+        assert!(ModbusValue::F32(3.15) < ModbusValue::F32(3.16));
+        assert!(ModbusValue::U16(10) > ModbusValue::I16(-10));
+        assert!(ModbusValue::Bool(true) >= ModbusValue::F64(1.0));
+        assert!(ModbusValue::I64(-1) < ModbusValue::U32(0));
+
+        let nan = ModbusValue::F32(f32::NAN);
+        for other in [
+            ModbusValue::Bool(false),
+            ModbusValue::U16(0),
+            ModbusValue::F32(f32::NAN),
+            ModbusValue::F64(f64::INFINITY),
+            ModbusValue::I64(i64::MIN),
+        ] {
+            assert_eq!(nan.partial_cmp(&other), None);
+            assert_eq!(other.partial_cmp(&nan), None);
+        }
+        // Same-variant NaN is `==` (for hashing) but still unordered
+        assert_eq!(nan, ModbusValue::F32(f32::NAN));
+    }
+
+    #[test]
+    fn test_partial_ord_is_exact_for_large_integers() {
+        let big = 1u64 << 53;
+        let (a, b) = (ModbusValue::U64(big + 1), ModbusValue::U64(big));
+        assert_ne!(a, b);
+        assert_eq!(a.partial_cmp(&b), Some(Ordering::Greater));
+        assert_eq!(a.total_cmp(&b), Ordering::Greater);
+
+        let (c, d) = (ModbusValue::I64(i64::MIN + 1), ModbusValue::I64(i64::MIN));
+        assert!(c > d);
+        assert!(ModbusValue::U64(u64::MAX) > ModbusValue::I64(i64::MAX));
+        assert!(ModbusValue::I64(-(big as i64) - 1) < ModbusValue::I64(-(big as i64)));
+        assert_eq!(
+            ModbusValue::U64(u64::MAX - 1).max_value(&ModbusValue::U64(u64::MAX)),
+            ModbusValue::U64(u64::MAX)
+        );
+
+        // Agrees with numeric_eq across integer variants
+        assert_eq!(
+            ModbusValue::U64(big + 1).partial_cmp(&ModbusValue::I64(big as i64 + 1)),
+            Some(Ordering::Equal)
+        );
+        assert!(!ModbusValue::U64(big + 1).numeric_eq(&ModbusValue::U64(big)));
+
+        let mut values = [
+            ModbusValue::U64(big + 2),
+            ModbusValue::U64(big),
+            ModbusValue::U64(big + 1),
+        ];
+        values.sort_by(ModbusValue::total_cmp);
+        assert_eq!(values, [big, big + 1, big + 2].map(ModbusValue::U64));
+    }
+
+    #[test]
+    fn test_total_cmp_sorts_nan_last() {
+        let nan = ModbusValue::F64(f64::NAN);
+        assert_eq!(
+            nan.total_cmp(&ModbusValue::F64(f64::INFINITY)),
+            Ordering::Greater
+        );
+        assert_eq!(ModbusValue::U16(1).total_cmp(&nan), Ordering::Less);
+        assert_eq!(nan.total_cmp(&ModbusValue::F32(-f32::NAN)), Ordering::Equal);
+
+        let mut values = [
+            ModbusValue::F32(f32::NAN),
+            ModbusValue::I16(-5),
+            ModbusValue::F64(2.5),
+            ModbusValue::F64(f64::NAN),
+            ModbusValue::U16(1),
+        ];
+        values.sort_by(ModbusValue::total_cmp);
+        assert_eq!(values[0], ModbusValue::I16(-5));
+        assert_eq!(values[1], ModbusValue::U16(1));
+        assert_eq!(values[2], ModbusValue::F64(2.5));
+        assert!(values[3..].iter().all(|v| v.as_f64().is_nan()));
+    }
+
+    #[test]
+    fn test_hash_map_keys() {
+        use std::collections::HashMap;
+
+        let keys = [
+            ModbusValue::Bool(true),
+            ModbusValue::U16(1),
+            ModbusValue::I16(1),
+            ModbusValue::U32(1),
+            ModbusValue::I32(1),
+            ModbusValue::F32(1.0),
+            ModbusValue::U64(1),
+            ModbusValue::I64(1),
+            ModbusValue::F64(1.0),
+        ];
+        let mut map: HashMap<ModbusValue, String> = HashMap::new();
+        for key in &keys {
+            map.insert(key.clone(), key.type_name().to_string());
+        }
+        // Same numeric value, different variants: nine distinct keys.
+        assert_eq!(map.len(), 9);
+        for key in &keys {
+            assert_eq!(map[key], key.type_name());
+        }
+
+        map.insert(ModbusValue::F32(f32::NAN), "first".to_string());
+        map.insert(ModbusValue::F32(-f32::NAN), "second".to_string());
+        assert_eq!(map.len(), 10);
+        assert_eq!(map[&ModbusValue::F32(f32::NAN)], "second");
+        assert_ne!(ModbusValue::F32(f32::NAN), ModbusValue::F64(f64::NAN));
+
+        map.insert(ModbusValue::F64(-0.0), "zero".to_string());
+        assert_eq!(map[&ModbusValue::F64(0.0)], "zero");
+    }
+
+    #[test]
+    fn test_float_equality() {
+        assert_ne!(ModbusValue::F32(1.0), ModbusValue::F32(2.0));
+        assert_eq!(ModbusValue::F64(f64::NAN), ModbusValue::F64(f64::NAN));
+        assert_ne!(ModbusValue::F64(f64::NAN), ModbusValue::F64(0.0));
+        assert_ne!(ModbusValue::U16(1), ModbusValue::I16(1));
+    }
+
+    #[test]
+    fn test_abs_and_is_finite() {
+        assert_eq!(ModbusValue::I16(-300).abs(), ModbusValue::I16(300));
+        assert_eq!(ModbusValue::I16(i16::MIN).abs(), ModbusValue::I16(i16::MAX));
+        assert_eq!(ModbusValue::F64(-2.5).abs(), ModbusValue::F64(2.5));
+        assert_eq!(ModbusValue::U16(7).abs(), ModbusValue::U16(7));
+        assert_eq!(ModbusValue::Bool(true).abs(), ModbusValue::Bool(true));
+
+        assert!(ModbusValue::I64(i64::MIN).is_finite());
+        assert!(ModbusValue::F32(1.0).is_finite());
+        assert!(!ModbusValue::F32(f32::NAN).is_finite());
+        assert!(!ModbusValue::F64(f64::NEG_INFINITY).is_finite());
+    }
+
+    #[test]
+    fn test_min_max_across_types() {
+        let small = ModbusValue::U16(5);
+        let big = ModbusValue::I32(70_000);
+        assert_eq!(small.min_value(&big), ModbusValue::I32(5));
+        assert_eq!(small.max_value(&big), ModbusValue::I32(70_000));
+        assert_eq!(
+            ModbusValue::I16(-3).min_value(&ModbusValue::F32(2.5)),
+            ModbusValue::F32(-3.0)
+        );
+        assert_eq!(
+            ModbusValue::F32(2.5).max_value(&ModbusValue::U64(2)),
+            ModbusValue::F64(2.5)
+        );
+        assert_eq!(
+            ModbusValue::U64(u64::MAX).max_value(&ModbusValue::I64(-1)),
+            ModbusValue::U64(u64::MAX)
+        );
+
+        let nan = ModbusValue::F64(f64::NAN);
+        assert_eq!(nan.min_value(&ModbusValue::U16(9)), ModbusValue::U16(9));
+        assert_eq!(ModbusValue::U16(9).max_value(&nan), ModbusValue::U16(9));
+    }
+
+    #[test]
+    fn test_scaled_value_roundtrip() {
+        let scaled = ScaledValue::new(ModbusValue::I16(-400), 0.5, 20.0);
+        assert_eq!(scaled.to_engineering(), -180.0);
+        assert_eq!(scaled.from_engineering(-180.2), ModbusValue::I16(-400));
+        assert_eq!(scaled.from_engineering(1e9), ModbusValue::I16(i16::MAX));
+
+        let float = ScaledValue::new(ModbusValue::F32(2.0), 10.0, 0.0);
+        assert_eq!(float.from_engineering(15.0), ModbusValue::F32(1.5));
+        assert!(ScaledValue::new(ModbusValue::from("x"), 1.0, 0.0)
+            .to_engineering()
+            .is_nan());
+    }
+
+    #[test]
+    fn test_clamp_value() {
+        let lo = ModbusValue::I16(-10);
+        let hi = ModbusValue::F64(100.0);
+        assert_eq!(
+            ModbusValue::I32(-50).clamp_value(&lo, &hi),
+            ModbusValue::I32(-10)
+        );
+        assert_eq!(
+            ModbusValue::I32(500).clamp_value(&lo, &hi),
+            ModbusValue::I32(100)
+        );
+        assert_eq!(
+            ModbusValue::I32(42).clamp_value(&lo, &hi),
+            ModbusValue::I32(42)
+        );
+        assert_eq!(
+            ModbusValue::U16(0).clamp_value(&lo, &hi),
+            ModbusValue::U16(0)
+        );
+        assert_eq!(
+            ModbusValue::U16(0).clamp_value(&ModbusValue::I16(5), &hi),
+            ModbusValue::U16(5)
+        );
+        let (lo, hi) = (ModbusValue::F64(-9.5), ModbusValue::F64(99.6));
+        assert_eq!(
+            ModbusValue::I32(500).clamp_value(&lo, &hi),
+            ModbusValue::I32(99)
+        );
+        assert_eq!(
+            ModbusValue::I32(-50).clamp_value(&lo, &hi),
+            ModbusValue::I32(-9)
+        );
+        assert_eq!(
+            ModbusValue::F32(500.0).clamp_value(&lo, &hi),
+            ModbusValue::F32(99.6)
+        );
+        assert!(ModbusValue::F32(f32::NAN)
+            .clamp_value(&lo, &hi)
+            .as_f64()
+            .is_nan());
+    }
+
+    #[test]
+    fn test_numeric_eq_and_clamp() {
+        use ModbusValue::*;
+        assert!(U16(1).numeric_eq(&I32(1)));
+        assert_ne!(U16(1), I32(1));
+        assert!(Bool(true).numeric_eq(&F64(1.0)));
+        assert!(Bcd32(42).numeric_eq(&I16(42)));
+        assert!(!U64(1 << 53).numeric_eq(&I64((1 << 53) + 1)));
+        assert!(!F32(f32::NAN).numeric_eq(&F32(f32::NAN)));
+        assert!(!U16(0).numeric_eq(&ModbusValue::from("0")));
+        assert!(ModbusValue::from("a").numeric_eq(&ModbusValue::from("a")));
+
+        assert_eq!(I32(500).clamp(I16(-10), F64(100.0)), I32(100));
+        assert_eq!(F32(-1.5).clamp(U16(0), U16(10)), F32(0.0));
+    }
+
     #[test]
     fn test_as_f64() {
         assert_eq!(ModbusValue::Bool(true).as_f64(), 1.0);
@@ -294,6 +1453,7 @@ mod tests {
         assert_eq!(format!("{}", ModbusValue::Bool(true)), "true");
         assert_eq!(format!("{}", ModbusValue::U16(1234)), "1234");
         assert_eq!(format!("{}", ModbusValue::I16(-1234)), "-1234");
+        assert_eq!(format!("{}", ModbusValue::from("FT-101")), "FT-101");
     }
 
     #[test]
@@ -301,5 +1461,138 @@ mod tests {
         assert_eq!(ModbusValue::Bool(true).type_name(), "bool");
         assert_eq!(ModbusValue::U16(0).type_name(), "u16");
         assert_eq!(ModbusValue::F32(0.0).type_name(), "f32");
+        assert_eq!(ModbusValue::from("A").type_name(), "str");
+    }
+
+    #[test]
+    fn test_compact_bytes_roundtrip() {
+        let values = [
+            ModbusValue::Bool(false),
+            ModbusValue::Bool(true),
+            ModbusValue::U16(u16::MAX),
+            ModbusValue::I16(i16::MIN),
+            ModbusValue::U32(u32::MAX),
+            ModbusValue::I32(i32::MIN),
+            ModbusValue::F32(f32::NAN),
+            ModbusValue::F32(-0.0),
+            ModbusValue::U64(u64::MAX),
+            ModbusValue::I64(i64::MIN),
+            ModbusValue::F64(f64::NAN),
+            ModbusValue::F64(f64::NEG_INFINITY),
+            ModbusValue::Str("FT-101".to_string()),
+            ModbusValue::Bcd32(99_999_999),
+        ];
+        for value in &values {
+            let bytes = value.to_bytes();
+            let decoded = ModbusValue::from_bytes(&bytes).unwrap();
+            // Bit-exact, so -0.0 and NaN payloads are preserved
+            assert_eq!(decoded.to_bytes(), bytes, "{:?}", value);
+            assert_eq!(&decoded, value);
+        }
+        let discriminants: Vec<u8> = values.iter().map(|v| v.to_bytes()[0]).collect();
+        assert_eq!(discriminants, [0, 0, 1, 2, 3, 4, 5, 5, 6, 7, 8, 8, 9, 10]);
+
+        let batch = ModbusValueVec::from(values.to_vec());
+        let bytes = batch.to_compact_bytes().unwrap();
+        assert_eq!(&bytes[..2], &[0, 14]);
+        assert_eq!(ModbusValueVec::from_compact_bytes(&bytes).unwrap(), *batch);
+        assert!(ModbusValueVec::from_compact_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(ModbusValueVec::from_compact_bytes(&[0]).is_err());
+        assert!(ModbusValueVec::from_compact_bytes(&[0, 0])
+            .unwrap()
+            .is_empty());
+
+        for lossy in [
+            vec![ModbusValue::Str("PUMP-STATION-07".to_string())],
+            vec![ModbusValue::Str("A\0".to_string())],
+            vec![ModbusValue::Bool(false); usize::from(u16::MAX) + 1],
+        ] {
+            assert!(matches!(
+                ModbusValueVec(lossy).to_compact_bytes(),
+                Err(ModbusError::InvalidData { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_compact_bytes_rejects_unknown_type() {
+        let mut bytes = ModbusValue::U16(7).to_bytes();
+        bytes[0] = 12;
+        assert!(matches!(
+            ModbusValue::from_bytes(&bytes),
+            Err(ModbusError::InvalidData { .. })
+        ));
+        let bad_bool = [0, 2, 0, 0, 0, 0, 0, 0, 0];
+        assert!(matches!(
+            ModbusValue::from_bytes(&bad_bool),
+            Err(ModbusError::InvalidData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_arithmetic_widens_operands() {
+        use ModbusValue::*;
+        assert_eq!(U16(1) + U16(2), U16(3));
+        assert_eq!(Bool(true) + U16(2), U16(3));
+        assert_eq!(U16(1) - I16(2), I32(-1));
+        assert_eq!(U32(7) * I16(-2), I64(-14));
+        assert_eq!(Bcd32(10) / U16(4), U32(2));
+        assert_eq!(U64(5) - I64(7), I64(-2));
+        assert!(U64(u64::MAX).try_sub(&I64(1)).is_err(), "does not fit i64");
+        assert_eq!(I16(3) * F32(0.5), F32(1.5));
+        assert_eq!(F32(1.0) + F64(0.25), F64(1.25));
+        assert_eq!(U16(1) / F64(0.0), F64(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_arithmetic_errors() {
+        use ModbusValue::*;
+        assert!(matches!(
+            Bool(true).try_add(&Bool(false)),
+            Err(ModbusError::TypeMismatch {
+                operation: "add",
+                left: "bool",
+                right: "bool"
+            })
+        ));
+        assert!(matches!(
+            U16(1).try_mul(&ModbusValue::from("x")),
+            Err(ModbusError::TypeMismatch { right: "str", .. })
+        ));
+        assert!(matches!(
+            U16(u16::MAX).try_add(&U16(1)),
+            Err(ModbusError::InvalidData { .. })
+        ));
+        assert!(U16(1).try_sub(&U16(2)).is_err());
+        assert!(I32(1).try_div(&U16(0)).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Type mismatch")]
+    fn test_arithmetic_operator_panics_on_mismatch() {
+        let _ = ModbusValue::from("a") + ModbusValue::U16(1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_tags_values_with_type_name() {
+        let values = [
+            ModbusValue::Bool(true),
+            ModbusValue::I16(-3),
+            ModbusValue::F32(1.5),
+            ModbusValue::U64(u64::MAX),
+            ModbusValue::Bcd32(1234),
+            ModbusValue::from("pump"),
+        ];
+        for value in values {
+            let json = serde_json::to_value(&value).unwrap();
+            assert_eq!(json["type"], value.type_name());
+            let back: ModbusValue = serde_json::from_value(json).unwrap();
+            assert_eq!(back, value);
+        }
+        assert_eq!(
+            serde_json::to_string(&ModbusValue::U16(42)).unwrap(),
+            r#"{"type":"u16","value":42}"#
+        );
     }
 }
@@ -0,0 +1,140 @@
+//! Concurrent Multi-Slave Read Tests
+//!
+//! Five mock Modbus TCP slaves answer FC03 after different delays;
+//! `read_slaves_concurrent` has to read all of them while never holding more
+//! than `max_concurrent` connections open.
+
+#![cfg(feature = "std")]
+
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use voltage_modbus::client::utils::{read_slaves_concurrent, read_tasks_concurrent, ReadTask};
+use voltage_modbus::{ModbusClient, ModbusTcpClient};
+
+/// Minimal Modbus TCP slave: answers every FC03 request with `value` in each
+/// register after `delay`.
+struct MockModbusTcpServer {
+    address: SocketAddr,
+}
+
+impl MockModbusTcpServer {
+    async fn start(value: u16, delay: Duration) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                tokio::spawn(async move {
+                    let mut request = [0u8; 12];
+                    while socket.read_exact(&mut request).await.is_ok() {
+                        tokio::time::sleep(delay).await;
+                        let quantity = u16::from_be_bytes([request[10], request[11]]);
+                        let byte_count = (quantity * 2) as u8;
+                        let mut response = request[..2].to_vec();
+                        response.extend_from_slice(&[0, 0]);
+                        response.extend_from_slice(&(3 + u16::from(byte_count)).to_be_bytes());
+                        response.extend_from_slice(&[request[6], 0x03, byte_count]);
+                        for _ in 0..quantity {
+                            response.extend_from_slice(&value.to_be_bytes());
+                        }
+                        if socket.write_all(&response).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+        Self { address }
+    }
+}
+
+/// Tracks how many connections are open at once
+#[derive(Default)]
+struct Gauge {
+    active: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl Gauge {
+    fn enter(&self) {
+        let now = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(now, Ordering::SeqCst);
+    }
+
+    fn leave(&self) {
+        self.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_reads_respect_limit_and_keep_order() {
+    let delays = [120, 20, 80, 10, 60];
+    let mut servers = Vec::new();
+    for (i, delay) in delays.iter().enumerate() {
+        servers
+            .push(MockModbusTcpServer::start(i as u16 * 100, Duration::from_millis(*delay)).await);
+    }
+
+    let gauge = Arc::new(Gauge::default());
+    let tasks = servers
+        .iter()
+        .enumerate()
+        .map(|(i, server)| {
+            let slave_id = i as u8 + 1;
+            let address = server.address;
+            let gauge = Arc::clone(&gauge);
+            (slave_id, move || async move {
+                gauge.enter();
+                let result = async {
+                    let mut client = ModbusTcpClient::new(address, Duration::from_secs(1)).await?;
+                    client.read_03(slave_id, 0, 2).await
+                }
+                .await;
+                gauge.leave();
+                result
+            })
+        })
+        .collect();
+
+    let results = read_slaves_concurrent(tasks, 2).await;
+
+    let slaves: Vec<u8> = results.iter().map(|(slave_id, _)| *slave_id).collect();
+    assert_eq!(slaves, [1, 2, 3, 4, 5]);
+    for (i, (_, result)) in results.into_iter().enumerate() {
+        let value = i as u16 * 100;
+        assert_eq!(result.unwrap(), [value, value]);
+    }
+    assert_eq!(gauge.peak.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_read_tasks_report_failures_in_place() {
+    let server = MockModbusTcpServer::start(7, Duration::from_millis(5)).await;
+
+    let mut tasks = Vec::new();
+    for (slave_id, addr, qty) in [(1, 0, 3), (2, 0, 0), (3, 10, 1)] {
+        let client = ModbusTcpClient::new(server.address, Duration::from_secs(1))
+            .await
+            .unwrap();
+        tasks.push(ReadTask {
+            slave_id,
+            addr,
+            qty,
+            client,
+        });
+    }
+
+    let results = read_tasks_concurrent(tasks, 0).await;
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].0, 1);
+    assert_eq!(results[0].1.as_ref().unwrap(), &[7, 7, 7]);
+    assert!(results[1].1.is_err(), "zero-quantity read must fail");
+    assert_eq!(results[2].1.as_ref().unwrap(), &[7]);
+}
@@ -0,0 +1,376 @@
+//! Spec-compliance table for `validate_pdu` and `validate_response_pdu`.
+//!
+//! Every supported function code has well-formed and malformed request and
+//! response byte sequences, plus the common error patterns seen in the field:
+//! truncated frames, wrong function codes, bad coil values and byte counts
+//! that disagree with the data.
+
+use voltage_modbus::pdu::{
+    validate_pdu, validate_response_pdu, ModbusPdu, PduBuilder, PduViolation,
+};
+
+use PduViolation::*;
+
+struct Case {
+    name: &'static str,
+    fc: u8,
+    bytes: &'static [u8],
+    expected: &'static [PduViolation],
+}
+
+const fn case(
+    name: &'static str,
+    fc: u8,
+    bytes: &'static [u8],
+    expected: &'static [PduViolation],
+) -> Case {
+    Case {
+        name,
+        fc,
+        bytes,
+        expected,
+    }
+}
+
+const REQUESTS: &[Case] = &[
+    // FC01 / FC02
+    case("fc01 ok", 0x01, &[0x01, 0x00, 0x13, 0x00, 0x13], &[]),
+    case(
+        "fc01 max quantity",
+        0x01,
+        &[0x01, 0x00, 0x00, 0x07, 0xD0],
+        &[],
+    ),
+    case(
+        "fc01 quantity zero",
+        0x01,
+        &[0x01, 0x00, 0x00, 0x00, 0x00],
+        &[QuantityOutOfRange {
+            quantity: 0,
+            max: 2000,
+        }],
+    ),
+    case(
+        "fc01 quantity too large",
+        0x01,
+        &[0x01, 0x00, 0x00, 0x07, 0xD1],
+        &[QuantityOutOfRange {
+            quantity: 2001,
+            max: 2000,
+        }],
+    ),
+    case("fc02 ok", 0x02, &[0x02, 0x00, 0xC4, 0x00, 0x16], &[]),
+    case(
+        "fc02 truncated",
+        0x02,
+        &[0x02, 0x00, 0xC4, 0x00],
+        &[InvalidLength {
+            expected: 5,
+            actual: 4,
+        }],
+    ),
+    // FC03 / FC04
+    case("fc03 ok", 0x03, &[0x03, 0x00, 0x6B, 0x00, 0x03], &[]),
+    case(
+        "fc03 trailing byte",
+        0x03,
+        &[0x03, 0x00, 0x6B, 0x00, 0x03, 0x00],
+        &[InvalidLength {
+            expected: 5,
+            actual: 6,
+        }],
+    ),
+    case(
+        "fc03 quantity too large",
+        0x03,
+        &[0x03, 0x00, 0x00, 0x00, 0x7E],
+        &[QuantityOutOfRange {
+            quantity: 126,
+            max: 125,
+        }],
+    ),
+    case("fc04 ok", 0x04, &[0x04, 0x00, 0x08, 0x00, 0x01], &[]),
+    case(
+        "fc04 sent as fc03",
+        0x04,
+        &[0x03, 0x00, 0x08, 0x00, 0x01],
+        &[FunctionCodeMismatch {
+            expected: 0x04,
+            actual: 0x03,
+        }],
+    ),
+    // FC05
+    case("fc05 on", 0x05, &[0x05, 0x00, 0xAC, 0xFF, 0x00], &[]),
+    case("fc05 off", 0x05, &[0x05, 0x00, 0xAC, 0x00, 0x00], &[]),
+    case(
+        "fc05 register value in coil position",
+        0x05,
+        &[0x05, 0x00, 0xAC, 0x12, 0x34],
+        &[InvalidCoilValue { value: 0x1234 }],
+    ),
+    case(
+        "fc05 byte-swapped on",
+        0x05,
+        &[0x05, 0x00, 0xAC, 0x00, 0xFF],
+        &[InvalidCoilValue { value: 0x00FF }],
+    ),
+    // FC06
+    case("fc06 ok", 0x06, &[0x06, 0x00, 0x01, 0x00, 0x03], &[]),
+    case(
+        "fc06 truncated",
+        0x06,
+        &[0x06, 0x00, 0x01],
+        &[InvalidLength {
+            expected: 5,
+            actual: 3,
+        }],
+    ),
+    // FC15
+    case(
+        "fc15 ok",
+        0x0F,
+        &[0x0F, 0x00, 0x13, 0x00, 0x0A, 0x02, 0xCD, 0x01],
+        &[],
+    ),
+    case(
+        "fc15 missing data byte",
+        0x0F,
+        &[0x0F, 0x00, 0x13, 0x00, 0x0A, 0x02, 0xCD],
+        &[ByteCountMismatch {
+            byte_count: 2,
+            data_len: 1,
+        }],
+    ),
+    case(
+        "fc15 byte count too small for quantity",
+        0x0F,
+        &[0x0F, 0x00, 0x13, 0x00, 0x0A, 0x01, 0xCD],
+        &[QuantityMismatch {
+            quantity: 10,
+            byte_count: 1,
+        }],
+    ),
+    case(
+        "fc15 no header",
+        0x0F,
+        &[0x0F, 0x00, 0x13, 0x00, 0x0A],
+        &[InvalidLength {
+            expected: 6,
+            actual: 5,
+        }],
+    ),
+    // FC16
+    case(
+        "fc16 ok",
+        0x10,
+        &[0x10, 0x00, 0x01, 0x00, 0x02, 0x04, 0x00, 0x0A, 0x01, 0x02],
+        &[],
+    ),
+    case(
+        "fc16 byte count counts registers",
+        0x10,
+        &[0x10, 0x00, 0x01, 0x00, 0x02, 0x02, 0x00, 0x0A, 0x01, 0x02],
+        &[
+            QuantityMismatch {
+                quantity: 2,
+                byte_count: 2,
+            },
+            ByteCountMismatch {
+                byte_count: 2,
+                data_len: 4,
+            },
+        ],
+    ),
+    case(
+        "fc16 zero registers",
+        0x10,
+        &[0x10, 0x00, 0x01, 0x00, 0x00, 0x00],
+        &[QuantityOutOfRange {
+            quantity: 0,
+            max: 123,
+        }],
+    ),
+    // Common error patterns
+    case("empty", 0x03, &[], &[Empty]),
+    case(
+        "unsupported expected function",
+        0x2B,
+        &[0x2B, 0x0E, 0x01, 0x00],
+        &[UnsupportedFunction { code: 0x2B }],
+    ),
+    case(
+        "exception is not a request",
+        0x03,
+        &[0x83, 0x02],
+        &[FunctionCodeMismatch {
+            expected: 0x03,
+            actual: 0x83,
+        }],
+    ),
+];
+
+const RESPONSES: &[Case] = &[
+    // FC01 / FC02
+    case("fc01 ok", 0x01, &[0x01, 0x03, 0xCD, 0x6B, 0x05], &[]),
+    case(
+        "fc01 missing data byte",
+        0x01,
+        &[0x01, 0x03, 0xCD, 0x6B],
+        &[ByteCountMismatch {
+            byte_count: 3,
+            data_len: 2,
+        }],
+    ),
+    case("fc02 ok", 0x02, &[0x02, 0x01, 0xAC], &[]),
+    case(
+        "fc02 no byte count",
+        0x02,
+        &[0x02],
+        &[InvalidLength {
+            expected: 2,
+            actual: 1,
+        }],
+    ),
+    // FC03 / FC04
+    case(
+        "fc03 ok",
+        0x03,
+        &[0x03, 0x06, 0x02, 0x2B, 0x00, 0x00, 0x00, 0x64],
+        &[],
+    ),
+    case(
+        "fc03 byte count is register count",
+        0x03,
+        &[0x03, 0x03, 0x02, 0x2B, 0x00, 0x00, 0x00, 0x64],
+        &[
+            OddByteCount { byte_count: 3 },
+            ByteCountMismatch {
+                byte_count: 3,
+                data_len: 6,
+            },
+        ],
+    ),
+    case("fc04 ok", 0x04, &[0x04, 0x02, 0x00, 0x0A], &[]),
+    case(
+        "fc04 half register",
+        0x04,
+        &[0x04, 0x01, 0x0A],
+        &[OddByteCount { byte_count: 1 }],
+    ),
+    // FC05 / FC06 echo the request
+    case("fc05 ok", 0x05, &[0x05, 0x00, 0xAC, 0xFF, 0x00], &[]),
+    case(
+        "fc05 bad echo",
+        0x05,
+        &[0x05, 0x00, 0xAC, 0x00, 0x01],
+        &[InvalidCoilValue { value: 0x0001 }],
+    ),
+    case("fc06 ok", 0x06, &[0x06, 0x00, 0x01, 0x00, 0x03], &[]),
+    // FC15 / FC16 echo address and quantity
+    case("fc15 ok", 0x0F, &[0x0F, 0x00, 0x13, 0x00, 0x0A], &[]),
+    case(
+        "fc16 with payload",
+        0x10,
+        &[0x10, 0x00, 0x01, 0x00, 0x02, 0x04],
+        &[InvalidLength {
+            expected: 5,
+            actual: 6,
+        }],
+    ),
+    case(
+        "fc16 zero quantity",
+        0x10,
+        &[0x10, 0x00, 0x01, 0x00, 0x00],
+        &[QuantityOutOfRange {
+            quantity: 0,
+            max: 123,
+        }],
+    ),
+    // Exceptions
+    case("fc03 exception", 0x03, &[0x83, 0x02], &[]),
+    case(
+        "fc03 exception without code",
+        0x03,
+        &[0x83],
+        &[InvalidLength {
+            expected: 2,
+            actual: 1,
+        }],
+    ),
+    case(
+        "exception for another function",
+        0x03,
+        &[0x84, 0x02],
+        &[FunctionCodeMismatch {
+            expected: 0x03,
+            actual: 0x84,
+        }],
+    ),
+    case("empty", 0x10, &[], &[Empty]),
+];
+
+fn run(cases: &[Case], validate: fn(&ModbusPdu, u8) -> Vec<PduViolation>) {
+    for case in cases {
+        let pdu = ModbusPdu::from_slice(case.bytes).unwrap();
+        assert_eq!(validate(&pdu, case.fc), case.expected, "{}", case.name);
+    }
+}
+
+#[test]
+fn request_table() {
+    run(REQUESTS, validate_pdu);
+}
+
+#[test]
+fn response_table() {
+    run(RESPONSES, validate_response_pdu);
+}
+
+#[test]
+fn builder_output_is_spec_compliant() {
+    let pdus = [
+        (0x01, PduBuilder::build_read_request(0x01, 0, 2000).unwrap()),
+        (0x02, PduBuilder::build_read_request(0x02, 10, 1).unwrap()),
+        (
+            0x03,
+            PduBuilder::build_read_request(0x03, 100, 125).unwrap(),
+        ),
+        (0x04, PduBuilder::build_read_request(0x04, 0, 3).unwrap()),
+        (0x05, PduBuilder::build_write_single_coil(7, false).unwrap()),
+        (
+            0x06,
+            PduBuilder::build_write_single_register(7, 0x1234).unwrap(),
+        ),
+        (
+            0x0F,
+            PduBuilder::build_write_multiple_coils(0, &[true; 9]).unwrap(),
+        ),
+        (
+            0x10,
+            PduBuilder::build_write_multiple_registers(0, &[1, 2, 3]).unwrap(),
+        ),
+    ];
+    for (fc, pdu) in &pdus {
+        assert_eq!(validate_pdu(pdu, *fc), [], "FC{:02X}", fc);
+    }
+}
+
+#[test]
+fn builder_can_still_produce_malformed_pdus() {
+    let pdu = PduBuilder::new()
+        .function_code(0x05)
+        .unwrap()
+        .address(1)
+        .unwrap()
+        .quantity(0x1234)
+        .unwrap()
+        .build();
+    assert_eq!(
+        validate_pdu(&pdu, 0x05),
+        [InvalidCoilValue { value: 0x1234 }]
+    );
+    assert_eq!(
+        InvalidCoilValue { value: 0x1234 }.to_string(),
+        "coil value 0x1234 is neither 0xFF00 nor 0x0000"
+    );
+}